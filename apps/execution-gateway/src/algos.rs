@@ -0,0 +1,598 @@
+use chrono::{DateTime, Utc};
+use rust_common::{ExecutionResult, OrderDecision, OrderStatus, TradingError};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{Clock, ExecutionGateway, OrderExecutionStatus};
+
+/// Quantity below which a parent order is considered completely filled
+const FILL_EPSILON: f64 = 1e-9;
+
+/// Default interval between status polls of a resting iceberg tranche
+const DEFAULT_TRANCHE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// One child order submitted by an execution algorithm
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgoSlice {
+    pub scheduled_at: DateTime<Utc>,
+    pub submitted_at: DateTime<Utc>,
+    pub quantity: f64,
+    pub result: Option<ExecutionResult>,
+    pub error: Option<String>,
+}
+
+/// Outcome of an algorithmic execution: the aggregated parent result and every child slice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgoExecutionReport {
+    pub parent: ExecutionResult,
+    pub slices: Vec<AlgoSlice>,
+}
+
+/// Running totals of the child fills of a parent order
+#[derive(Debug, Default)]
+struct FillTally {
+    filled_quantity: f64,
+    commission: f64,
+    notional: f64,
+}
+
+impl FillTally {
+    fn add(&mut self, child: &ExecutionResult) {
+        self.filled_quantity += child.filled_quantity;
+        self.commission += child.commission;
+        self.notional += child.filled_quantity * child.average_price.unwrap_or(0.0);
+    }
+
+    /// Parent result: filled once the total is reached, otherwise cancelled with `reason`
+    fn parent_result(
+        &self,
+        order_decision: &OrderDecision,
+        parent_order_id: String,
+        now: DateTime<Utc>,
+        reason: &str,
+    ) -> ExecutionResult {
+        let total_quantity = order_decision.risk_adjusted_quantity;
+        let mut parent = ExecutionResult::new(order_decision.decision_id.clone(), parent_order_id);
+        parent.filled_quantity = self.filled_quantity;
+        parent.commission = self.commission;
+        parent.average_price = (self.filled_quantity > 0.0).then(|| self.notional / self.filled_quantity);
+        if total_quantity - self.filled_quantity <= FILL_EPSILON {
+            parent.status = OrderStatus::Filled;
+            parent.filled_at = Some(now);
+        } else {
+            parent.status = OrderStatus::Cancelled;
+            parent.error_message = Some(format!(
+                "{} with {} of {} filled",
+                reason, self.filled_quantity, total_quantity
+            ));
+        }
+        parent
+    }
+}
+
+/// Child decision for `quantity` of the parent, taken at `at`
+fn child_decision(order_decision: &OrderDecision, quantity: f64, at: DateTime<Utc>) -> OrderDecision {
+    let mut child = order_decision.clone();
+    child.decision_id = Uuid::new_v4().to_string();
+    child.timestamp = at;
+    child.risk_adjusted_quantity = quantity;
+    child
+}
+
+/// Reject parents that have nothing to execute
+fn validate_parent_quantity(algo: &str, order_decision: &OrderDecision) -> Result<(), TradingError> {
+    let total_quantity = order_decision.risk_adjusted_quantity;
+    if total_quantity <= 0.0 {
+        return Err(TradingError::ExecutionError {
+            message: format!("{} quantity must be positive, got {}", algo, total_quantity),
+        });
+    }
+    Ok(())
+}
+
+/// Time-weighted average price execution.
+///
+/// The parent quantity is split into `slice_count` child orders submitted at even
+/// intervals across `duration`. A slice left resting keeps working and its size is
+/// not sliced again; whatever a slice ends without filling is spread over the slices
+/// still to come. Execution stops as soon as the parent is filled, and any slice still
+/// working when the schedule ends is cancelled.
+pub struct TwapExecutor {
+    gateway: Arc<ExecutionGateway>,
+    duration: Duration,
+    slice_count: u32,
+    clock: Clock,
+}
+
+impl TwapExecutor {
+    pub fn new(gateway: Arc<ExecutionGateway>, duration: Duration, slice_count: u32) -> Self {
+        Self {
+            gateway,
+            duration,
+            slice_count,
+            clock: Arc::new(Utc::now),
+        }
+    }
+
+    /// Replace the time source used to schedule slices
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Execute `order_decision` as a parent order, tracked under its decision ID
+    pub async fn execute(&self, order_decision: OrderDecision) -> Result<AlgoExecutionReport, TradingError> {
+        if self.slice_count == 0 {
+            return Err(TradingError::ExecutionError {
+                message: "TWAP needs at least one slice".to_string(),
+            });
+        }
+        validate_parent_quantity("TWAP", &order_decision)?;
+        let interval = chrono::Duration::from_std(self.duration / self.slice_count)
+            .map_err(|e| TradingError::ExecutionError {
+                message: format!("Invalid TWAP duration: {}", e),
+            })?;
+
+        let parent_order_id = format!("twap-{}", order_decision.decision_id);
+        let parent_client_id = self.gateway.open_parent_order(&order_decision, &parent_order_id).await?;
+
+        let total_quantity = order_decision.risk_adjusted_quantity;
+        let start = (self.clock)();
+        let mut slices = Vec::with_capacity(self.slice_count as usize);
+        let mut tally = FillTally::default();
+        let mut working: Vec<usize> = Vec::new(); // indexes of slices still resting on the exchange
+        let mut reason = "TWAP window ended";
+
+        for index in 0..self.slice_count {
+            if total_quantity - tally.filled_quantity <= FILL_EPSILON {
+                break;
+            }
+
+            let scheduled_at = start + interval * index as i32;
+            if let Ok(wait) = (scheduled_at - (self.clock)()).to_std() {
+                tokio::time::sleep(wait).await;
+            }
+            if self.gateway.is_parent_cancelled(&parent_order_id).await {
+                reason = "TWAP cancelled";
+                break;
+            }
+
+            let mut still_working = Vec::with_capacity(working.len());
+            for slice_index in working.drain(..) {
+                if !self.settle_slice(&parent_client_id, &mut slices[slice_index], &mut tally, order_decision.entry_price, false).await {
+                    still_working.push(slice_index);
+                }
+            }
+            working = still_working;
+
+            // Quantity resting on the exchange is already being worked
+            let working_quantity: f64 = working.iter()
+                .map(|&slice_index| &slices[slice_index])
+                .filter_map(|slice: &AlgoSlice| slice.result.as_ref().map(|child| slice.quantity - child.filled_quantity))
+                .sum();
+            let remaining = total_quantity - tally.filled_quantity - working_quantity;
+            if remaining <= FILL_EPSILON {
+                continue;
+            }
+
+            // Each child is a fresh decision taken at its slice time
+            let quantity = remaining / f64::from(self.slice_count - index);
+            let submitted_at = (self.clock)();
+            let mut slice = AlgoSlice {
+                scheduled_at,
+                submitted_at,
+                quantity,
+                result: None,
+                error: None,
+            };
+            match self.gateway.place_order(child_decision(&order_decision, quantity, submitted_at)).await {
+                Ok(child_result) => {
+                    self.gateway.record_child_fill(&parent_client_id, &child_result).await;
+                    tally.add(&child_result);
+                    if !OrderExecutionStatus::from(child_result.status).is_terminal() {
+                        working.push(slices.len());
+                    }
+                    slice.result = Some(child_result);
+                }
+                Err(e) => {
+                    warn!("TWAP slice {} of {} failed: {}", index + 1, parent_order_id, e);
+                    slice.error = Some(e.to_string());
+                }
+            }
+            slices.push(slice);
+        }
+
+        // Nothing may keep working for a parent that has finished
+        for slice_index in working {
+            self.settle_slice(&parent_client_id, &mut slices[slice_index], &mut tally, order_decision.entry_price, true).await;
+        }
+
+        let parent = tally.parent_result(&order_decision, parent_order_id, (self.clock)(), reason);
+        self.gateway.close_parent_order(&parent_client_id, parent.clone()).await;
+
+        info!(
+            "TWAP {} finished: {} of {} filled over {} slices",
+            parent.order_id,
+            tally.filled_quantity,
+            total_quantity,
+            slices.len()
+        );
+        Ok(AlgoExecutionReport { parent, slices })
+    }
+
+    /// Fold in what a resting slice has filled since it was placed, cancelling its remainder
+    /// first when `cancel` is set. Returns whether the slice has stopped working.
+    async fn settle_slice(
+        &self,
+        parent_client_id: &Uuid,
+        slice: &mut AlgoSlice,
+        tally: &mut FillTally,
+        limit_price: f64,
+        cancel: bool,
+    ) -> bool {
+        let Some(child) = slice.result.as_mut() else {
+            return true;
+        };
+
+        let status = if cancel {
+            match self.gateway.cancel_tracked_order(&child.order_id, "TWAP window ended").await {
+                Ok(()) => Ok(OrderExecutionStatus::Cancelled),
+                // The slice may have filled before the cancel reached the exchange
+                Err(e) => {
+                    warn!("Failed to cancel TWAP slice {}: {}", child.order_id, e);
+                    self.gateway.get_order_status(&child.order_id).await
+                }
+            }
+        } else {
+            self.gateway.get_order_status(&child.order_id).await
+        };
+        let filled_quantity = match status {
+            Ok(OrderExecutionStatus::Filled) => slice.quantity,
+            Ok(status) if status.is_terminal() => self.gateway.filled_quantity(&child.order_id).await.max(child.filled_quantity),
+            Ok(_) => return false,
+            Err(e) => {
+                warn!("Failed to poll TWAP slice {}: {}", child.order_id, e);
+                return false;
+            }
+        };
+
+        let newly_filled = filled_quantity - child.filled_quantity;
+        if newly_filled > FILL_EPSILON {
+            let mut fill = child.clone();
+            fill.filled_quantity = newly_filled;
+            fill.commission = 0.0;
+            fill.average_price = child.average_price.or(Some(limit_price));
+            fill.filled_at = Some(Utc::now());
+            self.gateway.record_child_fill(parent_client_id, &fill).await;
+            tally.add(&fill);
+
+            child.filled_quantity = filled_quantity;
+            child.average_price = fill.average_price;
+            child.filled_at = fill.filled_at;
+        }
+        child.status = if slice.quantity - filled_quantity <= FILL_EPSILON {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::Cancelled
+        };
+        true
+    }
+}
+
+/// Iceberg execution that only ever shows `visible_quantity` on the exchange.
+///
+/// Each tranche is replenished once the previous one has filled. Cancelling the
+/// parent through the gateway pulls the live tranche and stops replenishment.
+pub struct IcebergExecutor {
+    gateway: Arc<ExecutionGateway>,
+    visible_quantity: f64,
+    poll_interval: Duration,
+}
+
+impl IcebergExecutor {
+    pub fn new(gateway: Arc<ExecutionGateway>, visible_quantity: f64) -> Self {
+        Self {
+            gateway,
+            visible_quantity,
+            poll_interval: DEFAULT_TRANCHE_POLL_INTERVAL,
+        }
+    }
+
+    /// Interval between status polls while a tranche rests on the exchange
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Execute `order_decision` as a parent order, tracked under its decision ID
+    pub async fn execute(&self, order_decision: OrderDecision) -> Result<AlgoExecutionReport, TradingError> {
+        if self.visible_quantity <= 0.0 {
+            return Err(TradingError::ExecutionError {
+                message: format!("Iceberg visible quantity must be positive, got {}", self.visible_quantity),
+            });
+        }
+        validate_parent_quantity("Iceberg", &order_decision)?;
+
+        let parent_order_id = format!("iceberg-{}", order_decision.decision_id);
+        let parent_client_id = self.gateway.open_parent_order(&order_decision, &parent_order_id).await?;
+
+        let total_quantity = order_decision.risk_adjusted_quantity;
+        let mut slices = Vec::new();
+        let mut tally = FillTally::default();
+        let mut reason = "Iceberg stopped";
+
+        while total_quantity - tally.filled_quantity > FILL_EPSILON {
+            if self.gateway.is_parent_cancelled(&parent_order_id).await {
+                reason = "Iceberg cancelled";
+                break;
+            }
+
+            let quantity = self.visible_quantity.min(total_quantity - tally.filled_quantity);
+            let submitted_at = Utc::now();
+            let mut slice = AlgoSlice {
+                scheduled_at: submitted_at,
+                submitted_at,
+                quantity,
+                result: None,
+                error: None,
+            };
+
+            let child_result = match self.gateway.place_order(child_decision(&order_decision, quantity, submitted_at)).await {
+                Ok(child_result) => self.await_tranche(&parent_order_id, child_result, quantity, order_decision.entry_price).await,
+                Err(e) => {
+                    warn!("Iceberg tranche of {} failed: {}", parent_order_id, e);
+                    slice.error = Some(e.to_string());
+                    slices.push(slice);
+                    break;
+                }
+            };
+
+            self.gateway.record_child_fill(&parent_client_id, &child_result).await;
+            tally.add(&child_result);
+            let tranche_filled = child_result.status == OrderStatus::Filled;
+            slice.result = Some(child_result);
+            slices.push(slice);
+
+            // A tranche that ends unfilled means the order was pulled; don't show more
+            if !tranche_filled {
+                if self.gateway.is_parent_cancelled(&parent_order_id).await {
+                    reason = "Iceberg cancelled";
+                }
+                break;
+            }
+        }
+
+        let parent = tally.parent_result(&order_decision, parent_order_id, Utc::now(), reason);
+        self.gateway.close_parent_order(&parent_client_id, parent.clone()).await;
+
+        info!(
+            "Iceberg {} finished: {} of {} filled over {} tranches",
+            parent.order_id,
+            tally.filled_quantity,
+            total_quantity,
+            slices.len()
+        );
+        Ok(AlgoExecutionReport { parent, slices })
+    }
+
+    /// Wait for a resting tranche to fill, be cancelled, or for the parent to be cancelled
+    async fn await_tranche(
+        &self,
+        parent_order_id: &str,
+        mut tranche: ExecutionResult,
+        quantity: f64,
+        limit_price: f64,
+    ) -> ExecutionResult {
+        if OrderExecutionStatus::from(tranche.status).is_terminal() {
+            return tranche;
+        }
+
+        self.gateway.set_live_child(parent_order_id, Some(tranche.order_id.clone())).await;
+        loop {
+            tokio::time::sleep(self.poll_interval).await;
+
+            if self.gateway.is_parent_cancelled(parent_order_id).await {
+                // Cancellation may have landed before the tranche was registered as live
+                let still_open = self.gateway.get_order_update(&tranche.order_id).await
+                    .is_some_and(|order_update| !order_update.status.is_terminal());
+                if still_open {
+                    if let Err(e) = self.gateway.cancel_tracked_order(&tranche.order_id, "Parent order cancelled").await {
+                        warn!("Failed to cancel iceberg tranche {}: {}", tranche.order_id, e);
+                    }
+                }
+                tranche.status = OrderStatus::Cancelled;
+                break;
+            }
+
+            match self.gateway.get_order_status(&tranche.order_id).await {
+                Ok(OrderExecutionStatus::Filled) => {
+                    tranche.status = OrderStatus::Filled;
+                    tranche.filled_quantity = quantity;
+                    tranche.average_price = tranche.average_price.or(Some(limit_price));
+                    tranche.filled_at = Some(Utc::now());
+                    break;
+                }
+                Ok(status) if status.is_terminal() => {
+                    tranche.status = OrderStatus::Cancelled;
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Failed to poll iceberg tranche {}: {}", tranche.order_id, e),
+            }
+        }
+        self.gateway.set_live_child(parent_order_id, None).await;
+
+        tranche
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FillModel, GatewayConfig, MockExchangeAdapter, OrderExecutionStatus};
+    use rust_common::{Direction, OrderType, TimeInForce};
+
+    fn create_twap_decision() -> OrderDecision {
+        let mut decision = OrderDecision::new("twap_signal".to_string(), "BTCUSD".to_string());
+        decision.direction = Direction::Long;
+        decision.order_type = OrderType::Limit;
+        decision.risk_adjusted_quantity = 1.0;
+        decision.entry_price = 100.0;
+        decision.stop_loss = 95.0;
+        decision.take_profit = Some(110.0);
+        decision.portfolio_value = 10000.0;
+        decision.available_margin = 5000.0;
+        decision
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_twap_slices_on_schedule_and_redistributes_unfilled_slice() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        // The second slice fills nothing
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_sequence(vec![(1.0, 100.0), (0.0, 100.0), (1.0, 101.0), (1.0, 102.0)]);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // Simulated wall clock driven by tokio's paused time
+        let start = Utc::now();
+        let base = tokio::time::Instant::now();
+        let clock: Clock = Arc::new(move || start + chrono::Duration::from_std(base.elapsed()).unwrap());
+
+        // An IOC slice that fills nothing ends there, leaving its quantity to the later slices
+        let mut decision = create_twap_decision();
+        decision.time_in_force = TimeInForce::Ioc;
+        let parent_client_id = Uuid::parse_str(&decision.decision_id).unwrap();
+        let executor = TwapExecutor::new(gateway.clone(), Duration::from_secs(240), 4).with_clock(clock);
+        let report = executor.execute(decision).await.unwrap();
+
+        assert_eq!(report.slices.len(), 4);
+        for (index, slice) in report.slices.iter().enumerate() {
+            assert_eq!(slice.scheduled_at, start + chrono::Duration::seconds(60 * index as i64));
+            assert!(slice.submitted_at >= slice.scheduled_at);
+            assert!(slice.submitted_at - slice.scheduled_at < chrono::Duration::seconds(1));
+        }
+
+        // 0.25, then nothing, then the remaining 0.75 over the last two slices
+        let quantities: Vec<f64> = report.slices.iter().map(|slice| slice.quantity).collect();
+        for (quantity, expected) in quantities.iter().zip([0.25, 0.25, 0.375, 0.375]) {
+            assert!((quantity - expected).abs() < 1e-9);
+        }
+        assert_eq!(placed_orders.lock().unwrap().len(), 4);
+
+        assert_eq!(report.parent.status, OrderStatus::Filled);
+        assert!((report.parent.filled_quantity - 1.0).abs() < 1e-9);
+        let expected_average = 0.25 * 100.0 + 0.375 * 101.0 + 0.375 * 102.0;
+        assert!((report.parent.average_price.unwrap() - expected_average).abs() < 1e-9);
+
+        let parent = gateway.get_order_update(&report.parent.order_id).await.unwrap();
+        assert_eq!(parent.client_id, parent_client_id.to_string());
+        assert!(matches!(parent.status, OrderExecutionStatus::Filled));
+        assert_eq!(parent.fill_count, 3);
+        assert!((parent.total_filled - 1.0).abs() < 1e-9);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_twap_stops_once_parent_is_filled() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let executor = TwapExecutor::new(gateway, Duration::from_secs(60), 1);
+        let report = executor.execute(create_twap_decision()).await.unwrap();
+
+        assert_eq!(report.slices.len(), 1);
+        assert_eq!(report.parent.status, OrderStatus::Filled);
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_twap_does_not_reslice_resting_quantity() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        // Every slice rests on the book without filling
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_model(FillModel::Probabilistic { fill_prob: 0.0 });
+        let placed_orders = mock_adapter.placed_orders();
+        let resting_orders = mock_adapter.resting_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let report = TwapExecutor::new(gateway.clone(), Duration::from_secs(240), 4)
+            .execute(create_twap_decision())
+            .await
+            .unwrap();
+
+        let submitted: f64 = placed_orders.lock().unwrap().iter().map(|order| order.size).sum();
+        assert!(submitted <= 1.0 + 1e-9, "submitted {} for a parent of 1.0", submitted);
+
+        // Nothing is left working once the schedule ends
+        let resting_orders = resting_orders.lock().unwrap();
+        assert_eq!(resting_orders.len(), placed_orders.lock().unwrap().len());
+        assert!(resting_orders.values().all(|status| *status == OrderStatus::Cancelled));
+        assert_eq!(report.parent.status, OrderStatus::Cancelled);
+        assert_eq!(report.parent.filled_quantity, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_iceberg_replenishes_visible_tranches() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let mut decision = create_twap_decision();
+        decision.risk_adjusted_quantity = 10.0;
+        let report = IcebergExecutor::new(gateway.clone(), 2.0).execute(decision).await.unwrap();
+
+        assert_eq!(report.slices.len(), 5);
+        assert!(placed_orders.lock().unwrap().iter().all(|order| (order.size - 2.0).abs() < 1e-9));
+        assert_eq!(report.parent.status, OrderStatus::Filled);
+        assert!((report.parent.filled_quantity - 10.0).abs() < 1e-9);
+
+        let parent = gateway.get_order_update(&report.parent.order_id).await.unwrap();
+        assert!(matches!(parent.status, OrderExecutionStatus::Filled));
+        assert_eq!(parent.fill_count, 5);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancelling_iceberg_pulls_live_tranche() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        // The second tranche rests on the book
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_sequence(vec![(1.0, 100.0), (0.0, 100.0)]);
+        let placed_orders = mock_adapter.placed_orders();
+        let resting_orders = mock_adapter.resting_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let mut decision = create_twap_decision();
+        decision.risk_adjusted_quantity = 10.0;
+        let parent_order_id = format!("iceberg-{}", decision.decision_id);
+        let executor = IcebergExecutor::new(gateway.clone(), 2.0);
+        let handle = tokio::spawn(async move { executor.execute(decision).await });
+
+        while placed_orders.lock().unwrap().len() < 2 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let live_tranche_id = placed_orders.lock().unwrap()[1].id.to_string();
+        resting_orders.lock().unwrap().insert(live_tranche_id.clone(), OrderStatus::Pending);
+
+        gateway.cancel_order(&parent_order_id).await.unwrap();
+        let report = handle.await.unwrap().unwrap();
+
+        assert_eq!(resting_orders.lock().unwrap()[&live_tranche_id], OrderStatus::Cancelled);
+        assert_eq!(placed_orders.lock().unwrap().len(), 2);
+        assert_eq!(report.slices.len(), 2);
+        assert_eq!(report.parent.status, OrderStatus::Cancelled);
+        assert!((report.parent.filled_quantity - 2.0).abs() < 1e-9);
+        assert!(report.parent.error_message.unwrap().starts_with("Iceberg cancelled"));
+
+        let tranche = gateway.get_order_update(&live_tranche_id).await.unwrap();
+        assert!(matches!(tranche.status, OrderExecutionStatus::Cancelled));
+    }
+}
@@ -0,0 +1,184 @@
+use chrono::{DateTime, Utc};
+use rust_common::{OrderSide, Timeframe};
+
+use crate::{PartialFill, PositionTracker};
+
+/// Symbol the fills of a single-instrument curve are tracked under
+const CURVE_SYMBOL: &str = "";
+
+/// Account equity at each mark price from the fills of a single instrument.
+///
+/// Equity is `initial_equity` plus realized and unrealized PnL, net of commission. Fills
+/// are applied at or before the mark that follows them; fills after the last mark are
+/// not valued.
+pub fn equity_curve(
+    initial_equity: f64,
+    fills: &[(OrderSide, PartialFill)],
+    mark_prices: &[(DateTime<Utc>, f64)],
+) -> Vec<(DateTime<Utc>, f64)> {
+    let mut fills: Vec<&(OrderSide, PartialFill)> = fills.iter().collect();
+    fills.sort_by_key(|(_, fill)| fill.timestamp);
+    let mut mark_prices = mark_prices.to_vec();
+    mark_prices.sort_by_key(|(timestamp, _)| *timestamp);
+
+    let mut tracker = PositionTracker::new();
+    let mut commission = 0.0;
+    let mut pending = fills.into_iter().peekable();
+    let mut curve = Vec::with_capacity(mark_prices.len());
+
+    for (timestamp, mark_price) in mark_prices {
+        while let Some((side, fill)) = pending.next_if(|(_, fill)| fill.timestamp <= timestamp) {
+            tracker.apply_fill(CURVE_SYMBOL, side, fill);
+            commission += fill.commission;
+        }
+        tracker.update_mark_price(CURVE_SYMBOL, mark_price);
+
+        let pnl = tracker.get_position(CURVE_SYMBOL)
+            .map_or(0.0, |position| position.realized_pnl + position.unrealized_pnl.unwrap_or(0.0));
+        curve.push((timestamp, initial_equity + pnl - commission));
+    }
+    curve
+}
+
+/// Simple per-period returns between consecutive curve points, skipping non-positive bases
+pub fn returns(curve: &[(DateTime<Utc>, f64)]) -> Vec<f64> {
+    curve
+        .windows(2)
+        .filter(|pair| pair[0].1 > 0.0)
+        .map(|pair| pair[1].1 / pair[0].1 - 1.0)
+        .collect()
+}
+
+/// Largest fractional peak-to-trough decline of the curve; 0.0 for an empty or rising curve
+pub fn max_drawdown(curve: &[(DateTime<Utc>, f64)]) -> f64 {
+    let mut peak = f64::NEG_INFINITY;
+    let mut max_drawdown: f64 = 0.0;
+    for &(_, equity) in curve {
+        peak = peak.max(equity);
+        if peak > 0.0 {
+            max_drawdown = max_drawdown.max((peak - equity) / peak);
+        }
+    }
+    max_drawdown
+}
+
+/// Annualized Sharpe ratio of per-bar `returns` against an annual `risk_free` rate.
+///
+/// 0.0 with fewer than two returns or when they don't vary.
+pub fn sharpe_ratio(returns: &[f64], risk_free: f64, timeframe: Timeframe) -> f64 {
+    if returns.len() < 2 {
+        return 0.0;
+    }
+    let periods_per_year = timeframe.periods_per_year();
+    let mean = mean(returns);
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+    let std_dev = variance.sqrt();
+    if std_dev < f64::EPSILON {
+        return 0.0;
+    }
+    (mean - risk_free / periods_per_year) / std_dev * periods_per_year.sqrt()
+}
+
+/// Annualized Sortino ratio: like Sharpe, but only returns below the risk-free rate count as risk.
+///
+/// 0.0 with fewer than two returns or no downside.
+pub fn sortino_ratio(returns: &[f64], risk_free: f64, timeframe: Timeframe) -> f64 {
+    if returns.len() < 2 {
+        return 0.0;
+    }
+    let periods_per_year = timeframe.periods_per_year();
+    let period_risk_free = risk_free / periods_per_year;
+    let downside = returns.iter().map(|r| (r - period_risk_free).min(0.0).powi(2)).sum::<f64>() / returns.len() as f64;
+    let downside_deviation = downside.sqrt();
+    if downside_deviation < f64::EPSILON {
+        return 0.0;
+    }
+    (mean(returns) - period_risk_free) / downside_deviation * periods_per_year.sqrt()
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn day(n: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(n)
+    }
+
+    fn curve(values: &[f64]) -> Vec<(DateTime<Utc>, f64)> {
+        values.iter().enumerate().map(|(n, &value)| (day(n as i64), value)).collect()
+    }
+
+    fn fill(quantity: f64, price: f64, timestamp: DateTime<Utc>) -> PartialFill {
+        PartialFill {
+            fill_id: "fill".to_string(),
+            quantity,
+            price,
+            timestamp,
+            commission: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_equity_curve_marks_fills_net_of_commission() {
+        let fills = vec![
+            (OrderSide::Sell, fill(1.0, 120.0, day(2))),
+            (OrderSide::Buy, fill(1.0, 100.0, day(0))),
+        ];
+        let marks = vec![(day(3), 115.0), (day(1), 110.0)];
+
+        let curve = equity_curve(1000.0, &fills, &marks);
+        // Open long marked at 110, then closed at 120 with both commissions paid
+        assert_eq!(curve, vec![(day(1), 1009.0), (day(3), 1018.0)]);
+
+        assert!(equity_curve(1000.0, &fills, &[]).is_empty());
+        assert_eq!(equity_curve(1000.0, &[], &marks), vec![(day(1), 1000.0), (day(3), 1000.0)]);
+    }
+
+    #[test]
+    fn test_max_drawdown_is_largest_peak_to_trough() {
+        // 120 -> 90 is 25%; the later 130 -> 104 is only 20%
+        assert!((max_drawdown(&curve(&[100.0, 120.0, 90.0, 110.0, 130.0, 104.0])) - 0.25).abs() < 1e-12);
+        assert_eq!(max_drawdown(&curve(&[100.0, 110.0, 120.0])), 0.0);
+        assert_eq!(max_drawdown(&[]), 0.0);
+
+        let returns = returns(&curve(&[100.0, 110.0, 99.0]));
+        assert!((returns[0] - 0.1).abs() < 1e-12);
+        assert!((returns[1] - -0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_sharpe_and_sortino_on_known_returns() {
+        let returns = [0.01, -0.01, 0.02, 0.0];
+
+        // Mean 0.005, sample variance 5e-4 / 3
+        let expected_sharpe = 0.005 / (5e-4_f64 / 3.0).sqrt() * 365f64.sqrt();
+        assert!((sharpe_ratio(&returns, 0.0, Timeframe::D1) - expected_sharpe).abs() < 1e-9);
+        assert!((sharpe_ratio(&returns, 0.0, Timeframe::D1) - 7.3993).abs() < 1e-4);
+
+        // Downside deviation sqrt(0.01^2 / 4) = 0.005 equals the mean
+        assert!((sortino_ratio(&returns, 0.0, Timeframe::D1) - 365f64.sqrt()).abs() < 1e-9);
+
+        // An annual risk-free rate is charged per bar: 3.65% a year is 0.0001 a day
+        let expected_excess = 0.0049 / (5e-4_f64 / 3.0).sqrt() * 365f64.sqrt();
+        assert!((sharpe_ratio(&returns, 0.0365, Timeframe::D1) - expected_excess).abs() < 1e-9);
+
+        // Hourly bars annualize over 8760 periods
+        let hourly = sharpe_ratio(&returns, 0.0, Timeframe::H1);
+        assert!((hourly / sharpe_ratio(&returns, 0.0, Timeframe::D1) - 24f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ratios_on_degenerate_input() {
+        assert_eq!(sharpe_ratio(&[], 0.0, Timeframe::D1), 0.0);
+        assert_eq!(sharpe_ratio(&[0.01], 0.0, Timeframe::D1), 0.0);
+        assert_eq!(sharpe_ratio(&[0.01, 0.01, 0.01], 0.0, Timeframe::D1), 0.0);
+        assert_eq!(sortino_ratio(&[], 0.0, Timeframe::D1), 0.0);
+        assert_eq!(sortino_ratio(&[0.01, 0.02], 0.0, Timeframe::D1), 0.0);
+        assert!(returns(&[]).is_empty());
+    }
+}
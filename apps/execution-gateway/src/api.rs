@@ -1,18 +1,26 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    response::Json,
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        ConnectInfo, Path, Query, Request, State,
+    },
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Json, Response},
     routing::{get, post, delete},
     Router,
 };
 use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 use tower::ServiceBuilder;
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
 use tracing::{info, error};
 
-use crate::{ExecutionGateway, OrderExecutionStatus};
-use rust_common::{OrderDecision, ExecutionResult, TradingError};
+use crate::{
+    ApiKeyAuth, CancelAllSummary, DailyLossStats, ExchangeHealth, ExecutionGateway, LatencyStats, RateLimiter, OcoExecutionResult, OrderDetail, OrderExecutionStatus, OrderLifecycle, OrderLifecycleState, OrderStatistics,
+    OrderUpdate, RejectionFeedback, RiskLimits, SessionStats, TrackedPosition, evaluate_risk_rules, reject_reason,
+};
+use rust_common::{OrderDecision, ExecutionResult, RejectReason, TradingError};
 
 /// API request/response types
 #[derive(Debug, Serialize, Deserialize)]
@@ -25,6 +33,25 @@ pub struct PlaceOrderResponse {
     pub execution_result: ExecutionResult,
 }
 
+/// Outcome of one order in a batch, carrying the HTTP status it would have had on its own
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchOrderResult {
+    pub index: usize,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_result: Option<ExecutionResult>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+}
+
+/// Multi-status response for a batch placement, results in request order
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchOrderResponse {
+    pub results: Vec<BatchOrderResult>,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OrderStatusResponse {
     pub order_id: String,
@@ -37,10 +64,70 @@ pub struct CancelOrderResponse {
     pub cancelled: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AmendOrderRequest {
+    pub new_price: Option<f64>,
+    pub new_quantity: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CancelAllParams {
+    pub symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClosePositionParams {
+    pub exchange: Option<String>,
+}
+
+/// Default page size for order listings
+const DEFAULT_ORDER_PAGE_LIMIT: usize = 100;
+/// Largest page size an order listing may request
+const MAX_ORDER_PAGE_LIMIT: usize = 1000;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListOrdersParams {
+    pub state: Option<OrderLifecycleState>,
+    pub symbol: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListOrdersResponse {
+    pub orders: Vec<OrderLifecycle>,
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HealthResponse {
+    /// `healthy`, `degraded` when some exchange is unavailable, or `unhealthy` when none is
     pub status: String,
     pub active_orders: usize,
+    pub exchanges: Vec<ExchangeHealth>,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatsResponse {
+    pub decision_latency: LatencyStats,
+    pub session: SessionStats,
+    pub orders: OrderStatistics,
+    pub daily_loss: DailyLossStats,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TradingHaltResponse {
+    pub trading_halted: bool,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PositionsResponse {
+    pub positions: Vec<TrackedPosition>,
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
@@ -48,6 +135,18 @@ pub struct HealthResponse {
 pub struct ErrorResponse {
     pub error: String,
     pub code: String,
+    /// Why a risk gate rejected the decision, so the signal layer can correct it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rejection: Option<RejectionFeedback>,
+    /// How long the client should wait before retrying a transient failure
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after_ms: Option<u64>,
+    /// Why the order was refused, when it was
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reject_reason: Option<RejectReason>,
+    /// The request field that failed validation, when one did
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
 }
 
 /// Application state
@@ -55,12 +154,39 @@ pub type AppState = Arc<ExecutionGateway>;
 
 /// Create the API router
 pub fn create_router(gateway: Arc<ExecutionGateway>) -> Router {
-    Router::new()
-        .route("/health", get(health_check))
-        .route("/v1/orders", post(place_order))
+    let mut router = Router::new()
+        .route("/metrics", get(get_metrics))
+        .route("/v1/stats", get(get_stats))
+        .route("/v1/orders", post(place_order).get(list_orders))
+        .route("/v1/orders/batch", post(place_orders_batch))
+        .route("/v1/orders/oco", post(place_oco_order))
+        .route("/v1/orders/cancel-all", post(cancel_all_orders))
         .route("/v1/orders/:order_id", get(get_order_status))
-        .route("/v1/orders/:order_id", delete(cancel_order))
+        .route("/v1/orders/:order_id", delete(cancel_order).patch(amend_order))
         .route("/v1/orders/:order_id/status", get(get_order_status))
+        .route("/v1/orders/:order_id/detail", get(get_order_detail))
+        .route("/v1/orders/:order_id/stream", get(order_stream_ws))
+        .route("/v1/positions", get(get_positions))
+        .route("/v1/positions/:symbol/close", post(close_position))
+        .route("/v1/halt", post(halt_trading))
+        .route("/v1/risk-limits", get(get_risk_limits).post(update_risk_limits))
+        .route("/v1/resume", post(resume_trading))
+        .route("/v1/ws/orders", get(order_updates_ws));
+
+    // Authenticate and rate limit every route registered so far; health checks are
+    // added after and bypass both. The rate limit is the outer layer, so it also
+    // throttles clients guessing keys.
+    if !gateway.config().api_keys.is_empty() {
+        let auth = Arc::new(ApiKeyAuth::new(&gateway.config().api_keys));
+        router = router.route_layer(middleware::from_fn_with_state(auth, authenticate));
+    }
+    if let Some(requests_per_sec) = gateway.config().api_rate_limit_per_sec {
+        let limiter = Arc::new(RateLimiter::new(requests_per_sec, gateway.config().api_rate_limit_burst));
+        router = router.route_layer(middleware::from_fn_with_state(limiter, rate_limit));
+    }
+
+    router
+        .route("/health", get(health_check))
         .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
@@ -69,64 +195,344 @@ pub fn create_router(gateway: Arc<ExecutionGateway>) -> Router {
         .with_state(gateway)
 }
 
-/// Health check endpoint
-async fn health_check(State(gateway): State<AppState>) -> Result<Json<HealthResponse>, StatusCode> {
+/// Reject requests without a valid `Authorization: Bearer <key>` header with 401
+async fn authenticate(
+    State(auth): State<Arc<ApiKeyAuth>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    
+    if auth.is_authorized_header(header) {
+        return next.run(request).await;
+    }
+    
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, "Bearer")],
+        Json(ErrorResponse {
+            error: "Missing or invalid API key".to_string(),
+            code: "UNAUTHORIZED".to_string(),
+            rejection: None,
+            retry_after_ms: None,
+            reject_reason: None,
+            field: None,
+        }),
+    )
+        .into_response()
+}
+
+/// Reject requests from clients over their rate limit with 429 and a `Retry-After` header
+async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // Without connection info (e.g. in-process callers) all requests share one bucket
+    let client = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    
+    match limiter.check(client) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => error_reply(
+            StatusCode::TOO_MANY_REQUESTS,
+            ErrorResponse {
+                error: format!("Rate limit exceeded; retry in {} ms", retry_after.as_millis()),
+                code: "RATE_LIMITED".to_string(),
+                rejection: None,
+                retry_after_ms: Some(retry_after.as_millis() as u64),
+                reject_reason: Some(RejectReason::RateLimited),
+                field: None,
+            },
+        ),
+    }
+}
+
+/// Health check endpoint; 503 once no registered exchange can take orders
+async fn health_check(State(gateway): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
     let active_orders = gateway.get_active_orders_count().await;
+    let exchanges = gateway.exchange_health().await;
+    
+    let available = exchanges.iter().filter(|exchange| exchange.is_available()).count();
+    let (status_code, status) = if available == exchanges.len() {
+        (StatusCode::OK, "healthy")
+    } else if available > 0 {
+        (StatusCode::OK, "degraded")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "unhealthy")
+    };
     
     let response = HealthResponse {
-        status: "healthy".to_string(),
+        status: status.to_string(),
         active_orders,
+        exchanges,
         timestamp: chrono::Utc::now(),
     };
     
-    Ok(Json(response))
+    (status_code, Json(response))
+}
+
+/// Prometheus scrape endpoint
+async fn get_metrics(State(gateway): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+        gateway.render_metrics(),
+    )
+}
+
+/// Gateway statistics endpoint
+async fn get_stats(State(gateway): State<AppState>) -> Json<StatsResponse> {
+    Json(StatsResponse {
+        decision_latency: gateway.get_latency_stats(),
+        session: gateway.get_session_stats(),
+        orders: gateway.get_order_statistics().await,
+        daily_loss: gateway.get_daily_loss_stats(),
+        timestamp: chrono::Utc::now(),
+    })
 }
 
-/// Place order endpoint - implements idempotency
+/// Header carrying a client-chosen idempotency key for order placement
+const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Place order endpoint - implements idempotency, keyed on the `Idempotency-Key`
+/// header when present and on the decision ID otherwise
 async fn place_order(
     State(gateway): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<PlaceOrderRequest>,
-) -> Result<Json<PlaceOrderResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Json<PlaceOrderResponse>, Response> {
     info!("Received place order request for symbol: {}", request.order_decision.symbol);
     
-    // Validate the order decision
-    if let Err(validation_error) = request.order_decision.validate() {
-        error!("Order validation failed: {}", validation_error);
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty());
+    
+    match validate_and_place(&gateway, request.order_decision, idempotency_key).await {
+        Ok(execution_result) => Ok(Json(PlaceOrderResponse { execution_result })),
+        Err((status_code, error_response)) => Err(error_reply(status_code, error_response)),
+    }
+}
+
+/// Error response, with a `Retry-After` header when the error says when to retry
+fn error_reply(status_code: StatusCode, error_response: ErrorResponse) -> Response {
+    let retry_after_secs = error_response.retry_after_ms.map(|ms| ms.div_ceil(1000).max(1));
+    let mut response = (status_code, Json(error_response)).into_response();
+    if let Some(retry_after_secs) = retry_after_secs {
+        response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+    }
+    response
+}
+
+/// OCO order endpoint - places a linked take-profit/stop-loss pair
+async fn place_oco_order(
+    State(gateway): State<AppState>,
+    Json(request): Json<PlaceOrderRequest>,
+) -> Result<Json<OcoExecutionResult>, (StatusCode, Json<ErrorResponse>)> {
+    let order_decision = request.order_decision;
+    info!("Received OCO order request for symbol: {}", order_decision.symbol);
+    
+    if let Err((status_code, error_response)) = validate_decision(&order_decision) {
+        return Err((status_code, Json(error_response)));
+    }
+    
+    match gateway.place_oco_order(order_decision).await {
+        Ok(oco_result) => {
+            info!(
+                "OCO order placed: take profit {}, stop loss {}",
+                oco_result.take_profit.order_id, oco_result.stop_loss.order_id
+            );
+            Ok(Json(oco_result))
+        }
+        Err(e) => {
+            error!("Failed to place OCO order: {}", e);
+            let (status_code, error_response) = placement_error(e);
+            Err((status_code, Json(error_response)))
+        }
+    }
+}
+
+/// Batch place order endpoint - each order succeeds or fails on its own
+async fn place_orders_batch(
+    State(gateway): State<AppState>,
+    Json(order_decisions): Json<Vec<OrderDecision>>,
+) -> Result<(StatusCode, Json<BatchOrderResponse>), (StatusCode, Json<ErrorResponse>)> {
+    info!("Received batch place order request with {} orders", order_decisions.len());
+    
+    if order_decisions.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Batch contains no orders".to_string(),
+                code: "VALIDATION_ERROR".to_string(),
+                rejection: None,
+                retry_after_ms: None,
+                reject_reason: None,
+                field: None,
+            }),
+        ));
+    }
+    let max_batch_size = gateway.config().max_batch_size;
+    if order_decisions.len() > max_batch_size {
         return Err((
             StatusCode::BAD_REQUEST,
             Json(ErrorResponse {
-                error: validation_error,
+                error: format!("Batch of {} orders exceeds the maximum of {}", order_decisions.len(), max_batch_size),
                 code: "VALIDATION_ERROR".to_string(),
+                rejection: None,
+                retry_after_ms: None,
+                reject_reason: None,
+                field: None,
             }),
         ));
     }
     
-    match gateway.place_order(request.order_decision).await {
+    // Concurrency is bounded by the gateway's max_concurrent_orders permits
+    let handles: Vec<_> = order_decisions.into_iter()
+        .map(|order_decision| {
+            let gateway = gateway.clone();
+            tokio::spawn(async move { validate_and_place(&gateway, order_decision, None).await })
+        })
+        .collect();
+    
+    let mut results = Vec::with_capacity(handles.len());
+    for (index, handle) in handles.into_iter().enumerate() {
+        let (status_code, execution_result, error) = match handle.await {
+            Ok(Ok(execution_result)) => (StatusCode::OK, Some(execution_result), None),
+            Ok(Err((status_code, error_response))) => (status_code, None, Some(error_response)),
+            Err(e) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                None,
+                Some(ErrorResponse {
+                    error: format!("Order task failed: {}", e),
+                    code: "EXECUTION_ERROR".to_string(),
+                    rejection: None,
+                    retry_after_ms: None,
+                    reject_reason: None,
+                    field: None,
+                }),
+            ),
+        };
+        results.push(BatchOrderResult {
+            index,
+            status: status_code.as_u16(),
+            execution_result,
+            error,
+        });
+    }
+    
+    let succeeded = results.iter().filter(|result| result.error.is_none()).count();
+    let failed = results.len() - succeeded;
+    info!("Batch placed: {} succeeded, {} failed", succeeded, failed);
+    
+    Ok((StatusCode::MULTI_STATUS, Json(BatchOrderResponse { results, succeeded, failed })))
+}
+
+/// Validate and place a single order, mapping failures to an HTTP status and error body
+pub(crate) async fn validate_and_place(
+    gateway: &ExecutionGateway,
+    order_decision: OrderDecision,
+    idempotency_key: Option<&str>,
+) -> Result<ExecutionResult, (StatusCode, ErrorResponse)> {
+    validate_decision(&order_decision)?;
+    
+    let result = match idempotency_key {
+        Some(idempotency_key) => gateway.place_order_with_idempotency_key(idempotency_key, order_decision).await,
+        None => gateway.place_order(order_decision).await,
+    };
+    match result {
         Ok(execution_result) => {
             info!("Order placed successfully: {}", execution_result.order_id);
-            Ok(Json(PlaceOrderResponse { execution_result }))
+            Ok(execution_result)
         }
         Err(e) => {
             error!("Failed to place order: {}", e);
-            let (status_code, error_code) = match &e {
-                TradingError::RiskLimitError { .. } => (StatusCode::FORBIDDEN, "RISK_LIMIT_ERROR"),
-                TradingError::ExecutionError { .. } => (StatusCode::INTERNAL_SERVER_ERROR, "EXECUTION_ERROR"),
-                TradingError::NetworkError(_) => (StatusCode::BAD_GATEWAY, "NETWORK_ERROR"),
-                TradingError::DataError { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "DATA_ERROR"),
-                TradingError::SerializationError(_) => (StatusCode::BAD_REQUEST, "SERIALIZATION_ERROR"),
-            };
-            
-            Err((
-                status_code,
-                Json(ErrorResponse {
-                    error: e.to_string(),
-                    code: error_code.to_string(),
-                }),
-            ))
+            Err(placement_error(e))
         }
     }
 }
 
+/// Reject a decision that fails model validation with a 422 and its rejection feedback
+fn validate_decision(order_decision: &OrderDecision) -> Result<(), (StatusCode, ErrorResponse)> {
+    if let Err((field, message)) = order_decision.validate_fields() {
+        let e = TradingError::ValidationError { field: field.to_string(), message };
+        error!("Order validation failed: {}", e);
+        let (status_code, mut error_response) = placement_error(e);
+        // Validation shares its risk bounds with these rules, so they explain the same failure
+        error_response.rejection = evaluate_risk_rules(order_decision, 0.0).into_iter().next();
+        return Err((status_code, error_response));
+    }
+    Ok(())
+}
+
+/// Map a placement failure to an HTTP status and error body
+fn placement_error(e: TradingError) -> (StatusCode, ErrorResponse) {
+    let (status_code, error_code) = match &e {
+        TradingError::RiskLimitError { .. } => (StatusCode::FORBIDDEN, "RISK_LIMIT_ERROR"),
+        TradingError::CircuitBreakerOpen { .. } => (StatusCode::SERVICE_UNAVAILABLE, "CIRCUIT_BREAKER_OPEN"),
+        TradingError::ExecutionError { .. } => (StatusCode::INTERNAL_SERVER_ERROR, "EXECUTION_ERROR"),
+        TradingError::NetworkError(_) => (StatusCode::BAD_GATEWAY, "NETWORK_ERROR"),
+        TradingError::DataError { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "DATA_ERROR"),
+        TradingError::SerializationError(_) => (StatusCode::BAD_REQUEST, "SERIALIZATION_ERROR"),
+        TradingError::Timeout { .. } => (StatusCode::GATEWAY_TIMEOUT, "TIMEOUT"),
+        TradingError::RateLimited { .. } => (StatusCode::SERVICE_UNAVAILABLE, "EXCHANGE_RATE_LIMITED"),
+        TradingError::InsufficientFunds { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "INSUFFICIENT_FUNDS"),
+        TradingError::OrderRejected { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "ORDER_REJECTED"),
+        TradingError::ValidationError { .. } => (StatusCode::UNPROCESSABLE_ENTITY, "VALIDATION_ERROR"),
+        TradingError::Other(_) => (StatusCode::BAD_GATEWAY, "EXCHANGE_ERROR"),
+    };
+    
+    // Explained by the gate that refused the order, not re-derived from state that has since moved
+    let rejection = e.rejection().cloned();
+    let retry_after_ms = match &e {
+        TradingError::CircuitBreakerOpen { retry_after_ms, .. } => Some(*retry_after_ms),
+        _ => None,
+    };
+    let field = match &e {
+        TradingError::ValidationError { field, .. } => Some(field.clone()),
+        _ => None,
+    };
+    
+    (
+        status_code,
+        ErrorResponse {
+            error: e.to_string(),
+            code: error_code.to_string(),
+            rejection,
+            retry_after_ms,
+            reject_reason: reject_reason(&e),
+            field,
+        },
+    )
+}
+
+/// List orders endpoint - pages through tracked orders, filtered by state and symbol
+async fn list_orders(
+    State(gateway): State<AppState>,
+    Query(params): Query<ListOrdersParams>,
+) -> Json<ListOrdersResponse> {
+    let limit = params.limit.unwrap_or(DEFAULT_ORDER_PAGE_LIMIT).min(MAX_ORDER_PAGE_LIMIT);
+    let offset = params.offset.unwrap_or(0);
+    
+    let (orders, total) = gateway
+        .list_orders(params.state, params.symbol.as_deref(), limit, offset)
+        .await;
+    
+    Json(ListOrdersResponse {
+        orders,
+        total,
+        limit,
+        offset,
+    })
+}
+
 /// Get order status endpoint
 async fn get_order_status(
     State(gateway): State<AppState>,
@@ -148,12 +554,36 @@ async fn get_order_status(
                 Json(ErrorResponse {
                     error: e.to_string(),
                     code: "ORDER_NOT_FOUND".to_string(),
+                    rejection: None,
+                    retry_after_ms: None,
+                    reject_reason: None,
+                    field: None,
                 }),
             ))
         }
     }
 }
 
+/// Order detail endpoint: lifecycle history, fills and metadata
+async fn get_order_detail(
+    State(gateway): State<AppState>,
+    Path(order_id): Path<String>,
+) -> Result<Json<OrderDetail>, (StatusCode, Json<ErrorResponse>)> {
+    gateway.get_order_detail(&order_id).await.map(Json).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("Order not found: {}", order_id),
+                code: "ORDER_NOT_FOUND".to_string(),
+                rejection: None,
+                retry_after_ms: None,
+                reject_reason: None,
+                field: None,
+            }),
+        )
+    })
+}
+
 /// Cancel order endpoint
 async fn cancel_order(
     State(gateway): State<AppState>,
@@ -183,12 +613,263 @@ async fn cancel_order(
                 Json(ErrorResponse {
                     error: e.to_string(),
                     code: error_code.to_string(),
+                    rejection: None,
+                    retry_after_ms: None,
+                    reject_reason: None,
+                    field: None,
                 }),
             ))
         }
     }
 }
 
+/// Amend order endpoint - changes the price and/or quantity of an open order
+async fn amend_order(
+    State(gateway): State<AppState>,
+    Path(order_id): Path<String>,
+    Json(request): Json<AmendOrderRequest>,
+) -> Result<Json<OrderUpdate>, (StatusCode, Json<ErrorResponse>)> {
+    info!("Amending order: {}", order_id);
+    
+    if gateway.get_order_detail(&order_id).await.is_none() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("Order not found: {}", order_id),
+                code: "ORDER_NOT_FOUND".to_string(),
+                rejection: None,
+                retry_after_ms: None,
+                reject_reason: None,
+                field: None,
+            }),
+        ));
+    }
+    
+    match gateway.amend_order(&order_id, request.new_price, request.new_quantity).await {
+        Ok(order_update) => Ok(Json(order_update)),
+        Err(e) => {
+            error!("Failed to amend order: {}", e);
+            let (status_code, error_code) = match &e {
+                TradingError::ValidationError { field, .. } if field == "order_id" => {
+                    (StatusCode::CONFLICT, "ORDER_NOT_AMENDABLE")
+                }
+                TradingError::ValidationError { .. } => (StatusCode::BAD_REQUEST, "INVALID_AMENDMENT"),
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, "AMENDMENT_ERROR"),
+            };
+            let field = match &e {
+                TradingError::ValidationError { field, .. } => Some(field.clone()),
+                _ => None,
+            };
+            
+            Err((
+                status_code,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                    code: error_code.to_string(),
+                    rejection: None,
+                    retry_after_ms: None,
+                    reject_reason: None,
+                    field,
+                }),
+            ))
+        }
+    }
+}
+
+/// Cancel-all endpoint - cancels every open order, optionally in one symbol
+async fn cancel_all_orders(
+    State(gateway): State<AppState>,
+    Query(params): Query<CancelAllParams>,
+) -> Json<CancelAllSummary> {
+    info!("Cancelling all open orders (symbol: {:?})", params.symbol);
+    Json(gateway.cancel_all_orders(params.symbol.as_deref()).await)
+}
+
+/// Halt endpoint - refuse every new order until trading is resumed
+async fn halt_trading(State(gateway): State<AppState>) -> Json<TradingHaltResponse> {
+    gateway.set_trading_halted(true);
+    Json(TradingHaltResponse {
+        trading_halted: true,
+        timestamp: chrono::Utc::now(),
+    })
+}
+
+/// Resume endpoint - clear a trading halt
+async fn resume_trading(State(gateway): State<AppState>) -> Json<TradingHaltResponse> {
+    gateway.set_trading_halted(false);
+    Json(TradingHaltResponse {
+        trading_halted: false,
+        timestamp: chrono::Utc::now(),
+    })
+}
+
+/// Risk limits endpoint - portfolio limits currently enforced at placement
+async fn get_risk_limits(State(gateway): State<AppState>) -> Json<RiskLimits> {
+    Json(gateway.risk_limits().await)
+}
+
+/// Update risk limits endpoint - replaces every limit; omitted limits are disabled
+async fn update_risk_limits(
+    State(gateway): State<AppState>,
+    Json(risk_limits): Json<RiskLimits>,
+) -> Result<Json<RiskLimits>, (StatusCode, Json<ErrorResponse>)> {
+    if let Err(e) = gateway.set_risk_limits(risk_limits).await {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e,
+                code: "VALIDATION_ERROR".to_string(),
+                rejection: None,
+                retry_after_ms: None,
+                reject_reason: None,
+                field: None,
+            }),
+        ));
+    }
+    Ok(Json(gateway.risk_limits().await))
+}
+
+/// Positions endpoint - net size and PnL per symbol from the gateway's fills
+async fn get_positions(State(gateway): State<AppState>) -> Json<PositionsResponse> {
+    Json(PositionsResponse {
+        positions: gateway.get_positions().await,
+        timestamp: chrono::Utc::now(),
+    })
+}
+
+/// Close position endpoint - flattens the position in a symbol
+async fn close_position(
+    State(gateway): State<AppState>,
+    Path(symbol): Path<String>,
+    Query(params): Query<ClosePositionParams>,
+) -> Result<Json<PlaceOrderResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let exchange = params.exchange.unwrap_or_else(|| "default".to_string());
+    info!("Closing position for symbol: {} on exchange: {}", symbol, exchange);
+    
+    match gateway.close_position(&symbol, &exchange).await {
+        Ok(execution_result) => {
+            info!("Position closed successfully: {}", symbol);
+            Ok(Json(PlaceOrderResponse { execution_result }))
+        }
+        Err(e) => {
+            error!("Failed to close position: {}", e);
+            let (status_code, error_code) = match &e {
+                TradingError::ExecutionError { message } if message.contains("not found") => {
+                    (StatusCode::NOT_FOUND, "POSITION_NOT_FOUND")
+                }
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, "CLOSE_POSITION_ERROR"),
+            };
+            
+            Err((
+                status_code,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                    code: error_code.to_string(),
+                    rejection: None,
+                    retry_after_ms: None,
+                    reject_reason: None,
+                    field: None,
+                }),
+            ))
+        }
+    }
+}
+
+/// Order updates WebSocket endpoint - streams coalesced order state changes
+async fn order_updates_ws(
+    State(gateway): State<AppState>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let updates = gateway.subscribe_coalesced_order_updates();
+    ws.on_upgrade(move |socket| stream_order_updates(socket, updates))
+}
+
+async fn stream_order_updates(
+    mut socket: WebSocket,
+    mut updates: tokio::sync::mpsc::UnboundedReceiver<OrderUpdate>,
+) {
+    while let Some(update) = updates.recv().await {
+        let message = match serde_json::to_string(&update) {
+            Ok(message) => message,
+            Err(e) => {
+                error!("Failed to serialize order update: {}", e);
+                continue;
+            }
+        };
+        
+        if socket.send(Message::Text(message)).await.is_err() {
+            info!("Order updates client disconnected");
+            break;
+        }
+    }
+}
+
+/// Single order WebSocket endpoint - sends the current state, then every change until the order is terminal
+async fn order_stream_ws(
+    State(gateway): State<AppState>,
+    Path(order_id): Path<String>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    // Subscribe before taking the snapshot so no change falls between the two
+    let updates = gateway.subscribe_order_updates();
+    let Some(snapshot) = gateway.get_order_update(&order_id).await else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse {
+                error: format!("Order not found: {}", order_id),
+                code: "ORDER_NOT_FOUND".to_string(),
+                rejection: None,
+                retry_after_ms: None,
+                reject_reason: None,
+                field: None,
+            }),
+        )
+            .into_response();
+    };
+    ws.on_upgrade(move |socket| stream_single_order(socket, gateway, order_id, Some(snapshot), updates))
+}
+
+async fn stream_single_order(
+    mut socket: WebSocket,
+    gateway: AppState,
+    order_id: String,
+    snapshot: Option<OrderUpdate>,
+    mut updates: tokio::sync::broadcast::Receiver<OrderUpdate>,
+) {
+    use tokio::sync::broadcast::error::RecvError;
+    
+    let mut next_update = snapshot;
+    loop {
+        if let Some(update) = next_update.take() {
+            let is_terminal = update.status.is_terminal();
+            let message = match serde_json::to_string(&update) {
+                Ok(message) => message,
+                Err(e) => {
+                    error!("Failed to serialize order update: {}", e);
+                    continue;
+                }
+            };
+            
+            if socket.send(Message::Text(message)).await.is_err() {
+                info!("Order stream client disconnected: {}", order_id);
+                return;
+            }
+            if is_terminal {
+                let _ = socket.send(Message::Close(None)).await;
+                return;
+            }
+        }
+        
+        match updates.recv().await {
+            Ok(update) if update.order_id == order_id => next_update = Some(update),
+            Ok(_) => {}
+            // The skipped update may have been the last, so resend the current state
+            Err(RecvError::Lagged(_)) => next_update = gateway.get_order_update(&order_id).await,
+            Err(RecvError::Closed) => return,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,6 +918,57 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    async fn health_of(gateway: Arc<ExecutionGateway>) -> (StatusCode, HealthResponse) {
+        let request = Request::builder()
+            .uri("/health")
+            .body(Body::empty())
+            .unwrap();
+        let response = create_router(gateway).oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_health_reflects_exchange_availability() {
+        // Healthy: every exchange answers its ping
+        let gateway = create_test_gateway();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(MockExchangeAdapter::new().with_delay(0))).await;
+        let (status, health) = health_of(gateway.clone()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(health.status, "healthy");
+        assert_eq!(health.exchanges.len(), 1);
+        assert!(health.exchanges[0].reachable);
+        
+        // Degraded: one of two exchanges is unreachable
+        gateway.register_exchange_adapter("kraken".to_string(), Box::new(MockExchangeAdapter::new().with_failure(true))).await;
+        let (status, health) = health_of(gateway).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(health.status, "degraded");
+        assert!(!health.exchanges.iter().find(|exchange| exchange.exchange == "kraken").unwrap().reachable);
+        
+        // Unhealthy: nothing can take orders
+        let gateway = create_test_gateway();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(MockExchangeAdapter::new().with_failure(true))).await;
+        let (status, health) = health_of(gateway).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(health.status, "unhealthy");
+    }
+
+    #[tokio::test]
+    async fn test_get_stats() {
+        let gateway = create_test_gateway();
+        let app = create_router(gateway);
+
+        let request = Request::builder()
+            .uri("/v1/stats")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn test_place_order_success() {
         let gateway = create_test_gateway();
@@ -279,7 +1011,12 @@ mod tests {
             .unwrap();
 
         let response = app.oneshot(request).await.unwrap();
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "VALIDATION_ERROR");
+        let field = error.field.unwrap();
+        assert!(error.error.starts_with(&format!("Invalid {}:", field)), "{}", error.error);
     }
 
     #[tokio::test]
@@ -318,9 +1055,303 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_idempotent_order_placement() {
+    async fn test_order_detail_returns_lifecycle_history() {
         let gateway = create_test_gateway();
-        let mock_adapter = MockExchangeAdapter::new();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        let mut order_decision = create_test_order_decision();
+        order_decision.partial_fill_acceptable = true;
+        let execution_result = gateway.place_order(order_decision).await.unwrap();
+        let order_id = execution_result.order_id;
+        
+        let app = create_router(gateway);
+        // A status poll picks up the exchange having filled the rest
+        let request = Request::builder()
+            .uri(format!("/v1/orders/{}/status", order_id))
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(app.clone().oneshot(request).await.unwrap().status(), StatusCode::OK);
+        
+        let detail_request = |order_id: &str| {
+            Request::builder()
+                .uri(format!("/v1/orders/{}/detail", order_id))
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = app.clone().oneshot(detail_request(&order_id)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let detail: OrderDetail = serde_json::from_slice(&body).unwrap();
+        
+        assert_eq!(detail.lifecycle.order_id, order_id);
+        assert_eq!(detail.lifecycle.state, OrderLifecycleState::Filled);
+        let history: Vec<_> = detail.lifecycle.state_history.iter()
+            .map(|transition| (transition.from_state.clone(), transition.to_state.clone()))
+            .collect();
+        assert_eq!(
+            history,
+            vec![
+                (OrderLifecycleState::Created, OrderLifecycleState::Validated),
+                (OrderLifecycleState::Validated, OrderLifecycleState::Submitted),
+                (OrderLifecycleState::Submitted, OrderLifecycleState::Acknowledged),
+                (OrderLifecycleState::Acknowledged, OrderLifecycleState::PartiallyFilled),
+                (OrderLifecycleState::PartiallyFilled, OrderLifecycleState::Filled),
+            ]
+        );
+        let execution = detail.execution.unwrap();
+        assert!((execution.total_filled - 0.05).abs() < 1e-9);
+        let fill_quality = detail.fill_quality.unwrap();
+        assert!((fill_quality.fill_rate - 0.5).abs() < 1e-9);
+        assert_eq!(fill_quality.reference_price, Some(50000.0));
+        assert!(fill_quality.implementation_shortfall.is_some());
+        
+        let response = app.oneshot(detail_request("missing_order")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_halt_blocks_placement_but_allows_cancels() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        let oco_result = gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+        
+        let app = create_router(gateway.clone());
+        let post = |uri: &str| {
+            Request::builder()
+                .uri(uri)
+                .method("POST")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let place = || {
+            let mut order_decision = create_test_order_decision();
+            order_decision.base_quantity = 0.1;
+            order_decision.max_position_value = 5000.0;
+            let body = serde_json::to_string(&PlaceOrderRequest { order_decision }).unwrap();
+            Request::builder()
+                .uri("/v1/orders")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap()
+        };
+        
+        let response = app.clone().oneshot(post("/v1/halt")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let halt: TradingHaltResponse = serde_json::from_slice(&body).unwrap();
+        assert!(halt.trading_halted);
+        assert!(gateway.is_trading_halted());
+        
+        let response = app.clone().oneshot(place()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "RISK_LIMIT_ERROR");
+        assert!(error.error.contains("trading halted"));
+        
+        // Resting orders can still be pulled
+        let cancel = Request::builder()
+            .uri(format!("/v1/orders/{}", oco_result.take_profit.order_id))
+            .method("DELETE")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(app.clone().oneshot(cancel).await.unwrap().status(), StatusCode::OK);
+        
+        let response = app.oneshot(post("/v1/resume")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!gateway.is_trading_halted());
+        assert!(gateway.place_order(create_test_order_decision()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_risk_limits() {
+        let gateway = create_test_gateway();
+        let app = create_router(gateway.clone());
+        let update = |body: serde_json::Value| {
+            Request::builder()
+                .uri("/v1/risk-limits")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+        
+        let response = app.clone()
+            .oneshot(update(serde_json::json!({ "max_position_size": 5.0, "max_total_notional": 500000.0 })))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let risk_limits: RiskLimits = serde_json::from_slice(&body).unwrap();
+        assert_eq!(risk_limits.max_position_size, Some(5.0));
+        assert_eq!(gateway.risk_limits().await.max_total_notional, Some(500000.0));
+        
+        // Invalid limits are refused and the old ones kept
+        let response = app.oneshot(update(serde_json::json!({ "max_order_notional": 0.0 }))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(gateway.risk_limits().await.max_position_size, Some(5.0));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_filters_by_symbol() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+        
+        let app = create_router(gateway);
+        let cancel_all = |uri: &str| {
+            Request::builder()
+                .uri(uri)
+                .method("POST")
+                .body(Body::empty())
+                .unwrap()
+        };
+        
+        let response = app.clone().oneshot(cancel_all("/v1/orders/cancel-all?symbol=ETHUSD")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let summary: CancelAllSummary = serde_json::from_slice(&body).unwrap();
+        assert!(summary.cancelled.is_empty());
+        
+        let response = app.oneshot(cancel_all("/v1/orders/cancel-all")).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let summary: CancelAllSummary = serde_json::from_slice(&body).unwrap();
+        assert_eq!(summary.cancelled.len(), 2);
+        assert!(summary.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_amend_order_and_reject_amending_filled_order() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let oco_result = gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+        let mut order_decision = create_test_order_decision();
+        order_decision.base_quantity = 0.1;
+        order_decision.max_position_value = 5000.0;
+        let filled = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(filled.status, rust_common::OrderStatus::Filled);
+        
+        let app = create_router(gateway);
+        let amend = |order_id: &str| {
+            Request::builder()
+                .uri(format!("/v1/orders/{}", order_id))
+                .method("PATCH")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"new_price": 52100.0}"#))
+                .unwrap()
+        };
+        
+        let response = app.clone().oneshot(amend(&oco_result.take_profit.order_id)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let order_update: OrderUpdate = serde_json::from_slice(&body).unwrap();
+        assert_eq!(order_update.order_id, oco_result.take_profit.order_id);
+        
+        let response = app.clone().oneshot(amend(&filled.order_id)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "ORDER_NOT_AMENDABLE");
+        
+        let response = app.oneshot(amend("unknown")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_close_position_not_found() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let app = create_router(gateway);
+
+        let request = Request::builder()
+            .uri("/v1/positions/BTCUSD/close")
+            .method("POST")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_risk_rejection_returns_feedback() {
+        let gateway = create_test_gateway();
+        let app = create_router(gateway);
+
+        let mut order_decision = create_test_order_decision();
+        order_decision.leverage = 20.0;
+        let body = serde_json::to_string(&PlaceOrderRequest { order_decision }).unwrap();
+
+        let request = Request::builder()
+            .uri("/v1/orders")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error_response: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        let rejection = error_response.rejection.unwrap();
+        assert_eq!(rejection.reason, crate::RejectionReason::ExcessiveLeverage);
+        assert_eq!(rejection.rule, "max_leverage");
+    }
+
+    #[tokio::test]
+    async fn test_gate_rejections_return_feedback_from_the_gate() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig {
+            symbol_throttle: crate::ThrottleLimits { max_orders: Some(1), max_notional: None },
+            ..Default::default()
+        }));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        let app = create_router(gateway.clone());
+
+        let place = |app: Router| async move {
+            let mut order_decision = create_test_order_decision();
+            order_decision.base_quantity = 0.1;
+            order_decision.max_position_value = 5000.0;
+            let body = serde_json::to_string(&PlaceOrderRequest { order_decision }).unwrap();
+            let request = Request::builder()
+                .uri("/v1/orders")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap();
+            let response = app.oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            (status, serde_json::from_slice::<ErrorResponse>(&body).ok())
+        };
+
+        gateway.set_trading_halted(true);
+        let (status, error_response) = place(app.clone()).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let rejection = error_response.unwrap().rejection.unwrap();
+        assert_eq!(rejection.reason, crate::RejectionReason::TradingHalted);
+
+        gateway.set_trading_halted(false);
+        assert_eq!(place(app.clone()).await.0, StatusCode::OK);
+        let (status, error_response) = place(app).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        let rejection = error_response.unwrap().rejection.unwrap();
+        assert_eq!(rejection.reason, crate::RejectionReason::SymbolThrottled);
+        assert_eq!(rejection.rule, "symbol_throttle");
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_order_placement() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new();
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
         let app = create_router(gateway.clone());
@@ -345,4 +1376,376 @@ mod tests {
         // Should have only one active order due to idempotency
         assert_eq!(gateway.get_active_orders_count().await, 1);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_order_stream_websocket_sends_status() {
+        use futures::StreamExt;
+        use tokio_tungstenite::tungstenite::Message as ClientMessage;
+        
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let app = create_router(gateway.clone());
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        
+        let execution_result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        
+        let url = format!("ws://{}/v1/orders/{}/stream", address, execution_result.order_id);
+        let (mut socket, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+        
+        let frame = socket.next().await.unwrap().unwrap();
+        let ClientMessage::Text(text) = frame else {
+            panic!("Expected a text frame, got {:?}", frame);
+        };
+        let update: OrderUpdate = serde_json::from_str(&text).unwrap();
+        assert_eq!(update.order_id, execution_result.order_id);
+        assert!(matches!(update.status, OrderExecutionStatus::Filled));
+        
+        // The stream closes once the order is terminal
+        let frame = socket.next().await.unwrap().unwrap();
+        assert!(matches!(frame, ClientMessage::Close(_)));
+        
+        // Unknown orders are refused before the upgrade
+        let url = format!("ws://{}/v1/orders/unknown/stream", address);
+        match tokio_tungstenite::connect_async(url).await {
+            Err(tokio_tungstenite::tungstenite::Error::Http(response)) => {
+                assert_eq!(response.status(), StatusCode::NOT_FOUND);
+            }
+            other => panic!("expected a 404, got {:?}", other.map(|(_, response)| response)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_reports_each_order_individually() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let app = create_router(gateway.clone());
+        
+        let valid_decision = || {
+            let mut decision = create_test_order_decision();
+            decision.base_quantity = 0.1;
+            decision.max_position_value = 10000.0;
+            decision
+        };
+        let mut invalid_decision = valid_decision();
+        invalid_decision.risk_adjusted_quantity = -1.0;
+        
+        let batch = vec![valid_decision(), invalid_decision, valid_decision()];
+        let request = Request::builder()
+            .uri("/v1/orders/batch")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&batch).unwrap()))
+            .unwrap();
+        
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::MULTI_STATUS);
+        
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let batch_response: BatchOrderResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(batch_response.succeeded, 2);
+        assert_eq!(batch_response.failed, 1);
+        
+        let statuses: Vec<_> = batch_response.results.iter().map(|result| result.status).collect();
+        assert_eq!(statuses, vec![200, 422, 200]);
+        assert!(batch_response.results[0].execution_result.is_some());
+        assert_eq!(batch_response.results[1].error.as_ref().unwrap().code, "VALIDATION_ERROR");
+        assert_eq!(gateway.get_active_orders_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_above_max_size_rejected() {
+        let config = GatewayConfig { max_batch_size: 2, ..Default::default() };
+        let gateway = Arc::new(ExecutionGateway::new(config));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let batch = vec![create_test_order_decision(), create_test_order_decision(), create_test_order_decision()];
+        let request = Request::builder()
+            .uri("/v1/orders/batch")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&batch).unwrap()))
+            .unwrap();
+        
+        let response = create_router(gateway.clone()).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(gateway.get_active_orders_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_place_oco_order_returns_both_legs() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let app = create_router(gateway.clone());
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.base_quantity = 0.1;
+        order_decision.max_position_value = 10000.0;
+        let request = Request::builder()
+            .uri("/v1/orders/oco")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&PlaceOrderRequest { order_decision }).unwrap()))
+            .unwrap();
+        
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let oco_result: OcoExecutionResult = serde_json::from_slice(&body).unwrap();
+        assert_ne!(oco_result.take_profit.order_id, oco_result.stop_loss.order_id);
+        assert_eq!(gateway.get_active_orders_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_empty_batch_is_rejected() {
+        let app = create_router(create_test_gateway());
+        
+        let request = Request::builder()
+            .uri("/v1/orders/batch")
+            .method("POST")
+            .header("content-type", "application/json")
+            .body(Body::from("[]"))
+            .unwrap();
+        
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(app: Router, uri: &str) -> T {
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_orders_filters_and_paginates() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        for symbol in ["BTCUSD", "BTCUSD", "ETHUSD"] {
+            let mut decision = create_test_order_decision();
+            decision.symbol = symbol.to_string();
+            gateway.place_order(decision).await.unwrap();
+        }
+        let app = create_router(gateway);
+        
+        let all: ListOrdersResponse = get_json(app.clone(), "/v1/orders").await;
+        assert_eq!(all.total, 3);
+        assert_eq!(all.limit, DEFAULT_ORDER_PAGE_LIMIT);
+        
+        let btc: ListOrdersResponse = get_json(app.clone(), "/v1/orders?symbol=BTCUSD&state=Filled").await;
+        assert_eq!(btc.total, 2);
+        assert!(btc.orders.iter().all(|order| order.symbol == "BTCUSD"));
+        
+        let failed: ListOrdersResponse = get_json(app.clone(), "/v1/orders?state=Failed").await;
+        assert_eq!(failed.total, 0);
+        
+        let page: ListOrdersResponse = get_json(app.clone(), "/v1/orders?limit=2&offset=2").await;
+        assert_eq!(page.total, 3);
+        assert_eq!(page.orders.len(), 1);
+        
+        let clamped: ListOrdersResponse = get_json(app, "/v1/orders?limit=1000000").await;
+        assert_eq!(clamped.limit, MAX_ORDER_PAGE_LIMIT);
+    }
+
+    #[tokio::test]
+    async fn test_stats_include_order_statistics() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        
+        let stats: StatsResponse = get_json(create_router(gateway), "/v1/stats").await;
+        assert_eq!(stats.orders.total_orders, 1);
+        assert_eq!(stats.orders.filled, 1);
+        assert_eq!(stats.daily_loss.max_daily_loss, None);
+        assert!(!stats.daily_loss.breached);
+    }
+
+    #[tokio::test]
+    async fn test_positions_aggregate_fills() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_sequence(vec![(1.0, 50000.0), (1.0, 50020.0)]);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        gateway.update_mark_price("BTCUSD", 50110.0).await;
+        
+        let response: PositionsResponse = get_json(create_router(gateway), "/v1/positions").await;
+        assert_eq!(response.positions.len(), 1);
+        let position = &response.positions[0];
+        assert_eq!(position.symbol, "BTCUSD");
+        assert!((position.net_size - 0.2).abs() < 1e-9);
+        assert!((position.average_entry_price - 50010.0).abs() < 1e-6);
+        assert!((position.unrealized_pnl.unwrap() - 20.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_count_placed_orders() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        
+        let request = Request::builder().uri("/metrics").body(Body::empty()).unwrap();
+        let response = create_router(gateway).oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        
+        assert!(body.contains("gateway_order_status_total{status=\"filled\"} 1"), "{}", body);
+        assert!(body.contains("gateway_execution_latency_ms_count{exchange=\"default\"} 1"));
+        assert!(body.contains("gateway_circuit_breaker_state{exchange=\"default\"} 0"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_returns_429_and_spares_health_checks() {
+        let config = GatewayConfig {
+            api_rate_limit_per_sec: Some(0.5),
+            api_rate_limit_burst: 2,
+            ..Default::default()
+        };
+        let app = create_router(Arc::new(ExecutionGateway::new(config)));
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        
+        let mut statuses = Vec::new();
+        for _ in 0..4 {
+            let response = app.clone().oneshot(get("/v1/orders")).await.unwrap();
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after: u64 = response.headers()[header::RETRY_AFTER].to_str().unwrap().parse().unwrap();
+                assert!((1..=2).contains(&retry_after));
+            }
+            statuses.push(response.status());
+        }
+        assert_eq!(
+            statuses,
+            vec![StatusCode::OK, StatusCode::OK, StatusCode::TOO_MANY_REQUESTS, StatusCode::TOO_MANY_REQUESTS]
+        );
+        
+        // Health checks bypass the limiter
+        let response = app.oneshot(get("/health")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_api_key_required_except_for_health() {
+        let config = GatewayConfig {
+            api_keys: vec!["secret-key".to_string()],
+            ..Default::default()
+        };
+        let app = create_router(Arc::new(ExecutionGateway::new(config)));
+        let get = |uri: &str, authorization: Option<&str>| {
+            let mut request = Request::builder().uri(uri);
+            if let Some(authorization) = authorization {
+                request = request.header(header::AUTHORIZATION, authorization);
+            }
+            request.body(Body::empty()).unwrap()
+        };
+        
+        let missing = app.clone().oneshot(get("/v1/orders", None)).await.unwrap();
+        assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+        
+        let wrong = app.clone().oneshot(get("/v1/orders", Some("Bearer wrong-key"))).await.unwrap();
+        assert_eq!(wrong.status(), StatusCode::UNAUTHORIZED);
+        
+        let correct = app.clone().oneshot(get("/v1/orders", Some("Bearer secret-key"))).await.unwrap();
+        assert_eq!(correct.status(), StatusCode::OK);
+        
+        let health = app.oneshot(get("/health", None)).await.unwrap();
+        assert_eq!(health.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_header_deduplicates_placement() {
+        let gateway = create_test_gateway();
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        let app = create_router(gateway.clone());
+        
+        // The key replaces the decision ID for deduplication, so the ID needn't be a UUID
+        let mut order_decision = create_test_order_decision();
+        order_decision.decision_id = "client-decision-7".to_string();
+        order_decision.base_quantity = 0.1;
+        order_decision.max_position_value = 5000.0;
+        let body = serde_json::to_string(&PlaceOrderRequest { order_decision }).unwrap();
+        
+        let mut order_ids = Vec::new();
+        for _ in 0..2 {
+            let request = Request::builder()
+                .uri("/v1/orders")
+                .method("POST")
+                .header("content-type", "application/json")
+                .header("Idempotency-Key", "retry-safe-key")
+                .body(Body::from(body.clone()))
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let response: PlaceOrderResponse = serde_json::from_slice(&body).unwrap();
+            order_ids.push(response.execution_result.order_id);
+        }
+        
+        assert_eq!(order_ids[0], order_ids[1]);
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+        assert_eq!(gateway.get_active_orders_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_breaker_returns_503_with_retry_after() {
+        let config = GatewayConfig {
+            max_retries: 0,
+            circuit_breaker_failure_threshold: 1,
+            circuit_breaker_recovery_timeout_ms: 30_000,
+            ..Default::default()
+        };
+        let gateway = Arc::new(ExecutionGateway::new(config));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_failure(true);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        let app = create_router(gateway);
+        
+        let place = || {
+            let mut order_decision = create_test_order_decision();
+            order_decision.base_quantity = 0.1;
+            order_decision.max_position_value = 5000.0;
+            let body = serde_json::to_string(&PlaceOrderRequest { order_decision }).unwrap();
+            Request::builder()
+                .uri("/v1/orders")
+                .method("POST")
+                .header("content-type", "application/json")
+                .body(Body::from(body))
+                .unwrap()
+        };
+        
+        // The first failure trips the breaker
+        let response = app.clone().oneshot(place()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        
+        let response = app.oneshot(place()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let retry_after: u64 = response.headers()[header::RETRY_AFTER].to_str().unwrap().parse().unwrap();
+        assert!((29..=30).contains(&retry_after));
+        
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "CIRCUIT_BREAKER_OPEN");
+        assert!(error.error.contains("default"));
+        assert!(error.retry_after_ms.unwrap() <= 30_000);
+    }
+}
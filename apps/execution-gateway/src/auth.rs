@@ -0,0 +1,61 @@
+use sha2::{Digest, Sha256};
+
+/// Bearer API keys accepted by the HTTP API.
+///
+/// Only SHA-256 digests of the keys are kept, and presented keys are compared
+/// digest-to-digest in constant time so response timing reveals nothing about them.
+pub struct ApiKeyAuth {
+    key_digests: Vec<[u8; 32]>,
+}
+
+impl ApiKeyAuth {
+    pub fn new<I, K>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        Self {
+            key_digests: keys.into_iter().map(|key| digest(key.as_ref())).collect(),
+        }
+    }
+
+    /// Whether `presented` is one of the configured keys
+    pub fn is_authorized(&self, presented: &str) -> bool {
+        let presented = digest(presented);
+        // Check every key so the time taken doesn't depend on which one matched
+        self.key_digests
+            .iter()
+            .fold(false, |matched, key_digest| matched | constant_time_eq(key_digest, &presented))
+    }
+
+    /// Extract and check the key from an `Authorization: Bearer <key>` header value
+    pub fn is_authorized_header(&self, header: Option<&str>) -> bool {
+        header
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|key| self.is_authorized(key.trim()))
+    }
+}
+
+fn digest(key: &str) -> [u8; 32] {
+    Sha256::digest(key.as_bytes()).into()
+}
+
+fn constant_time_eq(a: &[u8; 32], b: &[u8; 32]) -> bool {
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearer_header_checked_against_configured_keys() {
+        let auth = ApiKeyAuth::new(["key-one", "key-two"]);
+
+        assert!(auth.is_authorized_header(Some("Bearer key-two")));
+        assert!(!auth.is_authorized_header(Some("Bearer key-three")));
+        assert!(!auth.is_authorized_header(Some("key-one")));
+        assert!(!auth.is_authorized_header(Some("Basic key-one")));
+        assert!(!auth.is_authorized_header(None));
+    }
+}
@@ -0,0 +1,190 @@
+use rand::Rng;
+use std::future::Future;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+/// Schedule for a single periodic task: a startup offset plus a jittered interval
+#[derive(Debug, Clone)]
+pub struct TaskSchedule {
+    pub name: String,
+    pub base_interval: Duration,
+    pub startup_offset: Duration,
+    pub jitter_pct: f64, // 0.0 to 1.0, fraction of base_interval
+}
+
+impl TaskSchedule {
+    pub fn new(name: String, base_interval: Duration, startup_offset: Duration, jitter_pct: f64) -> Self {
+        Self {
+            name,
+            base_interval,
+            startup_offset,
+            jitter_pct: jitter_pct.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Delay until the next tick: base interval ± jitter
+    pub fn next_delay(&self) -> Duration {
+        let base_ms = self.base_interval.as_millis() as u64;
+        let jitter_range = (base_ms as f64 * self.jitter_pct) as u64;
+        if jitter_range == 0 {
+            return self.base_interval;
+        }
+
+        let mut rng = rand::thread_rng();
+        let jitter = rng.gen_range(0..=jitter_range * 2);
+        Duration::from_millis(base_ms.saturating_sub(jitter_range) + jitter)
+    }
+
+    /// Offsets (from scheduler start) of the first `count` ticks
+    pub fn tick_offsets(&self, count: usize) -> Vec<Duration> {
+        let mut offsets = Vec::with_capacity(count);
+        let mut next = self.startup_offset;
+        for _ in 0..count {
+            offsets.push(next);
+            next += self.next_delay();
+        }
+        offsets
+    }
+}
+
+/// Owns the gateway's periodic background tasks and staggers them so they
+/// don't all contend for the same locks at the same instant
+pub struct BackgroundTasks {
+    max_startup_offset_ms: u64,
+    jitter_pct: f64,
+    schedules: Vec<TaskSchedule>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl BackgroundTasks {
+    pub fn new(max_startup_offset_ms: u64, jitter_pct: f64) -> Self {
+        Self {
+            max_startup_offset_ms,
+            jitter_pct,
+            schedules: Vec::new(),
+            handles: Vec::new(),
+        }
+    }
+
+    /// Create a schedule with a randomized startup offset
+    pub fn schedule(&self, name: &str, base_interval: Duration) -> TaskSchedule {
+        let startup_offset = if self.max_startup_offset_ms > 0 {
+            Duration::from_millis(rand::thread_rng().gen_range(0..=self.max_startup_offset_ms))
+        } else {
+            Duration::ZERO
+        };
+
+        TaskSchedule::new(name.to_string(), base_interval, startup_offset, self.jitter_pct)
+    }
+
+    /// Spawn a periodic task on a staggered, jittered schedule
+    pub fn spawn<F, Fut>(&mut self, name: &str, base_interval: Duration, task: F)
+    where
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let schedule = self.schedule(name, base_interval);
+        info!(
+            "Scheduling background task '{}' every {:?} (startup offset {:?})",
+            schedule.name, schedule.base_interval, schedule.startup_offset
+        );
+
+        let task_schedule = schedule.clone();
+        let handle = tokio::spawn(async move {
+            tokio::time::sleep(task_schedule.startup_offset).await;
+            loop {
+                task().await;
+                tokio::time::sleep(task_schedule.next_delay()).await;
+            }
+        });
+
+        self.schedules.push(schedule);
+        self.handles.push(handle);
+    }
+
+    /// Get the schedules of all spawned tasks
+    pub fn schedules(&self) -> &[TaskSchedule] {
+        &self.schedules
+    }
+
+    /// Stop all background tasks
+    pub fn shutdown(&mut self) {
+        for handle in self.handles.drain(..) {
+            handle.abort();
+        }
+    }
+}
+
+impl Drop for BackgroundTasks {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_distinct_offsets_never_share_a_tick() {
+        let interval = Duration::from_secs(3600);
+        let cleanup = TaskSchedule::new("cleanup".to_string(), interval, Duration::from_millis(0), 0.0);
+        let expiry = TaskSchedule::new("expiry".to_string(), interval, Duration::from_millis(1500), 0.0);
+
+        let cleanup_ticks: HashSet<Duration> = cleanup.tick_offsets(100).into_iter().collect();
+        let expiry_ticks: HashSet<Duration> = expiry.tick_offsets(100).into_iter().collect();
+
+        assert!(cleanup_ticks.is_disjoint(&expiry_ticks));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let schedule = TaskSchedule::new(
+            "cleanup".to_string(),
+            Duration::from_millis(1000),
+            Duration::ZERO,
+            0.1,
+        );
+
+        for _ in 0..1000 {
+            let delay = schedule.next_delay();
+            assert!(delay >= Duration::from_millis(900) && delay <= Duration::from_millis(1100));
+        }
+    }
+
+    #[test]
+    fn test_startup_offset_within_configured_max() {
+        let tasks = BackgroundTasks::new(5000, 0.1);
+        for _ in 0..100 {
+            let schedule = tasks.schedule("cleanup", Duration::from_secs(60));
+            assert!(schedule.startup_offset <= Duration::from_millis(5000));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spawned_task_runs_and_shuts_down() {
+        let mut tasks = BackgroundTasks::new(0, 0.0);
+        let counter = Arc::new(AtomicU32::new(0));
+
+        let task_counter = counter.clone();
+        tasks.spawn("counter", Duration::from_millis(10), move || {
+            let task_counter = task_counter.clone();
+            async move {
+                task_counter.fetch_add(1, Ordering::Relaxed);
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(55)).await;
+        tasks.shutdown();
+        let ticks = counter.load(Ordering::Relaxed);
+        assert!(ticks >= 2);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(counter.load(Ordering::Relaxed), ticks);
+        assert_eq!(tasks.schedules().len(), 1);
+    }
+}
@@ -0,0 +1,387 @@
+use chrono::{DateTime, Utc};
+use rust_common::{
+    Direction, MarketBar, OrderDecision, OrderRequest, OrderSide, OrderStatus, OrderType, TimeInForce, Timeframe, TradingError,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::analytics;
+use crate::{ExchangeAdapter, PaperExchangeAdapter};
+
+/// Starting equity when none is configured
+const DEFAULT_INITIAL_EQUITY: f64 = 10_000.0;
+
+/// Why a backtest trade was closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitReason {
+    StopLoss,
+    TakeProfit,
+    /// Still open on the last bar and closed at its close
+    EndOfData,
+}
+
+/// A round trip simulated by the backtester
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestTrade {
+    pub direction: Direction,
+    pub quantity: f64,
+    pub entry_time: DateTime<Utc>,
+    pub entry_price: f64,
+    pub exit_time: DateTime<Utc>,
+    pub exit_price: f64,
+    pub exit_reason: ExitReason,
+    /// PnL after entry and exit commission
+    pub pnl: f64,
+}
+
+/// Marked-to-market equity at a bar's close
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquityPoint {
+    pub timestamp: DateTime<Utc>,
+    pub equity: f64,
+    /// Fractional decline from the running equity peak
+    pub drawdown: f64,
+}
+
+/// Outcome of a backtest run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestReport {
+    pub initial_equity: f64,
+    pub final_equity: f64,
+    /// Fractional return over the run (0.1 is 10%)
+    pub total_return: f64,
+    /// Fraction of closed trades with positive PnL; 0.0 without trades
+    pub win_rate: f64,
+    /// Largest fractional peak-to-trough decline of the equity curve
+    pub max_drawdown: f64,
+    /// Annualized Sharpe ratio of per-bar returns, assuming a zero risk-free rate
+    pub sharpe_ratio: f64,
+    pub trades: Vec<BacktestTrade>,
+    pub equity_curve: Vec<EquityPoint>,
+}
+
+/// Position opened by a decision, waiting for its stop, take-profit or the end of data
+#[derive(Debug, Clone)]
+struct OpenTrade {
+    direction: Direction,
+    quantity: f64,
+    entry_time: DateTime<Utc>,
+    entry_price: f64,
+    entry_commission: f64,
+    stop_loss: Option<f64>,
+    take_profit: Option<f64>,
+}
+
+impl OpenTrade {
+    /// Signed PnL before commission if closed at `price`
+    fn gross_pnl(&self, price: f64) -> f64 {
+        let sign = match self.direction {
+            Direction::Long => 1.0,
+            Direction::Short => -1.0,
+        };
+        sign * self.quantity * (price - self.entry_price)
+    }
+
+    /// Marked-to-market PnL at `price`, net of the commission already paid on entry
+    fn unrealized_pnl(&self, price: f64) -> f64 {
+        self.gross_pnl(price) - self.entry_commission
+    }
+
+    /// Exit price and reason if `bar` reaches the stop or take-profit.
+    ///
+    /// A bar that gaps through a level fills at its open. When a bar spans both levels
+    /// the stop is assumed to have been hit first.
+    fn exit_within(&self, bar: &MarketBar) -> Option<(f64, ExitReason)> {
+        match self.direction {
+            Direction::Long => {
+                if let Some(stop) = self.stop_loss.filter(|stop| bar.low <= *stop) {
+                    return Some((bar.open.min(stop), ExitReason::StopLoss));
+                }
+                self.take_profit
+                    .filter(|target| bar.high >= *target)
+                    .map(|target| (bar.open.max(target), ExitReason::TakeProfit))
+            }
+            Direction::Short => {
+                if let Some(stop) = self.stop_loss.filter(|stop| bar.high >= *stop) {
+                    return Some((bar.open.max(stop), ExitReason::StopLoss));
+                }
+                self.take_profit
+                    .filter(|target| bar.low <= *target)
+                    .map(|target| (bar.open.min(target), ExitReason::TakeProfit))
+            }
+        }
+    }
+}
+
+/// Replays a bar series through a strategy, filling its decisions on a paper exchange.
+///
+/// Decisions made on a bar's close fill at the next bar's open. One trade is open at a
+/// time; decisions made while a trade is open are ignored.
+pub struct Backtester {
+    bars: Vec<MarketBar>,
+    adapter: PaperExchangeAdapter,
+    initial_equity: f64,
+}
+
+impl Backtester {
+    pub fn new(bars: Vec<MarketBar>, adapter: PaperExchangeAdapter) -> Self {
+        Self {
+            bars,
+            adapter,
+            initial_equity: DEFAULT_INITIAL_EQUITY,
+        }
+    }
+
+    pub fn with_initial_equity(mut self, initial_equity: f64) -> Self {
+        self.initial_equity = initial_equity;
+        self
+    }
+
+    /// Run `strategy` over the bars; it sees every bar up to and including the current one
+    pub async fn run<F>(&self, mut strategy: F) -> Result<BacktestReport, TradingError>
+    where
+        F: FnMut(&[MarketBar]) -> Option<OrderDecision>,
+    {
+        let mut cash = self.initial_equity;
+        let mut open_trade: Option<OpenTrade> = None;
+        let mut pending: Option<OrderDecision> = None;
+        let mut trades = Vec::new();
+        let mut equity_curve: Vec<EquityPoint> = Vec::with_capacity(self.bars.len());
+        let mut peak = self.initial_equity;
+
+        for (index, bar) in self.bars.iter().enumerate() {
+            if let Some(decision) = pending.take() {
+                open_trade = Some(self.enter(&decision, bar).await?);
+            }
+
+            if let Some((price, reason)) = open_trade.as_ref().and_then(|trade| trade.exit_within(bar)) {
+                if let Some(trade) = open_trade.take() {
+                    let closed = self.exit(trade, bar, price, reason).await?;
+                    cash += closed.pnl;
+                    trades.push(closed);
+                }
+            }
+
+            let equity = cash + open_trade.as_ref().map_or(0.0, |trade| trade.unrealized_pnl(bar.close));
+            peak = peak.max(equity);
+            equity_curve.push(EquityPoint {
+                timestamp: bar.timestamp,
+                equity,
+                drawdown: if peak > 0.0 { (peak - equity) / peak } else { 0.0 },
+            });
+
+            let decision = strategy(&self.bars[..=index]);
+            if open_trade.is_none() {
+                pending = decision.filter(|decision| decision.risk_adjusted_quantity > 0.0);
+            }
+        }
+
+        if let (Some(trade), Some(last)) = (open_trade.take(), self.bars.last()) {
+            let closed = self.exit(trade, last, last.close, ExitReason::EndOfData).await?;
+            cash += closed.pnl;
+            trades.push(closed);
+            if let Some(point) = equity_curve.last_mut() {
+                point.equity = cash;
+                point.drawdown = if peak > 0.0 { (peak - cash).max(0.0) / peak } else { 0.0 };
+            }
+        }
+
+        Ok(self.report(cash, trades, equity_curve))
+    }
+
+    async fn enter(&self, decision: &OrderDecision, bar: &MarketBar) -> Result<OpenTrade, TradingError> {
+        let side = match decision.direction {
+            Direction::Long => OrderSide::Buy,
+            Direction::Short => OrderSide::Sell,
+        };
+        let (quantity, entry_price, entry_commission) =
+            self.fill(&decision.symbol, side, decision.risk_adjusted_quantity, bar.open, false).await?;
+        Ok(OpenTrade {
+            direction: decision.direction,
+            quantity,
+            entry_time: bar.timestamp,
+            entry_price,
+            entry_commission,
+            stop_loss: Some(decision.stop_loss).filter(|stop| *stop > 0.0),
+            take_profit: decision.take_profit.filter(|target| *target > 0.0),
+        })
+    }
+
+    async fn exit(&self, trade: OpenTrade, bar: &MarketBar, price: f64, reason: ExitReason) -> Result<BacktestTrade, TradingError> {
+        let side = match trade.direction {
+            Direction::Long => OrderSide::Sell,
+            Direction::Short => OrderSide::Buy,
+        };
+        let (_, exit_price, exit_commission) = self.fill(&bar.symbol, side, trade.quantity, price, true).await?;
+        let pnl = trade.gross_pnl(exit_price) - trade.entry_commission - exit_commission;
+        Ok(BacktestTrade {
+            direction: trade.direction,
+            quantity: trade.quantity,
+            entry_time: trade.entry_time,
+            entry_price: trade.entry_price,
+            exit_time: bar.timestamp,
+            exit_price,
+            exit_reason: reason,
+            pnl,
+        })
+    }
+
+    /// Fill a market order at `price` on the paper exchange: (quantity, price, commission)
+    async fn fill(&self, symbol: &str, side: OrderSide, quantity: f64, price: f64, reduce_only: bool) -> Result<(f64, f64, f64), TradingError> {
+        self.adapter.set_price(price);
+        let result = self.adapter.place_order(OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: symbol.to_string(),
+            side,
+            size: quantity,
+            price: None,
+            order_type: OrderType::Market,
+            timestamp: Utc::now(),
+            reduce_only,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        }).await?;
+        if result.status != OrderStatus::Filled {
+            return Err(TradingError::ExecutionError {
+                message: format!("Paper order for {} was not filled: {:?}", symbol, result.status),
+            });
+        }
+        Ok((result.filled_quantity, result.average_price.unwrap_or(price), result.commission))
+    }
+
+    fn report(&self, final_equity: f64, trades: Vec<BacktestTrade>, equity_curve: Vec<EquityPoint>) -> BacktestReport {
+        let total_return = if self.initial_equity > 0.0 {
+            final_equity / self.initial_equity - 1.0
+        } else {
+            0.0
+        };
+        let win_rate = if trades.is_empty() {
+            0.0
+        } else {
+            trades.iter().filter(|trade| trade.pnl > 0.0).count() as f64 / trades.len() as f64
+        };
+        let max_drawdown = equity_curve.iter().map(|point| point.drawdown).fold(0.0, f64::max);
+        let curve: Vec<(DateTime<Utc>, f64)> = equity_curve.iter().map(|point| (point.timestamp, point.equity)).collect();
+        let timeframe = self.bars.first().map_or(Timeframe::D1, |bar| bar.timeframe);
+
+        BacktestReport {
+            initial_equity: self.initial_equity,
+            final_equity,
+            total_return,
+            win_rate,
+            max_drawdown,
+            sharpe_ratio: analytics::sharpe_ratio(&analytics::returns(&curve), 0.0, timeframe),
+            trades,
+            equity_curve,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CommissionModel;
+    use chrono::{Duration, TimeZone};
+
+    /// Daily bars from (open, high, low, close) tuples
+    fn bars(prices: &[(f64, f64, f64, f64)]) -> Vec<MarketBar> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        prices
+            .iter()
+            .enumerate()
+            .map(|(day, &(open, high, low, close))| MarketBar {
+                symbol: "BTCUSD".to_string(),
+                timeframe: Timeframe::D1,
+                timestamp: start + Duration::days(day as i64),
+                open,
+                high,
+                low,
+                close,
+                volume: 1000.0,
+                quote_volume: None,
+                trades_count: None,
+                taker_buy_volume: None,
+            })
+            .collect()
+    }
+
+    fn long(quantity: f64, stop_loss: f64, take_profit: Option<f64>) -> OrderDecision {
+        let mut decision = OrderDecision::new("backtest".to_string(), "BTCUSD".to_string());
+        decision.direction = Direction::Long;
+        decision.risk_adjusted_quantity = quantity;
+        decision.stop_loss = stop_loss;
+        decision.take_profit = take_profit;
+        decision
+    }
+
+    #[tokio::test]
+    async fn test_buy_and_hold_on_uptrend_reports_positive_return() {
+        // Noisy uptrend: +1% a bar with an occasional down day
+        let mut close: f64 = 100.0;
+        let mut prices = Vec::new();
+        for day in 0..60 {
+            let open = close;
+            close = if day % 5 == 4 { open * 0.995 } else { open * 1.01 };
+            prices.push((open, open.max(close) * 1.002, open.min(close) * 0.998, close));
+        }
+        let series = bars(&prices);
+        let first_open = series[1].open;
+        let last_close = series[59].close;
+
+        let adapter = PaperExchangeAdapter::new().with_commission_model(CommissionModel::FlatBps(0.0));
+        let report = Backtester::new(series, adapter)
+            .with_initial_equity(10_000.0)
+            .run(|history| (history.len() == 1).then(|| long(10.0, 0.0, None)))
+            .await
+            .unwrap();
+
+        assert_eq!(report.trades.len(), 1);
+        let trade = &report.trades[0];
+        assert_eq!(trade.exit_reason, ExitReason::EndOfData);
+        assert_eq!(trade.entry_price, first_open);
+        assert!((trade.pnl - 10.0 * (last_close - first_open)).abs() < 1e-6);
+
+        assert!(report.total_return > 0.0);
+        assert!((report.final_equity - (10_000.0 + trade.pnl)).abs() < 1e-6);
+        assert_eq!(report.win_rate, 1.0);
+        assert!(report.max_drawdown > 0.0 && report.max_drawdown < 0.01);
+        assert!(report.sharpe_ratio > 0.0);
+        assert_eq!(report.equity_curve.len(), 60);
+    }
+
+    #[tokio::test]
+    async fn test_stop_and_take_profit_checked_against_bar_range() {
+        let series = bars(&[
+            (100.0, 101.0, 99.0, 100.0),
+            (100.0, 103.0, 99.0, 102.0),  // entry at 100
+            (102.0, 111.0, 101.0, 110.0), // take-profit at 110
+            (110.0, 111.0, 109.0, 110.0), // re-entry at 110
+            (104.0, 105.0, 100.0, 101.0), // gaps through the 105 stop, filled at the open
+        ]);
+        let adapter = PaperExchangeAdapter::new().with_commission_model(CommissionModel::Fixed(1.0));
+        let report = Backtester::new(series, adapter)
+            .run(|history| match history.len() {
+                1 => Some(long(1.0, 95.0, Some(110.0))),
+                3 => Some(long(1.0, 105.0, None)),
+                _ => None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(report.trades.len(), 2);
+        let take_profit = &report.trades[0];
+        assert_eq!(take_profit.exit_reason, ExitReason::TakeProfit);
+        assert_eq!((take_profit.entry_price, take_profit.exit_price), (100.0, 110.0));
+        assert!((take_profit.pnl - 8.0).abs() < 1e-9);
+
+        let stop = &report.trades[1];
+        assert_eq!(stop.exit_reason, ExitReason::StopLoss);
+        assert_eq!((stop.entry_price, stop.exit_price), (110.0, 104.0));
+        assert!((stop.pnl - -8.0).abs() < 1e-9);
+
+        assert_eq!(report.win_rate, 0.5);
+        assert!((report.final_equity - DEFAULT_INITIAL_EQUITY).abs() < 1e-9);
+        assert!(report.max_drawdown > 0.0);
+    }
+}
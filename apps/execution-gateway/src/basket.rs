@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use rust_common::ExecutionResult;
+use serde::{Deserialize, Serialize};
+
+use super::OrderExecutionStatus;
+
+/// Overall state of a basket of orders placed together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BasketState {
+    /// Every leg was accepted and at least one is still working
+    Working,
+    /// Every leg is completely filled
+    Filled,
+    /// A leg failed and the others were left in place (non-atomic baskets)
+    PartiallyFailed,
+    /// A leg failed and the others were cancelled or reversed (atomic baskets)
+    RolledBack,
+}
+
+/// One leg of a basket as last seen by the gateway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasketLegStatus {
+    pub decision_id: String,
+    pub symbol: String,
+    /// `None` if the leg never reached an exchange
+    pub order_id: Option<String>,
+    /// Current status of the leg's order, once it has one that is still tracked
+    pub status: Option<OrderExecutionStatus>,
+    pub error: Option<String>,
+    /// Offsetting order that unwound the leg's fills during a rollback
+    pub reversal_order_id: Option<String>,
+}
+
+/// A basket with the state of each of its legs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasketStatus {
+    pub basket_id: String,
+    pub atomic: bool,
+    pub created_at: DateTime<Utc>,
+    pub state: BasketState,
+    pub legs: Vec<BasketLegStatus>,
+}
+
+impl BasketStatus {
+    /// Derive the overall state from the legs
+    pub(super) fn refresh_state(&mut self) {
+        if self.state == BasketState::RolledBack {
+            return;
+        }
+        self.state = if self.legs.iter().any(|leg| leg.error.is_some()) {
+            BasketState::PartiallyFailed
+        } else if self.legs.iter().all(|leg| matches!(leg.status, Some(OrderExecutionStatus::Filled))) {
+            BasketState::Filled
+        } else {
+            BasketState::Working
+        };
+    }
+}
+
+/// Outcome of placing every leg of a basket, in leg order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasketExecutionResult {
+    pub basket_id: String,
+    pub state: BasketState,
+    /// Placement result of each leg; `None` where the leg failed
+    pub results: Vec<Option<ExecutionResult>>,
+}
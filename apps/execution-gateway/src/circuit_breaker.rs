@@ -1,8 +1,10 @@
+use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Circuit breaker states
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CircuitBreakerState {
     Closed,   // Normal operation
     Open,     // Failing, blocking requests
@@ -15,7 +17,10 @@ pub struct CircuitBreaker {
     recovery_timeout_ms: u64,
     failure_count: AtomicU32,
     last_failure_time: AtomicU64,
+    last_success_time: AtomicU64, // unix millis; 0 until the first success
     state: std::sync::RwLock<CircuitBreakerState>,
+    half_open_max_probes: u32,
+    in_flight_probes: AtomicU32,
 }
 
 impl CircuitBreaker {
@@ -25,10 +30,35 @@ impl CircuitBreaker {
             recovery_timeout_ms,
             failure_count: AtomicU32::new(0),
             last_failure_time: AtomicU64::new(0),
+            last_success_time: AtomicU64::new(0),
             state: std::sync::RwLock::new(CircuitBreakerState::Closed),
+            half_open_max_probes: 1,
+            in_flight_probes: AtomicU32::new(0),
         }
     }
 
+    /// Limit how many requests are admitted while half-open
+    pub fn with_half_open_max_probes(mut self, half_open_max_probes: u32) -> Self {
+        self.half_open_max_probes = half_open_max_probes.max(1);
+        self
+    }
+
+    /// Admit a half-open probe if the probe limit allows it
+    fn try_acquire_probe(&self) -> bool {
+        self.in_flight_probes
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |probes| {
+                (probes < self.half_open_max_probes).then_some(probes + 1)
+            })
+            .is_ok()
+    }
+
+    /// Release a half-open probe slot without recording an outcome, e.g. when the
+    /// request failed for a reason that says nothing about exchange health
+    pub fn release_probe(&self) {
+        let _ = self.in_flight_probes
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |probes| probes.checked_sub(1));
+    }
+
     /// Check if circuit breaker is open (blocking requests)
     pub fn is_open(&self) -> bool {
         let state = *self.state.read().unwrap();
@@ -43,21 +73,41 @@ impl CircuitBreaker {
                 
                 let last_failure = self.last_failure_time.load(Ordering::Relaxed);
                 
-                if now - last_failure > self.recovery_timeout_ms {
+                // The clock moved backwards (e.g. NTP adjustment): stay open and
+                // restart the recovery timeout from the current clock
+                if now < last_failure {
+                    self.last_failure_time.store(now, Ordering::Relaxed);
+                    return true;
+                }
+                
+                if now.saturating_sub(last_failure) > self.recovery_timeout_ms {
                     // Transition to half-open to test recovery
-                    *self.state.write().unwrap() = CircuitBreakerState::HalfOpen;
-                    false
+                    {
+                        let mut state = self.state.write().unwrap();
+                        if *state == CircuitBreakerState::Open {
+                            *state = CircuitBreakerState::HalfOpen;
+                            self.in_flight_probes.store(0, Ordering::Release);
+                        }
+                    }
+                    !self.try_acquire_probe()
                 } else {
                     true
                 }
             }
-            CircuitBreakerState::HalfOpen => false, // Allow one test request
+            // Only admit up to half_open_max_probes test requests
+            CircuitBreakerState::HalfOpen => !self.try_acquire_probe(),
             CircuitBreakerState::Closed => false,
         }
     }
 
     /// Record a successful operation
     pub fn record_success(&self) {
+        self.release_probe();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        self.last_success_time.store(now, Ordering::Relaxed);
         let mut state = self.state.write().unwrap();
         
         match *state {
@@ -80,6 +130,7 @@ impl CircuitBreaker {
 
     /// Record a failed operation
     pub fn record_failure(&self) {
+        self.release_probe();
         let failure_count = self.failure_count.fetch_add(1, Ordering::Relaxed) + 1;
         
         let now = SystemTime::now()
@@ -112,6 +163,33 @@ impl CircuitBreaker {
         *self.state.read().unwrap()
     }
 
+    /// Estimated time until an open breaker admits a probe, in milliseconds; 0 otherwise
+    pub fn retry_after_ms(&self) -> u64 {
+        if self.get_state() != CircuitBreakerState::Open {
+            return 0;
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        let elapsed = now.saturating_sub(self.last_failure_time.load(Ordering::Relaxed));
+        self.recovery_timeout_ms.saturating_sub(elapsed)
+    }
+
+    /// Whether the breaker is open and still inside its recovery timeout.
+    /// Unlike `is_open`, this never admits a half-open probe.
+    pub fn is_tripped(&self) -> bool {
+        self.retry_after_ms() > 0
+    }
+
+    /// When the last successful operation was recorded, in unix milliseconds
+    pub fn last_success_ms(&self) -> Option<u64> {
+        match self.last_success_time.load(Ordering::Relaxed) {
+            0 => None,
+            millis => Some(millis),
+        }
+    }
+
     /// Get current failure count
     pub fn get_failure_count(&self) -> u32 {
         self.failure_count.load(Ordering::Relaxed)
@@ -194,6 +272,73 @@ mod tests {
         assert_eq!(cb.get_state(), CircuitBreakerState::HalfOpen);
     }
 
+    #[test]
+    fn test_circuit_breaker_clock_moved_backwards() {
+        let cb = CircuitBreaker::new(2, 100);
+        cb.force_open();
+        
+        // Simulate the clock stepping back an hour after the last failure
+        let future = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64 + 3_600_000;
+        cb.last_failure_time.store(future, Ordering::Relaxed);
+        
+        // Stays open rather than underflowing or closing spuriously
+        assert!(cb.is_open());
+        assert_eq!(cb.get_state(), CircuitBreakerState::Open);
+        
+        // Recovery is measured from the corrected clock, not the skewed timestamp
+        thread::sleep(Duration::from_millis(150));
+        assert!(!cb.is_open());
+        assert_eq!(cb.get_state(), CircuitBreakerState::HalfOpen);
+    }
+
+    #[test]
+    fn test_half_open_admits_limited_probes() {
+        let cb = std::sync::Arc::new(CircuitBreaker::new(2, 50).with_half_open_max_probes(3));
+        cb.force_open();
+        thread::sleep(Duration::from_millis(100));
+        
+        let barrier = std::sync::Arc::new(std::sync::Barrier::new(50));
+        let handles: Vec<_> = (0..50)
+            .map(|_| {
+                let cb = cb.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    !cb.is_open()
+                })
+            })
+            .collect();
+        
+        let admitted = handles.into_iter()
+            .map(|handle| handle.join().unwrap())
+            .filter(|admitted| *admitted)
+            .count();
+        assert_eq!(admitted, 3);
+        assert_eq!(cb.get_state(), CircuitBreakerState::HalfOpen);
+        
+        // A finished probe frees its slot for the next request
+        cb.release_probe();
+        assert!(!cb.is_open());
+        assert!(cb.is_open());
+    }
+
+    #[test]
+    fn test_half_open_defaults_to_single_probe() {
+        let cb = CircuitBreaker::new(2, 50);
+        cb.force_open();
+        thread::sleep(Duration::from_millis(100));
+        
+        assert!(!cb.is_open());
+        assert!(cb.is_open());
+        
+        cb.record_success();
+        assert_eq!(cb.get_state(), CircuitBreakerState::Closed);
+        assert!(!cb.is_open());
+    }
+
     #[test]
     fn test_circuit_breaker_half_open_success() {
         let cb = CircuitBreaker::new(2, 100);
@@ -0,0 +1,218 @@
+use chrono::{DateTime, Utc};
+use rust_common::{ExecutionResult, MarketBar, OrderDecision, Quote, Symbol, TradingError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::ExecutionGateway;
+
+/// Side of the trigger price the market must reach
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerDirection {
+    /// Fires once the price is at or above the trigger
+    Above,
+    /// Fires once the price is at or below the trigger
+    Below,
+}
+
+/// Order decision held in the gateway until the market reaches a trigger price.
+///
+/// Unlike exchange-native stops, nothing is sent to the exchange until the trigger fires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionalOrder {
+    pub id: String,
+    pub trigger_price: f64,
+    pub trigger_direction: TriggerDirection,
+    pub decision: OrderDecision,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ConditionalOrder {
+    pub fn new(trigger_price: f64, trigger_direction: TriggerDirection, decision: OrderDecision) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            trigger_price,
+            trigger_direction,
+            decision,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Whether trading anywhere between `low` and `high` reaches the trigger
+    pub fn is_triggered(&self, low: f64, high: f64) -> bool {
+        match self.trigger_direction {
+            TriggerDirection::Above => high >= self.trigger_price,
+            TriggerDirection::Below => low <= self.trigger_price,
+        }
+    }
+}
+
+/// Holds conditional orders and submits each one's decision through the gateway, exactly
+/// once, when a price update reaches its trigger. Callers feed it market data.
+pub struct ConditionalOrderMonitor {
+    gateway: Arc<ExecutionGateway>,
+    pending: Mutex<HashMap<String, ConditionalOrder>>,
+}
+
+impl ConditionalOrderMonitor {
+    pub fn new(gateway: Arc<ExecutionGateway>) -> Self {
+        Self {
+            gateway,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Hold `order` until its trigger fires; returns its id
+    pub async fn add(&self, order: ConditionalOrder) -> Result<String, TradingError> {
+        if !(order.trigger_price.is_finite() && order.trigger_price > 0.0) {
+            return Err(TradingError::ExecutionError {
+                message: format!("Invalid trigger price: {}", order.trigger_price),
+            });
+        }
+        let id = order.id.clone();
+        self.pending.lock().await.insert(id.clone(), order);
+        Ok(id)
+    }
+
+    /// Withdraw a pending conditional order; `None` if it already fired or never existed
+    pub async fn cancel(&self, id: &str) -> Option<ConditionalOrder> {
+        self.pending.lock().await.remove(id)
+    }
+
+    /// Pending conditional orders, oldest first
+    pub async fn pending(&self) -> Vec<ConditionalOrder> {
+        let mut pending: Vec<ConditionalOrder> = self.pending.lock().await.values().cloned().collect();
+        pending.sort_by_key(|order| order.created_at);
+        pending
+    }
+
+    /// Feed a traded or mark price
+    pub async fn on_price(&self, symbol: &str, price: f64) -> Vec<(String, Result<ExecutionResult, TradingError>)> {
+        self.on_range(symbol, price, price).await
+    }
+
+    /// Feed a bar; a trigger inside its high-low range fires
+    pub async fn on_bar(&self, bar: &MarketBar) -> Vec<(String, Result<ExecutionResult, TradingError>)> {
+        self.on_range(&bar.symbol, bar.low, bar.high).await
+    }
+
+    /// Feed a quote, triggering on its mid
+    pub async fn on_quote(&self, quote: &Quote) -> Vec<(String, Result<ExecutionResult, TradingError>)> {
+        self.on_price(&quote.symbol, quote.mid()).await
+    }
+
+    /// Submit every conditional order on `symbol` triggered by trading between `low` and `high`,
+    /// returning each one's id and placement result
+    async fn on_range(&self, symbol: &str, low: f64, high: f64) -> Vec<(String, Result<ExecutionResult, TradingError>)> {
+        let symbol = Symbol::from(symbol);
+        // Removed under the lock so a concurrent update can't fire the same order twice
+        let mut triggered: Vec<ConditionalOrder> = {
+            let mut pending = self.pending.lock().await;
+            let triggered_ids: Vec<String> = pending.values()
+                .filter(|order| Symbol::from(&order.decision.symbol) == symbol && order.is_triggered(low, high))
+                .map(|order| order.id.clone())
+                .collect();
+            triggered_ids.iter().filter_map(|id| pending.remove(id)).collect()
+        };
+        triggered.sort_by_key(|order| order.created_at);
+
+        let mut results = Vec::with_capacity(triggered.len());
+        for order in triggered {
+            info!(
+                "Conditional order {} triggered at {:?} {}; submitting decision {}",
+                order.id, order.trigger_direction, order.trigger_price, order.decision.decision_id
+            );
+            let result = self.gateway.place_order(order.decision).await;
+            if let Err(e) = &result {
+                warn!("Conditional order {} triggered but placement failed: {}", order.id, e);
+            }
+            results.push((order.id, result));
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GatewayConfig, MockExchangeAdapter};
+    use rust_common::{Direction, OrderType, Timeframe};
+
+    fn create_breakout_decision() -> OrderDecision {
+        let mut decision = OrderDecision::new("breakout_signal".to_string(), "BTCUSD");
+        decision.direction = Direction::Long;
+        decision.order_type = OrderType::Limit;
+        decision.risk_adjusted_quantity = 0.1;
+        decision.entry_price = 51000.0;
+        decision.stop_loss = 50000.0;
+        decision.risk_amount = 100.0;
+        decision.risk_percentage = 1.0;
+        decision.portfolio_value = 10000.0;
+        decision.available_margin = 5000.0;
+        decision.confidence_score = 0.8;
+        decision.confluence_score = 75.0;
+        decision.risk_reward_ratio = 2.0;
+        decision
+    }
+
+    async fn create_monitor() -> (ConditionalOrderMonitor, Arc<std::sync::Mutex<Vec<rust_common::OrderRequest>>>) {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        (ConditionalOrderMonitor::new(gateway), placed_orders)
+    }
+
+    #[tokio::test]
+    async fn test_conditional_order_submits_once_when_trigger_crossed() {
+        let (monitor, placed_orders) = create_monitor().await;
+        let id = monitor.add(ConditionalOrder::new(51000.0, TriggerDirection::Above, create_breakout_decision())).await.unwrap();
+
+        // Below the trigger, or on another symbol: nothing happens
+        assert!(monitor.on_price("BTCUSD", 50500.0).await.is_empty());
+        assert!(monitor.on_price("ETHUSD", 52000.0).await.is_empty());
+        assert_eq!(monitor.pending().await.len(), 1);
+
+        // A bar whose high crosses the trigger fires it, whatever the symbol's spelling
+        let bar = MarketBar {
+            symbol: "BTC-USD".to_string(),
+            timeframe: Timeframe::M1,
+            timestamp: Utc::now(),
+            open: 50800.0,
+            high: 51050.0,
+            low: 50700.0,
+            close: 50900.0,
+            volume: 12.0,
+            quote_volume: None,
+            trades_count: None,
+            taker_buy_volume: None,
+        };
+        let results = monitor.on_bar(&bar).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, id);
+        assert!(results[0].1.is_ok());
+
+        // One-shot: further crossings don't resubmit
+        assert!(monitor.on_price("BTCUSD", 51200.0).await.is_empty());
+        assert!(monitor.pending().await.is_empty());
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_conditional_order_never_submits() {
+        let (monitor, placed_orders) = create_monitor().await;
+        let id = monitor.add(ConditionalOrder::new(49000.0, TriggerDirection::Below, create_breakout_decision())).await.unwrap();
+
+        assert!(monitor.cancel(&id).await.is_some());
+        assert!(monitor.cancel(&id).await.is_none());
+        assert!(monitor.on_price("BTCUSD", 48000.0).await.is_empty());
+        assert!(placed_orders.lock().unwrap().is_empty());
+
+        let invalid = ConditionalOrder::new(f64::NAN, TriggerDirection::Below, create_breakout_decision());
+        assert!(monitor.add(invalid).await.is_err());
+    }
+}
@@ -0,0 +1,307 @@
+use chrono::{DateTime, Utc};
+use rust_common::TradingError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::{OrderLifecycleState, StateTransition};
+
+/// One change to an order's lifecycle, in the order it was applied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLogEntry {
+    pub sequence: u64,
+    pub order_id: String,
+    pub record: OrderEventRecord,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OrderEventRecord {
+    Created {
+        client_id: Uuid,
+        symbol: String,
+        created_at: DateTime<Utc>,
+        expires_at: Option<DateTime<Utc>>,
+    },
+    Transition(StateTransition),
+}
+
+/// One line of a persisted log
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum LogLine {
+    Entry(EventLogEntry),
+    /// Written first by compaction, so sequence numbers survive it across restarts
+    Sequence { last_sequence: u64 },
+}
+
+#[derive(Default)]
+struct LogState {
+    entries: Vec<EventLogEntry>,
+    next_sequence: u64,
+    file: Option<File>,
+}
+
+/// Append-only log of order lifecycle events, replayable into an `OrderManager`.
+///
+/// Sequence numbers start at 1 and keep increasing across compactions. A log opened
+/// from a file writes each entry through to it as a JSON line before accepting it.
+#[derive(Default)]
+pub struct EventLog {
+    state: Mutex<LogState>,
+    path: Option<PathBuf>,
+}
+
+impl EventLog {
+    /// Log that lives only as long as the process
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the log persisted at `path`, loading the entries a previous run wrote.
+    ///
+    /// A torn final line left by a crash mid-append is dropped; any other unreadable line is an error.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, TradingError> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(io_error("read", &path, e)),
+        };
+
+        let mut state = LogState::default();
+        let mut torn = false;
+        let lines: Vec<&str> = contents.lines().filter(|line| !line.trim().is_empty()).collect();
+        for (index, line) in lines.iter().enumerate() {
+            match serde_json::from_str(line) {
+                Ok(LogLine::Entry(entry)) => {
+                    state.next_sequence = state.next_sequence.max(entry.sequence);
+                    state.entries.push(entry);
+                }
+                Ok(LogLine::Sequence { last_sequence }) => state.next_sequence = last_sequence,
+                Err(e) if index + 1 == lines.len() && !contents.ends_with('\n') => {
+                    warn!("Dropping torn last entry of event log {}: {}", path.display(), e);
+                    torn = true;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let file = Self::open_for_append(&path).await?;
+        if torn {
+            // Later appends must start on a fresh line
+            let valid_len = contents.rfind('\n').map_or(0, |newline| newline + 1);
+            file.set_len(valid_len as u64).await.map_err(|e| io_error("truncate", &path, e))?;
+        }
+        state.file = Some(file);
+        Ok(Self {
+            state: Mutex::new(state),
+            path: Some(path),
+        })
+    }
+
+    async fn open_for_append(path: &Path) -> Result<File, TradingError> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+            .map_err(|e| io_error("open", path, e))
+    }
+
+    /// Append a record and return its sequence number; a persisted log has synced it to disk first
+    pub async fn append(&self, order_id: &str, record: OrderEventRecord) -> Result<u64, TradingError> {
+        let mut state = self.state.lock().await;
+        let entry = EventLogEntry {
+            sequence: state.next_sequence + 1,
+            order_id: order_id.to_string(),
+            record,
+        };
+        if let (Some(file), Some(path)) = (state.file.as_mut(), &self.path) {
+            let mut line = serde_json::to_vec(&LogLine::Entry(entry.clone()))?;
+            line.push(b'\n');
+            file.write_all(&line).await.map_err(|e| io_error("append to", path, e))?;
+            file.sync_data().await.map_err(|e| io_error("sync", path, e))?;
+        }
+        state.next_sequence = entry.sequence;
+        state.entries.push(entry);
+        Ok(state.next_sequence)
+    }
+
+    /// All retained entries, oldest first
+    pub async fn entries(&self) -> Vec<EventLogEntry> {
+        self.state.lock().await.entries.clone()
+    }
+
+    /// Entries with a sequence number greater than `sequence`
+    pub async fn entries_after(&self, sequence: u64) -> Vec<EventLogEntry> {
+        let state = self.state.lock().await;
+        let start = state.entries.partition_point(|entry| entry.sequence <= sequence);
+        state.entries[start..].to_vec()
+    }
+
+    pub async fn len(&self) -> usize {
+        self.state.lock().await.entries.len()
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.state.lock().await.entries.is_empty()
+    }
+
+    /// Sequence number of the most recent append, or 0 if nothing was ever appended
+    pub async fn last_sequence(&self) -> u64 {
+        self.state.lock().await.next_sequence
+    }
+
+    /// Drop every entry belonging to an order whose latest state is terminal.
+    /// Returns the number of entries removed.
+    ///
+    /// A persisted log is rewritten through a temporary file and rename, so a crash
+    /// leaves either the old or the compacted log on disk.
+    pub async fn compact(&self) -> Result<usize, TradingError> {
+        let mut state = self.state.lock().await;
+
+        let mut latest: HashMap<&str, &OrderLifecycleState> = HashMap::new();
+        for entry in &state.entries {
+            if let OrderEventRecord::Transition(transition) = &entry.record {
+                latest.insert(&entry.order_id, &transition.to_state);
+            }
+        }
+        let terminal: Vec<String> = latest
+            .into_iter()
+            .filter(|(_, state)| state.is_terminal())
+            .map(|(order_id, _)| order_id.to_string())
+            .collect();
+
+        let retained: Vec<EventLogEntry> = state.entries.iter()
+            .filter(|entry| !terminal.contains(&entry.order_id))
+            .cloned()
+            .collect();
+        let removed = state.entries.len() - retained.len();
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        if let Some(path) = &self.path {
+            let mut contents = serde_json::to_vec(&LogLine::Sequence { last_sequence: state.next_sequence })?;
+            contents.push(b'\n');
+            for entry in &retained {
+                contents.extend(serde_json::to_vec(&LogLine::Entry(entry.clone()))?);
+                contents.push(b'\n');
+            }
+
+            let temp_path = path.with_extension("tmp");
+            let mut temp_file = File::create(&temp_path).await.map_err(|e| io_error("write", &temp_path, e))?;
+            temp_file.write_all(&contents).await.map_err(|e| io_error("write", &temp_path, e))?;
+            temp_file.sync_all().await.map_err(|e| io_error("sync", &temp_path, e))?;
+            tokio::fs::rename(&temp_path, path).await.map_err(|e| io_error("replace", path, e))?;
+            sync_parent_dir(path).await.map_err(|e| io_error("sync the directory of", path, e))?;
+            state.file = Some(Self::open_for_append(path).await?);
+        }
+
+        state.entries = retained;
+        Ok(removed)
+    }
+}
+
+/// Flush a rename or file creation in `path`'s directory to disk
+pub(crate) async fn sync_parent_dir(path: &Path) -> std::io::Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir).await?.sync_all().await
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> TradingError {
+    TradingError::ExecutionError {
+        message: format!("Failed to {} event log {}: {}", action, path.display(), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from_state: OrderLifecycleState, to_state: OrderLifecycleState) -> OrderEventRecord {
+        OrderEventRecord::Transition(StateTransition {
+            from_state,
+            to_state,
+            timestamp: Utc::now(),
+            reason: "test".to_string(),
+            metadata: HashMap::new(),
+        })
+    }
+
+    fn created() -> OrderEventRecord {
+        OrderEventRecord::Created {
+            client_id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            created_at: Utc::now(),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compact_keeps_sequence_and_open_orders() {
+        use OrderLifecycleState::*;
+
+        let log = EventLog::new();
+        assert_eq!(log.append("done", created()).await.unwrap(), 1);
+        log.append("open", created()).await.unwrap();
+        log.append("done", transition(Created, Validated)).await.unwrap();
+        log.append("open", transition(Created, Validated)).await.unwrap();
+        log.append("done", transition(Validated, Rejected)).await.unwrap();
+
+        assert_eq!(log.compact().await.unwrap(), 3);
+        let entries = log.entries().await;
+        assert!(entries.iter().all(|entry| entry.order_id == "open"));
+        assert_eq!(entries.iter().map(|entry| entry.sequence).collect::<Vec<_>>(), vec![2, 4]);
+        assert_eq!(log.entries_after(2).await.len(), 1);
+
+        assert_eq!(log.append("open", transition(Validated, Submitted)).await.unwrap(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_persisted_log_survives_reopen_and_compaction() {
+        use OrderLifecycleState::*;
+
+        let path = std::env::temp_dir().join(format!("event_log_{}.jsonl", Uuid::new_v4()));
+        let log = EventLog::open(&path).await.unwrap();
+        log.append("done", created()).await.unwrap();
+        log.append("open", created()).await.unwrap();
+        log.append("done", transition(Created, Rejected)).await.unwrap();
+        drop(log);
+
+        let log = EventLog::open(&path).await.unwrap();
+        assert_eq!(log.len().await, 3);
+        assert_eq!(log.last_sequence().await, 3);
+        assert_eq!(log.compact().await.unwrap(), 2);
+        drop(log);
+
+        // Sequence numbers carry on from before the compaction
+        let log = EventLog::open(&path).await.unwrap();
+        let entries = log.entries().await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].order_id, "open");
+        assert_eq!(log.append("open", transition(Created, Validated)).await.unwrap(), 4);
+        drop(log);
+
+        // A crash mid-append leaves a torn line that is dropped on the next open
+        let mut contents = tokio::fs::read_to_string(&path).await.unwrap();
+        contents.push_str("{\"sequence\":5,\"order_id\":\"op");
+        tokio::fs::write(&path, contents).await.unwrap();
+        let log = EventLog::open(&path).await.unwrap();
+        assert_eq!(log.last_sequence().await, 4);
+        assert_eq!(log.append("open", transition(Validated, Submitted)).await.unwrap(), 5);
+        drop(log);
+        assert_eq!(EventLog::open(&path).await.unwrap().len().await, 3);
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
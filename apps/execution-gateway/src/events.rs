@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_common::TradingError;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Order lifecycle transition published to downstream consumers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExecutionEvent {
+    OrderSubmitted {
+        order_id: String,
+        client_id: Uuid,
+        symbol: String,
+        exchange: String,
+        timestamp: DateTime<Utc>,
+    },
+    /// Some, not all, of the order has filled; quantities are cumulative
+    PartialFill {
+        order_id: String,
+        client_id: Uuid,
+        symbol: String,
+        filled_quantity: f64,
+        average_price: Option<f64>,
+        timestamp: DateTime<Utc>,
+    },
+    Filled {
+        order_id: String,
+        client_id: Uuid,
+        symbol: String,
+        filled_quantity: f64,
+        average_price: Option<f64>,
+        timestamp: DateTime<Utc>,
+    },
+    /// Cancelled or expired, keeping whatever had filled
+    Cancelled {
+        order_id: String,
+        client_id: Uuid,
+        symbol: String,
+        filled_quantity: f64,
+        reason: String,
+        timestamp: DateTime<Utc>,
+    },
+    /// Refused by the exchange or failed to reach it
+    Rejected {
+        order_id: String,
+        client_id: Uuid,
+        symbol: String,
+        reason: String,
+        timestamp: DateTime<Utc>,
+    },
+}
+
+impl ExecutionEvent {
+    /// Snake-case event name, also the last segment of its NATS subject
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ExecutionEvent::OrderSubmitted { .. } => "order_submitted",
+            ExecutionEvent::PartialFill { .. } => "partial_fill",
+            ExecutionEvent::Filled { .. } => "filled",
+            ExecutionEvent::Cancelled { .. } => "cancelled",
+            ExecutionEvent::Rejected { .. } => "rejected",
+        }
+    }
+
+    pub fn order_id(&self) -> &str {
+        match self {
+            ExecutionEvent::OrderSubmitted { order_id, .. }
+            | ExecutionEvent::PartialFill { order_id, .. }
+            | ExecutionEvent::Filled { order_id, .. }
+            | ExecutionEvent::Cancelled { order_id, .. }
+            | ExecutionEvent::Rejected { order_id, .. } => order_id,
+        }
+    }
+}
+
+/// Sink for execution events; the gateway logs publish failures and carries on
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, event: &ExecutionEvent) -> Result<(), TradingError>;
+}
+
+/// Publisher that drops every event; the gateway's default
+#[derive(Debug, Default)]
+pub struct NoopEventPublisher;
+
+#[async_trait]
+impl EventPublisher for NoopEventPublisher {
+    async fn publish(&self, _event: &ExecutionEvent) -> Result<(), TradingError> {
+        Ok(())
+    }
+}
+
+/// Publishes each event as JSON to `<subject_prefix>.<kind>`, e.g. `execution.filled`
+#[cfg(feature = "nats")]
+pub struct NatsEventPublisher {
+    client: async_nats::Client,
+    subject_prefix: String,
+}
+
+#[cfg(feature = "nats")]
+impl NatsEventPublisher {
+    pub fn new(client: async_nats::Client, subject_prefix: impl Into<String>) -> Self {
+        Self {
+            client,
+            subject_prefix: subject_prefix.into(),
+        }
+    }
+
+    pub async fn connect(url: &str, subject_prefix: impl Into<String>) -> Result<Self, TradingError> {
+        let client = async_nats::connect(url).await.map_err(|e| TradingError::Other(format!("Failed to connect to NATS at {}: {}", url, e)))?;
+        Ok(Self::new(client, subject_prefix))
+    }
+}
+
+#[cfg(feature = "nats")]
+#[async_trait]
+impl EventPublisher for NatsEventPublisher {
+    async fn publish(&self, event: &ExecutionEvent) -> Result<(), TradingError> {
+        let subject = format!("{}.{}", self.subject_prefix, event.kind());
+        let payload = serde_json::to_vec(event)?;
+        self.client
+            .publish(subject, payload.into())
+            .await
+            .map_err(|e| TradingError::Other(format!("Failed to publish {} event for order {}: {}", event.kind(), event.order_id(), e)))
+    }
+}
@@ -1,8 +1,17 @@
 use async_trait::async_trait;
-use rust_common::{OrderRequest, TradingError, OrderStatus};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_common::{
+    round_f64_to_increment, FundingRate, OrderBook, OrderBookLevel, OrderRequest, OrderSide, OrderStatus, OrderType, Quote, RejectReason, RoundingMode,
+    TimeInForce, TradingError,
+};
+pub use rust_common::{ExchangeInfo, TradingHours};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
 use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::time::Duration;
 
 /// Result from exchange adapter order placement
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,31 +25,171 @@ pub struct AdapterOrderResult {
     pub partial_fills: Vec<HashMap<String, serde_json::Value>>,
 }
 
-/// Exchange-specific trading rules and constraints
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExchangeInfo {
-    pub name: String,
-    pub tick_size: f64,
-    pub lot_size: f64,
-    pub min_order_size: f64,
-    pub max_order_size: f64,
-    pub min_price: f64,
-    pub max_price: f64,
-    pub trading_hours: Vec<TradingHours>,
-    pub supported_order_types: Vec<String>,
+impl AdapterOrderResult {
+    /// Commission summed over the per-fill `commission` entries, falling back to
+    /// the top-level `commission` when no fill reports one
+    pub fn total_commission(&self) -> f64 {
+        let per_fill: Vec<f64> = self.partial_fills.iter()
+            .filter_map(|fill| fill.get("commission").and_then(|v| v.as_f64()))
+            .collect();
+        if per_fill.is_empty() {
+            self.commission
+        } else {
+            per_fill.iter().sum()
+        }
+    }
+}
+
+/// How an exchange charges commission on each fill
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommissionModel {
+    /// Basis points of fill notional
+    FlatBps(f64),
+    /// Basis points of fill notional, depending on whether the fill added (maker) or took (taker) liquidity
+    MakerTaker { maker_bps: f64, taker_bps: f64 },
+    /// Fixed amount per fill, in the quote asset
+    Fixed(f64),
+}
+
+impl Default for CommissionModel {
+    fn default() -> Self {
+        CommissionModel::FlatBps(10.0)
+    }
 }
 
+impl CommissionModel {
+    /// Commission charged on a single fill, in the quote asset
+    pub fn commission(&self, quantity: f64, price: f64, is_maker: bool) -> f64 {
+        if quantity <= 0.0 {
+            return 0.0;
+        }
+        let bps = match *self {
+            CommissionModel::FlatBps(bps) => bps,
+            CommissionModel::MakerTaker { maker_bps, taker_bps } => {
+                if is_maker { maker_bps } else { taker_bps }
+            }
+            CommissionModel::Fixed(amount) => return amount,
+        };
+        quantity * price * bps / 10_000.0
+    }
+}
+
+/// Simulated venue behaviour for orders the mock fills from the book, i.e. not scripted
+/// fills or resting stops. Models compose, so a slow, slippy, occasionally-rejecting venue
+/// is `Latency`, `Slippage`, `Probabilistic` and `Rejecting` together.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FillModel {
+    /// Fill in full straight away, as with no model configured
+    Instant,
+    /// Fill with probability `fill_prob`; a miss rests unfilled, or is rejected if a
+    /// `Rejecting` model is also configured
+    Probabilistic { fill_prob: f64 },
+    /// Take `ms` longer to answer, on top of the adapter delay
+    Latency { ms: u64 },
+    /// Fill `bps` basis points worse than the order or mark price
+    Slippage { bps: f64 },
+    /// Reject with `reason`: every order, or with `Probabilistic` the ones that miss
+    Rejecting { reason: RejectReason },
+}
+
+/// Order types and features an exchange accepts, checked by the gateway before submission
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdapterCapabilities {
+    pub order_types: HashSet<OrderType>,
+    pub supports_amend: bool,
+    /// Linked take-profit/stop-loss pairs
+    pub supports_oco: bool,
+    pub supports_post_only: bool,
+    pub supports_reduce_only: bool,
+    /// Highest leverage a decision may ask for; `None` for no limit
+    pub max_leverage: Option<f64>,
+}
+
+impl Default for AdapterCapabilities {
+    /// Everything supported, for adapters that don't say otherwise
+    fn default() -> Self {
+        Self {
+            order_types: [
+                OrderType::Market,
+                OrderType::Limit,
+                OrderType::Stop,
+                OrderType::StopLimit,
+                OrderType::StopLoss,
+                OrderType::TakeProfit,
+                OrderType::TrailingStop,
+            ]
+            .into_iter()
+            .collect(),
+            supports_amend: true,
+            supports_oco: true,
+            supports_post_only: true,
+            supports_reduce_only: true,
+            max_leverage: None,
+        }
+    }
+}
+
+impl AdapterCapabilities {
+    pub fn supports_order_type(&self, order_type: OrderType) -> bool {
+        self.order_types.contains(&order_type)
+    }
+}
+
+/// Per-operation request timeouts for an exchange adapter, in milliseconds
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TradingHours {
-    pub day_of_week: u8, // 0 = Sunday, 6 = Saturday
-    pub open_time: String, // "09:30:00"
-    pub close_time: String, // "16:00:00"
-    pub timezone: String, // "America/New_York"
+pub struct AdapterTimeouts {
+    pub get_exchange_info_ms: u64,
+    pub place_order_ms: u64,
+    pub cancel_order_ms: u64,
+    pub get_order_status_ms: u64,
+    pub amend_order_ms: u64,
+    pub get_account_info_ms: u64,
+    pub get_mark_price_ms: u64,
+    pub get_quote_ms: u64,
+    pub get_order_book_ms: u64,
+    pub get_funding_rate_ms: u64,
+    pub ping_ms: u64,
+}
+
+impl Default for AdapterTimeouts {
+    fn default() -> Self {
+        Self {
+            get_exchange_info_ms: 5000,
+            place_order_ms: 30000,
+            cancel_order_ms: 10000,
+            get_order_status_ms: 5000,
+            amend_order_ms: 10000,
+            get_account_info_ms: 10000,
+            get_mark_price_ms: 2000,
+            get_quote_ms: 2000,
+            get_order_book_ms: 2000,
+            get_funding_rate_ms: 2000,
+            ping_ms: 2000,
+        }
+    }
+}
+
+/// Run an adapter operation, failing with a retryable timeout error once `timeout_ms` elapses
+pub async fn with_timeout<T, F>(operation: &str, timeout_ms: u64, future: F) -> Result<T, TradingError>
+where
+    F: Future<Output = Result<T, TradingError>>,
+{
+    tokio::time::timeout(Duration::from_millis(timeout_ms), future)
+        .await
+        .map_err(|_| TradingError::Timeout {
+            operation: operation.to_string(),
+            timeout_ms,
+        })?
 }
 
 /// Exchange adapter trait for different trading platforms
 #[async_trait]
 pub trait ExchangeAdapter {
+    /// Check the exchange is reachable
+    async fn ping(&self) -> Result<(), TradingError>;
+    
     /// Get exchange information and trading rules
     async fn get_exchange_info(&self, symbol: &str) -> Result<ExchangeInfo, TradingError>;
     
@@ -59,6 +208,44 @@ pub trait ExchangeAdapter {
     /// Get account balance and positions
     async fn get_account_info(&self) -> Result<AccountInfo, TradingError>;
     
+    /// Get the current mark price for a symbol, if the exchange publishes one
+    async fn get_mark_price(&self, _symbol: &str) -> Result<Option<f64>, TradingError> {
+        Ok(None)
+    }
+    
+    /// Get the best bid and ask for a symbol, if the exchange quotes it
+    async fn get_quote(&self, _symbol: &str) -> Result<Option<Quote>, TradingError> {
+        Ok(None)
+    }
+    
+    /// Get up to `depth` levels per side of a symbol's order book, if the exchange publishes it
+    async fn get_order_book(&self, _symbol: &str, _depth: usize) -> Result<Option<OrderBook>, TradingError> {
+        Ok(None)
+    }
+    
+    /// Get the current funding rate of a perpetual contract, if the symbol is one
+    async fn get_funding_rate(&self, _symbol: &str) -> Result<Option<FundingRate>, TradingError> {
+        Ok(None)
+    }
+    
+    /// Commission schedule charged on fills, used to compare venues
+    fn commission_model(&self) -> CommissionModel {
+        CommissionModel::default()
+    }
+    
+    /// Order types and features the exchange accepts
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities::default()
+    }
+    
+    /// Whether `symbol` is inside one of its trading windows at `now`
+    async fn is_market_open(&self, symbol: &str, now: DateTime<Utc>) -> Result<bool, TradingError>
+    where
+        Self: Sync,
+    {
+        self.get_exchange_info(symbol).await?.is_open_at(now)
+    }
+    
     /// Validate order before submission
     async fn validate_order(&self, order: &OrderRequest) -> Result<(), TradingError>;
     
@@ -67,6 +254,41 @@ pub trait ExchangeAdapter {
     
     /// Round quantity to exchange lot size
     fn round_quantity(&self, quantity: f64, lot_size: f64) -> f64;
+    
+    /// Round an order to the exchange tick and lot sizes, rejecting it if rounding
+    /// leaves a zero or sub-minimum quantity, or a zero price
+    fn round_order(&self, mut order: OrderRequest, exchange_info: &ExchangeInfo) -> Result<OrderRequest, TradingError> {
+        let rounded_size = self.round_quantity(order.size, exchange_info.lot_size);
+        if rounded_size <= 0.0 {
+            return Err(TradingError::OrderRejected {
+                reason: RejectReason::SizeOutOfBounds,
+                message: format!("Order size {} rounds to zero at lot size {}", order.size, exchange_info.lot_size),
+            });
+        }
+        if rounded_size < exchange_info.min_order_size {
+            return Err(TradingError::OrderRejected {
+                reason: RejectReason::SizeOutOfBounds,
+                message: format!(
+                    "Order size {} rounds to {}, below minimum {}",
+                    order.size, rounded_size, exchange_info.min_order_size
+                ),
+            });
+        }
+        order.size = rounded_size;
+        
+        if let Some(price) = order.price {
+            let rounded_price = self.round_price(price, exchange_info.tick_size);
+            if rounded_price <= 0.0 {
+                return Err(TradingError::OrderRejected {
+                    reason: RejectReason::PriceOutOfBounds,
+                    message: format!("Order price {} rounds to zero at tick size {}", price, exchange_info.tick_size),
+                });
+            }
+            order.price = Some(rounded_price);
+        }
+        
+        Ok(order)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +310,9 @@ pub struct Position {
     pub current_price: f64,
     pub unrealized_pnl: f64,
     pub margin_used: f64,
+    /// Net funding paid on a perpetual; negative when received
+    #[serde(default)]
+    pub funding_paid: f64,
 }
 
 /// Mock exchange adapter for testing
@@ -96,6 +321,26 @@ pub struct MockExchangeAdapter {
     pub should_fail: bool,
     pub delay_ms: u64,
     pub partial_fill_ratio: f64, // 0.0 to 1.0
+    pub mark_price: Option<f64>,
+    pub quote: Option<(f64, f64)>, // (bid, ask)
+    pub order_book: Option<(Vec<OrderBookLevel>, Vec<OrderBookLevel>)>, // (bids, asks), best first
+    pub funding_rate: Option<(f64, DateTime<Utc>)>, // (rate, next funding time)
+    pub positions: Vec<Position>,
+    pub placed_orders: Arc<Mutex<Vec<OrderRequest>>>, // every order received, for assertions
+    pub fill_sequence: Arc<Mutex<VecDeque<(f64, f64)>>>, // scripted (fill ratio, fill price) per order
+    pub risk_rejection: Option<String>, // reject every order with this risk limit
+    pub rounding_mode: RoundingMode, // applied to prices; quantities always round down
+    pub price_path: Arc<Mutex<VecDeque<f64>>>, // scripted mark prices, one per lookup
+    pub last_price: Arc<Mutex<Option<f64>>>, // last mark served, used to fill market orders
+    pub resting_orders: Arc<Mutex<HashMap<String, OrderStatus>>>, // stop/take-profit orders awaiting their trigger
+    pub commission_model: CommissionModel, // limit orders fill as maker, everything else as taker
+    pub failing_symbols: Vec<String>, // orders in these symbols fail, e.g. a market being delisted
+    pub failing_cancels: bool, // cancels fail while placement keeps working
+    pub gtd_expiries: Arc<Mutex<HashMap<String, DateTime<Utc>>>>, // resting GTD remainders and when they expire
+    pub available_balance: Option<f64>, // orders with a larger notional fail for insufficient funds
+    pub fill_models: Vec<FillModel>, // applied together to every order filled from the book
+    pub fill_rng: Arc<Mutex<StdRng>>, // drives `FillModel::Probabilistic`
+    pub capabilities: AdapterCapabilities,
 }
 
 impl MockExchangeAdapter {
@@ -109,17 +354,19 @@ impl MockExchangeAdapter {
                 max_order_size: 1000.0,
                 min_price: 0.01,
                 max_price: 1000000.0,
-                trading_hours: vec![
-                    TradingHours {
-                        day_of_week: 1, // Monday
+                trading_hours: (0..7)
+                    .map(|day_of_week| TradingHours {
+                        day_of_week,
                         open_time: "00:00:00".to_string(),
                         close_time: "23:59:59".to_string(),
                         timezone: "UTC".to_string(),
-                    }
-                ],
+                    })
+                    .collect(),
                 supported_order_types: vec![
                     "market".to_string(),
                     "limit".to_string(),
+                    "stop".to_string(),
+                    "stop_limit".to_string(),
                     "stop_loss".to_string(),
                     "take_profit".to_string(),
                 ],
@@ -127,6 +374,26 @@ impl MockExchangeAdapter {
             should_fail: false,
             delay_ms: 100,
             partial_fill_ratio: 0.0,
+            mark_price: None,
+            quote: None,
+            order_book: None,
+            funding_rate: None,
+            positions: Vec::new(),
+            placed_orders: Arc::new(Mutex::new(Vec::new())),
+            fill_sequence: Arc::new(Mutex::new(VecDeque::new())),
+            risk_rejection: None,
+            rounding_mode: RoundingMode::HalfEven,
+            price_path: Arc::new(Mutex::new(VecDeque::new())),
+            last_price: Arc::new(Mutex::new(None)),
+            resting_orders: Arc::new(Mutex::new(HashMap::new())),
+            commission_model: CommissionModel::default(),
+            failing_symbols: Vec::new(),
+            failing_cancels: false,
+            gtd_expiries: Arc::new(Mutex::new(HashMap::new())),
+            available_balance: None,
+            fill_models: Vec::new(),
+            fill_rng: Arc::new(Mutex::new(StdRng::from_entropy())),
+            capabilities: AdapterCapabilities::default(),
         }
     }
 
@@ -144,6 +411,145 @@ impl MockExchangeAdapter {
         self.partial_fill_ratio = ratio.clamp(0.0, 1.0);
         self
     }
+
+    pub fn with_mark_price(mut self, mark_price: f64) -> Self {
+        self.mark_price = Some(mark_price);
+        self
+    }
+
+    pub fn with_quote(mut self, bid: f64, ask: f64) -> Self {
+        self.quote = Some((bid, ask));
+        self
+    }
+
+    /// Serve an order book of (price, size) levels, best first on each side
+    pub fn with_order_book(mut self, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> Self {
+        let levels = |levels: Vec<(f64, f64)>| {
+            levels.into_iter().map(|(price, size)| OrderBookLevel { price, size }).collect()
+        };
+        self.order_book = Some((levels(bids), levels(asks)));
+        self
+    }
+
+    pub fn with_funding_rate(mut self, rate: f64, next_funding_time: DateTime<Utc>) -> Self {
+        self.funding_rate = Some((rate, next_funding_time));
+        self
+    }
+
+    pub fn with_positions(mut self, positions: Vec<Position>) -> Self {
+        self.positions = positions;
+        self
+    }
+
+    pub fn with_rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+
+    pub fn with_trading_hours(mut self, trading_hours: Vec<TradingHours>) -> Self {
+        self.exchange_info.trading_hours = trading_hours;
+        self
+    }
+
+    pub fn with_commission_model(mut self, commission_model: CommissionModel) -> Self {
+        self.commission_model = commission_model;
+        self
+    }
+
+    /// Fail every order placed in `symbol` while other symbols keep trading
+    pub fn with_failing_symbol(mut self, symbol: &str) -> Self {
+        self.failing_symbols.push(symbol.to_string());
+        self
+    }
+
+    /// Fail every cancel, leaving working orders on the book
+    pub fn with_failing_cancels(mut self) -> Self {
+        self.failing_cancels = true;
+        self
+    }
+
+    pub fn with_risk_rejection(mut self, limit: &str) -> Self {
+        self.risk_rejection = Some(limit.to_string());
+        self
+    }
+
+    pub fn with_available_balance(mut self, balance: f64) -> Self {
+        self.available_balance = Some(balance);
+        self
+    }
+
+    pub fn with_capabilities(mut self, capabilities: AdapterCapabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Add a fill model; call repeatedly to compose several
+    pub fn with_fill_model(mut self, fill_model: FillModel) -> Self {
+        self.fill_models.push(fill_model);
+        self
+    }
+
+    /// Seed the randomness of probabilistic fills, for reproducible runs
+    pub fn with_fill_seed(mut self, seed: u64) -> Self {
+        self.fill_rng = Arc::new(Mutex::new(StdRng::seed_from_u64(seed)));
+        self
+    }
+
+    /// Script the (fill ratio, fill price) of successive orders; once exhausted,
+    /// orders fall back to the configured partial fill ratio
+    pub fn with_fill_sequence(self, fills: Vec<(f64, f64)>) -> Self {
+        *self.fill_sequence.lock().unwrap() = fills.into_iter().collect();
+        self
+    }
+
+    /// Script the market: each mark price lookup serves the next price; once
+    /// exhausted, lookups fall back to the configured mark price
+    pub fn with_price_path(self, prices: Vec<f64>) -> Self {
+        *self.price_path.lock().unwrap() = prices.into_iter().collect();
+        self
+    }
+
+    /// Commission on one fill of `order`
+    fn commission_for(&self, order: &OrderRequest, quantity: f64, price: f64) -> f64 {
+        let is_maker = order.order_type == OrderType::Limit;
+        self.commission_model.commission(quantity, price, is_maker)
+    }
+
+    /// Settle whatever `result` left unfilled according to the order's time in force
+    fn apply_time_in_force(&self, order: &OrderRequest, mut result: AdapterOrderResult) -> AdapterOrderResult {
+        if result.status == OrderStatus::Filled {
+            return result;
+        }
+
+        match order.time_in_force {
+            TimeInForce::Gtc => {}
+            TimeInForce::Ioc => result.status = OrderStatus::Cancelled,
+            TimeInForce::Fok => {
+                result.status = OrderStatus::Rejected;
+                result.filled_quantity = 0.0;
+                result.average_price = None;
+                result.commission = 0.0;
+                result.filled_at = None;
+                result.partial_fills.clear();
+            }
+            TimeInForce::Gtd(expires_at) => {
+                let order_id = order.id.to_string();
+                self.resting_orders.lock().unwrap().insert(order_id.clone(), result.status);
+                self.gtd_expiries.lock().unwrap().insert(order_id, expires_at);
+            }
+        }
+        result
+    }
+
+    /// Shared handle to the orders this adapter has received
+    pub fn placed_orders(&self) -> Arc<Mutex<Vec<OrderRequest>>> {
+        self.placed_orders.clone()
+    }
+
+    /// Shared handle to resting stop/take-profit orders; set an entry to simulate a trigger
+    pub fn resting_orders(&self) -> Arc<Mutex<HashMap<String, OrderStatus>>> {
+        self.resting_orders.clone()
+    }
 }
 
 impl Default for MockExchangeAdapter {
@@ -154,6 +560,17 @@ impl Default for MockExchangeAdapter {
 
 #[async_trait]
 impl ExchangeAdapter for MockExchangeAdapter {
+    async fn ping(&self) -> Result<(), TradingError> {
+        if self.should_fail {
+            return Err(TradingError::ExecutionError {
+                message: "Mock ping failure".to_string(),
+            });
+        }
+        
+        tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+        Ok(())
+    }
+
     async fn get_exchange_info(&self, _symbol: &str) -> Result<ExchangeInfo, TradingError> {
         if self.should_fail {
             return Err(TradingError::ExecutionError {
@@ -175,15 +592,141 @@ impl ExchangeAdapter for MockExchangeAdapter {
         // Simulate network delay
         tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
 
-        // Validate order
+        // Round to exchange increments, then validate
+        let order = self.round_order(order, &self.exchange_info)?;
         self.validate_order(&order).await?;
 
+        self.placed_orders.lock().unwrap().push(order.clone());
+
+        // A post-only limit that would match the quoted book is refused rather than filled as taker
+        if let (true, OrderType::Limit, Some(price), Some((bid, ask))) = (order.post_only, order.order_type, order.price, self.quote) {
+            let crosses = match order.side {
+                OrderSide::Buy => price >= ask,
+                OrderSide::Sell => price <= bid,
+            };
+            if crosses {
+                return Err(TradingError::OrderRejected {
+                    reason: RejectReason::WouldCrossBook,
+                    message: format!("Post-only order {} at {} would cross the book ({} / {})", order.id, price, bid, ask),
+                });
+            }
+        }
+
+        if self.failing_symbols.contains(&order.symbol) {
+            return Err(TradingError::ExecutionError {
+                message: format!("Mock order placement failure for {}", order.symbol),
+            });
+        }
+
+        if let Some(limit) = &self.risk_rejection {
+            return Err(TradingError::RiskLimitError { limit: limit.clone(), rejection: None });
+        }
+
+        if let Some(balance) = self.available_balance {
+            let notional = order.size * order.price.or(self.mark_price).unwrap_or(0.0);
+            if notional > balance {
+                return Err(TradingError::InsufficientFunds {
+                    message: format!("Order notional {} exceeds available balance {}", notional, balance),
+                });
+            }
+        }
+
+        // Stop and take-profit orders rest until their trigger price is reached
+        if matches!(
+            order.order_type,
+            OrderType::Stop | OrderType::StopLimit | OrderType::StopLoss | OrderType::TakeProfit
+        ) {
+            self.resting_orders.lock().unwrap().insert(order.id.to_string(), OrderStatus::Pending);
+            return Ok(AdapterOrderResult {
+                order_id: order.id.to_string(),
+                status: OrderStatus::Pending,
+                filled_quantity: 0.0,
+                average_price: None,
+                commission: 0.0,
+                filled_at: None,
+                partial_fills: Vec::new(),
+            });
+        }
+
+        let scripted_fill = self.fill_sequence.lock().unwrap().pop_front();
+        if let Some((ratio, price)) = scripted_fill {
+            let filled_quantity = order.size * ratio.clamp(0.0, 1.0);
+            let mut result = AdapterOrderResult {
+                order_id: order.id.to_string(),
+                status: if ratio >= 1.0 { OrderStatus::Filled } else { OrderStatus::PartiallyFilled },
+                filled_quantity,
+                average_price: Some(price),
+                commission: self.commission_for(&order, filled_quantity, price),
+                filled_at: Some(Utc::now()),
+                partial_fills: Vec::new(),
+            };
+
+            if ratio < 1.0 {
+                let mut partial_fill = HashMap::new();
+                partial_fill.insert("fill_id".to_string(), serde_json::Value::String(uuid::Uuid::new_v4().to_string()));
+                partial_fill.insert("quantity".to_string(), serde_json::json!(filled_quantity));
+                partial_fill.insert("price".to_string(), serde_json::json!(price));
+                partial_fill.insert("commission".to_string(), serde_json::json!(result.commission));
+                result.partial_fills.push(partial_fill);
+            }
+
+            return Ok(self.apply_time_in_force(&order, result));
+        }
+
+        let latency_ms: u64 = self.fill_models.iter()
+            .map(|model| if let FillModel::Latency { ms } = model { *ms } else { 0 })
+            .sum();
+        if latency_ms > 0 {
+            tokio::time::sleep(std::time::Duration::from_millis(latency_ms)).await;
+        }
+
+        let fill_prob = self.fill_models.iter()
+            .filter_map(|model| match model {
+                FillModel::Probabilistic { fill_prob } => Some(fill_prob.clamp(0.0, 1.0)),
+                _ => None,
+            })
+            .reduce(|combined, fill_prob| combined * fill_prob);
+        let fills = fill_prob.map_or(true, |fill_prob| self.fill_rng.lock().unwrap().gen_bool(fill_prob));
+        let rejection = self.fill_models.iter().find_map(|model| match model {
+            FillModel::Rejecting { reason } => Some(*reason),
+            _ => None,
+        });
+        if let Some(reason) = rejection.filter(|_| fill_prob.is_none() || !fills) {
+            return Err(TradingError::OrderRejected {
+                reason,
+                message: format!("Mock venue rejected order {}", order.id),
+            });
+        }
+        if !fills {
+            let result = self.apply_time_in_force(&order, AdapterOrderResult {
+                order_id: order.id.to_string(),
+                status: OrderStatus::Open,
+                filled_quantity: 0.0,
+                average_price: None,
+                commission: 0.0,
+                filled_at: None,
+                partial_fills: Vec::new(),
+            });
+            if order.time_in_force == TimeInForce::Gtc {
+                self.resting_orders.lock().unwrap().insert(order.id.to_string(), OrderStatus::Open);
+            }
+            return Ok(result);
+        }
+
+        // Market orders fill at the last mark served, less any slippage
+        let slippage_bps: f64 = self.fill_models.iter()
+            .map(|model| if let FillModel::Slippage { bps } = model { *bps } else { 0.0 })
+            .sum();
+        let fill_price = order.price.or(*self.last_price.lock().unwrap()).map(|price| match order.side {
+            OrderSide::Buy => price * (1.0 + slippage_bps / 10_000.0),
+            OrderSide::Sell => price * (1.0 - slippage_bps / 10_000.0),
+        });
         let mut result = AdapterOrderResult {
             order_id: order.id.to_string(),
             status: OrderStatus::Filled,
             filled_quantity: order.size,
-            average_price: order.price,
-            commission: order.size * order.price.unwrap_or(0.0) * 0.001, // 0.1% commission
+            average_price: fill_price,
+            commission: self.commission_for(&order, order.size, fill_price.unwrap_or(0.0)),
             filled_at: Some(Utc::now()),
             partial_fills: Vec::new(),
         };
@@ -196,32 +739,52 @@ impl ExchangeAdapter for MockExchangeAdapter {
             if partial_quantity > 0.0 {
                 result.status = OrderStatus::PartiallyFilled;
                 result.filled_quantity = partial_quantity;
+                result.commission = self.commission_for(&order, partial_quantity, fill_price.unwrap_or(0.0));
                 
                 let mut partial_fill = HashMap::new();
                 partial_fill.insert("fill_id".to_string(), serde_json::Value::String(uuid::Uuid::new_v4().to_string()));
                 partial_fill.insert("quantity".to_string(), serde_json::Value::Number(serde_json::Number::from_f64(partial_quantity).unwrap()));
-                partial_fill.insert("price".to_string(), serde_json::Value::Number(serde_json::Number::from_f64(order.price.unwrap_or(0.0)).unwrap()));
-                partial_fill.insert("commission".to_string(), serde_json::Value::Number(serde_json::Number::from_f64(partial_quantity * order.price.unwrap_or(0.0) * 0.001).unwrap()));
+                partial_fill.insert("price".to_string(), serde_json::Value::Number(serde_json::Number::from_f64(fill_price.unwrap_or(0.0)).unwrap()));
+                partial_fill.insert("commission".to_string(), serde_json::json!(result.commission));
                 
                 result.partial_fills.push(partial_fill);
             }
         }
 
-        Ok(result)
+        Ok(self.apply_time_in_force(&order, result))
     }
 
-    async fn cancel_order(&self, _order_id: &str) -> Result<(), TradingError> {
+    async fn cancel_order(&self, order_id: &str) -> Result<(), TradingError> {
         if self.should_fail {
             return Err(TradingError::ExecutionError {
                 message: "Mock order cancellation failure".to_string(),
             });
         }
+        if self.failing_cancels {
+            return Err(TradingError::ExecutionError {
+                message: format!("Mock cancel of {} failed", order_id),
+            });
+        }
 
         tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+        match self.resting_orders.lock().unwrap().entry(order_id.to_string()) {
+            Entry::Occupied(mut entry) => {
+                if *entry.get() == OrderStatus::Filled {
+                    return Err(TradingError::ExecutionError {
+                        message: format!("Order {} is already filled", order_id),
+                    });
+                }
+                entry.insert(OrderStatus::Cancelled);
+            }
+            // A cancelled partial fill stays cancelled rather than reporting filled later
+            Entry::Vacant(entry) => {
+                entry.insert(OrderStatus::Cancelled);
+            }
+        }
         Ok(())
     }
 
-    async fn get_order_status(&self, _order_id: &str) -> Result<OrderStatus, TradingError> {
+    async fn get_order_status(&self, order_id: &str) -> Result<OrderStatus, TradingError> {
         if self.should_fail {
             return Err(TradingError::ExecutionError {
                 message: "Mock order status failure".to_string(),
@@ -229,7 +792,14 @@ impl ExchangeAdapter for MockExchangeAdapter {
         }
 
         tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
-        Ok(OrderStatus::Filled)
+        let mut resting_orders = self.resting_orders.lock().unwrap();
+        let expires_at = self.gtd_expiries.lock().unwrap().get(order_id).copied();
+        if let (Some(status), Some(expires_at)) = (resting_orders.get_mut(order_id), expires_at) {
+            if expires_at <= Utc::now() && matches!(status, OrderStatus::Pending | OrderStatus::PartiallyFilled) {
+                *status = OrderStatus::Expired;
+            }
+        }
+        Ok(resting_orders.get(order_id).copied().unwrap_or(OrderStatus::Filled))
     }
 
     async fn amend_order(&self, _order_id: &str, _new_price: Option<f64>, _new_quantity: Option<f64>) -> Result<(), TradingError> {
@@ -255,23 +825,96 @@ impl ExchangeAdapter for MockExchangeAdapter {
         Ok(AccountInfo {
             account_id: "mock_account".to_string(),
             total_balance: 100000.0,
-            available_balance: 90000.0,
+            available_balance: self.available_balance.unwrap_or(90000.0),
             margin_used: 10000.0,
             margin_available: 90000.0,
-            positions: Vec::new(),
+            positions: self.positions.clone(),
         })
     }
 
+    async fn get_mark_price(&self, _symbol: &str) -> Result<Option<f64>, TradingError> {
+        if self.should_fail {
+            return Err(TradingError::ExecutionError {
+                message: "Mock mark price failure".to_string(),
+            });
+        }
+
+        let mark_price = self.price_path.lock().unwrap().pop_front().or(self.mark_price);
+        if mark_price.is_some() {
+            *self.last_price.lock().unwrap() = mark_price;
+        }
+        Ok(mark_price)
+    }
+
+    async fn get_quote(&self, symbol: &str) -> Result<Option<Quote>, TradingError> {
+        if self.should_fail {
+            return Err(TradingError::ExecutionError {
+                message: "Mock quote failure".to_string(),
+            });
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+        Ok(self.quote.map(|(bid, ask)| Quote {
+            symbol: symbol.to_string(),
+            bid,
+            ask,
+            bid_size: 0.0,
+            ask_size: 0.0,
+            timestamp: Utc::now(),
+        }))
+    }
+
+    async fn get_order_book(&self, symbol: &str, depth: usize) -> Result<Option<OrderBook>, TradingError> {
+        if self.should_fail {
+            return Err(TradingError::ExecutionError {
+                message: "Mock order book failure".to_string(),
+            });
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+        Ok(self.order_book.as_ref().map(|(bids, asks)| OrderBook {
+            symbol: symbol.to_string(),
+            bids: bids.iter().take(depth).copied().collect(),
+            asks: asks.iter().take(depth).copied().collect(),
+            timestamp: Utc::now(),
+        }))
+    }
+
+    async fn get_funding_rate(&self, symbol: &str) -> Result<Option<FundingRate>, TradingError> {
+        if self.should_fail {
+            return Err(TradingError::ExecutionError {
+                message: "Mock funding rate failure".to_string(),
+            });
+        }
+
+        Ok(self.funding_rate.map(|(rate, next_funding_time)| FundingRate {
+            symbol: symbol.to_string(),
+            rate,
+            next_funding_time,
+            timestamp: Utc::now(),
+        }))
+    }
+
+    fn commission_model(&self) -> CommissionModel {
+        self.commission_model
+    }
+
+    fn capabilities(&self) -> AdapterCapabilities {
+        self.capabilities.clone()
+    }
+
     async fn validate_order(&self, order: &OrderRequest) -> Result<(), TradingError> {
         // Validate order size
         if order.size < self.exchange_info.min_order_size {
-            return Err(TradingError::ExecutionError {
+            return Err(TradingError::OrderRejected {
+                reason: RejectReason::SizeOutOfBounds,
                 message: format!("Order size {} below minimum {}", order.size, self.exchange_info.min_order_size),
             });
         }
 
         if order.size > self.exchange_info.max_order_size {
-            return Err(TradingError::ExecutionError {
+            return Err(TradingError::OrderRejected {
+                reason: RejectReason::SizeOutOfBounds,
                 message: format!("Order size {} above maximum {}", order.size, self.exchange_info.max_order_size),
             });
         }
@@ -279,13 +922,15 @@ impl ExchangeAdapter for MockExchangeAdapter {
         // Validate price if provided
         if let Some(price) = order.price {
             if price < self.exchange_info.min_price {
-                return Err(TradingError::ExecutionError {
+                return Err(TradingError::OrderRejected {
+                    reason: RejectReason::PriceOutOfBounds,
                     message: format!("Order price {} below minimum {}", price, self.exchange_info.min_price),
                 });
             }
 
             if price > self.exchange_info.max_price {
-                return Err(TradingError::ExecutionError {
+                return Err(TradingError::OrderRejected {
+                    reason: RejectReason::PriceOutOfBounds,
                     message: format!("Order price {} above maximum {}", price, self.exchange_info.max_price),
                 });
             }
@@ -295,24 +940,20 @@ impl ExchangeAdapter for MockExchangeAdapter {
     }
 
     fn round_price(&self, price: f64, tick_size: f64) -> f64 {
-        if tick_size <= 0.0 {
-            return price;
-        }
-        (price / tick_size).round() * tick_size
+        round_f64_to_increment(price, tick_size, self.rounding_mode)
     }
 
     fn round_quantity(&self, quantity: f64, lot_size: f64) -> f64 {
-        if lot_size <= 0.0 {
-            return quantity;
-        }
-        (quantity / lot_size).floor() * lot_size
+        // Snap away f64 drift from upstream arithmetic so exact multiples of the lot size
+        // aren't floored down by a whole lot
+        let snapped = round_f64_to_increment(quantity, 1e-9, RoundingMode::HalfEven);
+        round_f64_to_increment(snapped, lot_size, RoundingMode::Down)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rust_common::{OrderSide, OrderType};
     use uuid::Uuid;
 
     #[tokio::test]
@@ -327,6 +968,9 @@ mod tests {
             price: Some(50000.0),
             order_type: OrderType::Limit,
             timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
         };
 
         let result = adapter.place_order(order).await;
@@ -337,6 +981,122 @@ mod tests {
         assert_eq!(order_result.filled_quantity, 0.1);
     }
 
+    fn market_order(side: OrderSide, size: f64) -> OrderRequest {
+        OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side,
+            size,
+            price: None,
+            order_type: OrderType::Market,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fill_models() {
+        let venue = |models: &[FillModel]| {
+            models.iter().fold(
+                MockExchangeAdapter::new().with_delay(0).with_mark_price(50000.0).with_fill_seed(7),
+                |adapter, model| adapter.with_fill_model(*model),
+            )
+        };
+        let place = |adapter: MockExchangeAdapter, order: OrderRequest| async move {
+            adapter.get_mark_price("BTCUSD").await.unwrap();
+            adapter.place_order(order).await
+        };
+
+        // Instant fills in full at the mark
+        let result = place(venue(&[FillModel::Instant]), market_order(OrderSide::Buy, 1.0)).await.unwrap();
+        assert_eq!(result.status, OrderStatus::Filled);
+        assert_eq!(result.filled_quantity, 1.0);
+        assert_eq!(result.average_price, Some(50000.0));
+
+        // Slippage moves the fill against the taker
+        let slippage = [FillModel::Slippage { bps: 10.0 }];
+        let result = place(venue(&slippage), market_order(OrderSide::Buy, 1.0)).await.unwrap();
+        assert!((result.average_price.unwrap() - 50050.0).abs() < 1e-6);
+        let result = place(venue(&slippage), market_order(OrderSide::Sell, 1.0)).await.unwrap();
+        assert!((result.average_price.unwrap() - 49950.0).abs() < 1e-6);
+
+        // Latency delays the answer
+        let started = std::time::Instant::now();
+        let result = place(venue(&[FillModel::Latency { ms: 50 }]), market_order(OrderSide::Buy, 1.0)).await.unwrap();
+        assert!(started.elapsed() >= std::time::Duration::from_millis(50));
+        assert_eq!(result.status, OrderStatus::Filled);
+
+        // A missed probabilistic fill rests unfilled, and shows as working
+        let adapter = venue(&[FillModel::Probabilistic { fill_prob: 0.0 }]);
+        let resting = adapter.resting_orders();
+        let order = market_order(OrderSide::Buy, 1.0);
+        let order_id = order.id.to_string();
+        let result = place(adapter, order).await.unwrap();
+        assert_eq!(result.status, OrderStatus::Open);
+        assert_eq!(result.filled_quantity, 0.0);
+        assert_eq!(result.average_price, None);
+        assert!(result.partial_fills.is_empty());
+        assert_eq!(resting.lock().unwrap().get(&order_id), Some(&OrderStatus::Open));
+        let result = place(venue(&[FillModel::Probabilistic { fill_prob: 1.0 }]), market_order(OrderSide::Buy, 1.0)).await.unwrap();
+        assert_eq!(result.status, OrderStatus::Filled);
+
+        // Over many orders the fill rate follows the probability
+        let adapter = venue(&[FillModel::Probabilistic { fill_prob: 0.5 }]);
+        adapter.get_mark_price("BTCUSD").await.unwrap();
+        let mut filled = 0;
+        for _ in 0..200 {
+            if adapter.place_order(market_order(OrderSide::Buy, 1.0)).await.unwrap().status == OrderStatus::Filled {
+                filled += 1;
+            }
+        }
+        assert!((60..=140).contains(&filled), "{} of 200 filled", filled);
+
+        // Rejecting alone refuses everything
+        let rejecting = FillModel::Rejecting { reason: RejectReason::MarketClosed };
+        let error = place(venue(&[rejecting]), market_order(OrderSide::Buy, 1.0)).await.unwrap_err();
+        assert!(matches!(error, TradingError::OrderRejected { reason: RejectReason::MarketClosed, .. }));
+
+        // Composed: slow, slippy, and rejecting only the orders that miss
+        let composed = [
+            FillModel::Latency { ms: 5 },
+            FillModel::Slippage { bps: 20.0 },
+            FillModel::Probabilistic { fill_prob: 0.5 },
+            rejecting,
+        ];
+        let adapter = venue(&composed);
+        adapter.get_mark_price("BTCUSD").await.unwrap();
+        let (mut filled, mut rejected) = (0, 0);
+        for _ in 0..40 {
+            match adapter.place_order(market_order(OrderSide::Buy, 1.0)).await {
+                Ok(result) => {
+                    assert_eq!(result.status, OrderStatus::Filled);
+                    assert!((result.average_price.unwrap() - 50100.0).abs() < 1e-6);
+                    filled += 1;
+                }
+                Err(TradingError::OrderRejected { reason: RejectReason::MarketClosed, .. }) => rejected += 1,
+                Err(e) => panic!("unexpected error: {}", e),
+            }
+        }
+        assert!(filled > 0 && rejected > 0);
+
+        // The same seed replays the same fill outcomes
+        let outcomes = |seed: u64| async move {
+            let adapter = MockExchangeAdapter::new()
+                .with_delay(0)
+                .with_mark_price(50000.0)
+                .with_fill_model(FillModel::Probabilistic { fill_prob: 0.5 })
+                .with_fill_seed(seed);
+            let mut statuses = Vec::new();
+            for _ in 0..20 {
+                statuses.push(adapter.place_order(market_order(OrderSide::Buy, 1.0)).await.unwrap().status);
+            }
+            statuses
+        };
+        assert_eq!(outcomes(11).await, outcomes(11).await);
+    }
+
     #[tokio::test]
     async fn test_mock_adapter_failure() {
         let adapter = MockExchangeAdapter::new().with_failure(true);
@@ -349,6 +1109,9 @@ mod tests {
             price: Some(50000.0),
             order_type: OrderType::Limit,
             timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
         };
 
         let result = adapter.place_order(order).await;
@@ -367,6 +1130,9 @@ mod tests {
             price: Some(50000.0),
             order_type: OrderType::Limit,
             timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
         };
 
         let result = adapter.place_order(order).await;
@@ -378,6 +1144,43 @@ mod tests {
         assert_eq!(order_result.partial_fills.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_mock_adapter_gtd_remainder_expires() {
+        let adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        
+        let order = OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size: 1.0,
+            price: Some(50000.0),
+            order_type: OrderType::Limit,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtd(Utc::now() + chrono::Duration::milliseconds(100)),
+            post_only: false,
+        };
+
+        let order_result = adapter.place_order(order).await.unwrap();
+        assert_eq!(order_result.status, OrderStatus::PartiallyFilled);
+        assert_eq!(adapter.get_order_status(&order_result.order_id).await.unwrap(), OrderStatus::PartiallyFilled);
+        
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(adapter.get_order_status(&order_result.order_id).await.unwrap(), OrderStatus::Expired);
+    }
+
+    #[tokio::test]
+    async fn test_with_timeout_fires_on_slow_operation() {
+        let adapter = MockExchangeAdapter::new().with_delay(100);
+        
+        let result = with_timeout("get_exchange_info", 10, adapter.get_exchange_info("BTCUSD")).await;
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("get_exchange_info timeout after 10ms"));
+        
+        let result = with_timeout("get_exchange_info", 1000, adapter.get_exchange_info("BTCUSD")).await;
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_price_rounding() {
         let adapter = MockExchangeAdapter::new();
@@ -385,6 +1188,27 @@ mod tests {
         assert_eq!(adapter.round_price(50000.123, 0.01), 50000.12);
         assert_eq!(adapter.round_price(50000.126, 0.01), 50000.13);
         assert_eq!(adapter.round_price(50000.125, 0.01), 50000.12); // Banker's rounding
+        
+        let adapter = MockExchangeAdapter::new().with_rounding_mode(RoundingMode::HalfUp);
+        assert_eq!(adapter.round_price(50000.125, 0.01), 50000.13);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_price_rounding_is_idempotent(price in 0.01f64..1_000_000.0, mode_index in 0usize..4) {
+            let mode = [RoundingMode::HalfEven, RoundingMode::HalfUp, RoundingMode::Down, RoundingMode::Up][mode_index];
+            let adapter = MockExchangeAdapter::new().with_rounding_mode(mode);
+            let rounded = adapter.round_price(price, 0.01);
+            proptest::prop_assert_eq!(adapter.round_price(rounded, 0.01), rounded);
+        }
+
+        #[test]
+        fn prop_quantity_rounding_is_idempotent(quantity in 0.0f64..1000.0) {
+            let adapter = MockExchangeAdapter::new();
+            let rounded = adapter.round_quantity(quantity, 0.001);
+            proptest::prop_assert!(rounded <= quantity + 1e-9);
+            proptest::prop_assert_eq!(adapter.round_quantity(rounded, 0.001), rounded);
+        }
     }
 
     #[test]
@@ -394,6 +1218,95 @@ mod tests {
         assert_eq!(adapter.round_quantity(0.1234, 0.001), 0.123);
         assert_eq!(adapter.round_quantity(0.1239, 0.001), 0.123);
         assert_eq!(adapter.round_quantity(1.5, 0.1), 1.5);
+        assert_eq!(adapter.round_quantity(0.3, 0.1), 0.3);
+    }
+
+    #[tokio::test]
+    async fn test_quantity_rounding_to_zero_is_rejected() {
+        let adapter = MockExchangeAdapter::new().with_delay(0);
+        assert_eq!(adapter.round_quantity(0.0005, 0.001), 0.0);
+        
+        let order = OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size: 0.0005, // Floors to 0.0 at lot size 0.001
+            price: Some(50000.0),
+            order_type: OrderType::Limit,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        };
+        
+        let error = adapter.round_order(order.clone(), &adapter.exchange_info).unwrap_err();
+        assert!(error.to_string().contains("rounds to zero"));
+        assert!(matches!(error, TradingError::OrderRejected { reason: RejectReason::SizeOutOfBounds, .. }));
+        
+        // The order must never reach the exchange
+        assert!(adapter.place_order(order).await.is_err());
+        assert!(adapter.placed_orders().lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mock_order_book_limited_to_depth() {
+        let adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_order_book(vec![(100.0, 1.0), (99.0, 2.0), (98.0, 3.0)], vec![(101.0, 1.0), (102.0, 2.0)]);
+        
+        let order_book = adapter.get_order_book("BTCUSD", 2).await.unwrap().unwrap();
+        assert_eq!(order_book.symbol, "BTCUSD");
+        assert_eq!(order_book.bids.len(), 2);
+        assert_eq!(order_book.asks.len(), 2);
+        assert!(order_book.validate().is_ok());
+        assert_eq!(order_book.best_quote().unwrap().mid(), 100.5);
+        
+        // Exchanges without depth publish no book
+        assert!(MockExchangeAdapter::new().with_delay(0).get_order_book("BTCUSD", 5).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_order_beyond_available_balance_fails_for_insufficient_funds() {
+        let adapter = MockExchangeAdapter::new().with_delay(0).with_available_balance(1000.0);
+        
+        let order = OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size: 0.1,
+            price: Some(50000.0),
+            order_type: OrderType::Limit,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        };
+        
+        let error = adapter.place_order(order).await.unwrap_err();
+        assert!(matches!(error, TradingError::InsufficientFunds { .. }));
+        assert_eq!(adapter.get_account_info().await.unwrap().available_balance, 1000.0);
+    }
+
+    #[test]
+    fn test_round_order_applies_tick_and_lot_size() {
+        let adapter = MockExchangeAdapter::new();
+        
+        let order = OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size: 0.1234,
+            price: Some(50000.126),
+            order_type: OrderType::Limit,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        };
+        
+        let rounded = adapter.round_order(order, &adapter.exchange_info).unwrap();
+        assert!((rounded.size - 0.123).abs() < 1e-9);
+        assert!((rounded.price.unwrap() - 50000.13).abs() < 1e-6);
     }
 
     #[tokio::test]
@@ -409,6 +1322,9 @@ mod tests {
             price: Some(50000.0),
             order_type: OrderType::Limit,
             timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
         };
         
         assert!(adapter.validate_order(&valid_order).await.is_ok());
@@ -422,8 +1338,119 @@ mod tests {
             price: Some(50000.0),
             order_type: OrderType::Limit,
             timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
         };
         
         assert!(adapter.validate_order(&small_order).await.is_err());
     }
+
+    fn new_york_window(day_of_week: u8, open_time: &str, close_time: &str) -> TradingHours {
+        TradingHours {
+            day_of_week,
+            open_time: open_time.to_string(),
+            close_time: close_time.to_string(),
+            timezone: "America/New_York".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_trading_hours_in_exchange_timezone() {
+        use chrono::TimeZone;
+        
+        // Monday 09:30-16:00 New York (UTC-4 in July)
+        let window = new_york_window(1, "09:30:00", "16:00:00");
+        assert!(window.contains(Utc.with_ymd_and_hms(2024, 7, 15, 14, 0, 0).unwrap()).unwrap());
+        assert!(window.contains(Utc.with_ymd_and_hms(2024, 7, 15, 20, 0, 0).unwrap()).unwrap());
+        assert!(!window.contains(Utc.with_ymd_and_hms(2024, 7, 15, 20, 0, 1).unwrap()).unwrap());
+        // 13:00 UTC Monday is 09:00 in New York, before the open
+        assert!(!window.contains(Utc.with_ymd_and_hms(2024, 7, 15, 13, 0, 0).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_overnight_trading_hours_cross_midnight() {
+        use chrono::TimeZone;
+        
+        // Monday 18:00 through Tuesday 04:00 New York
+        let window = new_york_window(1, "18:00:00", "04:00:00");
+        // 02:00 UTC Tuesday is still 22:00 Monday in New York
+        assert!(window.contains(Utc.with_ymd_and_hms(2024, 7, 16, 2, 0, 0).unwrap()).unwrap());
+        // 07:00 UTC Tuesday is 03:00 Tuesday, inside the overnight tail
+        assert!(window.contains(Utc.with_ymd_and_hms(2024, 7, 16, 7, 0, 0).unwrap()).unwrap());
+        // 09:00 UTC Tuesday is 05:00 Tuesday, after the close
+        assert!(!window.contains(Utc.with_ymd_and_hms(2024, 7, 16, 9, 0, 0).unwrap()).unwrap());
+        // 21:00 UTC Monday is 17:00 Monday, before the open
+        assert!(!window.contains(Utc.with_ymd_and_hms(2024, 7, 15, 21, 0, 0).unwrap()).unwrap());
+        // The tail only follows the configured day: 03:00 Wednesday is closed
+        assert!(!window.contains(Utc.with_ymd_and_hms(2024, 7, 17, 7, 0, 0).unwrap()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_is_market_open_uses_exchange_windows() {
+        use chrono::TimeZone;
+        
+        let adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_trading_hours(vec![new_york_window(1, "09:30:00", "16:00:00")]);
+        let monday_open = Utc.with_ymd_and_hms(2024, 7, 15, 14, 0, 0).unwrap();
+        let tuesday_open = Utc.with_ymd_and_hms(2024, 7, 16, 14, 0, 0).unwrap();
+        assert!(adapter.is_market_open("BTCUSD", monday_open).await.unwrap());
+        assert!(!adapter.is_market_open("BTCUSD", tuesday_open).await.unwrap());
+        
+        let always_open = MockExchangeAdapter::new().with_delay(0).with_trading_hours(Vec::new());
+        assert!(always_open.is_market_open("BTCUSD", tuesday_open).await.unwrap());
+        
+        let misconfigured = MockExchangeAdapter::new().with_delay(0).with_trading_hours(vec![TradingHours {
+            timezone: "Mars/Olympus_Mons".to_string(),
+            ..new_york_window(1, "09:30:00", "16:00:00")
+        }]);
+        assert!(misconfigured.is_market_open("BTCUSD", monday_open).await.is_err());
+    }
+
+    fn commission_order(order_type: OrderType, size: f64) -> OrderRequest {
+        OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size,
+            price: Some(50000.0),
+            order_type,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_maker_taker_commission_differs_for_limit_and_market() {
+        let adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_commission_model(CommissionModel::MakerTaker { maker_bps: 2.0, taker_bps: 5.0 });
+
+        let limit = adapter.place_order(commission_order(OrderType::Limit, 0.1)).await.unwrap();
+        let market = adapter.place_order(commission_order(OrderType::Market, 0.1)).await.unwrap();
+
+        assert!((limit.commission - 1.0).abs() < 1e-9);
+        assert!((market.commission - 2.5).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_commission_applied_per_partial_fill() {
+        let adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        let result = adapter.place_order(commission_order(OrderType::Limit, 0.2)).await.unwrap();
+
+        // Default model is 10 bps on the filled half only
+        assert!((result.commission - 5.0).abs() < 1e-9);
+        assert_eq!(result.partial_fills[0]["commission"].as_f64(), Some(result.commission));
+
+        let fixed = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_partial_fills(0.5)
+            .with_commission_model(CommissionModel::Fixed(0.75));
+        let result = fixed.place_order(commission_order(OrderType::Market, 0.2)).await.unwrap();
+        assert_eq!(result.commission, 0.75);
+        assert_eq!(CommissionModel::Fixed(0.75).commission(0.0, 50000.0, false), 0.0);
+    }
 }
\ No newline at end of file
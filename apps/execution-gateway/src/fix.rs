@@ -0,0 +1,349 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use rust_common::{OrderRequest, OrderSide, OrderStatus, OrderType, TimeInForce, TradingError};
+use std::collections::HashMap;
+
+use crate::AdapterOrderResult;
+
+/// FIX field delimiter
+pub const SOH: char = '\x01';
+
+const BEGIN_STRING: &str = "FIX.4.4";
+const SENDING_TIME_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+/// Session identity stamped into the standard header of every outgoing message
+#[derive(Debug, Clone)]
+pub struct FixSession {
+    pub sender_comp_id: String,
+    pub target_comp_id: String,
+}
+
+impl FixSession {
+    pub fn new(sender_comp_id: impl Into<String>, target_comp_id: impl Into<String>) -> Self {
+        Self {
+            sender_comp_id: sender_comp_id.into(),
+            target_comp_id: target_comp_id.into(),
+        }
+    }
+
+    /// Encode `order` as a NewOrderSingle (35=D) with sequence number `msg_seq_num`
+    pub fn new_order_single(&self, order: &OrderRequest, msg_seq_num: u64) -> Result<String, TradingError> {
+        let ord_type = match order.order_type {
+            OrderType::Market => "1",
+            OrderType::Limit => "2",
+            order_type => {
+                return Err(TradingError::ExecutionError {
+                    message: format!("FIX encoding does not support {:?} orders", order_type),
+                });
+            }
+        };
+        let price = match (order.order_type, order.price) {
+            (OrderType::Limit, None) => {
+                return Err(TradingError::ExecutionError {
+                    message: format!("Limit order {} has no price", order.id),
+                });
+            }
+            (OrderType::Limit, price) => price,
+            _ => None,
+        };
+
+        let mut body = vec![
+            (11, order.id.to_string()),
+            (55, order.symbol.clone()),
+            (54, side_code(&order.side).to_string()),
+            (60, order.timestamp.format(SENDING_TIME_FORMAT).to_string()),
+            (38, order.size.to_string()),
+            (40, ord_type.to_string()),
+        ];
+        if let Some(price) = price {
+            body.push((44, price.to_string()));
+        }
+        body.push((59, time_in_force_code(&order.time_in_force).to_string()));
+        if order.post_only {
+            // ExecInst: participate don't initiate
+            body.push((18, "6".to_string()));
+        }
+        if let TimeInForce::Gtd(expire_time) = order.time_in_force {
+            body.push((126, expire_time.format(SENDING_TIME_FORMAT).to_string()));
+        }
+        Ok(self.encode("D", msg_seq_num, Utc::now(), &body))
+    }
+
+    /// Frame `body` fields with the standard header, BodyLength and CheckSum
+    pub fn encode(&self, msg_type: &str, msg_seq_num: u64, sending_time: DateTime<Utc>, body: &[(u32, String)]) -> String {
+        let mut fields = vec![
+            (35, msg_type.to_string()),
+            (49, self.sender_comp_id.clone()),
+            (56, self.target_comp_id.clone()),
+            (34, msg_seq_num.to_string()),
+            (52, sending_time.format(SENDING_TIME_FORMAT).to_string()),
+        ];
+        fields.extend(body.iter().cloned());
+
+        let content: String = fields.iter().map(|(tag, value)| format!("{}={}{}", tag, value, SOH)).collect();
+        let mut message = format!("8={}{}9={}{}{}", BEGIN_STRING, SOH, content.len(), SOH, content);
+        let checksum = checksum(&message);
+        message.push_str(&format!("10={:03}{}", checksum, SOH));
+        message
+    }
+}
+
+/// Sum of the message bytes modulo 256, as carried in tag 10
+pub fn checksum(message: &str) -> u8 {
+    message.bytes().fold(0u8, |sum, byte| sum.wrapping_add(byte))
+}
+
+/// A parsed FIX message whose BeginString, BodyLength and CheckSum have been verified
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixMessage {
+    pub msg_type: String,
+    pub fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    pub fn parse(message: &str) -> Result<Self, TradingError> {
+        let trailer_start = message
+            .trim_end_matches(SOH)
+            .rfind(SOH)
+            .map(|index| index + 1)
+            .ok_or_else(|| malformed("message has no fields"))?;
+        let (framed, trailer) = message.split_at(trailer_start);
+        let expected: u8 = trailer
+            .trim_end_matches(SOH)
+            .strip_prefix("10=")
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(|| malformed("missing or invalid CheckSum (10)"))?;
+        let actual = checksum(framed);
+        if actual != expected {
+            return Err(malformed(&format!("CheckSum mismatch: expected {:03}, computed {:03}", expected, actual)));
+        }
+
+        let mut fields = Vec::new();
+        for field in framed.split(SOH).filter(|field| !field.is_empty()) {
+            let (tag, value) = field.split_once('=').ok_or_else(|| malformed(&format!("field without '=': {}", field)))?;
+            let tag: u32 = tag.parse().map_err(|_| malformed(&format!("invalid tag: {}", tag)))?;
+            fields.push((tag, value.to_string()));
+        }
+
+        match fields.as_slice() {
+            [(8, begin_string), (9, body_length), (35, _), ..] => {
+                if begin_string != BEGIN_STRING {
+                    return Err(malformed(&format!("unsupported BeginString: {}", begin_string)));
+                }
+                let header_len = format!("8={}{}9={}{}", begin_string, SOH, body_length, SOH).len();
+                if body_length.parse::<usize>().ok() != Some(framed.len() - header_len) {
+                    return Err(malformed(&format!("BodyLength {} does not match the message", body_length)));
+                }
+            }
+            _ => return Err(malformed("header must start with tags 8, 9 and 35")),
+        }
+
+        let msg_type = fields[2].1.clone();
+        Ok(Self {
+            msg_type,
+            fields: fields.split_off(3),
+        })
+    }
+
+    /// First value of `tag`
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields.iter().find(|(field_tag, _)| *field_tag == tag).map(|(_, value)| value.as_str())
+    }
+
+    fn require(&self, tag: u32) -> Result<&str, TradingError> {
+        self.get(tag).ok_or_else(|| malformed(&format!("missing tag {}", tag)))
+    }
+
+    fn parse_f64(&self, tag: u32) -> Result<Option<f64>, TradingError> {
+        self.get(tag)
+            .map(|value| value.parse().map_err(|_| malformed(&format!("invalid number in tag {}: {}", tag, value))))
+            .transpose()
+    }
+}
+
+/// Decode an ExecutionReport (35=8) into the adapter result for the order it reports on.
+///
+/// The order ID is the ClOrdID (11), matching the order ID sent in the NewOrderSingle.
+pub fn decode_execution_report(message: &str) -> Result<AdapterOrderResult, TradingError> {
+    let report = FixMessage::parse(message)?;
+    if report.msg_type != "8" {
+        return Err(malformed(&format!("expected ExecutionReport (35=8), got 35={}", report.msg_type)));
+    }
+
+    let status = match report.require(39)? {
+        "0" => OrderStatus::Open,
+        "1" => OrderStatus::PartiallyFilled,
+        "2" => OrderStatus::Filled,
+        "4" => OrderStatus::Cancelled,
+        "8" => OrderStatus::Rejected,
+        "A" => OrderStatus::Pending,
+        "C" => OrderStatus::Expired,
+        other => return Err(malformed(&format!("unsupported OrdStatus (39): {}", other))),
+    };
+    let filled_quantity = report.parse_f64(14)?.unwrap_or(0.0);
+    let average_price = report.parse_f64(6)?.filter(|_| filled_quantity > 0.0);
+    let filled_at = match report.get(60) {
+        Some(transact_time) if filled_quantity > 0.0 => Some(parse_timestamp(transact_time)?),
+        _ => None,
+    };
+
+    Ok(AdapterOrderResult {
+        order_id: report.require(11)?.to_string(),
+        status,
+        filled_quantity,
+        average_price,
+        commission: report.parse_f64(12)?.unwrap_or(0.0),
+        filled_at,
+        partial_fills: Vec::new(),
+    })
+}
+
+/// Decode a NewOrderSingle (35=D) into its core order fields, keyed by tag
+pub fn decode_new_order_single(message: &str) -> Result<HashMap<u32, String>, TradingError> {
+    let order = FixMessage::parse(message)?;
+    if order.msg_type != "D" {
+        return Err(malformed(&format!("expected NewOrderSingle (35=D), got 35={}", order.msg_type)));
+    }
+    Ok(order.fields.into_iter().filter(|(tag, _)| [11, 55, 54, 38, 44, 40, 59, 126].contains(tag)).collect())
+}
+
+fn side_code(side: &OrderSide) -> &'static str {
+    match side {
+        OrderSide::Buy => "1",
+        OrderSide::Sell => "2",
+    }
+}
+
+fn time_in_force_code(time_in_force: &TimeInForce) -> &'static str {
+    match time_in_force {
+        TimeInForce::Gtc => "1",
+        TimeInForce::Ioc => "3",
+        TimeInForce::Fok => "4",
+        TimeInForce::Gtd(_) => "6",
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, TradingError> {
+    NaiveDateTime::parse_from_str(value, SENDING_TIME_FORMAT)
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S"))
+        .map(|timestamp| timestamp.and_utc())
+        .map_err(|_| malformed(&format!("invalid timestamp: {}", value)))
+}
+
+fn malformed(reason: &str) -> TradingError {
+    TradingError::ExecutionError {
+        message: format!("Malformed FIX message: {}", reason),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn order(order_type: OrderType, price: Option<f64>, time_in_force: TimeInForce) -> OrderRequest {
+        OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Sell,
+            size: 0.25,
+            price,
+            order_type,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force,
+            post_only: false,
+        }
+    }
+
+    fn tags(message: &str) -> Vec<u32> {
+        message.split(SOH).filter(|field| !field.is_empty()).map(|field| field.split_once('=').unwrap().0.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_new_order_single_round_trip() {
+        let session = FixSession::new("GATEWAY", "VENUE");
+        let limit = order(OrderType::Limit, Some(50123.5), TimeInForce::Ioc);
+        let message = session.new_order_single(&limit, 7).unwrap();
+
+        assert_eq!(tags(&message)[..7], [8, 9, 35, 49, 56, 34, 52]);
+        assert_eq!(*tags(&message).last().unwrap(), 10);
+        let fields = decode_new_order_single(&message).unwrap();
+        assert_eq!(fields[&11], limit.id.to_string());
+        assert_eq!(fields[&55], "BTCUSD");
+        assert_eq!(fields[&54], "2");
+        assert_eq!(fields[&38], "0.25");
+        assert_eq!(fields[&44], "50123.5");
+        assert_eq!(fields[&40], "2");
+        assert_eq!(fields[&59], "3");
+
+        // Market orders carry no price
+        let market = session.new_order_single(&order(OrderType::Market, Some(1.0), TimeInForce::Gtc), 8).unwrap();
+        let fields = decode_new_order_single(&market).unwrap();
+        assert_eq!(fields[&40], "1");
+        assert!(!fields.contains_key(&44));
+
+        assert!(session.new_order_single(&order(OrderType::Limit, None, TimeInForce::Gtc), 9).is_err());
+        assert!(session.new_order_single(&order(OrderType::StopLimit, Some(1.0), TimeInForce::Gtc), 9).is_err());
+    }
+
+    #[test]
+    fn test_execution_report_round_trip() {
+        let venue = FixSession::new("VENUE", "GATEWAY");
+        let transact_time = Utc.with_ymd_and_hms(2024, 3, 5, 14, 30, 0).unwrap();
+        let body = vec![
+            (37, "venue-1".to_string()),
+            (11, "order-1".to_string()),
+            (17, "exec-1".to_string()),
+            (150, "F".to_string()),
+            (39, "1".to_string()),
+            (55, "BTCUSD".to_string()),
+            (54, "1".to_string()),
+            (14, "0.4".to_string()),
+            (6, "50010.25".to_string()),
+            (12, "2.5".to_string()),
+            (60, transact_time.format(SENDING_TIME_FORMAT).to_string()),
+        ];
+        let report = decode_execution_report(&venue.encode("8", 3, transact_time, &body)).unwrap();
+        assert_eq!(report.order_id, "order-1");
+        assert_eq!(report.status, OrderStatus::PartiallyFilled);
+        assert_eq!(report.filled_quantity, 0.4);
+        assert_eq!(report.average_price, Some(50010.25));
+        assert_eq!(report.commission, 2.5);
+        assert_eq!(report.filled_at, Some(transact_time));
+
+        let rejected = vec![(11, "order-2".to_string()), (39, "8".to_string()), (14, "0".to_string())];
+        let report = decode_execution_report(&venue.encode("8", 4, transact_time, &rejected)).unwrap();
+        assert_eq!(report.status, OrderStatus::Rejected);
+        assert_eq!(report.filled_quantity, 0.0);
+        assert_eq!(report.filled_at, None);
+
+        // A NewOrderSingle is not an execution report
+        let order_message = venue.new_order_single(&order(OrderType::Market, None, TimeInForce::Gtc), 5).unwrap();
+        assert!(decode_execution_report(&order_message).is_err());
+    }
+
+    #[test]
+    fn test_checksum_validation() {
+        // Heartbeat with an empty body: the byte sum of everything before tag 10 is 163 mod 256
+        let framed = "8=FIX.4.4\x019=5\x0135=0\x01";
+        assert_eq!(checksum(framed), 163);
+        assert!(FixMessage::parse(&format!("{}10=163\x01", framed)).is_ok());
+        assert!(FixMessage::parse(&format!("{}10=164\x01", framed)).is_err());
+
+        let session = FixSession::new("GATEWAY", "VENUE");
+        let message = session.new_order_single(&order(OrderType::Market, None, TimeInForce::Gtc), 1).unwrap();
+        assert!(FixMessage::parse(&message).is_ok());
+
+        // Any corrupted byte breaks the checksum
+        let tampered = message.replace("BTCUSD", "ETHUSD");
+        match FixMessage::parse(&tampered) {
+            Err(TradingError::ExecutionError { message }) => assert!(message.contains("CheckSum")),
+            other => panic!("expected a checksum error, got {:?}", other),
+        }
+
+        // A valid checksum does not excuse a wrong BodyLength
+        let framed = "8=FIX.4.4\x019=6\x0135=0\x01";
+        assert!(FixMessage::parse(&format!("{}10={:03}\x01", framed, checksum(framed))).is_err());
+    }
+}
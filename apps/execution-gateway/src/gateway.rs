@@ -1,21 +1,68 @@
-use rust_common::{OrderRequest, TradingError, OrderDecision, ExecutionResult};
-use std::collections::HashMap;
+use rust_common::{OrderRequest, TradingError, OrderDecision, ExecutionResult, PartialRetryPolicy, RejectReason, Symbol, TimeInForce};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::{RwLock, Mutex};
+use tokio::sync::{broadcast, mpsc, watch, RwLock, Semaphore, SemaphorePermit};
 use uuid::Uuid;
 use chrono::{DateTime, Utc, Duration};
 use serde::{Deserialize, Serialize};
 use std::time::Instant;
+use tracing::{info, warn};
 
+mod background_tasks;
+mod basket;
 mod circuit_breaker;
+mod event_log;
+mod events;
 mod exchange_adapter;
+mod latency_tracker;
+mod loss_limit;
+mod metrics;
 mod order_manager;
+mod order_store;
+mod order_updates;
+mod paper_adapter;
+mod position_tracker;
+mod rejection_feedback;
+mod rest_adapter;
 mod retry_logic;
+mod routing;
+mod session_clock;
+mod symbol_throttle;
+mod trailing_stop;
 
+pub use background_tasks::*;
+pub use basket::*;
 pub use circuit_breaker::*;
+pub use event_log::*;
+pub use events::*;
 pub use exchange_adapter::*;
+pub use latency_tracker::*;
+pub use loss_limit::*;
+pub use metrics::*;
 pub use order_manager::*;
+pub use order_store::*;
+pub use order_updates::*;
+pub use paper_adapter::*;
+pub use position_tracker::*;
+pub use rejection_feedback::*;
+pub use rest_adapter::*;
 pub use retry_logic::*;
+pub use routing::*;
+pub use session_clock::*;
+pub use symbol_throttle::*;
+pub use trailing_stop::*;
+
+/// What `place_order` does when `max_concurrent_orders` placements are already in flight
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConcurrencyLimitMode {
+    /// Wait for a placement to finish
+    #[default]
+    Queue,
+    /// Fail immediately so the caller can back off
+    Reject,
+}
 
 /// Configuration for the execution gateway
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,11 +70,94 @@ pub struct GatewayConfig {
     pub max_retries: u32,
     pub base_retry_delay_ms: u64,
     pub max_retry_delay_ms: u64,
+    /// How retry delays are randomised around the exponential backoff
+    pub retry_jitter: JitterStrategy,
+    /// Seed for the retry jitter, making delays reproducible; `None` seeds from entropy
+    pub retry_seed: Option<u64>,
+    /// Backoff schedules replacing the base/max retry delays for particular kinds of error
+    pub retry_class_schedules: HashMap<RetryErrorClass, BackoffSchedule>,
+    /// Sustained retries per second allowed across all orders; `None` disables the budget
+    pub retry_budget_per_sec: Option<f64>,
+    /// Retries that may burst above the sustained retry budget
+    pub retry_budget_burst: u32,
     pub circuit_breaker_failure_threshold: u32,
     pub circuit_breaker_recovery_timeout_ms: u64,
+    /// Maximum concurrent requests admitted while a circuit breaker is half-open
+    pub circuit_breaker_half_open_max_probes: u32,
+    /// Also keep a breaker per (exchange, symbol) so one broken market can be isolated
+    /// while the rest of the exchange keeps trading
+    pub enable_symbol_circuit_breakers: bool,
+    /// Consecutive failures that open a symbol breaker; keep it below the exchange threshold
+    pub symbol_circuit_breaker_failure_threshold: u32,
+    /// Per-attempt limit on order placement, and a cap on cancel and status calls, for
+    /// exchanges registered without their own `AdapterTimeouts`
     pub order_timeout_ms: u64,
+    /// How a venue is chosen for decisions that don't name an exchange
+    pub routing_strategy: RoutingStrategy,
     pub max_concurrent_orders: usize,
+    /// Whether placements beyond `max_concurrent_orders` queue or are rejected
+    pub concurrency_limit_mode: ConcurrencyLimitMode,
+    /// Most orders accepted in one batch request; larger batches are refused outright
+    pub max_batch_size: usize,
     pub enable_partial_fills: bool,
+    /// Reject orders placed outside the exchange's configured trading hours
+    pub enforce_trading_hours: bool,
+    /// Maximum allowed deviation of an order price from the last-known mark, in percent.
+    /// `None` disables the fat-finger check.
+    pub max_price_deviation_pct: Option<f64>,
+    /// How long a cached mark price is trusted before the exchange is asked again, in milliseconds
+    pub mark_price_ttl_ms: u64,
+    /// Portfolio-level notional and position limits; replaceable at runtime
+    pub risk_limits: RiskLimits,
+    /// Realized loss for the UTC day at which risk-increasing orders are refused, in the quote
+    /// asset; `None` disables the guard
+    pub max_daily_loss: Option<f64>,
+    /// Order count and notional each symbol may send per throttle window; unlimited by default
+    pub symbol_throttle: ThrottleLimits,
+    /// Per-symbol replacements for `symbol_throttle`
+    pub symbol_throttle_overrides: HashMap<String, ThrottleLimits>,
+    /// Length of the sliding window the symbol throttle counts over, in milliseconds
+    pub symbol_throttle_window_ms: u64,
+    /// Latency budget from decision timestamp to order submission, in milliseconds
+    pub decision_latency_budget_ms: u64,
+    /// Interval of the periodic completed-order cleanup task, in seconds
+    pub cleanup_interval_secs: u64,
+    /// Age at which the cleanup task cancels orders that are still open, in seconds;
+    /// `None` leaves them working
+    pub max_order_age_secs: Option<u64>,
+    /// Interval between mark price polls for armed trailing stops, in milliseconds
+    pub trailing_stop_poll_interval_ms: u64,
+    /// Interval between exchange status polls for resting OCO legs, in milliseconds
+    pub linked_order_poll_interval_ms: u64,
+    /// Interval between sweeps for orders past their `max_execution_time`, in milliseconds
+    pub order_expiry_poll_interval_ms: u64,
+    /// Interval between funding rate polls for open perpetual positions, in milliseconds
+    pub funding_rate_poll_interval_ms: u64,
+    /// How long shutdown waits for in-flight orders to settle, in milliseconds
+    pub shutdown_drain_timeout_ms: u64,
+    /// Maximum random startup offset applied to each background task, in milliseconds
+    pub background_task_max_offset_ms: u64,
+    /// Jitter applied to each background task interval, as a fraction (0.0 to 1.0)
+    pub background_task_jitter_pct: f64,
+    /// Local time at which the trading session rolls over ("17:00:00")
+    pub session_boundary_time: String,
+    /// IANA timezone of the session boundary ("America/New_York")
+    pub session_timezone: String,
+    /// Window within which updates to the same order are merged before being pushed, in milliseconds
+    pub order_update_coalesce_window_ms: u64,
+    /// Journal file orders are persisted to across restarts; `None` keeps them in memory only
+    pub order_store_path: Option<String>,
+    /// JSON lines file order lifecycle events are appended to and replayed from at startup;
+    /// `None` keeps them in memory only
+    pub event_log_path: Option<String>,
+    /// Sustained HTTP requests per second allowed per client IP; `None` disables rate limiting
+    pub api_rate_limit_per_sec: Option<f64>,
+    /// Requests a client IP may burst above the sustained rate
+    pub api_rate_limit_burst: u32,
+    /// Bearer keys accepted by the HTTP API; empty disables authentication
+    pub api_keys: Vec<String>,
+    /// How long an `Idempotency-Key` keeps returning its original order, in seconds
+    pub idempotency_key_ttl_secs: u64,
 }
 
 impl Default for GatewayConfig {
@@ -36,27 +166,208 @@ impl Default for GatewayConfig {
             max_retries: 3,
             base_retry_delay_ms: 100,
             max_retry_delay_ms: 5000,
+            retry_jitter: JitterStrategy::Full,
+            retry_seed: None,
+            // Rate-limited exchanges need far more breathing room than a dropped connection
+            retry_class_schedules: HashMap::from([(
+                RetryErrorClass::RateLimited,
+                BackoffSchedule { base_delay_ms: 1000, max_delay_ms: 30000 },
+            )]),
+            retry_budget_per_sec: Some(20.0),
+            retry_budget_burst: 50,
             circuit_breaker_failure_threshold: 5,
             circuit_breaker_recovery_timeout_ms: 60000,
+            circuit_breaker_half_open_max_probes: 1,
+            enable_symbol_circuit_breakers: false,
+            symbol_circuit_breaker_failure_threshold: 3,
             order_timeout_ms: 30000,
+            routing_strategy: RoutingStrategy::FirstAvailable,
             max_concurrent_orders: 100,
+            concurrency_limit_mode: ConcurrencyLimitMode::Queue,
+            max_batch_size: 100,
             enable_partial_fills: true,
+            enforce_trading_hours: true,
+            max_price_deviation_pct: Some(10.0),
+            mark_price_ttl_ms: 5000,
+            risk_limits: RiskLimits::default(),
+            max_daily_loss: None,
+            symbol_throttle: ThrottleLimits::default(),
+            symbol_throttle_overrides: HashMap::new(),
+            symbol_throttle_window_ms: 60000,
+            decision_latency_budget_ms: 1000,
+            cleanup_interval_secs: 3600,
+            max_order_age_secs: None,
+            trailing_stop_poll_interval_ms: 1000,
+            linked_order_poll_interval_ms: 1000,
+            order_expiry_poll_interval_ms: 1000,
+            funding_rate_poll_interval_ms: 60000,
+            shutdown_drain_timeout_ms: 30000,
+            background_task_max_offset_ms: 5000,
+            background_task_jitter_pct: 0.1,
+            session_boundary_time: "00:00:00".to_string(),
+            session_timezone: "UTC".to_string(),
+            order_update_coalesce_window_ms: 250,
+            order_store_path: None,
+            event_log_path: None,
+            api_rate_limit_per_sec: Some(50.0),
+            api_rate_limit_burst: 100,
+            api_keys: Vec::new(),
+            idempotency_key_ttl_secs: 86400,
         }
     }
 }
 
+/// Idempotency key -> (client ID, when first seen)
+type IdempotencyKeys = HashMap<String, (Uuid, DateTime<Utc>)>;
+
+/// Client ID -> receiver closed once the placement in flight for it returns
+type PendingPlacements = Arc<std::sync::Mutex<HashMap<Uuid, watch::Receiver<()>>>>;
+
+/// Shadow exchange -> task yielding its result and latency in milliseconds
+type ShadowHandles = Vec<(String, tokio::task::JoinHandle<(Result<AdapterOrderResult, TradingError>, u64)>)>;
+
+/// (exchange, symbol) -> (mark price, when it was seen)
+type MarkPrices = HashMap<(String, String), (f64, Instant)>;
+
 /// High-performance order execution gateway
 pub struct ExecutionGateway {
     config: GatewayConfig,
     order_manager: Arc<OrderManager>,
     exchange_adapters: Arc<RwLock<HashMap<String, Box<dyn ExchangeAdapter + Send + Sync>>>>,
     circuit_breakers: Arc<RwLock<HashMap<String, CircuitBreaker>>>,
+    symbol_circuit_breakers: Arc<RwLock<HashMap<(String, String), CircuitBreaker>>>, // (exchange, symbol) -> breaker
+    adapter_timeouts: Arc<RwLock<HashMap<String, AdapterTimeouts>>>,
     retry_logic: RetryLogic,
+    retry_budget: Option<RetryBudget>, // shared across orders to cap total retry pressure
     active_orders: Arc<RwLock<HashMap<Uuid, OrderExecution>>>,
     order_deduplication: Arc<RwLock<HashMap<Uuid, String>>>, // client_id -> order_id mapping
+    idempotency_keys: Arc<RwLock<IdempotencyKeys>>,
+    pending_placements: PendingPlacements, // decisions being placed but not yet in order_deduplication
+    execution_results: Arc<RwLock<HashMap<String, ExecutionResult>>>, // order_id -> final result
+    mark_prices: Arc<RwLock<MarkPrices>>,
+    risk_limits: Arc<RwLock<RiskLimits>>, // starts from config.risk_limits
+    exposure_reservations: Arc<RwLock<HashMap<Uuid, OpenOrderExposure>>>, // client_id -> unfilled exposure of a working order
+    loss_limit_guard: Arc<LossLimitGuard>,
+    symbol_throttle: Arc<SymbolThrottle>,
+    latency_tracker: Arc<LatencyTracker>,
+    shadow_adapters: Arc<RwLock<HashMap<String, Arc<dyn ExchangeAdapter + Send + Sync>>>>,
+    shadow_comparisons: Arc<RwLock<VecDeque<ShadowComparison>>>,
+    session_clock: Arc<SessionClock>,
+    session_counters: Arc<SessionCounters>,
+    order_updates: broadcast::Sender<OrderUpdate>,
+    order_permits: Arc<Semaphore>, // bounds in-flight placements to max_concurrent_orders
+    trailing_stops: Arc<RwLock<HashMap<String, TrailingStop>>>, // order_id -> armed trailing stop
+    position_tracker: Arc<RwLock<PositionTracker>>,
+    recorded_fills: Arc<RwLock<HashMap<String, f64>>>, // order_id -> quantity already applied to positions
+    order_store: Arc<dyn OrderStore>, // write-through copy of orders, dedup mappings and results
+    event_publisher: Arc<dyn EventPublisher>,
+    metrics: Arc<GatewayMetrics>,
+    algo_parents: Arc<RwLock<HashMap<String, AlgoParent>>>, // parent order_id -> algorithm working it
+    baskets: Arc<RwLock<HashMap<String, BasketStatus>>>, // basket_id -> legs as placed
+    draining: Arc<AtomicBool>, // set once shutdown starts; new orders are refused
+    trading_halted: Arc<AtomicBool>, // kill switch; new orders are refused until cleared
+    in_flight_placements: Arc<AtomicUsize>, // placement calls that haven't returned yet
+}
+
+/// Counts a placement call as in flight until dropped
+struct InFlightPlacement(Arc<AtomicUsize>);
+
+impl Drop for InFlightPlacement {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Claims a client ID for one placement; duplicates wait until it is dropped
+struct PendingPlacement {
+    client_id: Uuid,
+    pending_placements: PendingPlacements,
+    _done: watch::Sender<()>,
+}
+
+impl Drop for PendingPlacement {
+    fn drop(&mut self) {
+        self.pending_placements.lock().unwrap().remove(&self.client_id);
+    }
+}
+
+/// Capacity of the order update broadcast channel
+const ORDER_UPDATE_CHANNEL_CAPACITY: usize = 1024;
+
+/// Exchange used when a decision doesn't name one
+pub const DEFAULT_EXCHANGE: &str = "default";
+
+/// Quantity below which an order is considered completely filled
+const FILL_EPSILON: f64 = 1e-9;
+
+/// How often `drain` checks whether in-flight orders have settled
+const DRAIN_POLL_INTERVAL_MS: u64 = 10;
+
+/// Maximum number of shadow comparisons retained in memory
+const MAX_SHADOW_COMPARISONS: usize = 1000;
+
+/// Connectivity and breaker state of one registered exchange
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeHealth {
+    pub exchange: String,
+    pub reachable: bool,
+    pub breaker_state: CircuitBreakerState,
+    /// Whether the breaker is open and still refusing orders
+    pub breaker_open: bool,
+    pub last_success: Option<DateTime<Utc>>,
+}
+
+impl ExchangeHealth {
+    /// Whether orders can currently be sent to this exchange
+    pub fn is_available(&self) -> bool {
+        self.reachable && !self.breaker_open
+    }
+}
+
+/// Comparison of a shadow venue's execution against the primary fill
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowComparison {
+    pub shadow_exchange: String,
+    pub order_id: String,
+    pub symbol: String,
+    pub primary_price: Option<f64>,
+    pub shadow_price: Option<f64>,
+    pub price_difference: Option<f64>,
+    pub primary_latency_ms: u64,
+    pub shadow_latency_ms: u64,
+    pub shadow_error: Option<String>,
+    pub timestamp: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone)]
+/// Live state of a parent order worked through child orders by an execution algorithm
+#[derive(Debug, Clone, Default)]
+struct AlgoParent {
+    cancel_requested: bool,
+    live_child: Option<String>, // child order currently resting on the exchange
+}
+
+/// Outcome of cancelling every open order
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CancelAllSummary {
+    pub cancelled: Vec<String>,
+    pub failed: Vec<CancelFailure>,
+}
+
+/// An order that could not be cancelled, with the reason
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelFailure {
+    pub order_id: String,
+    pub error: String,
+}
+
+/// Outcome of placing a linked take-profit/stop-loss pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcoExecutionResult {
+    pub take_profit: ExecutionResult,
+    pub stop_loss: ExecutionResult,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderExecution {
     pub order_id: String,
     pub client_id: Uuid,
@@ -68,6 +379,79 @@ pub struct OrderExecution {
     pub partial_fills: Vec<PartialFill>,
     pub total_filled: f64,
     pub average_price: Option<f64>,
+    pub linked_order_id: Option<String>, // OCO sibling, cancelled when this order fills
+    /// Quantity the order asked for; zero for orders persisted before it was recorded
+    #[serde(default)]
+    pub requested_quantity: f64,
+    /// Long for buys, short for sells
+    #[serde(default)]
+    pub direction: Option<rust_common::Direction>,
+    /// Price the decision expected to trade at, the benchmark for fill quality
+    #[serde(default)]
+    pub reference_price: Option<f64>,
+    /// Symbol traded; empty for orders persisted before it was recorded
+    #[serde(default)]
+    pub symbol: String,
+    /// Risk amount of the whole order when it holds exposure against the risk limits;
+    /// `None` for orders that hold none, such as trailing stops and OCO legs
+    #[serde(default)]
+    pub reserved_risk_amount: Option<f64>,
+}
+
+impl OrderExecution {
+    /// Recompute the filled quantity and volume-weighted average price from the fills
+    fn recompute_fill_totals(&mut self) {
+        // Volume-weighted average over all fills, derived from the same sums as total_filled
+        let total_quantity: f64 = self.partial_fills.iter()
+            .map(|f| f.quantity)
+            .sum();
+        let weighted_sum: f64 = self.partial_fills.iter()
+            .map(|f| f.quantity * f.price)
+            .sum();
+        self.total_filled = total_quantity;
+        self.average_price = if total_quantity > 0.0 {
+            Some(weighted_sum / total_quantity)
+        } else {
+            None
+        };
+    }
+
+    /// Fraction of the requested quantity filled so far; zero before any fill
+    pub fn fill_rate(&self) -> f64 {
+        if self.requested_quantity <= 0.0 {
+            return 0.0;
+        }
+        self.total_filled / self.requested_quantity
+    }
+
+    /// Cost of the fills against `reference_price` in quote currency: positive when buys
+    /// averaged above it or sells below it. `None` before any fill or without a direction.
+    pub fn implementation_shortfall(&self, reference_price: f64) -> Option<f64> {
+        let average_price = self.average_price.filter(|_| self.total_filled > 0.0)?;
+        let per_unit = match self.direction? {
+            rust_common::Direction::Long => average_price - reference_price,
+            rust_common::Direction::Short => reference_price - average_price,
+        };
+        Some(per_unit * self.total_filled)
+    }
+
+    /// Fill rate and shortfall against the recorded reference price
+    pub fn fill_quality(&self) -> FillQuality {
+        FillQuality {
+            fill_rate: self.fill_rate(),
+            reference_price: self.reference_price,
+            implementation_shortfall: self.reference_price
+                .and_then(|reference_price| self.implementation_shortfall(reference_price)),
+        }
+    }
+}
+
+/// How well an order filled against what its decision asked for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FillQuality {
+    pub fill_rate: f64,
+    pub reference_price: Option<f64>,
+    pub implementation_shortfall: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,8 +461,59 @@ pub enum OrderExecutionStatus {
     PartiallyFilled,
     Filled,
     Cancelled,
+    /// Cancelled after part of the order filled; `total_filled` holds how much
+    PartiallyFilledCancelled,
     Rejected,
     Failed,
+    Expired,
+}
+
+impl From<rust_common::OrderStatus> for OrderExecutionStatus {
+    fn from(status: rust_common::OrderStatus) -> Self {
+        match status {
+            rust_common::OrderStatus::Pending => OrderExecutionStatus::Pending,
+            rust_common::OrderStatus::PartiallyFilled => OrderExecutionStatus::PartiallyFilled,
+            rust_common::OrderStatus::Filled => OrderExecutionStatus::Filled,
+            rust_common::OrderStatus::Cancelled => OrderExecutionStatus::Cancelled,
+            rust_common::OrderStatus::Rejected => OrderExecutionStatus::Rejected,
+            rust_common::OrderStatus::Open => OrderExecutionStatus::Submitted,
+            rust_common::OrderStatus::Expired => OrderExecutionStatus::Expired,
+        }
+    }
+}
+
+impl OrderExecutionStatus {
+    /// Whether the order can no longer change state
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderExecutionStatus::Filled
+                | OrderExecutionStatus::Cancelled
+                | OrderExecutionStatus::PartiallyFilledCancelled
+                | OrderExecutionStatus::Rejected
+                | OrderExecutionStatus::Failed
+                | OrderExecutionStatus::Expired
+        )
+    }
+
+    /// Tell a cancellation that left fills behind apart from one that left none
+    pub fn with_fills(self, total_filled: f64) -> Self {
+        match self {
+            OrderExecutionStatus::Cancelled if total_filled > 0.0 => OrderExecutionStatus::PartiallyFilledCancelled,
+            status => status,
+        }
+    }
+}
+
+/// An order's full lifecycle with its execution and fill data, for auditing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderDetail {
+    #[serde(flatten)]
+    pub lifecycle: OrderLifecycle,
+    /// Execution tracking, absent once the order has been cleaned up
+    pub execution: Option<OrderExecution>,
+    /// Derived from `execution`, and absent with it
+    pub fill_quality: Option<FillQuality>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -91,19 +526,211 @@ pub struct PartialFill {
 }
 
 impl ExecutionGateway {
+    /// Create a gateway from `config`.
+    ///
+    /// Panics if the trading session settings are invalid; use [`Self::try_new`] to handle that.
     pub fn new(config: GatewayConfig) -> Self {
+        Self::try_new(config).unwrap_or_else(|e| panic!("Invalid gateway config: {}", e))
+    }
+
+    /// Create a gateway, refusing a config whose trading session settings don't parse
+    pub fn try_new(config: GatewayConfig) -> Result<Self, TradingError> {
+        let session_clock = SessionClock::new(&config.session_boundary_time, &config.session_timezone)?;
+        Ok(Self::with_session_clock(config, session_clock))
+    }
+
+    /// Create a gateway with an explicit session clock
+    pub fn with_session_clock(config: GatewayConfig, session_clock: SessionClock) -> Self {
+        let session_clock = Arc::new(session_clock);
         Self {
             config: config.clone(),
-            order_manager: Arc::new(OrderManager::new()),
+            order_manager: Arc::new(OrderManager::new().with_event_log(Arc::new(EventLog::new()))),
             exchange_adapters: Arc::new(RwLock::new(HashMap::new())),
             circuit_breakers: Arc::new(RwLock::new(HashMap::new())),
-            retry_logic: RetryLogic::new(
-                config.max_retries,
-                config.base_retry_delay_ms,
-                config.max_retry_delay_ms,
+            symbol_circuit_breakers: Arc::new(RwLock::new(HashMap::new())),
+            adapter_timeouts: Arc::new(RwLock::new(HashMap::new())),
+            retry_logic: config.retry_class_schedules.iter().fold(
+                {
+                    let retry_logic = RetryLogic::new(
+                        config.max_retries,
+                        config.base_retry_delay_ms,
+                        config.max_retry_delay_ms,
+                        config.retry_jitter,
+                    );
+                    match config.retry_seed {
+                        Some(seed) => retry_logic.with_seed(seed),
+                        None => retry_logic,
+                    }
+                },
+                |retry_logic, (class, schedule)| retry_logic.with_class_schedule(*class, *schedule),
             ),
+            retry_budget: config.retry_budget_per_sec
+                .map(|retries_per_sec| RetryBudget::new(retries_per_sec, config.retry_budget_burst)),
             active_orders: Arc::new(RwLock::new(HashMap::new())),
             order_deduplication: Arc::new(RwLock::new(HashMap::new())),
+            idempotency_keys: Arc::new(RwLock::new(HashMap::new())),
+            pending_placements: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            execution_results: Arc::new(RwLock::new(HashMap::new())),
+            mark_prices: Arc::new(RwLock::new(HashMap::new())),
+            risk_limits: Arc::new(RwLock::new(config.risk_limits.clone())),
+            exposure_reservations: Arc::new(RwLock::new(HashMap::new())),
+            loss_limit_guard: Arc::new(LossLimitGuard::with_clock(config.max_daily_loss, session_clock.clone())),
+            symbol_throttle: Arc::new(SymbolThrottle::new(
+                std::time::Duration::from_millis(config.symbol_throttle_window_ms),
+                config.symbol_throttle,
+                config.symbol_throttle_overrides.clone(),
+            )),
+            latency_tracker: Arc::new(LatencyTracker::new(config.decision_latency_budget_ms)),
+            shadow_adapters: Arc::new(RwLock::new(HashMap::new())),
+            shadow_comparisons: Arc::new(RwLock::new(VecDeque::new())),
+            session_counters: Arc::new(SessionCounters::new(session_clock.clone())),
+            session_clock,
+            order_updates: broadcast::channel(ORDER_UPDATE_CHANNEL_CAPACITY).0,
+            order_permits: Arc::new(Semaphore::new(config.max_concurrent_orders.max(1))),
+            trailing_stops: Arc::new(RwLock::new(HashMap::new())),
+            position_tracker: Arc::new(RwLock::new(PositionTracker::new())),
+            recorded_fills: Arc::new(RwLock::new(HashMap::new())),
+            order_store: Arc::new(InMemoryOrderStore::new()),
+            event_publisher: Arc::new(NoopEventPublisher),
+            metrics: Arc::new(GatewayMetrics::new()),
+            algo_parents: Arc::new(RwLock::new(HashMap::new())),
+            baskets: Arc::new(RwLock::new(HashMap::new())),
+            draining: Arc::new(AtomicBool::new(false)),
+            trading_halted: Arc::new(AtomicBool::new(false)),
+            in_flight_placements: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Persist order state to `order_store`; call `restore_from_store` to pick up a previous run's state
+    pub fn with_order_store(mut self, order_store: Arc<dyn OrderStore>) -> Self {
+        self.order_store = order_store;
+        self
+    }
+
+    /// Record order lifecycles in `event_log` after replaying what a previous run left in it
+    pub async fn with_event_log(mut self, event_log: Arc<EventLog>) -> Result<Self, TradingError> {
+        let order_manager = OrderManager::rebuild_from_log(event_log).await?;
+        info!("Restored {} order lifecycles from the event log", order_manager.get_statistics().await.total_orders);
+        self.order_manager = Arc::new(order_manager);
+        Ok(self)
+    }
+
+    /// Publish an execution event at each order lifecycle transition
+    pub fn with_event_publisher(mut self, event_publisher: Arc<dyn EventPublisher>) -> Self {
+        self.event_publisher = event_publisher;
+        self
+    }
+
+    /// Rehydrate tracked orders and idempotency mappings from the order store, returning the order count
+    pub async fn restore_from_store(&self) -> Result<usize, TradingError> {
+        let dedup_mappings = self.order_store.load_dedup_mappings().await?;
+        let orders = self.order_store.load_active().await?;
+        let order_count = orders.len();
+
+        self.order_deduplication.write().await.extend(dedup_mappings);
+        for order_execution in orders {
+            if !order_execution.status.is_terminal() {
+                self.restore_lifecycle(&order_execution).await;
+                if let Some(reservation) = Self::restored_reservation(&order_execution) {
+                    self.exposure_reservations.write().await.insert(order_execution.client_id, reservation);
+                }
+            }
+            self.active_orders.write().await.insert(order_execution.client_id, order_execution);
+        }
+
+        info!("Restored {} orders from the order store", order_count);
+        Ok(order_count)
+    }
+
+    /// Recreate the lifecycle of a working order the order manager lost in a restart,
+    /// advanced to the state its stored status implies
+    async fn restore_lifecycle(&self, order_execution: &OrderExecution) {
+        let order_id = &order_execution.order_id;
+        if self.order_manager.get_order(order_id).await.is_some() {
+            return;
+        }
+
+        let mut states = vec![OrderLifecycleState::Validated, OrderLifecycleState::Submitted];
+        match order_execution.status {
+            OrderExecutionStatus::Submitted => states.push(OrderLifecycleState::Acknowledged),
+            OrderExecutionStatus::PartiallyFilled => {
+                states.extend([OrderLifecycleState::Acknowledged, OrderLifecycleState::PartiallyFilled]);
+            }
+            _ => {}
+        }
+
+        let mut restored = self.order_manager
+            .create_order(order_id.clone(), order_execution.client_id, order_execution.symbol.clone(), None)
+            .await;
+        for state in states {
+            restored = match restored {
+                Ok(()) => self.order_manager
+                    .transition_state(order_id, state, "Restored from the order store".to_string(), None)
+                    .await,
+                Err(e) => Err(e),
+            };
+        }
+        if let Err(e) = restored {
+            warn!("Failed to restore lifecycle of order {}: {}", order_id, e);
+        }
+    }
+
+    /// Exposure a stored working order still holds against the risk limits
+    fn restored_reservation(order_execution: &OrderExecution) -> Option<OpenOrderExposure> {
+        let risk_amount = order_execution.reserved_risk_amount?;
+        let price = order_execution.reference_price?;
+        let signed_quantity = match order_execution.direction? {
+            rust_common::Direction::Long => order_execution.requested_quantity,
+            rust_common::Direction::Short => -order_execution.requested_quantity,
+        };
+        let remaining = (order_execution.requested_quantity - order_execution.total_filled).max(0.0);
+        if remaining <= FILL_EPSILON {
+            return None;
+        }
+
+        let mut reservation = OpenOrderExposure {
+            symbol: order_execution.symbol.clone(),
+            signed_quantity,
+            price,
+            risk_amount,
+        };
+        reservation.set_remaining(remaining);
+        Some(reservation)
+    }
+
+    /// Write an order's current state through to the order store
+    async fn persist_order(&self, client_id: &Uuid) {
+        let order_execution = self.active_orders.read().await.get(client_id).cloned();
+        if let Some(order_execution) = order_execution {
+            if let Err(e) = self.order_store.save_order(&order_execution).await {
+                warn!("Failed to persist order {}: {}", order_execution.order_id, e);
+            }
+        }
+    }
+
+    /// Persist a new order and its idempotency mapping, then track both in memory.
+    /// Nothing is tracked unless both reached the order store.
+    async fn record_new_order(&self, order_execution: OrderExecution) -> Result<(), TradingError> {
+        let client_id = order_execution.client_id;
+        let order_id = order_execution.order_id.clone();
+        self.order_store.save_order(&order_execution).await?;
+        if let Err(e) = self.order_store.save_dedup_mapping(client_id, &order_id).await {
+            if let Err(e) = self.order_store.remove_order(&client_id).await {
+                warn!("Failed to remove unplaced order {} from the order store: {}", order_id, e);
+            }
+            return Err(e);
+        }
+
+        self.order_deduplication.write().await.insert(client_id, order_id);
+        self.active_orders.write().await.insert(client_id, order_execution);
+        Ok(())
+    }
+
+    /// Keep an order's final result in memory and in the order store
+    async fn store_result(&self, exec_result: &ExecutionResult) {
+        self.execution_results.write().await.insert(exec_result.order_id.clone(), exec_result.clone());
+        if let Err(e) = self.order_store.save_result(exec_result).await {
+            warn!("Failed to persist result of order {}: {}", exec_result.order_id, e);
         }
     }
 
@@ -116,150 +743,199 @@ impl ExecutionGateway {
         let mut adapters = self.exchange_adapters.write().await;
         adapters.insert(exchange_name.clone(), adapter);
         
+        self.metrics.set_circuit_breaker_state(&exchange_name, CircuitBreakerState::Closed);
         let mut circuit_breakers = self.circuit_breakers.write().await;
         circuit_breakers.insert(
             exchange_name,
             CircuitBreaker::new(
                 self.config.circuit_breaker_failure_threshold,
                 self.config.circuit_breaker_recovery_timeout_ms,
-            ),
+            )
+            .with_half_open_max_probes(self.config.circuit_breaker_half_open_max_probes),
         );
     }
 
-    /// Place an order with idempotency and retry logic
-    pub async fn place_order(&self, order_decision: OrderDecision) -> Result<ExecutionResult, TradingError> {
-        let client_id = Uuid::parse_str(&order_decision.decision_id)
-            .map_err(|e| TradingError::ExecutionError { 
-                message: format!("Invalid decision ID: {}", e) 
-            })?;
+    /// Register an exchange adapter with per-operation timeouts
+    pub async fn register_exchange_adapter_with_timeouts(
+        &self,
+        exchange_name: String,
+        adapter: Box<dyn ExchangeAdapter + Send + Sync>,
+        timeouts: AdapterTimeouts,
+    ) {
+        self.register_exchange_adapter(exchange_name.clone(), adapter).await;
+        
+        let mut adapter_timeouts = self.adapter_timeouts.write().await;
+        adapter_timeouts.insert(exchange_name, timeouts);
+    }
 
-        // Check for duplicate orders using client_id
-        {
-            let dedup_map = self.order_deduplication.read().await;
-            if let Some(existing_order_id) = dedup_map.get(&client_id) {
-                // Return existing order result
-                return self.get_order_result(existing_order_id).await;
+    /// Get the operation timeouts for an exchange; order placement defaults to `order_timeout_ms`,
+    /// which also caps the default cancel and status timeouts
+    async fn get_adapter_timeouts(&self, exchange_name: &str) -> AdapterTimeouts {
+        let adapter_timeouts = self.adapter_timeouts.read().await;
+        adapter_timeouts.get(exchange_name).cloned().unwrap_or_else(|| {
+            let defaults = AdapterTimeouts::default();
+            AdapterTimeouts {
+                place_order_ms: self.config.order_timeout_ms,
+                cancel_order_ms: defaults.cancel_order_ms.min(self.config.order_timeout_ms),
+                get_order_status_ms: defaults.get_order_status_ms.min(self.config.order_timeout_ms),
+                ..defaults
             }
-        }
+        })
+    }
 
-        let order_id = Uuid::new_v4().to_string();
-        
-        // Store deduplication mapping
-        {
-            let mut dedup_map = self.order_deduplication.write().await;
-            dedup_map.insert(client_id, order_id.clone());
+    fn parse_decision_id(order_decision: &OrderDecision) -> Result<Uuid, TradingError> {
+        Uuid::parse_str(&order_decision.decision_id).map_err(|e| TradingError::ValidationError {
+            field: "decision_id".to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Reject a good-till-date decision whose date has already passed
+    fn check_time_in_force(order_decision: &OrderDecision) -> Result<(), TradingError> {
+        match order_decision.time_in_force {
+            TimeInForce::Gtd(expires_at) if expires_at <= Utc::now() => Err(TradingError::ValidationError {
+                field: "time_in_force".to_string(),
+                message: format!("good-till-date {} has already passed", expires_at),
+            }),
+            _ => Ok(()),
         }
+    }
 
-        // Create order execution tracking
-        let order_execution = OrderExecution {
-            order_id: order_id.clone(),
-            client_id,
-            exchange: "default".to_string(), // TODO: Determine exchange from order
-            status: OrderExecutionStatus::Pending,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            retry_count: 0,
-            partial_fills: Vec::new(),
-            total_filled: 0.0,
-            average_price: None,
+    /// Reject a decision asking for an order type or feature its exchange doesn't offer
+    async fn check_capabilities(&self, order_decision: &OrderDecision, oco: bool) -> Result<(), TradingError> {
+        let exchange_name = Self::target_exchange(order_decision);
+        let capabilities = {
+            let adapters = self.exchange_adapters.read().await;
+            // A missing adapter is reported when the order is submitted
+            let Some(adapter) = adapters.get(exchange_name) else {
+                return Ok(());
+            };
+            adapter.capabilities()
         };
+        let unsupported = |feature: String| Err(TradingError::OrderRejected {
+            reason: RejectReason::Unsupported,
+            message: format!("Exchange {} does not support {}", exchange_name, feature),
+        });
 
+        if oco {
+            if !capabilities.supports_oco {
+                return unsupported("OCO orders".to_string());
+            }
+            if !capabilities.supports_reduce_only {
+                return unsupported("reduce-only orders".to_string());
+            }
+            for order_type in [rust_common::OrderType::TakeProfit, rust_common::OrderType::StopLoss] {
+                if !capabilities.supports_order_type(order_type) {
+                    return unsupported(format!("{:?} orders", order_type));
+                }
+            }
+        } else if order_decision.order_type != rust_common::OrderType::TrailingStop
+            // Trailing stops are managed by the gateway, never sent as such
+            && !capabilities.supports_order_type(order_decision.order_type)
         {
-            let mut active_orders = self.active_orders.write().await;
-            active_orders.insert(client_id, order_execution);
+            return unsupported(format!("{:?} orders", order_decision.order_type));
+        }
+        if order_decision.post_only && !capabilities.supports_post_only {
+            return unsupported("post-only orders".to_string());
         }
+        if order_decision.reduce_only && !capabilities.supports_reduce_only {
+            return unsupported("reduce-only orders".to_string());
+        }
+        if let Some(max_leverage) = capabilities.max_leverage {
+            if order_decision.leverage > max_leverage {
+                return unsupported(format!(
+                    "leverage {}x (maximum {}x)",
+                    order_decision.leverage, max_leverage
+                ));
+            }
+        }
+        Ok(())
+    }
 
-        // Execute order with retry logic
-        let result = self.execute_order_with_retry(&order_decision, &order_id).await;
-        
-        // Update order status based on result
-        self.update_order_status(&client_id, &result).await;
+    /// Count a decision against its symbol's throttle, refusing it once the window is full
+    fn check_symbol_throttle(&self, order_decision: &OrderDecision) -> Result<(), TradingError> {
+        let notional = order_decision.risk_adjusted_quantity * order_decision.entry_price;
+        self.symbol_throttle.check(&order_decision.symbol, notional).map_err(|exceeded| {
+            let limit = match exceeded {
+                ThrottleExceeded::Orders { limit, .. } => format!("{} orders", limit),
+                ThrottleExceeded::Notional { limit, .. } => format!("{} notional", limit),
+            };
+            let message = format!(
+                "{} throttled: {} per {} ms reached",
+                order_decision.symbol, limit, self.config.symbol_throttle_window_ms
+            );
+            Self::throttled(message, &exceeded)
+        })
+    }
 
-        result
+    /// Throttle refusal, explained to the signal layer like a risk rejection
+    fn throttled(message: String, exceeded: &ThrottleExceeded) -> TradingError {
+        let retry_after_ms = exceeded.retry_after().as_millis() as u64;
+        TradingError::RateLimited {
+            rejection: Some(RejectionFeedback::new(
+                RejectionReason::SymbolThrottled,
+                "symbol_throttle",
+                message.clone(),
+                format!("Retry in {} ms", retry_after_ms),
+            )),
+            message,
+            retry_after_ms: Some(retry_after_ms),
+        }
     }
 
-    /// Execute order with retry logic and circuit breaker
-    async fn execute_order_with_retry(
-        &self,
-        order_decision: &OrderDecision,
-        order_id: &str,
-    ) -> Result<ExecutionResult, TradingError> {
-        let exchange_name = "default"; // TODO: Determine from order
-        
-        let mut execution_result = ExecutionResult::new(
-            order_decision.decision_id.clone(),
-            order_id.to_string(),
-        );
+    /// Seconds an order may stay working before the expiry sweep pulls it
+    fn expires_in_seconds(order_decision: &OrderDecision) -> u64 {
+        match order_decision.time_in_force {
+            // Round up so the sweep never fires before the requested date
+            TimeInForce::Gtd(expires_at) => {
+                let remaining_ms = (expires_at - Utc::now()).num_milliseconds().max(0) as u64;
+                remaining_ms.div_ceil(1000)
+            }
+            _ => u64::from(order_decision.max_execution_time),
+        }
+    }
 
-        let start_time = Instant::now();
+    /// Reject a decision whose symbol is outside its exchange trading hours
+    async fn check_trading_hours(&self, order_decision: &OrderDecision) -> Result<(), TradingError> {
+        if !self.config.enforce_trading_hours {
+            return Ok(());
+        }
 
-        for attempt in 0..=self.config.max_retries {
-            // Check circuit breaker
-            {
-                let circuit_breakers = self.circuit_breakers.read().await;
-                if let Some(cb) = circuit_breakers.get(exchange_name) {
-                    if cb.is_open() {
-                        return Err(TradingError::ExecutionError {
-                            message: format!("Circuit breaker open for exchange: {}", exchange_name),
-                        });
-                    }
-                }
-            }
+        let exchange_name = Self::target_exchange(order_decision);
+        let timeouts = self.get_adapter_timeouts(exchange_name).await;
+        let adapters = self.exchange_adapters.read().await;
+        // A missing adapter is reported when the order is submitted
+        let Some(adapter) = adapters.get(exchange_name) else {
+            return Ok(());
+        };
 
-            // Attempt order execution
-            let result = self.execute_single_order(order_decision, order_id).await;
-            
-            match result {
-                Ok(mut exec_result) => {
-                    exec_result.execution_time_ms = Some(start_time.elapsed().as_millis() as u32);
-                    exec_result.retry_count = attempt;
-                    
-                    // Record success in circuit breaker
-                    {
-                        let mut circuit_breakers = self.circuit_breakers.write().await;
-                        if let Some(cb) = circuit_breakers.get_mut(exchange_name) {
-                            cb.record_success();
-                        }
-                    }
-                    
-                    return Ok(exec_result);
-                }
-                Err(e) => {
-                    execution_result.retry_count = attempt;
-                    execution_result.error_message = Some(e.to_string());
-                    
-                    // Record failure in circuit breaker
-                    {
-                        let mut circuit_breakers = self.circuit_breakers.write().await;
-                        if let Some(cb) = circuit_breakers.get_mut(exchange_name) {
-                            cb.record_failure();
-                        }
-                    }
-                    
-                    // If this is the last attempt, return the error
-                    if attempt == self.config.max_retries {
-                        return Err(e);
-                    }
-                    
-                    // Wait before retry with exponential backoff and jitter
-                    let delay = self.retry_logic.calculate_delay(attempt);
-                    tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
-                }
+        let now = self.session_clock.now();
+        let is_open = with_timeout(
+            "is_market_open",
+            timeouts.get_exchange_info_ms,
+            adapter.is_market_open(&order_decision.symbol, now),
+        )
+        .await;
+        match is_open {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(TradingError::OrderRejected {
+                reason: RejectReason::MarketClosed,
+                message: format!(
+                    "Market closed for {} on {} at {}",
+                    order_decision.symbol, exchange_name, now
+                ),
+            }),
+            // An unreachable exchange is handled by the submission retry path
+            Err(e) => {
+                warn!("Could not check trading hours for {} on {}: {}", order_decision.symbol, exchange_name, e);
+                Ok(())
             }
         }
-
-        Err(TradingError::ExecutionError {
-            message: "Max retries exceeded".to_string(),
-        })
     }
 
-    /// Execute a single order attempt
-    async fn execute_single_order(
-        &self,
-        order_decision: &OrderDecision,
-        order_id: &str,
-    ) -> Result<ExecutionResult, TradingError> {
-        let exchange_name = "default"; // TODO: Determine from order
+    /// Get exchange information and trading rules for a symbol
+    pub async fn get_exchange_info(&self, exchange_name: &str, symbol: &str) -> Result<ExchangeInfo, TradingError> {
+        let timeouts = self.get_adapter_timeouts(exchange_name).await;
         
         let adapters = self.exchange_adapters.read().await;
         let adapter = adapters.get(exchange_name)
@@ -267,444 +943,5550 @@ impl ExecutionGateway {
                 message: format!("Exchange adapter not found: {}", exchange_name),
             })?;
 
-        // Convert OrderDecision to OrderRequest for adapter
-        let order_request = self.convert_decision_to_request(order_decision, order_id)?;
-        
-        // Execute through adapter
-        let adapter_result = adapter.place_order(order_request).await?;
-        
-        // Convert adapter result to ExecutionResult
-        let mut execution_result = ExecutionResult::new(
-            order_decision.decision_id.clone(),
-            order_id.to_string(),
-        );
-        
-        execution_result.status = adapter_result.status;
-        execution_result.filled_quantity = adapter_result.filled_quantity;
-        execution_result.average_price = adapter_result.average_price;
-        execution_result.commission = adapter_result.commission;
-        execution_result.filled_at = adapter_result.filled_at;
-        
-        // Handle partial fills if enabled
-        if self.config.enable_partial_fills && adapter_result.partial_fills.len() > 0 {
-            self.handle_partial_fills(&order_decision.decision_id, &adapter_result.partial_fills).await?;
-        }
+        with_timeout("get_exchange_info", timeouts.get_exchange_info_ms, adapter.get_exchange_info(symbol)).await
+    }
 
-        Ok(execution_result)
+    /// Register a shadow adapter that mirrors every order without affecting results
+    pub async fn register_shadow_adapter(
+        &self,
+        exchange_name: String,
+        adapter: Box<dyn ExchangeAdapter + Send + Sync>,
+    ) {
+        let mut shadow_adapters = self.shadow_adapters.write().await;
+        shadow_adapters.insert(exchange_name, Arc::from(adapter));
     }
 
-    /// Convert OrderDecision to OrderRequest
-    fn convert_decision_to_request(
+    /// Get recorded shadow comparisons, oldest first
+    pub async fn get_shadow_comparisons(&self) -> Vec<ShadowComparison> {
+        let shadow_comparisons = self.shadow_comparisons.read().await;
+        shadow_comparisons.iter().cloned().collect()
+    }
+
+    /// Fire the order at every shadow adapter in the background
+    async fn dispatch_shadow_orders(
         &self,
-        decision: &OrderDecision,
-        order_id: &str,
-    ) -> Result<OrderRequest, TradingError> {
-        use rust_common::{OrderSide, OrderType};
-        
-        let side = match decision.direction {
-            rust_common::Direction::Long => OrderSide::Buy,
-            rust_common::Direction::Short => OrderSide::Sell,
-        };
+        order_decision: &OrderDecision,
+    ) -> ShadowHandles {
+        // Trailing stops rest in the gateway, so there is nothing to mirror until they trigger
+        if order_decision.order_type == rust_common::OrderType::TrailingStop {
+            return Vec::new();
+        }
 
-        let order_type = match decision.order_type {
-            rust_common::OrderType::Market => OrderType::Market,
-            rust_common::OrderType::Limit => OrderType::Limit,
-            rust_common::OrderType::StopLoss => OrderType::StopLoss,
-            rust_common::OrderType::TakeProfit => OrderType::TakeProfit,
-        };
+        let shadow_adapters = self.shadow_adapters.read().await;
+        let mut handles = Vec::with_capacity(shadow_adapters.len());
 
-        Ok(OrderRequest {
-            id: Uuid::parse_str(order_id).map_err(|e| TradingError::ExecutionError {
-                message: format!("Invalid order ID: {}", e),
-            })?,
-            symbol: decision.symbol.clone(),
-            side,
-            size: decision.risk_adjusted_quantity,
-            price: Some(decision.entry_price),
-            order_type,
-            timestamp: decision.timestamp,
-        })
+        for (exchange_name, adapter) in shadow_adapters.iter() {
+            // Shadow orders get their own id so they never collide with the primary
+            let order_request = match self.convert_decision_to_request(order_decision, &Uuid::new_v4().to_string()) {
+                Ok(order_request) => order_request,
+                Err(e) => {
+                    warn!("Failed to build shadow order for {}: {}", exchange_name, e);
+                    continue;
+                }
+            };
+
+            let adapter = adapter.clone();
+            let handle = tokio::spawn(async move {
+                let start_time = Instant::now();
+                let result = adapter.place_order(order_request).await;
+                (result, start_time.elapsed().as_millis() as u64)
+            });
+            handles.push((exchange_name.clone(), handle));
+        }
+
+        handles
     }
 
-    /// Handle partial fills
-    async fn handle_partial_fills(
+    /// Record shadow results against the primary fill once they complete
+    fn record_shadow_comparisons(
         &self,
-        decision_id: &str,
-        partial_fills: &[HashMap<String, serde_json::Value>],
-    ) -> Result<(), TradingError> {
-        let client_id = Uuid::parse_str(decision_id)
-            .map_err(|e| TradingError::ExecutionError {
-                message: format!("Invalid decision ID: {}", e),
-            })?;
+        order_id: &str,
+        symbol: &str,
+        primary_result: &Result<ExecutionResult, TradingError>,
+        primary_latency_ms: u64,
+        shadow_handles: ShadowHandles,
+    ) {
+        if shadow_handles.is_empty() {
+            return;
+        }
 
-        let mut active_orders = self.active_orders.write().await;
-        if let Some(order_execution) = active_orders.get_mut(&client_id) {
-            for fill_data in partial_fills {
-                let partial_fill = PartialFill {
-                    fill_id: fill_data.get("fill_id")
-                        .and_then(|v| v.as_str())
-                        .unwrap_or("unknown")
-                        .to_string(),
-                    quantity: fill_data.get("quantity")
-                        .and_then(|v| v.as_f64())
-                        .unwrap_or(0.0),
-                    price: fill_data.get("price")
-                        .and_then(|v| v.as_f64())
-                        .unwrap_or(0.0),
+        let primary_price = primary_result.as_ref().ok().and_then(|r| r.average_price);
+        let shadow_comparisons = self.shadow_comparisons.clone();
+        let order_id = order_id.to_string();
+        let symbol = symbol.to_string();
+
+        tokio::spawn(async move {
+            for (shadow_exchange, handle) in shadow_handles {
+                let (shadow_price, shadow_latency_ms, shadow_error) = match handle.await {
+                    Ok((Ok(adapter_result), latency_ms)) => (adapter_result.average_price, latency_ms, None),
+                    Ok((Err(e), latency_ms)) => (None, latency_ms, Some(e.to_string())),
+                    Err(e) => (None, 0, Some(format!("Shadow task failed: {}", e))),
+                };
+
+                if let Some(error) = &shadow_error {
+                    warn!("Shadow order on {} failed: {}", shadow_exchange, error);
+                }
+
+                let comparison = ShadowComparison {
+                    shadow_exchange,
+                    order_id: order_id.clone(),
+                    symbol: symbol.clone(),
+                    primary_price,
+                    shadow_price,
+                    price_difference: primary_price.zip(shadow_price).map(|(p, s)| s - p),
+                    primary_latency_ms,
+                    shadow_latency_ms,
+                    shadow_error,
                     timestamp: Utc::now(),
-                    commission: fill_data.get("commission")
-                        .and_then(|v| v.as_f64())
-                        .unwrap_or(0.0),
                 };
-                
-                order_execution.partial_fills.push(partial_fill.clone());
-                order_execution.total_filled += partial_fill.quantity;
-                
-                // Update average price
-                if order_execution.average_price.is_none() {
-                    order_execution.average_price = Some(partial_fill.price);
-                } else {
-                    let current_avg = order_execution.average_price.unwrap();
-                    let total_quantity: f64 = order_execution.partial_fills.iter()
-                        .map(|f| f.quantity)
-                        .sum();
-                    let weighted_sum: f64 = order_execution.partial_fills.iter()
-                        .map(|f| f.quantity * f.price)
-                        .sum();
-                    order_execution.average_price = Some(weighted_sum / total_quantity);
+
+                let mut shadow_comparisons = shadow_comparisons.write().await;
+                if shadow_comparisons.len() >= MAX_SHADOW_COMPARISONS {
+                    shadow_comparisons.pop_front();
                 }
+                shadow_comparisons.push_back(comparison);
             }
-            
-            order_execution.updated_at = Utc::now();
-        }
+        });
+    }
 
-        Ok(())
+    /// Place an order with idempotency and retry logic
+    pub async fn place_order(&self, order_decision: OrderDecision) -> Result<ExecutionResult, TradingError> {
+        let client_id = Self::parse_decision_id(&order_decision)?;
+        self.place_order_for_client(client_id, order_decision).await
     }
 
-    /// Update order status based on execution result
-    async fn update_order_status(
+    /// Place an order deduplicated on a client-supplied idempotency key instead of the decision ID.
+    /// Repeating a key within `idempotency_key_ttl_secs` returns the original order's result.
+    pub async fn place_order_with_idempotency_key(
         &self,
-        client_id: &Uuid,
-        result: &Result<ExecutionResult, TradingError>,
-    ) {
-        let mut active_orders = self.active_orders.write().await;
-        if let Some(order_execution) = active_orders.get_mut(client_id) {
-            match result {
-                Ok(exec_result) => {
-                    order_execution.status = match exec_result.status {
-                        rust_common::OrderStatus::Pending => OrderExecutionStatus::Pending,
-                        rust_common::OrderStatus::PartiallyFilled => OrderExecutionStatus::PartiallyFilled,
-                        rust_common::OrderStatus::Filled => OrderExecutionStatus::Filled,
-                        rust_common::OrderStatus::Cancelled => OrderExecutionStatus::Cancelled,
-                        rust_common::OrderStatus::Rejected => OrderExecutionStatus::Rejected,
-                    };
+        idempotency_key: &str,
+        order_decision: OrderDecision,
+    ) -> Result<ExecutionResult, TradingError> {
+        let now = Utc::now();
+        let ttl = Duration::seconds(self.config.idempotency_key_ttl_secs as i64);
+        let client_id = {
+            let mut idempotency_keys = self.idempotency_keys.write().await;
+            let entry = idempotency_keys
+                .entry(idempotency_key.to_string())
+                .or_insert_with(|| (Uuid::new_v4(), now));
+            if now - entry.1 > ttl {
+                *entry = (Uuid::new_v4(), now);
+            }
+            entry.0
+        };
+        self.place_order_for_client(client_id, order_decision).await
+    }
+
+    async fn place_order_for_client(
+        &self,
+        client_id: Uuid,
+        order_decision: OrderDecision,
+    ) -> Result<ExecutionResult, TradingError> {
+        let _pending = loop {
+            // Checked and claimed under the dedup lock so concurrent duplicates can't both pass
+            let mut in_flight = {
+                let dedup_map = self.order_deduplication.write().await;
+                if let Some(existing_order_id) = dedup_map.get(&client_id).cloned() {
+                    drop(dedup_map);
+                    // Return existing order result
+                    return self.get_order_result(&existing_order_id).await;
                 }
-                Err(_) => {
-                    order_execution.status = OrderExecutionStatus::Failed;
+                let mut pending_placements = self.pending_placements.lock().unwrap();
+                match pending_placements.get(&client_id) {
+                    Some(in_flight) => in_flight.clone(),
+                    None => {
+                        let (done, in_flight) = watch::channel(());
+                        pending_placements.insert(client_id, in_flight);
+                        break PendingPlacement {
+                            client_id,
+                            pending_placements: self.pending_placements.clone(),
+                            _done: done,
+                        };
+                    }
                 }
+            };
+            // Replay the first placement once it returns, or take over if it failed before being recorded
+            while in_flight.changed().await.is_ok() {}
+        };
+
+        let _in_flight = self.begin_placement(order_decision.reduce_only)?;
+
+        // Pin unrouted decisions to one venue so every later step agrees on it
+        let mut order_decision = order_decision;
+        order_decision.symbol = Symbol::normalize(&order_decision.symbol);
+        if order_decision.exchange.is_none() {
+            order_decision.exchange = Some(self.route_order(&order_decision).await);
+        }
+
+        // Reject decisions failing a risk gate before anything is tracked or submitted
+        self.reserve_exposure(client_id, &order_decision).await?;
+        let _permit = match self.admit_order(&order_decision).await {
+            Ok(permit) => permit,
+            Err(e) => {
+                self.release_exposure(&client_id).await;
+                return Err(e);
             }
-            order_execution.updated_at = Utc::now();
+        };
+
+        let order_id = Uuid::new_v4().to_string();
+
+        // Create order execution tracking
+        let order_execution = OrderExecution {
+            order_id: order_id.clone(),
+            client_id,
+            exchange: Self::target_exchange(&order_decision).to_string(),
+            status: OrderExecutionStatus::Pending,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            retry_count: 0,
+            partial_fills: Vec::new(),
+            total_filled: 0.0,
+            average_price: None,
+            linked_order_id: None,
+            requested_quantity: order_decision.risk_adjusted_quantity,
+            direction: Some(order_decision.direction),
+            reference_price: Some(order_decision.entry_price),
+            symbol: order_decision.symbol.clone(),
+            reserved_risk_amount: (order_decision.order_type != rust_common::OrderType::TrailingStop)
+                .then_some(order_decision.risk_amount),
+        };
+
+        // An order that could not be recovered after a restart is never sent
+        if let Err(e) = self.record_new_order(order_execution).await {
+            self.release_exposure(&client_id).await;
+            return Err(e);
+        }
+
+        self.track_submission(&order_id, client_id, &order_decision).await;
+
+        // Mirror the order to any shadow venues; their outcome never affects the result
+        let shadow_handles = self.dispatch_shadow_orders(&order_decision).await;
+
+        // Execute order with retry logic
+        let start_time = Instant::now();
+        let result = self.execute_order_with_retry(&order_decision, &order_id).await;
+        let primary_latency_ms = start_time.elapsed().as_millis() as u64;
+
+        // All-or-nothing orders may resubmit the unfilled remainder before giving up;
+        // immediate orders never leave a remainder working, whatever the exchange did
+        let result = match (result, order_decision.partial_retry_policy) {
+            (Ok(exec_result), _)
+                if matches!(order_decision.time_in_force, TimeInForce::Ioc | TimeInForce::Fok)
+                    && matches!(
+                        exec_result.status,
+                        rust_common::OrderStatus::Pending | rust_common::OrderStatus::PartiallyFilled
+                    ) =>
+            {
+                Ok(self.cancel_unfilled_remainder(&order_decision, exec_result).await)
+            }
+            (Ok(exec_result), Some(policy))
+                if !order_decision.partial_fill_acceptable
+                    && exec_result.status == rust_common::OrderStatus::PartiallyFilled =>
+            {
+                Ok(self.retry_partial_remainder(&order_decision, exec_result, policy).await)
+            }
+            (result, _) => result,
+        };
+        
+        // Update order status based on result
+        self.update_order_status(&client_id, &result).await;
+
+        // Keep the final result so idempotent replays return it
+        if let Ok(exec_result) = &result {
+            self.store_result(exec_result).await;
+        }
+        self.track_outcome(&order_id, &result).await;
+
+        if let Ok(exec_result) = &result {
+            self.session_counters.record_order(
+                exec_result.filled_quantity * exec_result.average_price.unwrap_or(0.0),
+            );
         }
+
+        self.record_shadow_comparisons(
+            &order_id,
+            &order_decision.symbol,
+            &result,
+            primary_latency_ms,
+            shadow_handles,
+        );
+
+        result
     }
 
-    /// Get order result by order ID
-    async fn get_order_result(&self, order_id: &str) -> Result<ExecutionResult, TradingError> {
-        // This would typically query a database or cache
-        // For now, return a placeholder result
-        Ok(ExecutionResult::new("placeholder".to_string(), order_id.to_string()))
+    /// Checks between the risk gates and submission, ending with a concurrency permit
+    async fn admit_order(&self, order_decision: &OrderDecision) -> Result<SemaphorePermit<'_>, TradingError> {
+        self.check_daily_loss_limit(order_decision).await?;
+
+        self.check_trading_hours(order_decision).await?;
+        Self::check_time_in_force(order_decision)?;
+        self.check_capabilities(order_decision, false).await?;
+        self.check_symbol_throttle(order_decision)?;
+
+        // No more than max_concurrent_orders are in flight; the rest wait or are turned away
+        match self.config.concurrency_limit_mode {
+            ConcurrencyLimitMode::Queue => self.order_permits.acquire().await
+                .map_err(|e| TradingError::ExecutionError {
+                    message: format!("Order placement unavailable: {}", e),
+                }),
+            ConcurrencyLimitMode::Reject => self.order_permits.try_acquire()
+                .map_err(|_| TradingError::ExecutionError {
+                    message: "concurrency limit reached".to_string(),
+                }),
+        }
     }
 
-    /// Cancel an order
-    pub async fn cancel_order(&self, order_id: &str) -> Result<(), TradingError> {
-        let exchange_name = "default"; // TODO: Determine from order
-        
-        let adapters = self.exchange_adapters.read().await;
-        let adapter = adapters.get(exchange_name)
-            .ok_or_else(|| TradingError::ExecutionError {
-                message: format!("Exchange adapter not found: {}", exchange_name),
-            })?;
+    /// Record a new order's lifecycle through to submission
+    async fn track_submission(&self, order_id: &str, client_id: Uuid, order_decision: &OrderDecision) {
+        let tracked = self.order_manager
+            .create_order(
+                order_id.to_string(),
+                client_id,
+                order_decision.symbol.clone(),
+                Some(Self::expires_in_seconds(order_decision)),
+            )
+            .await;
+        let tracked = match tracked {
+            Ok(()) => self.transition_lifecycle(order_id, OrderLifecycleState::Validated, "Passed risk checks".to_string())
+                .await,
+            Err(e) => Err(e),
+        };
+        let tracked = match tracked {
+            Ok(()) => self.transition_lifecycle(
+                    order_id,
+                    OrderLifecycleState::Submitted,
+                    format!("Submitted to {}", Self::target_exchange(order_decision)),
+                )
+                .await,
+            Err(e) => Err(e),
+        };
 
-        adapter.cancel_order(order_id).await
+        // Lifecycle tracking is observational and never blocks an order
+        if let Err(e) = tracked {
+            warn!("Failed to track lifecycle of order {}: {}", order_id, e);
+        }
     }
 
-    /// Get order status
-    pub async fn get_order_status(&self, order_id: &str) -> Result<OrderExecutionStatus, TradingError> {
-        let exchange_name = "default"; // TODO: Determine from order
-        
-        let adapters = self.exchange_adapters.read().await;
-        let adapter = adapters.get(exchange_name)
-            .ok_or_else(|| TradingError::ExecutionError {
-                message: format!("Exchange adapter not found: {}", exchange_name),
-            })?;
+    /// Record the exchange's answer in an order's lifecycle
+    async fn track_outcome(&self, order_id: &str, result: &Result<ExecutionResult, TradingError>) {
+        let transitions = match result {
+            Ok(exec_result) => {
+                // Resting orders stay acknowledged until the exchange reports more
+                let final_state = OrderLifecycleState::from(exec_result.status);
+                let mut transitions = vec![OrderLifecycleState::Acknowledged];
+                if !matches!(final_state, OrderLifecycleState::Submitted | OrderLifecycleState::Acknowledged) {
+                    transitions.push(final_state);
+                }
+                transitions
+            }
+            Err(_) => vec![OrderLifecycleState::Failed],
+        };
 
-        let status = adapter.get_order_status(order_id).await?;
-        
-        Ok(match status {
-            rust_common::OrderStatus::Pending => OrderExecutionStatus::Pending,
-            rust_common::OrderStatus::PartiallyFilled => OrderExecutionStatus::PartiallyFilled,
-            rust_common::OrderStatus::Filled => OrderExecutionStatus::Filled,
-            rust_common::OrderStatus::Cancelled => OrderExecutionStatus::Cancelled,
-            rust_common::OrderStatus::Rejected => OrderExecutionStatus::Rejected,
-        })
+        for state in transitions {
+            let reason = match result {
+                Ok(_) => format!("Exchange reported {:?}", state),
+                Err(e) => e.to_string(),
+            };
+            if let Err(e) = self.transition_lifecycle(order_id, state, reason).await {
+                warn!("Failed to track lifecycle of order {}: {}", order_id, e);
+                return;
+            }
+        }
     }
 
-    /// Get active orders count
-    pub async fn get_active_orders_count(&self) -> usize {
-        let active_orders = self.active_orders.read().await;
-        active_orders.len()
+    /// Move an order to its next lifecycle state and publish the matching execution event
+    async fn transition_lifecycle(&self, order_id: &str, state: OrderLifecycleState, reason: String) -> Result<(), TradingError> {
+        let state = match state {
+            OrderLifecycleState::Cancelled => state.with_fills(self.filled_quantity(order_id).await),
+            state => state,
+        };
+        self.order_manager.transition_state(order_id, state.clone(), reason.clone(), None).await?;
+
+        if let Some(event) = self.execution_event(order_id, state, reason).await {
+            // Downstream consumers never hold up an order
+            if let Err(e) = self.event_publisher.publish(&event).await {
+                warn!("Failed to publish {} event for order {}: {}", event.kind(), order_id, e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Quantity filled so far on a tracked order
+    pub(crate) async fn filled_quantity(&self, order_id: &str) -> f64 {
+        let tracked = match self.order_manager.get_order(order_id).await {
+            Some(lifecycle) => self.active_orders.read().await
+                .get(&lifecycle.client_id)
+                .map_or(0.0, |execution| execution.total_filled),
+            None => 0.0,
+        };
+        let reported = self.execution_results.read().await
+            .get(order_id)
+            .map_or(0.0, |exec_result| exec_result.filled_quantity);
+        tracked.max(reported)
+    }
+
+    /// Event for entering `state`; `None` for states downstream systems don't track
+    async fn execution_event(&self, order_id: &str, state: OrderLifecycleState, reason: String) -> Option<ExecutionEvent> {
+        let lifecycle = self.order_manager.get_order(order_id).await?;
+        let execution = self.active_orders.read().await.get(&lifecycle.client_id).cloned();
+        let (exchange, mut filled_quantity, mut average_price) = execution
+            .map(|execution| (execution.exchange, execution.total_filled, execution.average_price))
+            .unwrap_or_default();
+        // The exchange's result covers fills reported without a fill breakdown
+        if let Some(exec_result) = self.execution_results.read().await.get(order_id) {
+            filled_quantity = exec_result.filled_quantity;
+            average_price = exec_result.average_price;
+        }
+        let (order_id, client_id, symbol, timestamp) = (order_id.to_string(), lifecycle.client_id, lifecycle.symbol, Utc::now());
+
+        match state {
+            OrderLifecycleState::Submitted => Some(ExecutionEvent::OrderSubmitted { order_id, client_id, symbol, exchange, timestamp }),
+            OrderLifecycleState::PartiallyFilled => Some(ExecutionEvent::PartialFill {
+                order_id,
+                client_id,
+                symbol,
+                filled_quantity,
+                average_price,
+                timestamp,
+            }),
+            OrderLifecycleState::Filled => Some(ExecutionEvent::Filled {
+                order_id,
+                client_id,
+                symbol,
+                filled_quantity,
+                average_price,
+                timestamp,
+            }),
+            OrderLifecycleState::Cancelled
+            | OrderLifecycleState::PartiallyFilledCancelled
+            | OrderLifecycleState::Expired => Some(ExecutionEvent::Cancelled {
+                order_id,
+                client_id,
+                symbol,
+                filled_quantity,
+                reason,
+                timestamp,
+            }),
+            OrderLifecycleState::Rejected | OrderLifecycleState::Failed => Some(ExecutionEvent::Rejected { order_id, client_id, symbol, reason, timestamp }),
+            OrderLifecycleState::Created | OrderLifecycleState::Validated | OrderLifecycleState::Acknowledged => None,
+        }
+    }
+
+    /// Lifecycle history and fills of an order, if it is tracked
+    pub async fn get_order_detail(&self, order_id: &str) -> Option<OrderDetail> {
+        let lifecycle = self.order_manager.get_order(order_id).await?;
+        let execution = self.active_orders.read().await
+            .get(&lifecycle.client_id)
+            .filter(|order_execution| order_execution.order_id == order_id)
+            .cloned();
+        let fill_quality = execution.as_ref().map(OrderExecution::fill_quality);
+        Some(OrderDetail { lifecycle, execution, fill_quality })
+    }
+
+    /// Breakdown of tracked orders by lifecycle state
+    pub async fn get_order_statistics(&self) -> OrderStatistics {
+        self.order_manager.get_statistics().await
+    }
+
+    /// Page through tracked orders, optionally filtered by state and symbol
+    pub async fn list_orders(
+        &self,
+        state: Option<OrderLifecycleState>,
+        symbol: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> (Vec<OrderLifecycle>, usize) {
+        let symbol = symbol.map(Symbol::normalize);
+        self.order_manager.list_orders(state, symbol.as_deref(), limit, offset).await
+    }
+
+    /// Update the cached mark price for a symbol on the default exchange
+    pub async fn update_mark_price(&self, symbol: &str, price: f64) {
+        self.update_exchange_mark_price(DEFAULT_EXCHANGE, symbol, price).await;
+    }
+
+    /// Update the cached mark price for a symbol on one exchange
+    pub async fn update_exchange_mark_price(&self, exchange_name: &str, symbol: &str, price: f64) {
+        let symbol = Symbol::normalize(symbol);
+        let symbol = symbol.as_str();
+        {
+            let mut mark_prices = self.mark_prices.write().await;
+            mark_prices.insert((exchange_name.to_string(), symbol.to_string()), (price, Instant::now()));
+        }
+        self.position_tracker.write().await.update_mark_price(symbol, price);
+
+        self.process_trailing_stops(exchange_name, symbol, price).await;
+    }
+
+    /// Positions built from this gateway's fills, sorted by symbol
+    pub async fn get_positions(&self) -> Vec<TrackedPosition> {
+        self.position_tracker.read().await.positions()
+    }
+
+    /// Position built from this gateway's fills in a symbol
+    pub async fn get_position(&self, symbol: &str) -> Option<TrackedPosition> {
+        self.position_tracker.read().await.get_position(symbol)
+    }
+
+    /// Fold an adapter fill into the tracked position for its symbol.
+    ///
+    /// `adapter_result` carries the order's cumulative fill, so only the quantity not yet
+    /// applied for `order_id` moves the position; reporting the same fill twice is harmless.
+    async fn record_fill(&self, symbol: &str, side: &rust_common::OrderSide, order_id: &str, adapter_result: &AdapterOrderResult) {
+        let Some(price) = adapter_result.average_price else {
+            return;
+        };
+        let quantity = {
+            let mut recorded_fills = self.recorded_fills.write().await;
+            let recorded = recorded_fills.entry(order_id.to_string()).or_insert(0.0);
+            let quantity = adapter_result.filled_quantity - *recorded;
+            if quantity <= 1e-12 {
+                return;
+            }
+            *recorded = adapter_result.filled_quantity;
+            quantity
+        };
+        let fill = PartialFill {
+            fill_id: order_id.to_string(),
+            quantity,
+            price,
+            timestamp: adapter_result.filled_at.unwrap_or_else(Utc::now),
+            commission: adapter_result.total_commission() * quantity / adapter_result.filled_quantity,
+        };
+        let mark_price = self.latest_mark_price(symbol).await;
+
+        let mut position_tracker = self.position_tracker.write().await;
+        let realized_before = position_tracker.total_realized_pnl();
+        position_tracker.apply_fill(symbol, side, &fill);
+        if let Some(mark_price) = mark_price {
+            position_tracker.update_mark_price(symbol, mark_price);
+        }
+        self.loss_limit_guard.record_realized_pnl(position_tracker.total_realized_pnl() - realized_before - fill.commission);
+    }
+
+    /// Fold whatever a tracked order has filled so far into its position.
+    ///
+    /// Parents are skipped: their fills are their children's, which are recorded as they happen.
+    async fn record_order_fill(&self, client_id: &Uuid) {
+        let tracked = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.get(client_id)
+                .filter(|order_execution| !order_execution.symbol.is_empty())
+                .and_then(|order_execution| {
+                    let side = match order_execution.direction? {
+                        rust_common::Direction::Long => rust_common::OrderSide::Buy,
+                        rust_common::Direction::Short => rust_common::OrderSide::Sell,
+                    };
+                    let fill = AdapterOrderResult {
+                        order_id: order_execution.order_id.clone(),
+                        status: rust_common::OrderStatus::PartiallyFilled,
+                        filled_quantity: order_execution.total_filled,
+                        average_price: order_execution.average_price,
+                        commission: order_execution.partial_fills.iter().map(|fill| fill.commission).sum(),
+                        filled_at: Some(order_execution.updated_at),
+                        partial_fills: Vec::new(),
+                    };
+                    Some((order_execution.symbol.clone(), side, fill))
+                })
+        };
+        let Some((symbol, side, fill)) = tracked else {
+            return;
+        };
+        if self.algo_parents.read().await.contains_key(&fill.order_id) {
+            return;
+        }
+        self.record_fill(&symbol, &side, &fill.order_id, &fill).await;
+    }
+
+    /// Realized PnL for the current UTC day and whether it has breached `max_daily_loss`
+    pub fn get_daily_loss_stats(&self) -> DailyLossStats {
+        self.loss_limit_guard.snapshot()
+    }
+
+    /// Once the daily loss limit is breached only orders that shrink an open position are accepted
+    async fn check_daily_loss_limit(&self, order_decision: &OrderDecision) -> Result<(), TradingError> {
+        if !self.loss_limit_guard.is_breached() || order_decision.reduce_only {
+            return Ok(());
+        }
+
+        let current_size = self.get_position(&order_decision.symbol).await.map_or(0.0, |position| position.net_size);
+        let signed_quantity = match order_decision.direction {
+            rust_common::Direction::Long => order_decision.risk_adjusted_quantity,
+            rust_common::Direction::Short => -order_decision.risk_adjusted_quantity,
+        };
+        let reduces_position = current_size != 0.0
+            && signed_quantity.signum() != current_size.signum()
+            && signed_quantity.abs() <= current_size.abs() + 1e-9;
+        if reduces_position {
+            return Ok(());
+        }
+
+        let stats = self.loss_limit_guard.snapshot();
+        Err(TradingError::risk_rejection(RejectionFeedback::new(
+            RejectionReason::DailyLossLimitReached,
+            "max_daily_loss",
+            format!(
+                "daily loss limit reached: realized PnL {:.2} for {} (max loss {:.2}); only position-reducing orders are accepted",
+                stats.realized_pnl,
+                stats.day,
+                stats.max_daily_loss.unwrap_or_default()
+            ),
+            "Only reduce open positions until the next trading day".to_string(),
+        )))
+    }
+
+    /// Get an armed trailing stop by order ID
+    pub async fn get_trailing_stop(&self, order_id: &str) -> Option<TrailingStop> {
+        let trailing_stops = self.trailing_stops.read().await;
+        trailing_stops.get(order_id).cloned()
+    }
+
+    /// Fetch fresh mark prices for every symbol with an armed trailing stop
+    pub async fn poll_trailing_stops(&self) {
+        let mut markets: Vec<(String, String)> = {
+            let trailing_stops = self.trailing_stops.read().await;
+            trailing_stops.values()
+                .map(|stop| (stop.symbol.clone(), stop.exchange.clone()))
+                .collect()
+        };
+        markets.sort();
+        markets.dedup();
+
+        for (symbol, exchange_name) in markets {
+            let timeouts = self.get_adapter_timeouts(&exchange_name).await;
+            let mark_price = {
+                let adapters = self.exchange_adapters.read().await;
+                match adapters.get(&exchange_name) {
+                    Some(adapter) => with_timeout("get_mark_price", timeouts.get_mark_price_ms, adapter.get_mark_price(&symbol)).await,
+                    None => continue,
+                }
+            };
+
+            match mark_price {
+                Ok(Some(price)) => self.update_exchange_mark_price(&exchange_name, &symbol, price).await,
+                Ok(None) => {}
+                Err(e) => warn!("Failed to poll mark price for trailing stops on {}: {}", symbol, e),
+            }
+        }
+    }
+
+    /// Fetch funding rates for every open position and accrue any that have come due.
+    ///
+    /// Positions aren't tied to an exchange, so the first exchange by name that quotes a
+    /// funding rate for the symbol is used.
+    pub async fn poll_funding_rates(&self) {
+        let symbols: Vec<String> = self.get_positions().await
+            .into_iter()
+            .filter(|position| position.net_size != 0.0)
+            .map(|position| position.symbol)
+            .collect();
+        let mut exchange_names: Vec<String> = self.exchange_adapters.read().await.keys().cloned().collect();
+        exchange_names.sort();
+
+        for symbol in symbols {
+            for exchange_name in &exchange_names {
+                let timeouts = self.get_adapter_timeouts(exchange_name).await;
+                let funding_rate = {
+                    let adapters = self.exchange_adapters.read().await;
+                    match adapters.get(exchange_name) {
+                        Some(adapter) => with_timeout("get_funding_rate", timeouts.get_funding_rate_ms, adapter.get_funding_rate(&symbol)).await,
+                        None => continue,
+                    }
+                };
+
+                match funding_rate {
+                    Ok(Some(funding_rate)) => {
+                        let received = self.position_tracker.write().await.update_funding_rate(funding_rate, Utc::now());
+                        self.loss_limit_guard.record_realized_pnl(received);
+                        break;
+                    }
+                    Ok(None) => {}
+                    Err(e) => warn!("Failed to poll funding rate for {} on {}: {}", symbol, exchange_name, e),
+                }
+            }
+        }
+
+        let received = self.position_tracker.write().await.settle_funding(Utc::now());
+        self.loss_limit_guard.record_realized_pnl(received);
+    }
+
+    /// Arm a trailing stop from a decision; it rests in the gateway until triggered
+    async fn arm_trailing_stop(
+        &self,
+        order_decision: &OrderDecision,
+        order_id: &str,
+    ) -> Result<ExecutionResult, TradingError> {
+        let trail_pct = order_decision.trail_pct
+            .ok_or_else(|| TradingError::ValidationError {
+                field: "trail_pct".to_string(),
+                message: "trailing stop order requires a trail percentage".to_string(),
+            })?;
+        let exchange_name = Self::target_exchange(order_decision);
+        if !self.exchange_adapters.read().await.contains_key(exchange_name) {
+            return Err(TradingError::ExecutionError {
+                message: format!("Exchange adapter not found: {}", exchange_name),
+            });
+        }
+
+        let reference_price = self.get_mark_price(&order_decision.symbol, exchange_name).await
+            .unwrap_or(order_decision.entry_price);
+        let trailing_stop = TrailingStop::new(
+            order_id.to_string(),
+            order_decision.symbol.clone(),
+            exchange_name.to_string(),
+            order_decision.direction,
+            order_decision.risk_adjusted_quantity,
+            trail_pct,
+            reference_price,
+        );
+
+        {
+            let mut trailing_stops = self.trailing_stops.write().await;
+            trailing_stops.insert(order_id.to_string(), trailing_stop);
+        }
+
+        Ok(ExecutionResult::new(order_decision.decision_id.clone(), order_id.to_string()))
+    }
+
+    /// Ratchet every trailing stop on `symbol` at `exchange_name` to `price` and fire those that are hit
+    async fn process_trailing_stops(&self, exchange_name: &str, symbol: &str, price: f64) {
+        let triggered: Vec<TrailingStop> = {
+            let mut trailing_stops = self.trailing_stops.write().await;
+            let triggered_ids: Vec<String> = trailing_stops.values_mut()
+                .filter(|stop| stop.exchange == exchange_name && stop.symbol == symbol)
+                .filter_map(|stop| stop.update(price).then(|| stop.order_id.clone()))
+                .collect();
+            triggered_ids.iter()
+                .filter_map(|order_id| trailing_stops.remove(order_id))
+                .collect()
+        };
+
+        for trailing_stop in triggered {
+            // Boxed because order placement can itself refresh a mark and land back here
+            match Box::pin(self.submit_trailing_stop_exit(&trailing_stop, price)).await {
+                Ok(exec_result) => self.finish_trailing_stop(&trailing_stop.order_id, Ok(exec_result)).await,
+                Err(e) => {
+                    // The position is still open, so the stop goes back on watch for the next price
+                    warn!("Trailing stop {} triggered but exit order failed, re-arming: {}", trailing_stop.order_id, e);
+                    self.trailing_stops.write().await.insert(trailing_stop.order_id.clone(), trailing_stop);
+                }
+            }
+        }
+    }
+
+    /// Send the reduce-only market order that closes a triggered trailing stop, with retries
+    async fn submit_trailing_stop_exit(&self, trailing_stop: &TrailingStop, price: f64) -> Result<ExecutionResult, TradingError> {
+        let client_id = self.trailing_stop_client_id(&trailing_stop.order_id).await;
+
+        let mut exit_decision = OrderDecision::new(
+            format!("trailing_stop:{}", trailing_stop.order_id),
+            trailing_stop.symbol.clone(),
+        );
+        exit_decision.decision_id = client_id.map(|client_id| client_id.to_string()).unwrap_or_default();
+        exit_decision.direction = match trailing_stop.direction {
+            rust_common::Direction::Long => rust_common::Direction::Short,
+            rust_common::Direction::Short => rust_common::Direction::Long,
+        };
+        exit_decision.order_type = rust_common::OrderType::Market;
+        exit_decision.base_quantity = trailing_stop.quantity;
+        exit_decision.risk_adjusted_quantity = trailing_stop.quantity;
+        exit_decision.entry_price = price;
+        exit_decision.reduce_only = true;
+        exit_decision.exchange = Some(trailing_stop.exchange.clone());
+
+        self.execute_order_with_retry(&exit_decision, &trailing_stop.order_id).await
+    }
+
+    /// Client ID of the tracked order behind a trailing stop
+    async fn trailing_stop_client_id(&self, order_id: &str) -> Option<Uuid> {
+        let active_orders = self.active_orders.read().await;
+        active_orders.iter()
+            .find(|(_, order_execution)| order_execution.order_id == order_id)
+            .map(|(client_id, _)| *client_id)
+    }
+
+    /// Record the final outcome of a trailing stop that has left the book
+    async fn finish_trailing_stop(&self, order_id: &str, result: Result<ExecutionResult, TradingError>) {
+        let Some(client_id) = self.trailing_stop_client_id(order_id).await else {
+            warn!("Trailing stop {} finished but its order is no longer tracked", order_id);
+            return;
+        };
+
+        let result = result.map(|mut exec_result| {
+            exec_result.decision_id = client_id.to_string();
+            exec_result
+        });
+        self.update_order_status(&client_id, &result).await;
+
+        let (state, reason) = match &result {
+            Ok(exec_result) => (OrderLifecycleState::from(exec_result.status), "Trailing stop finished".to_string()),
+            Err(e) => (OrderLifecycleState::Failed, e.to_string()),
+        };
+        if let Err(e) = self.transition_lifecycle(order_id, state, reason).await {
+            warn!("Failed to track lifecycle of order {}: {}", order_id, e);
+        }
+
+        if let Ok(exec_result) = &result {
+            self.session_counters.record_order(
+                exec_result.filled_quantity * exec_result.average_price.unwrap_or(0.0),
+            );
+            self.store_result(exec_result).await;
+        }
+    }
+
+    /// Freshest unexpired mark for a symbol on any exchange
+    async fn latest_mark_price(&self, symbol: &str) -> Option<f64> {
+        let ttl = std::time::Duration::from_millis(self.config.mark_price_ttl_ms);
+        let mark_prices = self.mark_prices.read().await;
+        mark_prices.iter()
+            .filter(|((_, cached_symbol), (_, seen_at))| cached_symbol == symbol && seen_at.elapsed() < ttl)
+            .max_by_key(|(_, (_, seen_at))| *seen_at)
+            .map(|(_, (price, _))| *price)
+    }
+
+    /// Get the mark price from the cache while it is fresh, otherwise from the exchange adapter
+    async fn get_mark_price(&self, symbol: &str, exchange_name: &str) -> Option<f64> {
+        {
+            let ttl = std::time::Duration::from_millis(self.config.mark_price_ttl_ms);
+            let mark_prices = self.mark_prices.read().await;
+            if let Some((price, seen_at)) = mark_prices.get(&(exchange_name.to_string(), symbol.to_string())) {
+                if seen_at.elapsed() < ttl {
+                    return Some(*price);
+                }
+            }
+        }
+
+        // A failed mark lookup must not block trading; the check is simply skipped
+        let timeouts = self.get_adapter_timeouts(exchange_name).await;
+        let mark_price = {
+            let adapters = self.exchange_adapters.read().await;
+            match adapters.get(exchange_name) {
+                Some(adapter) => with_timeout("get_mark_price", timeouts.get_mark_price_ms, adapter.get_mark_price(symbol))
+                    .await
+                    .ok()
+                    .flatten(),
+                None => None,
+            }
+        };
+
+        if let Some(price) = mark_price {
+            self.update_exchange_mark_price(exchange_name, symbol, price).await;
+        }
+
+        mark_price
+    }
+
+    /// Run every risk gate against a decision and explain the first one that fails
+    pub async fn explain_rejection(&self, decision: &OrderDecision) -> Option<RejectionFeedback> {
+        self.explain_rejections(decision).await.into_iter().next()
+    }
+
+    /// Run every risk gate against a decision and explain all that fail
+    pub async fn explain_rejections(&self, decision: &OrderDecision) -> Vec<RejectionFeedback> {
+        let price_failure = self.check_mark_price_deviation(decision).await;
+        let open_orders: Vec<OpenOrderExposure> = self.exposure_reservations.read().await.values().cloned().collect();
+        self.risk_failures(decision, price_failure, &open_orders).await
+    }
+
+    /// Run every risk gate against a decision and, if it passes, hold its exposure against
+    /// later checks until it fills, is cancelled or fails
+    async fn reserve_exposure(&self, client_id: Uuid, decision: &OrderDecision) -> Result<(), TradingError> {
+        // The exchange refuses any part of a reduce-only order that would add exposure
+        if decision.reduce_only {
+            return Ok(());
+        }
+
+        // Looking up the mark can fire trailing stops, so it happens before the reservations are locked
+        let price_failure = self.check_mark_price_deviation(decision).await;
+
+        // Checking and reserving under one lock keeps concurrent orders from passing against the same headroom
+        let mut reservations = self.exposure_reservations.write().await;
+        let open_orders: Vec<OpenOrderExposure> = reservations.values().cloned().collect();
+        if let Some(feedback) = self.risk_failures(decision, price_failure, &open_orders).await.into_iter().next() {
+            return Err(TradingError::risk_rejection(feedback));
+        }
+        // A trailing stop only ever closes a position, so it holds no exposure while armed
+        if decision.order_type != rust_common::OrderType::TrailingStop {
+            reservations.insert(client_id, OpenOrderExposure::for_decision(decision));
+        }
+        Ok(())
+    }
+
+    /// Shrink an order's reservation to what is still unfilled, dropping it once the order is done
+    async fn refresh_exposure_reservation(&self, client_id: &Uuid) {
+        let remaining = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.get(client_id)
+                .filter(|order_execution| !order_execution.status.is_terminal())
+                .map(|order_execution| (order_execution.requested_quantity - order_execution.total_filled).max(0.0))
+        };
+
+        let mut reservations = self.exposure_reservations.write().await;
+        match remaining {
+            Some(remaining) if remaining > 1e-12 => {
+                if let Some(reservation) = reservations.get_mut(client_id) {
+                    reservation.set_remaining(remaining);
+                }
+            }
+            _ => {
+                reservations.remove(client_id);
+            }
+        }
+    }
+
+    /// Release an order's reservation when it is turned away before being tracked
+    async fn release_exposure(&self, client_id: &Uuid) {
+        self.exposure_reservations.write().await.remove(client_id);
+    }
+
+    /// Fat-finger check of the decision's price against the venue's mark, if configured
+    async fn check_mark_price_deviation(&self, decision: &OrderDecision) -> Option<RejectionFeedback> {
+        let max_deviation_pct = self.config.max_price_deviation_pct?;
+        let mark_price = self.get_mark_price(&decision.symbol, Self::target_exchange(decision)).await?;
+        check_price_deviation(decision, mark_price, max_deviation_pct)
+    }
+
+    /// Every failing risk gate, counting `open_orders` as exposure already taken
+    async fn risk_failures(
+        &self,
+        decision: &OrderDecision,
+        price_failure: Option<RejectionFeedback>,
+        open_orders: &[OpenOrderExposure],
+    ) -> Vec<RejectionFeedback> {
+        let open_risk_amount: f64 = open_orders.iter().map(|open_order| open_order.risk_amount).sum();
+        let mut failures = evaluate_risk_rules(decision, open_risk_amount);
+        failures.extend(price_failure);
+
+        let risk_limits = self.risk_limits.read().await.clone();
+        failures.extend(check_risk_limits(decision, &risk_limits, &self.get_positions().await, open_orders));
+
+        failures
+    }
+
+    /// Portfolio limits currently enforced at placement
+    pub async fn risk_limits(&self) -> RiskLimits {
+        self.risk_limits.read().await.clone()
+    }
+
+    /// Replace the portfolio limits enforced at placement
+    pub async fn set_risk_limits(&self, risk_limits: RiskLimits) -> Result<(), String> {
+        risk_limits.validate()?;
+        info!("Risk limits updated: {:?}", risk_limits);
+        *self.risk_limits.write().await = risk_limits;
+        Ok(())
+    }
+
+    /// Execute order with retry logic and circuit breaker
+    async fn execute_order_with_retry(
+        &self,
+        order_decision: &OrderDecision,
+        order_id: &str,
+    ) -> Result<ExecutionResult, TradingError> {
+        let exchange_name = Self::target_exchange(order_decision);
+        
+        let mut execution_result = ExecutionResult::new(
+            order_decision.decision_id.clone(),
+            order_id.to_string(),
+        );
+
+        let start_time = Instant::now();
+        let mut previous_delay_ms = self.config.base_retry_delay_ms;
+
+        for attempt in 0..=self.config.max_retries {
+            // Check circuit breaker
+            {
+                let circuit_breakers = self.circuit_breakers.read().await;
+                if let Some(cb) = circuit_breakers.get(exchange_name) {
+                    let is_open = cb.is_open();
+                    self.metrics.set_circuit_breaker_state(exchange_name, cb.get_state());
+                    if is_open {
+                        return Err(TradingError::CircuitBreakerOpen {
+                            exchange: exchange_name.to_string(),
+                            retry_after_ms: cb.retry_after_ms(),
+                        });
+                    }
+                }
+            }
+            if let Err(e) = self.check_symbol_circuit_breaker(exchange_name, &order_decision.symbol).await {
+                // Hand back any probe the exchange breaker admitted
+                if let Some(cb) = self.circuit_breakers.read().await.get(exchange_name) {
+                    cb.release_probe();
+                }
+                return Err(e);
+            }
+
+            if attempt > 0 {
+                self.metrics.record_retry(exchange_name);
+            }
+
+            // Measure signal-to-submission latency on the first submission
+            if attempt == 0 {
+                let latency_ms = (Utc::now() - order_decision.timestamp).num_milliseconds().max(0) as u64;
+                self.latency_tracker.record(latency_ms);
+                execution_result.decision_to_submit_ms = Some(latency_ms);
+            }
+
+            // Attempt order execution
+            let result = self.execute_single_order(order_decision, order_id).await;
+            
+            match result {
+                Ok(mut exec_result) => {
+                    exec_result.execution_time_ms = Some(start_time.elapsed().as_millis() as u32);
+                    exec_result.decision_to_submit_ms = execution_result.decision_to_submit_ms;
+                    exec_result.retry_count = attempt;
+                    
+                    // Record success in circuit breaker
+                    {
+                        let mut circuit_breakers = self.circuit_breakers.write().await;
+                        if let Some(cb) = circuit_breakers.get_mut(exchange_name) {
+                            cb.record_success();
+                            self.metrics.set_circuit_breaker_state(exchange_name, cb.get_state());
+                        }
+                    }
+                    self.with_symbol_circuit_breaker(exchange_name, &order_decision.symbol, CircuitBreaker::record_success).await;
+                    
+                    self.metrics.record_execution(exchange_name, &exec_result);
+                    return Ok(exec_result);
+                }
+                Err(e) => {
+                    execution_result.retry_count = attempt;
+                    execution_result.error_message = Some(e.to_string());
+                    execution_result.reject_reason = reject_reason(&e);
+                    
+                    // Non-retriable errors say nothing about exchange health
+                    let retry_policy = determine_retry_policy(&e);
+                    if matches!(retry_policy, RetryPolicy::NoRetry) {
+                        let circuit_breakers = self.circuit_breakers.read().await;
+                        if let Some(cb) = circuit_breakers.get(exchange_name) {
+                            cb.release_probe();
+                        }
+                        drop(circuit_breakers);
+                        self.with_symbol_circuit_breaker(exchange_name, &order_decision.symbol, CircuitBreaker::release_probe).await;
+                        return Err(e);
+                    }
+                    
+                    // Record failure in circuit breaker
+                    {
+                        let mut circuit_breakers = self.circuit_breakers.write().await;
+                        if let Some(cb) = circuit_breakers.get_mut(exchange_name) {
+                            cb.record_failure();
+                            self.metrics.set_circuit_breaker_state(exchange_name, cb.get_state());
+                        }
+                    }
+                    self.with_symbol_circuit_breaker(exchange_name, &order_decision.symbol, CircuitBreaker::record_failure).await;
+                    
+                    // If this is the last attempt, return the error
+                    if attempt == self.config.max_retries {
+                        return Err(e);
+                    }
+                    
+                    // Fail fast rather than add to a retry storm
+                    if self.retry_budget.as_ref().is_some_and(|budget| !budget.try_acquire()) {
+                        warn!("Retry budget exhausted; not retrying order {}: {}", order_id, e);
+                        return Err(e);
+                    }
+                    
+                    // Wait before retry with exponential backoff and jitter, on the schedule
+                    // for this kind of error, or longer if the exchange sent a Retry-After.
+                    // The upcoming retry is attempt + 1; next_delay(0, _) is the initial (undelayed) attempt.
+                    if matches!(retry_policy, RetryPolicy::ExponentialBackoff) {
+                        let delay = self.retry_logic.next_delay_after(&e, attempt + 1, previous_delay_ms);
+                        previous_delay_ms = delay;
+                        tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                    }
+                }
+            }
+        }
+
+        Err(TradingError::ExecutionError {
+            message: "Max retries exceeded".to_string(),
+        })
+    }
+
+    /// Check the breaker of one symbol on an exchange, creating it on first use
+    async fn check_symbol_circuit_breaker(&self, exchange_name: &str, symbol: &str) -> Result<(), TradingError> {
+        if !self.config.enable_symbol_circuit_breakers {
+            return Ok(());
+        }
+
+        let mut symbol_circuit_breakers = self.symbol_circuit_breakers.write().await;
+        let cb = symbol_circuit_breakers
+            .entry((exchange_name.to_string(), symbol.to_string()))
+            .or_insert_with(|| {
+                CircuitBreaker::new(
+                    self.config.symbol_circuit_breaker_failure_threshold,
+                    self.config.circuit_breaker_recovery_timeout_ms,
+                )
+                .with_half_open_max_probes(self.config.circuit_breaker_half_open_max_probes)
+            });
+        if cb.is_open() {
+            return Err(TradingError::CircuitBreakerOpen {
+                exchange: format!("{}/{}", exchange_name, symbol),
+                retry_after_ms: cb.retry_after_ms(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Apply `f` to the breaker of one symbol on an exchange, if symbol breakers are enabled
+    async fn with_symbol_circuit_breaker(&self, exchange_name: &str, symbol: &str, f: impl FnOnce(&CircuitBreaker)) {
+        if !self.config.enable_symbol_circuit_breakers {
+            return;
+        }
+        let symbol_circuit_breakers = self.symbol_circuit_breakers.read().await;
+        if let Some(cb) = symbol_circuit_breakers.get(&(exchange_name.to_string(), symbol.to_string())) {
+            f(cb);
+        }
+    }
+
+    /// Resubmit the unfilled remainder of a partially filled order, aggregating
+    /// every fill into the parent result. Cancels the remainder once attempts run out;
+    /// a remainder that cannot be cancelled is left working and never resubmitted.
+    async fn retry_partial_remainder(
+        &self,
+        order_decision: &OrderDecision,
+        mut execution_result: ExecutionResult,
+        policy: PartialRetryPolicy,
+    ) -> ExecutionResult {
+        let target_quantity = order_decision.risk_adjusted_quantity;
+        let mut filled_notional = execution_result.filled_quantity * execution_result.average_price.unwrap_or(0.0);
+        let mut resting_order_id = execution_result.order_id.clone();
+        // Tracking ID of the resting resubmission; the original order is settled by the caller
+        let mut resting_residual: Option<Uuid> = None;
+
+        for attempt in 1..=policy.max_attempts {
+            let remaining_quantity = target_quantity - execution_result.filled_quantity;
+            if remaining_quantity <= FILL_EPSILON {
+                break;
+            }
+
+            // Pull the resting remainder before resubmitting it; one that can't be pulled may still fill
+            if let Err(e) = self.cancel_remainder(order_decision, &resting_order_id, resting_residual).await {
+                warn!("Stopping partial retries of order {}: {}", execution_result.order_id, e);
+                return Self::remainder_left_working(execution_result, remaining_quantity);
+            }
+            resting_residual = None;
+
+            // Fills can land until the cancel does, so ask the exchange what became of the remainder
+            match self.order_status_on(Self::target_exchange(order_decision), &resting_order_id).await {
+                Ok(rust_common::OrderStatus::Filled) => {
+                    filled_notional += remaining_quantity * order_decision.entry_price;
+                    execution_result.filled_quantity += remaining_quantity;
+                    execution_result.average_price = Some(filled_notional / execution_result.filled_quantity);
+                    execution_result.slippage = execution_result.average_price
+                        .and_then(|average_price| Self::calculate_slippage(order_decision, average_price));
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    warn!("Stopping partial retries of order {}: {}", execution_result.order_id, e);
+                    return Self::remainder_left_working(execution_result, remaining_quantity);
+                }
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(policy.delay_ms)).await;
+
+            let mut residual_decision = order_decision.clone();
+            residual_decision.risk_adjusted_quantity = remaining_quantity;
+            let residual_order_id = Uuid::new_v4().to_string();
+            let residual_client_id = self
+                .track_residual_order(&residual_decision, &residual_order_id, &execution_result.order_id)
+                .await;
+
+            let residual = self.execute_single_order(&residual_decision, &residual_order_id).await;
+            self.update_order_status(&residual_client_id, &residual).await;
+            self.track_outcome(&residual_order_id, &residual).await;
+            match residual {
+                Ok(residual_result) => {
+                    resting_order_id = residual_order_id;
+                    if !OrderExecutionStatus::from(residual_result.status).is_terminal() {
+                        resting_residual = Some(residual_client_id);
+                    }
+                    if residual_result.filled_quantity > 0.0 {
+                        filled_notional += residual_result.filled_quantity * residual_result.average_price.unwrap_or(0.0);
+                        execution_result.filled_quantity += residual_result.filled_quantity;
+                        execution_result.average_price = Some(filled_notional / execution_result.filled_quantity);
+                        execution_result.slippage = execution_result.average_price
+                            .and_then(|average_price| Self::calculate_slippage(order_decision, average_price));
+                        execution_result.commission += residual_result.commission;
+                        execution_result.filled_at = residual_result.filled_at.or(execution_result.filled_at);
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "Partial retry {}/{} for order {} failed: {}",
+                        attempt, policy.max_attempts, execution_result.order_id, e
+                    );
+                }
+            }
+        }
+
+        let remaining_quantity = target_quantity - execution_result.filled_quantity;
+        if remaining_quantity <= FILL_EPSILON {
+            execution_result.status = rust_common::OrderStatus::Filled;
+        } else if let Err(e) = self.cancel_remainder(order_decision, &resting_order_id, resting_residual).await {
+            warn!("Failed to cancel remainder of order {}: {}", execution_result.order_id, e);
+            return Self::remainder_left_working(execution_result, remaining_quantity);
+        } else {
+            execution_result.status = rust_common::OrderStatus::Cancelled;
+            execution_result.error_message = Some(format!(
+                "Remainder of {} unfilled after {} partial retries; cancelled",
+                remaining_quantity, policy.max_attempts
+            ));
+        }
+
+        execution_result
+    }
+
+    /// Report an order whose remainder could not be cancelled as still working
+    fn remainder_left_working(mut execution_result: ExecutionResult, remaining_quantity: f64) -> ExecutionResult {
+        execution_result.status = rust_common::OrderStatus::PartiallyFilled;
+        execution_result.error_message = Some(format!(
+            "Remainder of {} could not be cancelled and is still working",
+            remaining_quantity
+        ));
+        execution_result
+    }
+
+    /// Track a resubmitted remainder as an order of its own, linked to the order it completes
+    async fn track_residual_order(&self, residual_decision: &OrderDecision, residual_order_id: &str, original_order_id: &str) -> Uuid {
+        let client_id = Uuid::new_v4();
+        let order_execution = OrderExecution {
+            order_id: residual_order_id.to_string(),
+            client_id,
+            exchange: Self::target_exchange(residual_decision).to_string(),
+            status: OrderExecutionStatus::Pending,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            retry_count: 0,
+            partial_fills: Vec::new(),
+            total_filled: 0.0,
+            average_price: None,
+            linked_order_id: None,
+            requested_quantity: residual_decision.risk_adjusted_quantity,
+            direction: Some(residual_decision.direction),
+            reference_price: Some(residual_decision.entry_price),
+            symbol: residual_decision.symbol.clone(),
+            reserved_risk_amount: None,
+        };
+        self.active_orders.write().await.insert(client_id, order_execution);
+        self.persist_order(&client_id).await;
+
+        self.track_submission(residual_order_id, client_id, residual_decision).await;
+        if let Err(e) = self.order_manager
+            .update_metadata(residual_order_id, "remainder_of".to_string(), serde_json::json!(original_order_id))
+            .await
+        {
+            warn!("Failed to link remainder {} to order {}: {}", residual_order_id, original_order_id, e);
+        }
+        client_id
+    }
+
+    /// Cancel the resting remainder `order_id`, marking it cancelled if it is a tracked resubmission
+    async fn cancel_remainder(
+        &self,
+        order_decision: &OrderDecision,
+        order_id: &str,
+        residual_client_id: Option<Uuid>,
+    ) -> Result<(), TradingError> {
+        self.cancel_order_on(Self::target_exchange(order_decision), order_id).await?;
+        if let Some(client_id) = residual_client_id {
+            self.mark_order_cancelled(&client_id, order_id, "Remainder resubmitted or abandoned").await;
+        }
+        Ok(())
+    }
+
+    /// Pull the working remainder of an immediate-or-cancel or fill-or-kill order
+    async fn cancel_unfilled_remainder(
+        &self,
+        order_decision: &OrderDecision,
+        mut execution_result: ExecutionResult,
+    ) -> ExecutionResult {
+        if let Err(e) = self.cancel_order_on(Self::target_exchange(order_decision), &execution_result.order_id).await {
+            warn!("Failed to cancel remainder of order {}: {}", execution_result.order_id, e);
+        }
+        execution_result.status = rust_common::OrderStatus::Cancelled;
+        execution_result.error_message = Some(format!(
+            "Unfilled remainder cancelled under {:?} time in force",
+            order_decision.time_in_force
+        ));
+        execution_result
+    }
+
+    /// Round an order to the symbol's tick and lot sizes and run the adapter's checks on it.
+    ///
+    /// Adapters aren't required to validate inside `place_order`, so the gateway does it
+    /// before submission. Failures are reported as invalid orders, which are never retried.
+    async fn prepare_order_request(
+        adapter: &(dyn ExchangeAdapter + Send + Sync),
+        timeouts: &AdapterTimeouts,
+        order_request: OrderRequest,
+    ) -> Result<OrderRequest, TradingError> {
+        let invalid = |e: TradingError| {
+            let (reason, message) = match e {
+                TradingError::OrderRejected { reason, message } => (reason, message),
+                TradingError::ExecutionError { message } => (RejectReason::Unknown, message),
+                other => (RejectReason::Unknown, other.to_string()),
+            };
+            TradingError::OrderRejected {
+                reason,
+                message: format!("Invalid order: {}", message),
+            }
+        };
+
+        // Without exchange info the order goes out unrounded and the exchange has the final say
+        let exchange_info = with_timeout(
+            "get_exchange_info",
+            timeouts.get_exchange_info_ms,
+            adapter.get_exchange_info(&order_request.symbol),
+        )
+        .await;
+        let order_request = match exchange_info {
+            Ok(exchange_info) => adapter.round_order(order_request, &exchange_info).map_err(invalid)?,
+            Err(e) => {
+                warn!("Could not get exchange info to round order {}: {}", order_request.id, e);
+                order_request
+            }
+        };
+
+        adapter.validate_order(&order_request).await.map_err(invalid)?;
+        Ok(order_request)
+    }
+
+    /// Execute a single order attempt
+    async fn execute_single_order(
+        &self,
+        order_decision: &OrderDecision,
+        order_id: &str,
+    ) -> Result<ExecutionResult, TradingError> {
+        if order_decision.order_type == rust_common::OrderType::TrailingStop {
+            return self.arm_trailing_stop(order_decision, order_id).await;
+        }
+
+        let exchange_name = Self::target_exchange(order_decision);
+        let timeouts = self.get_adapter_timeouts(exchange_name).await;
+        
+        let adapters = self.exchange_adapters.read().await;
+        let adapter = adapters.get(exchange_name)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Exchange adapter not found: {}", exchange_name),
+            })?;
+
+        // Convert OrderDecision to OrderRequest for adapter
+        let order_request = self.convert_decision_to_request(order_decision, order_id)?;
+        let order_request = Self::prepare_order_request(adapter.as_ref(), &timeouts, order_request).await?;
+        let side = order_request.side.clone();
+        
+        // Execute through adapter
+        let adapter_result = with_timeout("place_order", timeouts.place_order_ms, adapter.place_order(order_request)).await?;
+        self.record_fill(&order_decision.symbol, &side, order_id, &adapter_result).await;
+        
+        let mut execution_result = Self::execution_result_from(
+            order_decision.decision_id.clone(),
+            order_id.to_string(),
+            &adapter_result,
+        );
+        execution_result.slippage = adapter_result.average_price
+            .and_then(|average_price| Self::calculate_slippage(order_decision, average_price));
+        
+        // Handle partial fills if enabled
+        if self.config.enable_partial_fills && adapter_result.partial_fills.len() > 0 {
+            self.handle_partial_fills(&order_decision.decision_id, &adapter_result.partial_fills).await?;
+        }
+
+        if let Some(slippage) = execution_result.slippage {
+            if slippage > order_decision.slippage_tolerance {
+                if !order_decision.partial_fill_acceptable {
+                    return Err(TradingError::OrderRejected {
+                        reason: RejectReason::PriceOutOfBounds,
+                        message: format!(
+                            "Fill at {:?} exceeds slippage tolerance: {:.4}% > {:.4}%",
+                            execution_result.average_price,
+                            slippage * 100.0,
+                            order_decision.slippage_tolerance * 100.0
+                        ),
+                    });
+                }
+                warn!(
+                    "Order {} filled with slippage {:.4}% above tolerance {:.4}%",
+                    order_id,
+                    slippage * 100.0,
+                    order_decision.slippage_tolerance * 100.0
+                );
+            }
+        }
+
+        Ok(execution_result)
+    }
+
+    /// Convert an adapter result, carrying its partial fills and their summed commission
+    fn execution_result_from(decision_id: String, order_id: String, adapter_result: &AdapterOrderResult) -> ExecutionResult {
+        let mut execution_result = ExecutionResult::new(decision_id, order_id);
+        execution_result.status = adapter_result.status;
+        execution_result.filled_quantity = adapter_result.filled_quantity;
+        execution_result.average_price = adapter_result.average_price;
+        execution_result.commission = adapter_result.total_commission();
+        execution_result.filled_at = adapter_result.filled_at;
+        execution_result.partial_fills = adapter_result.partial_fills.clone();
+        execution_result
+    }
+
+    /// Fractional slippage of a fill from the decision's entry price; positive is adverse
+    fn calculate_slippage(order_decision: &OrderDecision, average_price: f64) -> Option<f64> {
+        if order_decision.entry_price <= 0.0 {
+            return None;
+        }
+        let slippage = (average_price - order_decision.entry_price) / order_decision.entry_price;
+        Some(match order_decision.direction {
+            rust_common::Direction::Long => slippage,
+            rust_common::Direction::Short => -slippage,
+        })
+    }
+
+    /// Convert OrderDecision to OrderRequest
+    fn convert_decision_to_request(
+        &self,
+        decision: &OrderDecision,
+        order_id: &str,
+    ) -> Result<OrderRequest, TradingError> {
+        use rust_common::{OrderSide, OrderType};
+        
+        let side = match decision.direction {
+            rust_common::Direction::Long => OrderSide::Buy,
+            rust_common::Direction::Short => OrderSide::Sell,
+        };
+
+        let order_type = match decision.order_type {
+            OrderType::Market => OrderType::Market,
+            OrderType::Limit => OrderType::Limit,
+            OrderType::Stop => OrderType::Stop,
+            OrderType::StopLimit => OrderType::StopLimit,
+            OrderType::StopLoss => OrderType::StopLoss,
+            OrderType::TakeProfit => OrderType::TakeProfit,
+            // Never submitted as-is; the gateway trails the stop and sends a market exit
+            OrderType::TrailingStop => OrderType::StopLoss,
+        };
+
+        Ok(OrderRequest {
+            id: Uuid::parse_str(order_id).map_err(|e| TradingError::ExecutionError {
+                message: format!("Invalid order ID: {}", e),
+            })?,
+            symbol: decision.symbol.clone(),
+            side,
+            size: decision.risk_adjusted_quantity,
+            // A closing market order takes what the book offers rather than a stale reference price
+            price: (!decision.reduce_only || order_type != OrderType::Market).then_some(decision.entry_price),
+            order_type,
+            timestamp: decision.timestamp,
+            reduce_only: decision.reduce_only,
+            time_in_force: decision.time_in_force,
+            post_only: decision.post_only,
+        })
+    }
+
+    /// Handle partial fills
+    async fn handle_partial_fills(
+        &self,
+        decision_id: &str,
+        partial_fills: &[HashMap<String, serde_json::Value>],
+    ) -> Result<(), TradingError> {
+        let client_id = Uuid::parse_str(decision_id)
+            .map_err(|e| TradingError::ExecutionError {
+                message: format!("Invalid decision ID: {}", e),
+            })?;
+
+        let mut active_orders = self.active_orders.write().await;
+        if let Some(order_execution) = active_orders.get_mut(&client_id) {
+            for fill_data in partial_fills {
+                let partial_fill = PartialFill {
+                    fill_id: fill_data.get("fill_id")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("unknown")
+                        .to_string(),
+                    quantity: fill_data.get("quantity")
+                        .and_then(|v| v.as_f64())
+                        .unwrap_or(0.0),
+                    price: fill_data.get("price")
+                        .and_then(|v| v.as_f64())
+                        .unwrap_or(0.0),
+                    timestamp: Utc::now(),
+                    commission: fill_data.get("commission")
+                        .and_then(|v| v.as_f64())
+                        .unwrap_or(0.0),
+                };
+                
+                order_execution.partial_fills.push(partial_fill);
+            }
+            
+            order_execution.recompute_fill_totals();
+            order_execution.updated_at = Utc::now();
+            self.publish_order_update(order_execution);
+        }
+        drop(active_orders);
+        self.record_order_fill(&client_id).await;
+        self.refresh_exposure_reservation(&client_id).await;
+
+        Ok(())
+    }
+
+    /// Update order status based on execution result
+    async fn update_order_status(
+        &self,
+        client_id: &Uuid,
+        result: &Result<ExecutionResult, TradingError>,
+    ) {
+        let filled_sibling = {
+            let mut active_orders = self.active_orders.write().await;
+            let Some(order_execution) = active_orders.get_mut(client_id) else {
+                return;
+            };
+            match result {
+                Ok(exec_result) => {
+                    // Exchanges that report a fill without itemising it still count towards fill quality
+                    if order_execution.partial_fills.is_empty() && exec_result.filled_quantity > 0.0 {
+                        order_execution.total_filled = exec_result.filled_quantity;
+                        order_execution.average_price = exec_result.average_price;
+                    }
+                    order_execution.status = OrderExecutionStatus::from(exec_result.status)
+                        .with_fills(order_execution.total_filled);
+                }
+                Err(_) => {
+                    order_execution.status = OrderExecutionStatus::Failed;
+                }
+            }
+            order_execution.updated_at = Utc::now();
+            self.metrics.record_order_status(&order_execution.status);
+            self.publish_order_update(order_execution);
+
+            // Any fill on an OCO leg cancels the other leg
+            match order_execution.status {
+                OrderExecutionStatus::Filled | OrderExecutionStatus::PartiallyFilled => {
+                    order_execution.linked_order_id.clone()
+                }
+                _ => None,
+            }
+        };
+
+        self.persist_order(client_id).await;
+        self.refresh_exposure_reservation(client_id).await;
+
+        if let Some(sibling_order_id) = filled_sibling {
+            self.cancel_linked_order(&sibling_order_id).await;
+        }
+    }
+
+    /// Cancel the resting sibling of a filled OCO leg
+    async fn cancel_linked_order(&self, order_id: &str) {
+        let sibling = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.values()
+                .find(|order_execution| order_execution.order_id == order_id)
+                .filter(|order_execution| !order_execution.status.is_terminal())
+                .map(|order_execution| (order_execution.client_id, order_execution.exchange.clone()))
+        };
+        let Some((client_id, exchange_name)) = sibling else {
+            return;
+        };
+
+        if let Err(e) = self.cancel_order_on(&exchange_name, order_id).await {
+            warn!("Failed to cancel OCO sibling {}: {}", order_id, e);
+            return;
+        }
+
+        self.mark_order_cancelled(&client_id, order_id, "OCO sibling filled").await;
+    }
+
+    /// Record a cancellation the exchange has accepted
+    async fn mark_order_cancelled(&self, client_id: &Uuid, order_id: &str, reason: &str) {
+        {
+            let mut active_orders = self.active_orders.write().await;
+            if let Some(order_execution) = active_orders.get_mut(client_id) {
+                order_execution.status = OrderExecutionStatus::Cancelled.with_fills(order_execution.total_filled);
+                order_execution.updated_at = Utc::now();
+                self.metrics.record_order_status(&order_execution.status);
+                self.publish_order_update(order_execution);
+            }
+        }
+        self.persist_order(client_id).await;
+        self.refresh_exposure_reservation(client_id).await;
+        self.record_exchange_status(order_id, rust_common::OrderStatus::Cancelled, reason).await;
+    }
+
+    /// Record an order pulled from the exchange after outliving its execution window
+    async fn mark_order_expired(&self, client_id: &Uuid, order_id: &str) {
+        {
+            let mut active_orders = self.active_orders.write().await;
+            if let Some(order_execution) = active_orders.get_mut(client_id) {
+                order_execution.status = OrderExecutionStatus::Expired;
+                order_execution.updated_at = Utc::now();
+                self.metrics.record_order_status(&order_execution.status);
+                self.publish_order_update(order_execution);
+            }
+        }
+        self.persist_order(client_id).await;
+        self.refresh_exposure_reservation(client_id).await;
+
+        if let Err(e) = self
+            .transition_lifecycle(order_id, OrderLifecycleState::Expired, "Exceeded max execution time".to_string())
+            .await
+        {
+            warn!("Failed to track lifecycle of order {}: {}", order_id, e);
+        }
+
+        let updated_result = {
+            let mut execution_results = self.execution_results.write().await;
+            execution_results.get_mut(order_id).map(|exec_result| {
+                exec_result.status = rust_common::OrderStatus::Expired;
+                exec_result.clone()
+            })
+        };
+        if let Some(exec_result) = updated_result {
+            self.store_result(&exec_result).await;
+        }
+    }
+
+    /// Carry an exchange-reported status into the order's lifecycle and stored result
+    async fn record_exchange_status(&self, order_id: &str, status: rust_common::OrderStatus, reason: &str) {
+        if let Err(e) = self.transition_lifecycle(order_id, OrderLifecycleState::from(status), reason.to_string()).await {
+            warn!("Failed to track lifecycle of order {}: {}", order_id, e);
+        }
+
+        let updated_result = {
+            let mut execution_results = self.execution_results.write().await;
+            execution_results.get_mut(order_id).map(|exec_result| {
+                exec_result.status = status;
+                exec_result.clone()
+            })
+        };
+        if let Some(exec_result) = updated_result {
+            self.store_result(&exec_result).await;
+        }
+    }
+
+    /// Apply a status polled from the exchange to a tracked order, if it changed
+    async fn apply_exchange_status(&self, order_id: &str, status: rust_common::OrderStatus) {
+        let changed = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.values()
+                .find(|order_execution| order_execution.order_id == order_id)
+                .filter(|order_execution| {
+                    std::mem::discriminant(&order_execution.status)
+                        != std::mem::discriminant(&OrderExecutionStatus::from(status).with_fills(order_execution.total_filled))
+                })
+                .map(|order_execution| (
+                    order_execution.client_id,
+                    order_execution.requested_quantity,
+                    order_execution.average_price.or(order_execution.reference_price),
+                ))
+        };
+        let Some((client_id, requested_quantity, resting_price)) = changed else {
+            return;
+        };
+
+        let mut exec_result = ExecutionResult::new(client_id.to_string(), order_id.to_string());
+        exec_result.status = status;
+        if status == rust_common::OrderStatus::Filled {
+            // A resting order that filled while unwatched fills at the price it rested at
+            exec_result.filled_quantity = requested_quantity;
+            exec_result.average_price = resting_price;
+        }
+        self.record_exchange_status(order_id, status, "Exchange status update").await;
+        self.update_order_status(&client_id, &Ok(exec_result)).await;
+        self.record_order_fill(&client_id).await;
+    }
+
+    /// Push the latest state of an order to subscribers
+    fn publish_order_update(&self, order_execution: &OrderExecution) {
+        // Sending only fails when nobody is subscribed
+        let _ = self.order_updates.send(OrderUpdate::from(order_execution));
+    }
+
+    /// Subscribe to every order state change
+    pub fn subscribe_order_updates(&self) -> broadcast::Receiver<OrderUpdate> {
+        self.order_updates.subscribe()
+    }
+
+    /// Latest known state of an order by exchange order ID
+    pub async fn get_order_update(&self, order_id: &str) -> Option<OrderUpdate> {
+        let active_orders = self.active_orders.read().await;
+        active_orders.values()
+            .find(|order_execution| order_execution.order_id == order_id)
+            .map(OrderUpdate::from)
+    }
+
+    /// Subscribe to order state changes, coalesced per order over the configured window
+    pub fn subscribe_coalesced_order_updates(&self) -> mpsc::UnboundedReceiver<OrderUpdate> {
+        coalesce_order_updates(
+            self.order_updates.subscribe(),
+            self.active_orders.clone(),
+            std::time::Duration::from_millis(self.config.order_update_coalesce_window_ms),
+        )
+    }
+
+    /// Get order result by order ID
+    async fn get_order_result(&self, order_id: &str) -> Result<ExecutionResult, TradingError> {
+        if let Some(exec_result) = self.execution_results.read().await.get(order_id) {
+            return Ok(exec_result.clone());
+        }
+
+        // Results from before a restart are only in the order store
+        let stored_result = self.order_store.load_result(order_id).await?
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Execution result not found for order: {}", order_id),
+            })?;
+        self.execution_results.write().await.insert(order_id.to_string(), stored_result.clone());
+        Ok(stored_result)
+    }
+
+    /// Exchange an order decision is routed to
+    fn target_exchange(order_decision: &OrderDecision) -> &str {
+        order_decision.exchange.as_deref().unwrap_or(DEFAULT_EXCHANGE)
+    }
+
+    /// Pick a venue for a decision that doesn't name one, passing over venues whose
+    /// exchange or symbol breaker is open
+    async fn route_order(&self, order_decision: &OrderDecision) -> String {
+        let mut exchanges: Vec<String> = self.exchange_adapters.read().await.keys().cloned().collect();
+        exchanges.sort_by(|a, b| (a != DEFAULT_EXCHANGE, a).cmp(&(b != DEFAULT_EXCHANGE, b)));
+
+        let mut candidates = Vec::with_capacity(exchanges.len());
+        for exchange in exchanges {
+            let mut candidate = VenueCandidate { exchange, quote: None, commission: 0.0 };
+            if self.config.routing_strategy != RoutingStrategy::FirstAvailable {
+                let timeouts = self.get_adapter_timeouts(&candidate.exchange).await;
+                let adapters = self.exchange_adapters.read().await;
+                if let Some(adapter) = adapters.get(&candidate.exchange) {
+                    // A venue that can't quote is ranked last rather than failing the order
+                    candidate.quote = with_timeout("get_quote", timeouts.get_quote_ms, adapter.get_quote(&order_decision.symbol))
+                        .await
+                        .unwrap_or_else(|e| {
+                            warn!("Failed to get quote for {} on {}: {}", order_decision.symbol, candidate.exchange, e);
+                            None
+                        });
+                    let price = match (&candidate.quote, order_decision.direction) {
+                        (Some(quote), rust_common::Direction::Long) => quote.ask,
+                        (Some(quote), rust_common::Direction::Short) => quote.bid,
+                        (None, _) => order_decision.entry_price,
+                    };
+                    let is_maker = order_decision.order_type == rust_common::OrderType::Limit;
+                    candidate.commission = adapter.commission_model()
+                        .commission(order_decision.risk_adjusted_quantity, price, is_maker);
+                }
+            }
+            candidates.push(candidate);
+        }
+
+        let ranked = rank_venues(self.config.routing_strategy, order_decision.direction, candidates);
+        let circuit_breakers = self.circuit_breakers.read().await;
+        let symbol_circuit_breakers = self.symbol_circuit_breakers.read().await;
+        let venue = ranked.iter()
+            .find(|exchange| {
+                let exchange_tripped = circuit_breakers.get(*exchange).is_some_and(CircuitBreaker::is_tripped);
+                let symbol_tripped = symbol_circuit_breakers
+                    .get(&((*exchange).clone(), order_decision.symbol.clone()))
+                    .is_some_and(CircuitBreaker::is_tripped);
+                !exchange_tripped && !symbol_tripped
+            })
+            .or(ranked.first())
+            .cloned()
+            // With nothing registered the order fails on the default exchange as before
+            .unwrap_or_else(|| DEFAULT_EXCHANGE.to_string());
+
+        if ranked.len() > 1 {
+            info!(
+                "Routed {} order to {} ({:?})",
+                order_decision.symbol, venue, self.config.routing_strategy
+            );
+        }
+        venue
+    }
+
+    /// Exchange an order was placed on, falling back to the default exchange for unknown orders
+    async fn exchange_for_order(&self, order_id: &str) -> String {
+        let active_orders = self.active_orders.read().await;
+        active_orders.values()
+            .find(|order_execution| order_execution.order_id == order_id)
+            .map(|order_execution| order_execution.exchange.clone())
+            .unwrap_or_else(|| DEFAULT_EXCHANGE.to_string())
+    }
+
+    /// Cancel an order
+    pub async fn cancel_order(&self, order_id: &str) -> Result<(), TradingError> {
+        // Armed trailing stops never reached the exchange
+        let trailing_stop = self.trailing_stops.write().await.remove(order_id);
+        if let Some(trailing_stop) = trailing_stop {
+            let mut exec_result = ExecutionResult::new(String::new(), trailing_stop.order_id.clone());
+            exec_result.status = rust_common::OrderStatus::Cancelled;
+            self.finish_trailing_stop(order_id, Ok(exec_result)).await;
+            return Ok(());
+        }
+
+        // Algorithmic parents stop replenishing and pull their live child
+        let live_child = {
+            let mut algo_parents = self.algo_parents.write().await;
+            algo_parents.get_mut(order_id).map(|parent| {
+                parent.cancel_requested = true;
+                parent.live_child.take()
+            })
+        };
+        if let Some(live_child) = live_child {
+            if let Some(child_order_id) = live_child {
+                self.cancel_tracked_order(&child_order_id, "Parent order cancelled").await?;
+            }
+            return Ok(());
+        }
+
+        let exchange_name = self.exchange_for_order(order_id).await;
+        self.cancel_order_on(&exchange_name, order_id).await?;
+
+        // A tracked order is marked cancelled here, which also releases its reserved exposure
+        let client_id = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.values()
+                .find(|order_execution| order_execution.order_id == order_id && !order_execution.status.is_terminal())
+                .map(|order_execution| order_execution.client_id)
+        };
+        if let Some(client_id) = client_id {
+            self.mark_order_cancelled(&client_id, order_id, "Cancelled on request").await;
+        }
+        Ok(())
+    }
+
+    /// Cancel an order on a specific exchange
+    async fn cancel_order_on(&self, exchange_name: &str, order_id: &str) -> Result<(), TradingError> {
+        let timeouts = self.get_adapter_timeouts(exchange_name).await;
+        
+        let adapters = self.exchange_adapters.read().await;
+        let adapter = adapters.get(exchange_name)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Exchange adapter not found: {}", exchange_name),
+            })?;
+
+        with_timeout("cancel_order", timeouts.cancel_order_ms, adapter.cancel_order(order_id)).await
+    }
+
+    /// Change the price and/or quantity of an open order.
+    ///
+    /// The new values must sit on the exchange's tick and lot grid and inside its limits;
+    /// they are not rounded, so the caller sees exactly what was rejected.
+    pub async fn amend_order(
+        &self,
+        order_id: &str,
+        new_price: Option<f64>,
+        new_quantity: Option<f64>,
+    ) -> Result<OrderUpdate, TradingError> {
+        if new_price.is_none() && new_quantity.is_none() {
+            return Err(TradingError::ValidationError {
+                field: "amendment".to_string(),
+                message: "nothing to change".to_string(),
+            });
+        }
+
+        let (client_id, exchange_name, total_filled, requested_quantity, reference_price) = {
+            let active_orders = self.active_orders.read().await;
+            let order_execution = active_orders.values()
+                .find(|order_execution| order_execution.order_id == order_id)
+                .ok_or_else(|| TradingError::ExecutionError {
+                    message: format!("Order not found: {}", order_id),
+                })?;
+            if order_execution.status.is_terminal() {
+                return Err(TradingError::ValidationError {
+                    field: "order_id".to_string(),
+                    message: format!(
+                        "order {} is {:?} and cannot be amended",
+                        order_id, order_execution.status
+                    ),
+                });
+            }
+            (
+                order_execution.client_id,
+                order_execution.exchange.clone(),
+                order_execution.total_filled,
+                order_execution.requested_quantity,
+                order_execution.reference_price,
+            )
+        };
+
+        let symbol = self.order_manager.get_order(order_id).await
+            .map(|lifecycle| lifecycle.symbol)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Order not found: {}", order_id),
+            })?;
+        let exchange_info = self.get_exchange_info(&exchange_name, &symbol).await?;
+        validate_amendment(&exchange_info, new_price, new_quantity, total_filled)?;
+
+        // A larger or repriced order is held to the same limits as a new one before the exchange sees it
+        if let Some(new_quantity) = new_quantity {
+            let added_notional = (new_quantity - requested_quantity) * new_price.or(reference_price).unwrap_or(0.0);
+            if added_notional > 0.0 {
+                self.check_amendment_throttle(&symbol, added_notional)?;
+            }
+        }
+        let new_remaining = new_quantity.map(|new_quantity| new_quantity - total_filled);
+        let previous_reservation = self.reserve_amendment(client_id, new_price, new_remaining).await?;
+
+        let amended = async {
+            let timeouts = self.get_adapter_timeouts(&exchange_name).await;
+            let adapters = self.exchange_adapters.read().await;
+            let adapter = adapters.get(&exchange_name)
+                .ok_or_else(|| TradingError::ExecutionError {
+                    message: format!("Exchange adapter not found: {}", exchange_name),
+                })?;
+            if !adapter.capabilities().supports_amend {
+                return Err(TradingError::OrderRejected {
+                    reason: RejectReason::Unsupported,
+                    message: format!("Exchange {} does not support amending orders", exchange_name),
+                });
+            }
+            with_timeout(
+                "amend_order",
+                timeouts.amend_order_ms,
+                adapter.amend_order(order_id, new_price, new_quantity),
+            ).await
+        };
+        if let Err(e) = amended.await {
+            self.restore_reservation(client_id, previous_reservation).await;
+            return Err(e);
+        }
+
+        for (key, value) in [("amended_price", new_price), ("amended_quantity", new_quantity)] {
+            if let Some(value) = value {
+                if let Err(e) = self.order_manager
+                    .update_metadata(order_id, key.to_string(), serde_json::json!(value))
+                    .await
+                {
+                    warn!("Failed to record amendment of order {}: {}", order_id, e);
+                }
+            }
+        }
+
+        let order_update = {
+            let mut active_orders = self.active_orders.write().await;
+            let order_execution = active_orders.get_mut(&client_id)
+                .ok_or_else(|| TradingError::ExecutionError {
+                    message: format!("Order not found: {}", order_id),
+                })?;
+            if let Some(new_quantity) = new_quantity {
+                order_execution.requested_quantity = new_quantity;
+            }
+            order_execution.updated_at = Utc::now();
+            self.publish_order_update(order_execution);
+            OrderUpdate::from(&*order_execution)
+        };
+        self.persist_order(&client_id).await;
+        self.refresh_exposure_reservation(&client_id).await;
+
+        info!("Amended order {}: price {:?}, quantity {:?}", order_id, new_price, new_quantity);
+        Ok(order_update)
+    }
+
+    /// Check an amendment against the risk limits as if the amended order were new, and if it
+    /// passes move the order's reservation to the amended size and price. Returns the previous
+    /// reservation so a failed amendment can put it back; orders holding none are not checked.
+    async fn reserve_amendment(
+        &self,
+        client_id: Uuid,
+        new_price: Option<f64>,
+        new_remaining: Option<f64>,
+    ) -> Result<Option<OpenOrderExposure>, TradingError> {
+        let mut reservations = self.exposure_reservations.write().await;
+        let Some(previous) = reservations.get(&client_id).cloned() else {
+            return Ok(None);
+        };
+
+        let mut amended = previous.clone();
+        if let Some(new_remaining) = new_remaining {
+            amended.set_remaining(new_remaining);
+        }
+        if let Some(new_price) = new_price {
+            amended.price = new_price;
+        }
+
+        let mut decision = OrderDecision::new(String::new(), amended.symbol.as_str());
+        decision.direction = if amended.signed_quantity < 0.0 {
+            rust_common::Direction::Short
+        } else {
+            rust_common::Direction::Long
+        };
+        decision.risk_adjusted_quantity = amended.signed_quantity.abs();
+        decision.entry_price = amended.price;
+        let open_orders: Vec<OpenOrderExposure> = reservations.iter()
+            .filter(|(reserved_id, _)| **reserved_id != client_id)
+            .map(|(_, reservation)| reservation.clone())
+            .collect();
+        let risk_limits = self.risk_limits.read().await.clone();
+        if let Some(feedback) = check_risk_limits(&decision, &risk_limits, &self.get_positions().await, &open_orders)
+            .into_iter()
+            .next()
+        {
+            return Err(TradingError::risk_rejection(feedback));
+        }
+
+        reservations.insert(client_id, amended);
+        Ok(Some(previous))
+    }
+
+    /// Put back the reservation an amendment replaced, unless the order has since finished
+    async fn restore_reservation(&self, client_id: Uuid, previous: Option<OpenOrderExposure>) {
+        if let Some(previous) = previous {
+            if let Some(reservation) = self.exposure_reservations.write().await.get_mut(&client_id) {
+                *reservation = previous;
+            }
+        }
+    }
+
+    /// Count the notional an amendment adds against its symbol's throttle
+    fn check_amendment_throttle(&self, symbol: &str, added_notional: f64) -> Result<(), TradingError> {
+        self.symbol_throttle.check(symbol, added_notional).map_err(|exceeded| {
+            let message = format!(
+                "{} throttled: amendment adds {} notional within {} ms",
+                symbol, added_notional, self.config.symbol_throttle_window_ms
+            );
+            Self::throttled(message, &exceeded)
+        })
+    }
+
+    /// Start tracking a parent order that an execution algorithm fills through child orders
+    pub(crate) async fn open_parent_order(&self, order_decision: &OrderDecision, order_id: &str) -> Result<Uuid, TradingError> {
+        let client_id = Self::parse_decision_id(order_decision)?;
+        // Child placements are counted individually; this only refuses new parents while draining
+        drop(self.begin_placement(false)?);
+
+        {
+            let mut active_orders = self.active_orders.write().await;
+            if active_orders.contains_key(&client_id) {
+                return Err(TradingError::ExecutionError {
+                    message: format!("Decision {} is already being executed", client_id),
+                });
+            }
+            let order_execution = OrderExecution {
+                order_id: order_id.to_string(),
+                client_id,
+                exchange: Self::target_exchange(order_decision).to_string(),
+                status: OrderExecutionStatus::Submitted,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                retry_count: 0,
+                partial_fills: Vec::new(),
+                total_filled: 0.0,
+                average_price: None,
+                linked_order_id: None,
+                requested_quantity: order_decision.risk_adjusted_quantity,
+                direction: Some(order_decision.direction),
+                reference_price: Some(order_decision.entry_price),
+                symbol: order_decision.symbol.clone(),
+                reserved_risk_amount: None,
+            };
+            self.publish_order_update(&order_execution);
+            active_orders.insert(client_id, order_execution);
+        }
+        self.algo_parents.write().await.insert(order_id.to_string(), AlgoParent::default());
+        self.persist_order(&client_id).await;
+        self.track_submission(order_id, client_id, order_decision).await;
+
+        Ok(client_id)
+    }
+
+    /// Record which child order of a parent is resting on the exchange
+    pub(crate) async fn set_live_child(&self, parent_order_id: &str, child_order_id: Option<String>) {
+        if let Some(parent) = self.algo_parents.write().await.get_mut(parent_order_id) {
+            parent.live_child = child_order_id;
+        }
+    }
+
+    /// Whether cancellation of a parent order has been requested
+    pub(crate) async fn is_parent_cancelled(&self, parent_order_id: &str) -> bool {
+        self.algo_parents.read().await
+            .get(parent_order_id)
+            .map_or(true, |parent| parent.cancel_requested)
+    }
+
+    /// Cancel a tracked order on its exchange and record the cancellation
+    pub(crate) async fn cancel_tracked_order(&self, order_id: &str, reason: &str) -> Result<(), TradingError> {
+        let tracked = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.values()
+                .find(|order_execution| order_execution.order_id == order_id)
+                .map(|order_execution| (order_execution.client_id, order_execution.exchange.clone()))
+        };
+        let (client_id, exchange_name) = tracked.ok_or_else(|| TradingError::ExecutionError {
+            message: format!("Order not found: {}", order_id),
+        })?;
+
+        self.cancel_order_on(&exchange_name, order_id).await?;
+        self.mark_order_cancelled(&client_id, order_id, reason).await;
+        Ok(())
+    }
+
+    /// Fold a child order's fill into its parent
+    pub(crate) async fn record_child_fill(&self, parent_client_id: &Uuid, child: &ExecutionResult) {
+        let Some(price) = child.average_price.filter(|_| child.filled_quantity > 0.0) else {
+            return;
+        };
+
+        {
+            let mut active_orders = self.active_orders.write().await;
+            let Some(order_execution) = active_orders.get_mut(parent_client_id) else {
+                return;
+            };
+            order_execution.partial_fills.push(PartialFill {
+                fill_id: child.order_id.clone(),
+                quantity: child.filled_quantity,
+                price,
+                timestamp: child.filled_at.unwrap_or_else(Utc::now),
+                commission: child.commission,
+            });
+            order_execution.recompute_fill_totals();
+            order_execution.status = OrderExecutionStatus::PartiallyFilled;
+            order_execution.updated_at = Utc::now();
+            self.publish_order_update(order_execution);
+        }
+        self.persist_order(parent_client_id).await;
+    }
+
+    /// Settle a parent order once its algorithm has finished
+    pub(crate) async fn close_parent_order(&self, parent_client_id: &Uuid, exec_result: ExecutionResult) {
+        self.algo_parents.write().await.remove(&exec_result.order_id);
+        self.store_result(&exec_result).await;
+        let order_id = exec_result.order_id.clone();
+        let result = Ok(exec_result);
+        self.update_order_status(parent_client_id, &result).await;
+        self.track_outcome(&order_id, &result).await;
+    }
+
+    /// Cancel every open order, armed trailing stop and algorithmic parent, optionally only in `symbol`.
+    ///
+    /// Individual failures are reported in the summary rather than aborting the rest.
+    pub async fn cancel_all_orders(&self, symbol: Option<&str>) -> CancelAllSummary {
+        let mut summary = CancelAllSummary::default();
+
+        let mut off_exchange_ids: Vec<String> = {
+            let trailing_stops = self.trailing_stops.read().await;
+            trailing_stops.values()
+                .filter(|trailing_stop| symbol.map_or(true, |symbol| trailing_stop.symbol == symbol))
+                .map(|trailing_stop| trailing_stop.order_id.clone())
+                .collect()
+        };
+        // Parents go first so their live children are already cancelled below
+        let parent_ids: Vec<String> = self.algo_parents.read().await.keys().cloned().collect();
+        for order_id in parent_ids {
+            let in_symbol = match symbol {
+                Some(symbol) => self.order_manager.get_order(&order_id).await
+                    .is_some_and(|lifecycle| lifecycle.symbol == symbol),
+                None => true,
+            };
+            if in_symbol {
+                off_exchange_ids.push(order_id);
+            }
+        }
+        for order_id in off_exchange_ids {
+            match self.cancel_order(&order_id).await {
+                Ok(()) => summary.cancelled.push(order_id),
+                Err(e) => summary.failed.push(CancelFailure { order_id, error: e.to_string() }),
+            }
+        }
+
+        let open_orders: Vec<(Uuid, String, String)> = {
+            let algo_parents = self.algo_parents.read().await;
+            let active_orders = self.active_orders.read().await;
+            active_orders.values()
+                .filter(|order_execution| !order_execution.status.is_terminal())
+                .filter(|order_execution| !algo_parents.contains_key(&order_execution.order_id))
+                .map(|order_execution| (
+                    order_execution.client_id,
+                    order_execution.order_id.clone(),
+                    order_execution.exchange.clone(),
+                ))
+                .collect()
+        };
+
+        for (client_id, order_id, exchange_name) in open_orders {
+            if let Some(symbol) = symbol {
+                let in_symbol = self.order_manager.get_order(&order_id).await
+                    .is_some_and(|lifecycle| lifecycle.symbol == symbol);
+                if !in_symbol {
+                    continue;
+                }
+            }
+
+            match self.cancel_order_on(&exchange_name, &order_id).await {
+                Ok(()) => {
+                    self.mark_order_cancelled(&client_id, &order_id, "Cancel-all requested").await;
+                    summary.cancelled.push(order_id);
+                }
+                Err(e) => {
+                    warn!("Failed to cancel order {} during cancel-all: {}", order_id, e);
+                    summary.failed.push(CancelFailure { order_id, error: e.to_string() });
+                }
+            }
+        }
+
+        info!(
+            "Cancel-all finished: {} cancelled, {} failed",
+            summary.cancelled.len(),
+            summary.failed.len()
+        );
+        summary
+    }
+
+    /// Cancel every order still open past its `max_execution_time` and mark it expired.
+    ///
+    /// Trailing stops and algorithmic parents are cancelled through `cancel_order`, which
+    /// settles them as cancelled. Returns how many orders were pulled.
+    pub async fn expire_orders(&self) -> usize {
+        let mut expired = 0;
+
+        for lifecycle in self.order_manager.get_expired_orders().await {
+            let order_id = lifecycle.order_id;
+            let off_exchange = self.trailing_stops.read().await.contains_key(&order_id)
+                || self.algo_parents.read().await.contains_key(&order_id);
+            if off_exchange {
+                match self.cancel_order(&order_id).await {
+                    Ok(()) => expired += 1,
+                    Err(e) => warn!("Failed to cancel expired order {}: {}", order_id, e),
+                }
+                continue;
+            }
+
+            let tracked = {
+                let active_orders = self.active_orders.read().await;
+                active_orders.get(&lifecycle.client_id)
+                    .filter(|order_execution| !order_execution.status.is_terminal())
+                    .map(|order_execution| order_execution.exchange.clone())
+            };
+            let Some(exchange_name) = tracked else {
+                continue;
+            };
+
+            match self.cancel_order_on(&exchange_name, &order_id).await {
+                Ok(()) => {
+                    info!("Order {} expired after exceeding its max execution time", order_id);
+                    self.mark_order_expired(&lifecycle.client_id, &order_id).await;
+                    expired += 1;
+                }
+                Err(e) => warn!("Failed to cancel expired order {}: {}", order_id, e),
+            }
+        }
+
+        expired
+    }
+
+    /// Cancel open orders created more than `max_age` ago, returning the number cancelled
+    pub async fn sweep_stale_orders(&self, max_age: Duration) -> usize {
+        let cutoff_time = Utc::now() - max_age;
+        let stale: Vec<(Uuid, String, String)> = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.values()
+                .filter(|order_execution| order_execution.created_at < cutoff_time && !order_execution.status.is_terminal())
+                .map(|order_execution| {
+                    (order_execution.client_id, order_execution.order_id.clone(), order_execution.exchange.clone())
+                })
+                .collect()
+        };
+
+        let mut cancelled = 0;
+        for (client_id, order_id, exchange_name) in stale {
+            let off_exchange = self.trailing_stops.read().await.contains_key(&order_id)
+                || self.algo_parents.read().await.contains_key(&order_id);
+            let result = if off_exchange {
+                self.cancel_order(&order_id).await
+            } else {
+                self.cancel_order_on(&exchange_name, &order_id).await
+            };
+
+            match result {
+                Ok(()) => {
+                    info!("Order {} cancelled after exceeding the maximum order age", order_id);
+                    if !off_exchange {
+                        self.mark_order_cancelled(&client_id, &order_id, "Exceeded maximum order age").await;
+                    }
+                    cancelled += 1;
+                }
+                Err(e) => warn!("Failed to cancel stale order {}: {}", order_id, e),
+            }
+        }
+
+        cancelled
+    }
+
+    /// Get order status
+    pub async fn get_order_status(&self, order_id: &str) -> Result<OrderExecutionStatus, TradingError> {
+        let exchange_name = self.exchange_for_order(order_id).await;
+        let status = self.order_status_on(&exchange_name, order_id).await?;
+        self.apply_exchange_status(order_id, status).await;
+        Ok(OrderExecutionStatus::from(status))
+    }
+
+    /// Ask a specific exchange for an order's status without applying it to the tracked order
+    async fn order_status_on(&self, exchange_name: &str, order_id: &str) -> Result<rust_common::OrderStatus, TradingError> {
+        let timeouts = self.get_adapter_timeouts(exchange_name).await;
+        
+        let adapters = self.exchange_adapters.read().await;
+        let adapter = adapters.get(exchange_name)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Exchange adapter not found: {}", exchange_name),
+            })?;
+
+        with_timeout("get_order_status", timeouts.get_order_status_ms, adapter.get_order_status(order_id)).await
+    }
+
+    /// Refresh the status of every resting OCO leg so fills cancel their siblings
+    pub async fn poll_linked_orders(&self) {
+        let order_ids: Vec<String> = {
+            let active_orders = self.active_orders.read().await;
+            active_orders.values()
+                .filter(|order_execution| order_execution.linked_order_id.is_some() && !order_execution.status.is_terminal())
+                .map(|order_execution| order_execution.order_id.clone())
+                .collect()
+        };
+
+        for order_id in order_ids {
+            if let Err(e) = self.get_order_status(&order_id).await {
+                warn!("Failed to poll status of OCO leg {}: {}", order_id, e);
+            }
+        }
+    }
+
+    /// Place a linked take-profit/stop-loss pair exiting the decision's position;
+    /// a fill on either leg cancels the other
+    pub async fn place_oco_order(&self, mut order_decision: OrderDecision) -> Result<OcoExecutionResult, TradingError> {
+        use rust_common::OrderSide;
+
+        order_decision.symbol = Symbol::normalize(&order_decision.symbol);
+        let client_id = Self::parse_decision_id(&order_decision)?;
+        let take_profit_price = order_decision.take_profit
+            .ok_or_else(|| TradingError::ValidationError {
+                field: "take_profit".to_string(),
+                message: "OCO order requires a take profit price".to_string(),
+            })?;
+        let exchange_name = Self::target_exchange(&order_decision).to_string();
+        let _in_flight = self.begin_placement(false)?;
+        self.check_trading_hours(&order_decision).await?;
+        self.check_capabilities(&order_decision, true).await?;
+
+        let side = match order_decision.direction {
+            rust_common::Direction::Long => OrderSide::Sell,
+            rust_common::Direction::Short => OrderSide::Buy,
+        };
+        let leg = |order_type: rust_common::OrderType, price: f64| OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: order_decision.symbol.clone(),
+            side: side.clone(),
+            size: order_decision.risk_adjusted_quantity,
+            price: Some(price),
+            order_type,
+            timestamp: Utc::now(),
+            reduce_only: true,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        };
+        let take_profit_request = leg(rust_common::OrderType::TakeProfit, take_profit_price);
+        let stop_loss_request = leg(rust_common::OrderType::StopLoss, order_decision.stop_loss);
+        let take_profit_id = take_profit_request.id;
+        let stop_loss_id = stop_loss_request.id;
+        let leg_direction = match order_decision.direction {
+            rust_common::Direction::Long => rust_common::Direction::Short,
+            rust_common::Direction::Short => rust_common::Direction::Long,
+        };
+
+        // Each leg is tracked under its own order id and points at its sibling
+        let legs: Vec<OrderExecution> = [
+            (take_profit_id, stop_loss_id, take_profit_price),
+            (stop_loss_id, take_profit_id, order_decision.stop_loss),
+        ]
+        .into_iter()
+        .map(|(leg_id, sibling_id, leg_price)| OrderExecution {
+            order_id: leg_id.to_string(),
+            client_id: leg_id,
+            exchange: exchange_name.clone(),
+            status: OrderExecutionStatus::Pending,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            retry_count: 0,
+            partial_fills: Vec::new(),
+            total_filled: 0.0,
+            average_price: None,
+            linked_order_id: Some(sibling_id.to_string()),
+            requested_quantity: order_decision.risk_adjusted_quantity,
+            direction: Some(leg_direction),
+            reference_price: Some(leg_price),
+            symbol: order_decision.symbol.clone(),
+            reserved_risk_amount: None,
+        })
+        .collect();
+
+        // The decision maps to its take profit leg, which links to the stop loss
+        {
+            let mut dedup_map = self.order_deduplication.write().await;
+            if let Some(existing_order_id) = dedup_map.get(&client_id).cloned() {
+                drop(dedup_map);
+                return self.get_oco_result(&existing_order_id).await;
+            }
+            if self.pending_placements.lock().unwrap().contains_key(&client_id) {
+                return Err(TradingError::ExecutionError {
+                    message: format!("Decision {} is already being placed", order_decision.decision_id),
+                });
+            }
+            dedup_map.insert(client_id, take_profit_id.to_string());
+        }
+        if let Err(e) = self.persist_oco_legs(client_id, &legs).await {
+            self.order_deduplication.write().await.remove(&client_id);
+            return Err(e);
+        }
+
+        for order_execution in legs {
+            let leg_id = order_execution.client_id;
+            self.active_orders.write().await.insert(leg_id, order_execution);
+            self.track_submission(&leg_id.to_string(), leg_id, &order_decision).await;
+        }
+
+        let take_profit_result = self
+            .submit_order_request(&exchange_name, take_profit_request, order_decision.decision_id.clone())
+            .await;
+        let take_profit_result = match take_profit_result {
+            Ok(exec_result) => exec_result,
+            Err(e) => {
+                let failure = Err(TradingError::ExecutionError { message: e.to_string() });
+                for leg_id in [take_profit_id, stop_loss_id] {
+                    self.update_order_status(&leg_id, &failure).await;
+                    self.track_outcome(&leg_id.to_string(), &failure).await;
+                }
+                return Err(e);
+            }
+        };
+
+        let stop_loss_result = self
+            .submit_order_request(&exchange_name, stop_loss_request, order_decision.decision_id.clone())
+            .await;
+        let stop_loss_result = match stop_loss_result {
+            Ok(exec_result) => exec_result,
+            Err(e) => {
+                // Never leave a take profit resting without its stop; one that already
+                // finished keeps the result the exchange reported
+                let mut take_profit_result = take_profit_result;
+                if !OrderExecutionStatus::from(take_profit_result.status).is_terminal() {
+                    match self.cancel_order_on(&exchange_name, &take_profit_id.to_string()).await {
+                        Ok(()) => take_profit_result.status = rust_common::OrderStatus::Cancelled,
+                        Err(cancel_error) => {
+                            warn!("Failed to cancel OCO take profit {} after stop loss failed: {}", take_profit_id, cancel_error);
+                            // The take profit may have filled in the meantime
+                            if let Ok(status) = self.order_status_on(&exchange_name, &take_profit_id.to_string()).await {
+                                if status == rust_common::OrderStatus::Filled {
+                                    take_profit_result.filled_quantity = order_decision.risk_adjusted_quantity;
+                                    take_profit_result.average_price = Some(take_profit_price);
+                                }
+                                take_profit_result.status = status;
+                            }
+                        }
+                    }
+                }
+                self.store_result(&take_profit_result).await;
+                let take_profit_result = Ok(take_profit_result);
+                self.update_order_status(&take_profit_id, &take_profit_result).await;
+                self.track_outcome(&take_profit_id.to_string(), &take_profit_result).await;
+
+                let failure = Err(TradingError::ExecutionError { message: e.to_string() });
+                self.update_order_status(&stop_loss_id, &failure).await;
+                self.track_outcome(&stop_loss_id.to_string(), &failure).await;
+                return Err(e);
+            }
+        };
+
+        self.store_result(&take_profit_result).await;
+        self.store_result(&stop_loss_result).await;
+        for (leg_id, exec_result) in [(take_profit_id, &take_profit_result), (stop_loss_id, &stop_loss_result)] {
+            let result = Ok(exec_result.clone());
+            self.track_outcome(&leg_id.to_string(), &result).await;
+            self.update_order_status(&leg_id, &result).await;
+        }
+
+        Ok(OcoExecutionResult {
+            take_profit: take_profit_result,
+            stop_loss: stop_loss_result,
+        })
+    }
+
+    /// Persist both legs of an OCO pair and the decision's mapping to its take profit leg.
+    /// Nothing is left in the order store unless all of it was saved.
+    async fn persist_oco_legs(&self, client_id: Uuid, legs: &[OrderExecution]) -> Result<(), TradingError> {
+        let mut saved = Vec::new();
+        let mut persisted = Ok(());
+        for order_execution in legs {
+            persisted = self.order_store.save_order(order_execution).await;
+            if persisted.is_err() {
+                break;
+            }
+            saved.push(order_execution.client_id);
+        }
+        if persisted.is_ok() {
+            if let Some(take_profit) = legs.first() {
+                persisted = self.order_store.save_dedup_mapping(client_id, &take_profit.order_id).await;
+            }
+        }
+
+        if persisted.is_err() {
+            for leg_id in saved {
+                if let Err(e) = self.order_store.remove_order(&leg_id).await {
+                    warn!("Failed to remove unplaced OCO leg {} from the order store: {}", leg_id, e);
+                }
+            }
+        }
+        persisted
+    }
+
+    /// Result of a placed OCO pair, found from its take profit leg
+    async fn get_oco_result(&self, take_profit_id: &str) -> Result<OcoExecutionResult, TradingError> {
+        let take_profit = self.get_order_result(take_profit_id).await?;
+        let leg_id = Uuid::parse_str(take_profit_id).map_err(|_| TradingError::ExecutionError {
+            message: format!("Order {} is not an OCO leg", take_profit_id),
+        })?;
+        let tracked = self.active_orders.read().await.get(&leg_id).cloned();
+        let tracked = match tracked {
+            Some(order_execution) => Some(order_execution),
+            None => self.order_store.load_order(&leg_id).await?,
+        };
+        let stop_loss_id = tracked
+            .and_then(|order_execution| order_execution.linked_order_id)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Order {} is not an OCO leg", take_profit_id),
+            })?;
+        let stop_loss = self.get_order_result(&stop_loss_id).await?;
+
+        Ok(OcoExecutionResult { take_profit, stop_loss })
+    }
+
+    /// Submit a ready-made order request to an exchange
+    async fn submit_order_request(
+        &self,
+        exchange_name: &str,
+        order_request: OrderRequest,
+        decision_id: String,
+    ) -> Result<ExecutionResult, TradingError> {
+        let timeouts = self.get_adapter_timeouts(exchange_name).await;
+        let adapters = self.exchange_adapters.read().await;
+        let adapter = adapters.get(exchange_name)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Exchange adapter not found: {}", exchange_name),
+            })?;
+
+        let order_id = order_request.id.to_string();
+        let symbol = order_request.symbol.clone();
+        let side = order_request.side.clone();
+        let adapter_result = with_timeout("place_order", timeouts.place_order_ms, adapter.place_order(order_request)).await?;
+        self.record_fill(&symbol, &side, &order_id, &adapter_result).await;
+
+        Ok(Self::execution_result_from(decision_id, order_id, &adapter_result))
+    }
+
+    /// Close the open position in a symbol with an offsetting reduce-only market order,
+    /// placed and tracked like any other decision
+    pub async fn close_position(&self, symbol: &str, exchange: &str) -> Result<ExecutionResult, TradingError> {
+        let symbol = Symbol::normalize(symbol);
+
+        let timeouts = self.get_adapter_timeouts(exchange).await;
+        let adapters = self.exchange_adapters.read().await;
+        let adapter = adapters.get(exchange)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Exchange adapter not found: {}", exchange),
+            })?;
+
+        let account_info = with_timeout("get_account_info", timeouts.get_account_info_ms, adapter.get_account_info()).await?;
+        drop(adapters);
+        let position = account_info.positions.iter()
+            .find(|p| Symbol::normalize(&p.symbol) == symbol && p.size.abs() > 0.0)
+            .ok_or_else(|| TradingError::ExecutionError {
+                message: format!("Open position not found for symbol: {}", symbol),
+            })?;
+
+        let direction = match position.side.as_str() {
+            "long" => rust_common::Direction::Short,
+            "short" => rust_common::Direction::Long,
+            other => {
+                return Err(TradingError::ExecutionError {
+                    message: format!("Unknown position side '{}' for symbol: {}", other, symbol),
+                });
+            }
+        };
+
+        let mut order_decision = OrderDecision::new(format!("close_position:{}", symbol), symbol.clone());
+        order_decision.direction = direction;
+        order_decision.order_type = rust_common::OrderType::Market;
+        order_decision.base_quantity = position.size.abs();
+        order_decision.risk_adjusted_quantity = position.size.abs();
+        order_decision.entry_price = position.current_price;
+        order_decision.reduce_only = true;
+        order_decision.exchange = Some(exchange.to_string());
+        order_decision.decision_reason = format!("Close {} position in {}", position.side, symbol);
+
+        self.place_order(order_decision).await
+    }
+
+    /// Place several decisions as one basket, e.g. the legs of a spread, in order.
+    ///
+    /// With `atomic`, the first leg to fail stops the basket: earlier legs have their
+    /// unfilled remainder cancelled and their fills reversed with reduce-only market orders,
+    /// best effort, and the error names the basket and the failed leg. Otherwise every leg
+    /// is attempted and failures are recorded per leg.
+    pub async fn place_basket(&self, legs: Vec<OrderDecision>, atomic: bool) -> Result<BasketExecutionResult, TradingError> {
+        if legs.is_empty() {
+            return Err(TradingError::ExecutionError {
+                message: "Basket has no legs".to_string(),
+            });
+        }
+
+        let basket_id = Uuid::new_v4().to_string();
+        let mut basket = BasketStatus {
+            basket_id: basket_id.clone(),
+            atomic,
+            created_at: Utc::now(),
+            state: BasketState::Working,
+            legs: legs.iter()
+                .map(|leg| BasketLegStatus {
+                    decision_id: leg.decision_id.clone(),
+                    symbol: leg.symbol.clone(),
+                    order_id: None,
+                    status: None,
+                    error: None,
+                    reversal_order_id: None,
+                })
+                .collect(),
+        };
+
+        let mut results = Vec::with_capacity(legs.len());
+        let mut failure = None;
+        for (index, leg) in legs.iter().enumerate() {
+            match self.place_order(leg.clone()).await {
+                Ok(exec_result) => {
+                    basket.legs[index].order_id = Some(exec_result.order_id.clone());
+                    results.push(Some(exec_result));
+                }
+                Err(e) => {
+                    basket.legs[index].error = Some(e.to_string());
+                    results.push(None);
+                    if atomic {
+                        failure = Some((index, e));
+                        break;
+                    }
+                }
+            }
+        }
+
+        if let Some((failed_index, error)) = failure {
+            for (index, exec_result) in results.iter().enumerate() {
+                let Some(exec_result) = exec_result else { continue };
+                basket.legs[index].reversal_order_id = self.roll_back_basket_leg(&basket_id, &legs[index], exec_result).await;
+            }
+            basket.state = BasketState::RolledBack;
+            self.baskets.write().await.insert(basket_id.clone(), basket);
+            return Err(TradingError::ExecutionError {
+                message: format!(
+                    "Basket {} rolled back: leg {} ({}) failed: {}",
+                    basket_id, failed_index + 1, legs[failed_index].symbol, error
+                ),
+            });
+        }
+
+        self.baskets.write().await.insert(basket_id.clone(), basket);
+        let state = self.get_basket(&basket_id).await.map_or(BasketState::Working, |basket| basket.state);
+        Ok(BasketExecutionResult { basket_id, state, results })
+    }
+
+    /// Undo a placed basket leg: cancel what is still working and reverse what filled.
+    /// Returns the reversal order's id, if one was placed.
+    async fn roll_back_basket_leg(&self, basket_id: &str, leg: &OrderDecision, exec_result: &ExecutionResult) -> Option<String> {
+        use rust_common::OrderSide;
+
+        if !exec_result.is_fully_filled() {
+            if let Err(e) = self.cancel_order(&exec_result.order_id).await {
+                warn!("Basket {}: failed to cancel leg {}: {}", basket_id, exec_result.order_id, e);
+            }
+            // The leg may have filled further before the cancel landed, so ask the exchange again
+            if let Err(e) = self.get_order_status(&exec_result.order_id).await {
+                warn!("Basket {}: failed to refresh leg {}: {}", basket_id, exec_result.order_id, e);
+            }
+        }
+        let filled_quantity = self.filled_quantity(&exec_result.order_id).await.max(exec_result.filled_quantity);
+        if filled_quantity <= FILL_EPSILON {
+            return None;
+        }
+
+        let order_request = OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: leg.symbol.clone(),
+            side: match leg.direction {
+                rust_common::Direction::Long => OrderSide::Sell,
+                rust_common::Direction::Short => OrderSide::Buy,
+            },
+            size: filled_quantity,
+            price: None,
+            order_type: rust_common::OrderType::Market,
+            timestamp: Utc::now(),
+            reduce_only: true,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        };
+        let reversal_order_id = order_request.id.to_string();
+        let exchange_name = self.exchange_for_order(&exec_result.order_id).await;
+        match self.submit_order_request(&exchange_name, order_request, format!("basket_rollback:{}", basket_id)).await {
+            Ok(_) => Some(reversal_order_id),
+            Err(e) => {
+                warn!("Basket {}: failed to reverse {} filled on leg {}: {}", basket_id, filled_quantity, exec_result.order_id, e);
+                None
+            }
+        }
+    }
+
+    /// A basket with the current status of each leg's order
+    pub async fn get_basket(&self, basket_id: &str) -> Option<BasketStatus> {
+        let mut basket = self.baskets.read().await.get(basket_id).cloned()?;
+        {
+            let active_orders = self.active_orders.read().await;
+            for leg in &mut basket.legs {
+                leg.status = leg.order_id.as_ref().and_then(|order_id| {
+                    active_orders.values()
+                        .find(|order_execution| &order_execution.order_id == order_id)
+                        .map(|order_execution| order_execution.status.clone())
+                });
+            }
+        }
+        basket.refresh_state();
+        Some(basket)
+    }
+
+    /// Get signal-to-execution latency statistics
+    pub fn get_latency_stats(&self) -> LatencyStats {
+        self.latency_tracker.get_stats()
+    }
+
+    /// Render the gateway's Prometheus metrics
+    pub fn render_metrics(&self) -> String {
+        self.metrics.render()
+    }
+
+    /// Get the identifier of the current trading session
+    pub fn current_session_id(&self) -> String {
+        self.session_clock.current_session_id()
+    }
+
+    /// Get activity counters for the current trading session
+    pub fn get_session_stats(&self) -> SessionStats {
+        self.session_counters.snapshot()
+    }
+
+    /// Get gateway configuration
+    pub fn config(&self) -> &GatewayConfig {
+        &self.config
+    }
+
+    /// Whether shutdown has started and new orders are being refused
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Halt or resume trading gateway-wide. While halted every new order is refused;
+    /// cancellations and position closes still go through.
+    pub fn set_trading_halted(&self, halted: bool) {
+        let was_halted = self.trading_halted.swap(halted, Ordering::SeqCst);
+        if halted && !was_halted {
+            warn!("Trading halted; new orders will be rejected until resumed");
+        } else if !halted && was_halted {
+            info!("Trading resumed");
+        }
+    }
+
+    pub fn is_trading_halted(&self) -> bool {
+        self.trading_halted.load(Ordering::SeqCst)
+    }
+
+    /// Count a placement as in flight, refusing it once draining has started or, unless it
+    /// only reduces a position, trading is halted
+    fn begin_placement(&self, reduce_only: bool) -> Result<InFlightPlacement, TradingError> {
+        // Counted before the check so drain can't miss a placement that got past it
+        self.in_flight_placements.fetch_add(1, Ordering::SeqCst);
+        let placement = InFlightPlacement(self.in_flight_placements.clone());
+        if self.is_draining() {
+            return Err(TradingError::ExecutionError {
+                message: "Gateway is shutting down and not accepting new orders".to_string(),
+            });
+        }
+        if self.is_trading_halted() && !reduce_only {
+            return Err(TradingError::risk_rejection(RejectionFeedback::new(
+                RejectionReason::TradingHalted,
+                "trading_halted",
+                "trading halted".to_string(),
+                "Wait for trading to be resumed".to_string(),
+            )));
+        }
+        Ok(placement)
+    }
+
+    /// Stop accepting new orders and wait up to `timeout` for in-flight placements to return.
+    ///
+    /// Orders resting on the exchange have been acknowledged and don't hold up shutdown.
+    /// Returns how many placements were still in flight when the timeout elapsed.
+    pub async fn drain(&self, timeout: std::time::Duration) -> usize {
+        self.draining.store(true, Ordering::SeqCst);
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let in_flight = self.in_flight_placements.load(Ordering::SeqCst);
+            if in_flight == 0 {
+                info!("Gateway drained; no orders in flight");
+                return 0;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                let active_orders = self.active_orders.read().await;
+                for order_execution in active_orders.values() {
+                    if matches!(order_execution.status, OrderExecutionStatus::Pending) {
+                        warn!(
+                            "Order {} still in flight at shutdown; its exchange state is unknown",
+                            order_execution.order_id
+                        );
+                    }
+                }
+                return in_flight;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(DRAIN_POLL_INTERVAL_MS)).await;
+        }
+    }
+
+    /// Ping every registered exchange and report it alongside its breaker, ordered by name
+    pub async fn exchange_health(&self) -> Vec<ExchangeHealth> {
+        let mut reachability = Vec::new();
+        for (exchange_name, adapter) in self.exchange_adapters.read().await.iter() {
+            let timeouts = self.get_adapter_timeouts(exchange_name).await;
+            let reachable = match with_timeout("ping", timeouts.ping_ms, adapter.ping()).await {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("Exchange {} failed its health ping: {}", exchange_name, e);
+                    false
+                }
+            };
+            reachability.push((exchange_name.clone(), reachable));
+        }
+
+        let circuit_breakers = self.circuit_breakers.read().await;
+        let mut health: Vec<ExchangeHealth> = reachability.into_iter()
+            .map(|(exchange, reachable)| {
+                let breaker = circuit_breakers.get(&exchange);
+                ExchangeHealth {
+                    reachable,
+                    breaker_state: breaker.map_or(CircuitBreakerState::Closed, CircuitBreaker::get_state),
+                    breaker_open: breaker.is_some_and(CircuitBreaker::is_tripped),
+                    last_success: breaker
+                        .and_then(CircuitBreaker::last_success_ms)
+                        .and_then(|millis| DateTime::from_timestamp_millis(millis as i64)),
+                    exchange,
+                }
+            })
+            .collect();
+        health.sort_by(|a, b| a.exchange.cmp(&b.exchange));
+        health
+    }
+
+    /// Get active orders count
+    pub async fn get_active_orders_count(&self) -> usize {
+        let active_orders = self.active_orders.read().await;
+        active_orders.len()
+    }
+
+    /// Clean up completed orders (should be called periodically), returning the number removed
+    pub async fn cleanup_completed_orders(&self, max_age_hours: i64) -> usize {
+        let cutoff_time = Utc::now() - Duration::hours(max_age_hours);
+        
+        let mut active_orders = self.active_orders.write().await;
+        let mut dedup_map = self.order_deduplication.write().await;
+        let mut execution_results = self.execution_results.write().await;
+        let mut recorded_fills = self.recorded_fills.write().await;
+        
+        let mut to_remove = Vec::new();
+        
+        for (client_id, order_execution) in active_orders.iter() {
+            // Keep pending and partially filled orders
+            if order_execution.updated_at < cutoff_time && order_execution.status.is_terminal() {
+                to_remove.push(*client_id);
+            }
+        }
+        
+        let removed_count = to_remove.len();
+        
+        for client_id in &to_remove {
+            if let Some(order_execution) = active_orders.remove(client_id) {
+                execution_results.remove(&order_execution.order_id);
+                recorded_fills.remove(&order_execution.order_id);
+            }
+            dedup_map.remove(client_id);
+        }
+        drop((active_orders, dedup_map, execution_results, recorded_fills));
+        
+        let key_cutoff = Utc::now() - Duration::seconds(self.config.idempotency_key_ttl_secs as i64);
+        self.idempotency_keys.write().await.retain(|_, (_, first_seen)| *first_seen >= key_cutoff);
+        
+        for client_id in &to_remove {
+            if let Err(e) = self.order_store.remove_order(client_id).await {
+                warn!("Failed to remove order {} from the order store: {}", client_id, e);
+            }
+        }
+        // Lifecycles age out on the same cutoff so the order manager doesn't grow without bound
+        self.order_manager.cleanup_old_orders(max_age_hours).await;
+
+        // Finished orders no longer need replaying on restart
+        if let Some(event_log) = self.order_manager.event_log() {
+            if let Err(e) = event_log.compact().await {
+                warn!("Failed to compact the event log: {}", e);
+            }
+        }
+        
+        removed_count
+    }
+}
+
+impl Default for ExecutionGateway {
+    fn default() -> Self {
+        Self::new(GatewayConfig::default())
+    }
+}
+
+/// Check amended values against the exchange's increments and limits
+fn validate_amendment(
+    exchange_info: &ExchangeInfo,
+    new_price: Option<f64>,
+    new_quantity: Option<f64>,
+    total_filled: f64,
+) -> Result<(), TradingError> {
+    let invalid = |field: &str, message: String| TradingError::ValidationError { field: field.to_string(), message };
+
+    if let Some(price) = new_price {
+        if !price.is_finite() || price <= 0.0 {
+            return Err(invalid("new_price", format!("price {} must be positive", price)));
+        }
+        if price < exchange_info.min_price || price > exchange_info.max_price {
+            return Err(invalid("new_price", format!(
+                "price {} outside [{}, {}]",
+                price, exchange_info.min_price, exchange_info.max_price
+            )));
+        }
+        if !is_multiple_of(price, exchange_info.tick_size) {
+            return Err(invalid("new_price", format!("price {} is not a multiple of tick size {}", price, exchange_info.tick_size)));
+        }
+    }
+
+    if let Some(quantity) = new_quantity {
+        if !quantity.is_finite() || quantity <= 0.0 {
+            return Err(invalid("new_quantity", format!("quantity {} must be positive", quantity)));
+        }
+        if quantity < exchange_info.min_order_size || quantity > exchange_info.max_order_size {
+            return Err(invalid("new_quantity", format!(
+                "quantity {} outside [{}, {}]",
+                quantity, exchange_info.min_order_size, exchange_info.max_order_size
+            )));
+        }
+        if !is_multiple_of(quantity, exchange_info.lot_size) {
+            return Err(invalid("new_quantity", format!("quantity {} is not a multiple of lot size {}", quantity, exchange_info.lot_size)));
+        }
+        if quantity < total_filled - FILL_EPSILON {
+            return Err(invalid("new_quantity", format!("quantity {} is below the {} already filled", quantity, total_filled)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `value` lies on the `increment` grid, within floating-point noise
+fn is_multiple_of(value: f64, increment: f64) -> bool {
+    if increment <= 0.0 {
+        return true;
+    }
+    let steps = value / increment;
+    (steps - steps.round()).abs() < 1e-6
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_common::{OrderSide, Direction, OrderType as RustOrderType};
+    use uuid::Uuid;
+    use chrono::Utc;
+    use tokio_test;
+    use std::time::Duration;
+
+    fn create_test_order_decision() -> OrderDecision {
+        let mut decision = OrderDecision::new("test_signal".to_string(), "BTCUSD".to_string());
+        decision.direction = Direction::Long;
+        decision.order_type = RustOrderType::Limit;
+        decision.risk_adjusted_quantity = 0.1;
+        decision.entry_price = 50000.0;
+        decision.stop_loss = 49000.0;
+        decision.take_profit = Some(52000.0);
+        decision.risk_amount = 100.0;
+        decision.risk_percentage = 1.0;
+        decision.leverage = 1.0;
+        decision.portfolio_value = 10000.0;
+        decision.available_margin = 5000.0;
+        decision.current_exposure = 0.1;
+        decision.confidence_score = 0.8;
+        decision.confluence_score = 75.0;
+        decision.risk_reward_ratio = 2.0;
+        decision
+    }
+
+    #[tokio::test]
+    async fn test_gateway_creation() {
+        let config = GatewayConfig::default();
+        let gateway = ExecutionGateway::new(config);
+        assert_eq!(gateway.get_active_orders_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_register_exchange_adapter() {
+        let config = GatewayConfig::default();
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new();
+        gateway.register_exchange_adapter("test_exchange".to_string(), Box::new(mock_adapter)).await;
+        
+        // Verify adapter is registered by checking circuit breaker exists
+        let circuit_breakers = gateway.circuit_breakers.read().await;
+        assert!(circuit_breakers.contains_key("test_exchange"));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_idempotency() {
+        let config = GatewayConfig::default();
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let order_decision = create_test_order_decision();
+        
+        // Place the same order twice
+        let result1 = gateway.place_order(order_decision.clone()).await;
+        let result2 = gateway.place_order(order_decision).await;
+        
+        assert!(result1.is_ok());
+        assert!(result2.is_ok());
+        
+        // Should have only one active order due to idempotency
+        assert_eq!(gateway.get_active_orders_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_replay_returns_original_result() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let order_decision = create_test_order_decision();
+        let original = gateway.place_order(order_decision.clone()).await.unwrap();
+        let replayed = gateway.place_order(order_decision).await.unwrap();
+        
+        assert_eq!(replayed, original);
+        assert_eq!(replayed.status, rust_common::OrderStatus::PartiallyFilled);
+        assert!((replayed.filled_quantity - 0.05).abs() < 1e-9);
+        assert_eq!(replayed.average_price, Some(50000.0));
+        
+        // The replay never reaches the exchange
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_duplicates_wait_for_first_placement() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+
+        let mock_adapter = MockExchangeAdapter::new().with_delay(50);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let order_decision = create_test_order_decision();
+        let (first, second, third) = tokio::join!(
+            gateway.place_order_with_idempotency_key("key-1", order_decision.clone()),
+            gateway.place_order_with_idempotency_key("key-1", order_decision.clone()),
+            gateway.place_order_with_idempotency_key("key-1", order_decision),
+        );
+        let first = first.unwrap();
+
+        // Only one placement reaches the exchange; the duplicates replay its result
+        assert_eq!(second.unwrap().order_id, first.order_id);
+        assert_eq!(third.unwrap().order_id, first.order_id);
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+        assert_eq!(gateway.get_active_orders_count().await, 1);
+        assert!(gateway.pending_placements.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejected_placement_releases_its_idempotency_claim() {
+        let gateway = ExecutionGateway::new(GatewayConfig {
+            risk_limits: RiskLimits {
+                max_position_size: Some(0.05),
+                ..RiskLimits::default()
+            },
+            ..Default::default()
+        });
+
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let order_decision = create_test_order_decision();
+        let result = gateway.place_order(order_decision.clone()).await;
+        assert!(matches!(result, Err(TradingError::RiskLimitError { .. })));
+        assert!(gateway.pending_placements.lock().unwrap().is_empty());
+
+        // The same decision is placed afresh once the limit allows it
+        gateway.set_risk_limits(RiskLimits { max_position_size: Some(1.0), ..RiskLimits::default() }).await.unwrap();
+        gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_place_order_with_retry() {
+        let config = GatewayConfig {
+            max_retries: 2,
+            base_retry_delay_ms: 10,
+            max_retry_delay_ms: 100,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        // First adapter fails, then succeeds
+        let mut mock_adapter = MockExchangeAdapter::new().with_failure(true);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let order_decision = create_test_order_decision();
+        let result = gateway.place_order(order_decision).await;
+        
+        // Should fail after retries
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_first_retry_waits_for_backoff() {
+        let config = GatewayConfig {
+            max_retries: 1,
+            base_retry_delay_ms: 200,
+            max_retry_delay_ms: 1000,
+            retry_jitter: JitterStrategy::Equal,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new().with_failure(true).with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        // Two attempts: the only delay is between the first and second attempt
+        let start = std::time::Instant::now();
+        let result = gateway.place_order(create_test_order_decision()).await;
+        let elapsed = start.elapsed();
+        
+        assert!(result.is_err());
+        // Equal jitter waits between 100ms and 200ms before the first retry
+        assert!(elapsed >= Duration::from_millis(100), "first retry was not delayed: {:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_non_retriable_error_makes_single_attempt() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_risk_rejection("Position limit exceeded");
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let result = gateway.place_order(create_test_order_decision()).await;
+        assert!(matches!(result, Err(TradingError::RiskLimitError { .. })));
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+        
+        let circuit_breakers = gateway.circuit_breakers.read().await;
+        assert_eq!(circuit_breakers.get("default").unwrap().get_failure_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_validation_error_is_typed_and_not_retried() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.decision_id = "not-a-uuid".to_string();
+        let error = gateway.place_order(order_decision).await.unwrap_err();
+        assert!(matches!(&error, TradingError::ValidationError { field, .. } if field == "decision_id"));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.time_in_force = TimeInForce::Gtd(Utc::now() - chrono::Duration::seconds(1));
+        let error = gateway.place_order(order_decision).await.unwrap_err();
+        assert!(matches!(&error, TradingError::ValidationError { field, .. } if field == "time_in_force"));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
+        
+        assert!(placed_orders.lock().unwrap().is_empty());
+        assert_eq!(gateway.circuit_breakers.read().await.get("default").unwrap().get_failure_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_symbol_throttle_limits_orders_per_window() {
+        let gateway = ExecutionGateway::new(GatewayConfig {
+            symbol_throttle: ThrottleLimits { max_orders: Some(10), max_notional: Some(100_000.0) },
+            symbol_throttle_window_ms: 500,
+            ..Default::default()
+        });
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        for _ in 0..10 {
+            gateway.place_order(create_test_order_decision()).await.unwrap();
+        }
+        let error = gateway.place_order(create_test_order_decision()).await.unwrap_err();
+        assert!(matches!(error, TradingError::RateLimited { retry_after_ms: Some(_), .. }));
+        assert!(error.to_string().contains("BTCUSD throttled: 10 orders"));
+        assert_eq!(placed_orders.lock().unwrap().len(), 10);
+        
+        // Other symbols have their own window
+        let mut other_symbol = create_test_order_decision();
+        other_symbol.symbol = "ETHUSD".to_string();
+        assert!(gateway.place_order(other_symbol).await.is_ok());
+        
+        // A fresh window admits orders again
+        tokio::time::sleep(std::time::Duration::from_millis(550)).await;
+        assert!(gateway.place_order(create_test_order_decision()).await.is_ok());
+        assert_eq!(placed_orders.lock().unwrap().len(), 12);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_functionality() {
+        let config = GatewayConfig {
+            circuit_breaker_failure_threshold: 2,
+            circuit_breaker_recovery_timeout_ms: 100,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new().with_failure(true);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let order_decision = create_test_order_decision();
+        
+        // Trigger circuit breaker with failures
+        for _ in 0..3 {
+            let _ = gateway.place_order(order_decision.clone()).await;
+        }
+        
+        // Circuit breaker should be open
+        let circuit_breakers = gateway.circuit_breakers.read().await;
+        let cb = circuit_breakers.get("default").unwrap();
+        assert!(cb.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_symbol_circuit_breaker_isolates_failing_symbol() {
+        let config = GatewayConfig {
+            max_retries: 0,
+            circuit_breaker_failure_threshold: 5,
+            enable_symbol_circuit_breakers: true,
+            symbol_circuit_breaker_failure_threshold: 2,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_failing_symbol("DELISTED");
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let delisted_decision = || {
+            let mut order_decision = create_test_order_decision();
+            order_decision.symbol = "DELISTED".to_string();
+            order_decision
+        };
+        for _ in 0..2 {
+            let err = gateway.place_order(delisted_decision()).await.unwrap_err();
+            assert!(matches!(err, TradingError::ExecutionError { .. }));
+        }
+        
+        // The symbol breaker now stops orders before they reach the exchange
+        let err = gateway.place_order(delisted_decision()).await.unwrap_err();
+        match err {
+            TradingError::CircuitBreakerOpen { exchange, retry_after_ms } => {
+                assert_eq!(exchange, "default/DELISTED");
+                assert!(retry_after_ms > 0);
+            }
+            other => panic!("expected an open circuit breaker, got {:?}", other),
+        }
+        assert_eq!(placed_orders.lock().unwrap().len(), 2);
+        
+        // Other symbols on the exchange keep trading
+        let execution_result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert_eq!(execution_result.status, rust_common::OrderStatus::Filled);
+        let circuit_breakers = gateway.circuit_breakers.read().await;
+        assert_eq!(circuit_breakers.get("default").unwrap().get_state(), CircuitBreakerState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_partial_fill_handling() {
+        let config = GatewayConfig {
+            enable_partial_fills: true,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new().with_partial_fills(0.5);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let order_decision = create_test_order_decision();
+        let result = gateway.place_order(order_decision).await;
+        
+        assert!(result.is_ok());
+        let execution_result = result.unwrap();
+        assert_eq!(execution_result.status, rust_common::OrderStatus::PartiallyFilled);
+        assert_eq!(execution_result.filled_quantity, 0.05); // 50% of 0.1
+    }
+
+    #[tokio::test]
+    async fn test_partial_retry_completes_all_or_nothing_order() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_sequence(vec![(0.5, 50000.0), (1.0, 50100.0)]);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.partial_fill_acceptable = false;
+        order_decision.partial_retry_policy = Some(PartialRetryPolicy { max_attempts: 3, delay_ms: 0 });
+        order_decision.slippage_tolerance = 0.005; // the remainder fills 0.2% above entry
+        
+        let execution_result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(execution_result.status, rust_common::OrderStatus::Filled);
+        assert!((execution_result.filled_quantity - 0.1).abs() < 1e-9);
+        // VWAP of 0.05 @ 50000 and 0.05 @ 50100
+        assert!((execution_result.average_price.unwrap() - 50050.0).abs() < 1e-6);
+        assert!((execution_result.slippage.unwrap() - 0.001).abs() < 1e-9);
+        
+        let residual_order_id = {
+            let placed_orders = placed_orders.lock().unwrap();
+            assert_eq!(placed_orders.len(), 2);
+            assert!((placed_orders[1].size - 0.05).abs() < 1e-9);
+            placed_orders[1].id.to_string()
+        };
+        
+        // The resubmitted remainder is tracked as an order of its own
+        let detail = gateway.get_order_detail(&residual_order_id).await.unwrap();
+        assert_eq!(detail.lifecycle.metadata["remainder_of"], serde_json::json!(execution_result.order_id));
+        let residual = detail.execution.unwrap();
+        assert!(matches!(residual.status, OrderExecutionStatus::Filled));
+        assert!((residual.requested_quantity - 0.05).abs() < 1e-9);
+        assert_eq!(gateway.get_active_orders_count().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_slippage_beyond_tolerance_rejects_all_or_nothing_order() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        // 50100 is 0.2% above the 50000 entry, past the 0.1% tolerance
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_sequence(vec![(1.0, 50100.0)]);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.partial_fill_acceptable = false;
+        
+        let error = gateway.place_order(order_decision).await.unwrap_err();
+        assert!(error.to_string().contains("exceeds slippage tolerance"));
+        // The fill already happened, so it must not be resubmitted
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_slippage_beyond_tolerance_is_flagged_when_partial_fills_acceptable() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_sequence(vec![(1.0, 50100.0), (1.0, 50100.0)]);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let execution_result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert!((execution_result.slippage.unwrap() - 0.002).abs() < 1e-9);
+        
+        // The same price is an improvement for a short
+        let mut short_decision = create_test_order_decision();
+        short_decision.direction = Direction::Short;
+        short_decision.stop_loss = 51000.0;
+        short_decision.take_profit = Some(48000.0);
+        let execution_result = gateway.place_order(short_decision).await.unwrap();
+        assert!((execution_result.slippage.unwrap() + 0.002).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_partial_retry_cancels_after_max_attempts() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.partial_fill_acceptable = false;
+        order_decision.partial_retry_policy = Some(PartialRetryPolicy { max_attempts: 2, delay_ms: 0 });
+        
+        let execution_result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(execution_result.status, rust_common::OrderStatus::Cancelled);
+        // 0.05 + 0.025 + 0.0125 filled before giving up
+        assert!((execution_result.filled_quantity - 0.0875).abs() < 1e-9);
+        assert!(execution_result.error_message.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_partial_retry_stops_when_remainder_cannot_be_cancelled() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5).with_failing_cancels();
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.partial_fill_acceptable = false;
+        order_decision.partial_retry_policy = Some(PartialRetryPolicy { max_attempts: 3, delay_ms: 0 });
+        let client_id = Uuid::parse_str(&order_decision.decision_id).unwrap();
+        
+        // The remainder is still on the book, so resubmitting it could fill past the decision's size
+        let execution_result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+        assert_eq!(execution_result.status, rust_common::OrderStatus::PartiallyFilled);
+        assert!((execution_result.filled_quantity - 0.05).abs() < 1e-9);
+        assert!(execution_result.error_message.unwrap().contains("could not be cancelled"));
+        
+        // The working remainder keeps its exposure reserved
+        let reservation = gateway.exposure_reservations.read().await.get(&client_id).cloned().unwrap();
+        assert!((reservation.signed_quantity - 0.05).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_partial_fills_volume_weighted_average() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let client_id = Uuid::new_v4();
+        {
+            let mut active_orders = gateway.active_orders.write().await;
+            active_orders.insert(client_id, OrderExecution {
+                order_id: Uuid::new_v4().to_string(),
+                client_id,
+                exchange: DEFAULT_EXCHANGE.to_string(),
+                status: OrderExecutionStatus::Pending,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                retry_count: 0,
+                partial_fills: Vec::new(),
+                total_filled: 0.0,
+                average_price: None,
+                linked_order_id: None,
+                requested_quantity: 0.0,
+                direction: None,
+                reference_price: None,
+                symbol: "BTCUSD".to_string(),
+                reserved_risk_amount: None,
+            });
+        }
+        
+        let fill = |quantity: f64, price: f64| {
+            let mut fill_data = HashMap::new();
+            fill_data.insert("fill_id".to_string(), serde_json::json!(Uuid::new_v4().to_string()));
+            fill_data.insert("quantity".to_string(), serde_json::json!(quantity));
+            fill_data.insert("price".to_string(), serde_json::json!(price));
+            fill_data
+        };
+        
+        // A zero-quantity fill must not skew the average
+        gateway.handle_partial_fills(&client_id.to_string(), &[fill(0.0, 10000.0)]).await.unwrap();
+        {
+            let active_orders = gateway.active_orders.read().await;
+            assert_eq!(active_orders.get(&client_id).unwrap().average_price, None);
+        }
+        
+        let fills = vec![fill(1.0, 100.0), fill(2.0, 110.0), fill(3.0, 120.0)];
+        gateway.handle_partial_fills(&client_id.to_string(), &fills).await.unwrap();
+        
+        let active_orders = gateway.active_orders.read().await;
+        let order_execution = active_orders.get(&client_id).unwrap();
+        // (1*100 + 2*110 + 3*120) / 6 = 680 / 6
+        assert!((order_execution.average_price.unwrap() - 680.0 / 6.0).abs() < 1e-9);
+        assert!((order_execution.total_filled - 6.0).abs() < 1e-9);
+        assert_eq!(order_execution.partial_fills.len(), 4);
+    }
+
+    #[test]
+    fn test_fill_quality_against_reference_price() {
+        let long_order = |total_filled: f64, average_price: Option<f64>| OrderExecution {
+            order_id: Uuid::new_v4().to_string(),
+            client_id: Uuid::new_v4(),
+            exchange: DEFAULT_EXCHANGE.to_string(),
+            status: OrderExecutionStatus::PartiallyFilled,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            retry_count: 0,
+            partial_fills: Vec::new(),
+            total_filled,
+            average_price,
+            linked_order_id: None,
+            requested_quantity: 2.0,
+            direction: Some(rust_common::Direction::Long),
+            reference_price: Some(100.0),
+            symbol: "BTCUSD".to_string(),
+            reserved_risk_amount: None,
+        };
+
+        // Buying above the reference costs money, buying below it saves
+        let filled_above = long_order(1.5, Some(101.0));
+        assert!((filled_above.implementation_shortfall(100.0).unwrap() - 1.5).abs() < 1e-9);
+        assert!((filled_above.fill_rate() - 0.75).abs() < 1e-9);
+        let filled_below = long_order(2.0, Some(99.5));
+        assert!((filled_below.implementation_shortfall(100.0).unwrap() + 1.0).abs() < 1e-9);
+        assert!((filled_below.fill_quality().fill_rate - 1.0).abs() < 1e-9);
+
+        // A short selling below the reference is the costly side
+        let mut short_order = long_order(1.0, Some(99.0));
+        short_order.direction = Some(rust_common::Direction::Short);
+        assert!((short_order.implementation_shortfall(100.0).unwrap() - 1.0).abs() < 1e-9);
+
+        let unfilled = long_order(0.0, None);
+        assert_eq!(unfilled.fill_rate(), 0.0);
+        assert_eq!(unfilled.implementation_shortfall(100.0), None);
+        assert_eq!(unfilled.fill_quality().implementation_shortfall, None);
+        let mut unsized_order = unfilled.clone();
+        unsized_order.requested_quantity = 0.0;
+        assert_eq!(unsized_order.fill_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_execution_result_sums_partial_fill_commissions() {
+        let fill = |quantity: f64, price: f64, commission: f64| {
+            let mut fill_data = HashMap::new();
+            fill_data.insert("fill_id".to_string(), serde_json::json!(Uuid::new_v4().to_string()));
+            fill_data.insert("quantity".to_string(), serde_json::json!(quantity));
+            fill_data.insert("price".to_string(), serde_json::json!(price));
+            fill_data.insert("commission".to_string(), serde_json::json!(commission));
+            fill_data
+        };
+        let adapter_result = AdapterOrderResult {
+            order_id: Uuid::new_v4().to_string(),
+            status: rust_common::OrderStatus::Filled,
+            filled_quantity: 6.0,
+            average_price: Some(680.0 / 6.0),
+            // Only the last fill's commission, as some venues report it
+            commission: 0.36,
+            filled_at: Some(Utc::now()),
+            partial_fills: vec![fill(1.0, 100.0, 0.1), fill(2.0, 110.0, 0.22), fill(3.0, 120.0, 0.36)],
+        };
+
+        let result = ExecutionGateway::execution_result_from("decision".to_string(), adapter_result.order_id.clone(), &adapter_result);
+        assert!((result.commission - 0.68).abs() < 1e-9);
+        assert_eq!(result.partial_fills.len(), 3);
+        assert!((result.total_cost() - 680.68).abs() < 1e-9);
+
+        let no_fill_commissions = AdapterOrderResult { partial_fills: Vec::new(), ..adapter_result };
+        assert_eq!(no_fill_commissions.total_commission(), 0.36);
+    }
+
+    #[tokio::test]
+    async fn test_price_sanity_check_rejects_fat_finger() {
+        let config = GatewayConfig {
+            max_price_deviation_pct: Some(10.0),
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new().with_mark_price(50000.0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        // Limit buy 50% above the mark
+        let mut order_decision = create_test_order_decision();
+        order_decision.entry_price = 75000.0;
+        order_decision.stop_loss = 74000.0;
+        order_decision.take_profit = Some(77000.0);
+        
+        let result = gateway.place_order(order_decision).await;
+        assert!(matches!(result, Err(TradingError::RiskLimitError { .. })));
+        assert!(matches!(
+            determine_retry_policy(&result.unwrap_err()),
+            RetryPolicy::NoRetry
+        ));
+        assert_eq!(gateway.get_active_orders_count().await, 0);
+        
+        // A price within the band is accepted
+        let result = gateway.place_order(create_test_order_decision()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_expired_mark_price_is_refreshed_from_exchange() {
+        let config = GatewayConfig {
+            max_price_deviation_pct: Some(10.0),
+            mark_price_ttl_ms: 0,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_price_path(vec![50000.0, 60000.0]);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+
+        // The market has moved 20%; the first mark must not pin the band forever
+        let mut order_decision = create_test_order_decision();
+        order_decision.entry_price = 60000.0;
+        order_decision.stop_loss = 59000.0;
+        order_decision.take_profit = Some(62000.0);
+        assert!(gateway.place_order(order_decision).await.is_ok());
+
+        // Marks are kept per exchange
+        gateway.update_exchange_mark_price("other", "BTCUSD", 1.0).await;
+        assert!(gateway.mark_prices.read().await.contains_key(&("default".to_string(), "BTCUSD".to_string())));
+        assert_eq!(gateway.mark_prices.read().await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_position_limit_rejects_order_past_limit() {
+        let config = GatewayConfig {
+            risk_limits: RiskLimits {
+                max_position_size: Some(0.15),
+                ..RiskLimits::default()
+            },
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // 0.1 fits under the limit, a second 0.1 would take the position to 0.2
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        let result = gateway.place_order(create_test_order_decision()).await;
+        match result {
+            Err(TradingError::RiskLimitError { limit, .. }) => assert!(limit.contains("BTCUSD")),
+            other => panic!("expected a position limit rejection, got {:?}", other),
+        }
+
+        let mut smaller = create_test_order_decision();
+        smaller.risk_adjusted_quantity = 0.05;
+        gateway.place_order(smaller).await.unwrap();
+        assert!((gateway.get_position("BTCUSD").await.unwrap().net_size - 0.15).abs() < 1e-9);
+
+        // Raising the limit at runtime lets the larger order through
+        gateway.set_risk_limits(RiskLimits { max_position_size: Some(1.0), ..RiskLimits::default() }).await.unwrap();
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_amended_quantity_resizes_reservation() {
+        let config = GatewayConfig {
+            risk_limits: RiskLimits {
+                max_position_size: Some(0.15),
+                ..RiskLimits::default()
+            },
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_model(FillModel::Probabilistic { fill_prob: 0.0 });
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let resting = gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert!(matches!(
+            gateway.place_order(create_test_order_decision()).await,
+            Err(TradingError::RiskLimitError { .. })
+        ));
+
+        // Shrinking the working order frees the headroom it no longer needs
+        gateway.amend_order(&resting.order_id, None, Some(0.05)).await.unwrap();
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_amendment_held_to_risk_limits() {
+        let config = GatewayConfig {
+            risk_limits: RiskLimits {
+                max_position_size: Some(0.15),
+                max_order_notional: Some(6000.0),
+                ..RiskLimits::default()
+            },
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_model(FillModel::Probabilistic { fill_prob: 0.0 });
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let resting = gateway.place_order(create_test_order_decision()).await.unwrap();
+        let client_id = Uuid::parse_str(&resting.decision_id).unwrap();
+
+        // Growing the order past the position limit is refused and leaves the reservation alone
+        assert!(matches!(
+            gateway.amend_order(&resting.order_id, None, Some(0.2)).await,
+            Err(TradingError::RiskLimitError { .. })
+        ));
+        let reservation = gateway.exposure_reservations.read().await.get(&client_id).cloned().unwrap();
+        assert!((reservation.signed_quantity - 0.1).abs() < 1e-9);
+
+        // So is repricing it past the order notional limit
+        assert!(matches!(
+            gateway.amend_order(&resting.order_id, Some(61000.0), None).await,
+            Err(TradingError::RiskLimitError { .. })
+        ));
+
+        // An allowed amendment moves the reservation to the new size and price
+        gateway.amend_order(&resting.order_id, Some(49000.0), Some(0.12)).await.unwrap();
+        let reservation = gateway.exposure_reservations.read().await.get(&client_id).cloned().unwrap();
+        assert!((reservation.signed_quantity - 0.12).abs() < 1e-9);
+        assert!((reservation.price - 49000.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_working_orders_reserve_position_limit() {
+        let config = GatewayConfig {
+            risk_limits: RiskLimits {
+                max_position_size: Some(0.15),
+                ..RiskLimits::default()
+            },
+            ..Default::default()
+        };
+        let gateway = std::sync::Arc::new(ExecutionGateway::new(config));
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(50)
+            .with_fill_model(FillModel::Probabilistic { fill_prob: 0.0 });
+        let resting_orders = mock_adapter.resting_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // Two orders racing for the same headroom: only one is let through
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let gateway = gateway.clone();
+                tokio::spawn(async move { gateway.place_order(create_test_order_decision()).await })
+            })
+            .collect();
+        let mut resting = Vec::new();
+        for handle in handles {
+            match handle.await.unwrap() {
+                Ok(result) => resting.push(result),
+                Err(TradingError::RiskLimitError { limit, .. }) => assert!(limit.contains("BTCUSD")),
+                Err(e) => panic!("expected a position limit rejection, got {:?}", e),
+            }
+        }
+        assert_eq!(resting.len(), 1);
+
+        // Cancelling the resting order gives its headroom back
+        gateway.cancel_order(&resting[0].order_id).await.unwrap();
+        let result = gateway.place_order(create_test_order_decision()).await.unwrap();
+
+        // Once it fills the exposure is held by the position instead, not counted twice
+        resting_orders.lock().unwrap().insert(result.order_id.clone(), rust_common::OrderStatus::Filled);
+        gateway.get_order_status(&result.order_id).await.unwrap();
+        let mut smaller = create_test_order_decision();
+        smaller.risk_adjusted_quantity = 0.05;
+        gateway.place_order(smaller).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_daily_loss_limit_blocks_risk_increasing_orders() {
+        let config = GatewayConfig {
+            max_daily_loss: Some(50.0),
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_sequence(vec![(1.0, 50000.0), (1.0, 49000.0)]);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let mut entry = create_test_order_decision();
+        entry.risk_adjusted_quantity = 0.2;
+        gateway.place_order(entry).await.unwrap();
+        assert!(!gateway.get_daily_loss_stats().breached);
+
+        // Closing half at 49000 realizes a 100 loss
+        let exit = |quantity: f64| {
+            let mut decision = create_test_order_decision();
+            decision.direction = Direction::Short;
+            decision.risk_adjusted_quantity = quantity;
+            decision.entry_price = 49000.0;
+            decision.stop_loss = 51000.0;
+            decision.take_profit = Some(48000.0);
+            decision
+        };
+        gateway.place_order(exit(0.1)).await.unwrap();
+        let stats = gateway.get_daily_loss_stats();
+        assert!(stats.realized_pnl <= -100.0);
+        assert!(stats.breached);
+
+        match gateway.place_order(create_test_order_decision()).await {
+            Err(TradingError::RiskLimitError { limit, rejection }) => {
+                assert!(limit.contains("daily loss limit"));
+                assert_eq!(rejection.unwrap().reason, RejectionReason::DailyLossLimitReached);
+            }
+            other => panic!("expected a daily loss limit rejection, got {:?}", other),
+        }
+
+        // Reducing the remaining long still goes through, flipping it short does not
+        assert!(matches!(gateway.place_order(exit(0.2)).await, Err(TradingError::RiskLimitError { .. })));
+        gateway.place_order(exit(0.05)).await.unwrap();
+        assert!((gateway.get_position("BTCUSD").await.unwrap().net_size - 0.05).abs() < 1e-9);
+    }
+
+    #[derive(Default)]
+    struct RecordingEventPublisher {
+        events: std::sync::Mutex<Vec<ExecutionEvent>>,
+        fail: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl EventPublisher for RecordingEventPublisher {
+        async fn publish(&self, event: &ExecutionEvent) -> Result<(), TradingError> {
+            self.events.lock().unwrap().push(event.clone());
+            if self.fail {
+                return Err(TradingError::Other("broker unavailable".to_string()));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_events_for_filled_order() {
+        let publisher = Arc::new(RecordingEventPublisher::default());
+        let gateway = ExecutionGateway::new(GatewayConfig::default()).with_event_publisher(publisher.clone());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let result = gateway.place_order(create_test_order_decision()).await.unwrap();
+
+        let events = publisher.events.lock().unwrap().clone();
+        let kinds: Vec<&str> = events.iter().map(ExecutionEvent::kind).collect();
+        assert_eq!(kinds, vec!["order_submitted", "filled"]);
+        assert!(events.iter().all(|event| event.order_id() == result.order_id));
+        match &events[1] {
+            ExecutionEvent::Filled { symbol, filled_quantity, average_price, .. } => {
+                assert_eq!(symbol, "BTCUSD");
+                assert!((filled_quantity - 0.1).abs() < 1e-9);
+                assert_eq!(*average_price, result.average_price);
+            }
+            other => panic!("expected a filled event, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execution_event_publish_failures_are_not_fatal() {
+        let publisher = Arc::new(RecordingEventPublisher { fail: true, ..Default::default() });
+        let gateway = ExecutionGateway::new(GatewayConfig::default()).with_event_publisher(publisher.clone());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_failure(true);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        assert!(gateway.place_order(create_test_order_decision()).await.is_err());
+        let kinds: Vec<&str> = publisher.events.lock().unwrap().iter().map(ExecutionEvent::kind).collect();
+        assert_eq!(kinds, vec!["order_submitted", "rejected"]);
+
+        gateway.register_exchange_adapter("default".to_string(), Box::new(MockExchangeAdapter::new().with_delay(0))).await;
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_explain_rejection_over_leverage() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        assert!(gateway.explain_rejection(&create_test_order_decision()).await.is_none());
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.leverage = 20.0;
+        order_decision.risk_percentage = 0.2;
+        
+        let feedback = gateway.explain_rejection(&order_decision).await.unwrap();
+        assert_eq!(feedback.reason, RejectionReason::ExcessiveLeverage);
+        assert_eq!(feedback.rule, "max_leverage");
+        assert_eq!(feedback.suggested_correction.as_deref(), Some("Reduce leverage to 10x or less"));
+        assert_eq!(gateway.explain_rejections(&order_decision).await.len(), 1);
+        
+        let result = gateway.place_order(order_decision).await;
+        assert!(matches!(result, Err(TradingError::RiskLimitError { .. })));
+        assert_eq!(gateway.get_active_orders_count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_decision_to_submit_latency_slo() {
+        let config = GatewayConfig {
+            decision_latency_budget_ms: 1000,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        // Decision produced 5 seconds ago blows the 1s budget
+        let mut order_decision = create_test_order_decision();
+        order_decision.timestamp = Utc::now() - chrono::Duration::seconds(5);
+        
+        let execution_result = gateway.place_order(order_decision).await.unwrap();
+        let latency_ms = execution_result.decision_to_submit_ms.unwrap();
+        assert!((5000..6000).contains(&latency_ms));
+        
+        let stats = gateway.get_latency_stats();
+        assert_eq!(stats.count, 1);
+        assert_eq!(stats.slo_violations_total, 1);
+        
+        // A fresh decision stays within budget
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        let stats = gateway.get_latency_stats();
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.slo_violations_total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_session_counters_track_orders() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        
+        let stats = gateway.get_session_stats();
+        assert_eq!(stats.session_id, gateway.current_session_id());
+        assert_eq!(stats.session_id, Utc::now().format("%Y-%m-%d").to_string());
+        assert_eq!(stats.orders_placed, 1);
+        assert!((stats.filled_notional - 5000.0).abs() < 1e-6);
+    }
+
+    #[tokio::test]
+    async fn test_order_updates_are_published() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut updates = gateway.subscribe_order_updates();
+        let execution_result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        
+        // One update for the partial fill, one for the final status
+        let fill_update = updates.recv().await.unwrap();
+        assert_eq!(fill_update.order_id, execution_result.order_id);
+        assert_eq!(fill_update.fill_count, 1);
+        
+        let status_update = updates.recv().await.unwrap();
+        assert!(matches!(status_update.status, OrderExecutionStatus::PartiallyFilled));
+        assert!((status_update.total_filled - 0.05).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_shadow_adapter_mirrors_orders() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let primary_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(primary_adapter)).await;
+        
+        // Shadow venue only partially fills; the client must still see the primary fill
+        let shadow_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        let shadow_orders = shadow_adapter.placed_orders();
+        gateway.register_shadow_adapter("shadow".to_string(), Box::new(shadow_adapter)).await;
+        
+        let execution_result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert_eq!(execution_result.status, rust_common::OrderStatus::Filled);
+        assert_eq!(execution_result.filled_quantity, 0.1);
+        
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        
+        let shadow_orders = shadow_orders.lock().unwrap().clone();
+        assert_eq!(shadow_orders.len(), 1);
+        assert_eq!(shadow_orders[0].symbol, "BTCUSD");
+        assert_ne!(shadow_orders[0].id.to_string(), execution_result.order_id);
+        
+        let comparisons = gateway.get_shadow_comparisons().await;
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].shadow_exchange, "shadow");
+        assert_eq!(comparisons[0].order_id, execution_result.order_id);
+        assert_eq!(comparisons[0].price_difference, Some(0.0));
+    }
+
+    #[tokio::test]
+    async fn test_shadow_adapter_failure_does_not_affect_result() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let primary_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(primary_adapter)).await;
+        
+        let shadow_adapter = MockExchangeAdapter::new().with_failure(true);
+        gateway.register_shadow_adapter("shadow".to_string(), Box::new(shadow_adapter)).await;
+        
+        let result = gateway.place_order(create_test_order_decision()).await;
+        assert!(result.is_ok());
+        
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        
+        let comparisons = gateway.get_shadow_comparisons().await;
+        assert_eq!(comparisons.len(), 1);
+        assert!(comparisons[0].shadow_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_per_operation_adapter_timeouts() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(100);
+        let timeouts = AdapterTimeouts {
+            get_exchange_info_ms: 20,
+            place_order_ms: 1000,
+            ..AdapterTimeouts::default()
+        };
+        gateway.register_exchange_adapter_with_timeouts("default".to_string(), Box::new(mock_adapter), timeouts).await;
+        
+        // Exchange info lookup exceeds its short timeout
+        let result = gateway.get_exchange_info("default", "BTCUSD").await;
+        assert!(result.unwrap_err().to_string().contains("get_exchange_info timeout"));
+        
+        // Order placement takes the same time but is within its longer timeout
+        let result = gateway.place_order(create_test_order_decision()).await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().retry_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_order_timeout_is_retried_and_trips_breaker() {
+        let config = GatewayConfig {
+            order_timeout_ms: 20,
+            max_retries: 2,
+            circuit_breaker_failure_threshold: 10,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(MockExchangeAdapter::new().with_delay(200))).await;
+
+        let result = gateway.place_order(create_test_order_decision()).await;
+        let error = result.unwrap_err();
+        assert!(matches!(error, TradingError::Timeout { ref operation, timeout_ms: 20 } if operation == "place_order"));
+        // Each timed-out attempt was retried and counted against the exchange breaker
+        assert_eq!(gateway.circuit_breakers.read().await.get("default").unwrap().get_failure_count(), 3);
+
+        let result = gateway.cancel_order(&Uuid::new_v4().to_string()).await;
+        assert!(matches!(result, Err(TradingError::Timeout { ref operation, .. }) if operation == "cancel_order"));
+        let result = gateway.get_order_status(&Uuid::new_v4().to_string()).await;
+        assert!(matches!(result, Err(TradingError::Timeout { ref operation, .. }) if operation == "get_order_status"));
+    }
+
+    #[tokio::test]
+    async fn test_post_only_order_rejected_when_crossing_and_not_retried() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_quote(49990.0, 50010.0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // A buy below the ask rests as maker
+        let mut order_decision = create_test_order_decision();
+        order_decision.post_only = true;
+        order_decision.entry_price = 49990.0;
+        assert!(gateway.place_order(order_decision).await.is_ok());
+        assert!(placed_orders.lock().unwrap()[0].post_only);
+
+        // A buy at the ask would take liquidity
+        let mut order_decision = create_test_order_decision();
+        order_decision.post_only = true;
+        order_decision.entry_price = 50010.0;
+        let error = gateway.place_order(order_decision).await.unwrap_err();
+        assert!(matches!(error, TradingError::OrderRejected { reason: RejectReason::WouldCrossBook, .. }));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
+        assert_eq!(placed_orders.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_atomic_basket_reverses_filled_leg_when_another_fails() {
+        use rust_common::OrderSide;
+
+        let config = GatewayConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_failing_symbol("ETHUSD");
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let btc_leg = create_test_order_decision();
+        let mut eth_leg = create_test_order_decision();
+        eth_leg.symbol = "ETHUSD".to_string();
+        eth_leg.direction = rust_common::Direction::Short;
+        eth_leg.entry_price = 3000.0;
+        eth_leg.stop_loss = 3100.0;
+
+        let error = gateway.place_basket(vec![btc_leg.clone(), eth_leg.clone()], true).await.unwrap_err();
+        assert!(error.to_string().contains("leg 2 (ETHUSD) failed"), "{}", error);
+
+        // The filled BTC leg was unwound with an offsetting reduce-only sell
+        {
+            let placed_orders = placed_orders.lock().unwrap();
+            let reversal = placed_orders.iter()
+                .find(|order| order.symbol == "BTCUSD" && matches!(order.side, OrderSide::Sell))
+                .expect("BTC leg was not reversed");
+            assert!(reversal.reduce_only);
+            assert!((reversal.size - btc_leg.risk_adjusted_quantity).abs() < 1e-9);
+        }
+
+        let basket_id = gateway.baskets.read().await.keys().next().unwrap().clone();
+        let basket = gateway.get_basket(&basket_id).await.unwrap();
+        assert_eq!(basket.state, BasketState::RolledBack);
+        assert!(basket.atomic);
+        assert!(matches!(basket.legs[0].status, Some(OrderExecutionStatus::Filled)));
+        assert!(basket.legs[0].reversal_order_id.is_some());
+        assert!(basket.legs[1].order_id.is_none());
+        assert!(basket.legs[1].error.is_some());
+
+        // Without atomicity the filled leg stays and the failure is reported per leg
+        let mut btc_leg = create_test_order_decision();
+        btc_leg.decision_id = Uuid::new_v4().to_string();
+        eth_leg.decision_id = Uuid::new_v4().to_string();
+        let result = gateway.place_basket(vec![btc_leg, eth_leg], false).await.unwrap();
+        assert_eq!(result.state, BasketState::PartiallyFailed);
+        assert!(result.results[0].is_some());
+        assert!(result.results[1].is_none());
+        let basket = gateway.get_basket(&result.basket_id).await.unwrap();
+        assert!(basket.legs[0].reversal_order_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_basket_rollback_reverses_fills_landing_after_placement() {
+        use rust_common::OrderSide;
+
+        let config = GatewayConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(20)
+            .with_fill_model(FillModel::Probabilistic { fill_prob: 0.0 })
+            .with_failing_symbol("ETHUSD");
+        let placed_orders = mock_adapter.placed_orders();
+        let resting_orders = mock_adapter.resting_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // The BTC leg rests unfilled, then fills on the exchange while the ETH leg is in flight
+        let filler = tokio::spawn(async move {
+            loop {
+                if let Some(status) = resting_orders.lock().unwrap().values_mut().next() {
+                    *status = rust_common::OrderStatus::Filled;
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        });
+
+        let btc_leg = create_test_order_decision();
+        let mut eth_leg = create_test_order_decision();
+        eth_leg.symbol = "ETHUSD".to_string();
+        eth_leg.entry_price = 3000.0;
+        eth_leg.stop_loss = 2900.0;
+
+        gateway.place_basket(vec![btc_leg.clone(), eth_leg], true).await.unwrap_err();
+        filler.await.unwrap();
+
+        let placed_orders = placed_orders.lock().unwrap();
+        let reversal = placed_orders.iter()
+            .find(|order| order.symbol == "BTCUSD" && matches!(order.side, OrderSide::Sell))
+            .expect("Fill landing after placement was not reversed");
+        assert!((reversal.size - btc_leg.risk_adjusted_quantity).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_orders_routed_to_named_exchange() {
+        let config = GatewayConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        // Binance fails every call, so anything wrongly routed there surfaces as an error
+        let binance_adapter = MockExchangeAdapter::new().with_delay(0).with_failure(true);
+        let binance_orders = binance_adapter.placed_orders();
+        gateway.register_exchange_adapter("binance".to_string(), Box::new(binance_adapter)).await;
+        
+        let coinbase_adapter = MockExchangeAdapter::new().with_delay(0);
+        let coinbase_orders = coinbase_adapter.placed_orders();
+        gateway.register_exchange_adapter("coinbase".to_string(), Box::new(coinbase_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.exchange = Some("coinbase".to_string());
+        let client_id = Uuid::parse_str(&order_decision.decision_id).unwrap();
+        
+        let execution_result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(coinbase_orders.lock().unwrap().len(), 1);
+        assert!(binance_orders.lock().unwrap().is_empty());
+        
+        {
+            let active_orders = gateway.active_orders.read().await;
+            assert_eq!(active_orders.get(&client_id).unwrap().exchange, "coinbase");
+        }
+        
+        // Follow-up calls find the exchange the order was placed on
+        assert!(gateway.get_order_status(&execution_result.order_id).await.is_ok());
+        assert!(gateway.cancel_order(&execution_result.order_id).await.is_ok());
+        
+        // Failures are attributed to the target exchange's circuit breaker
+        let mut order_decision = create_test_order_decision();
+        order_decision.exchange = Some("binance".to_string());
+        assert!(gateway.place_order(order_decision).await.is_err());
+        
+        let circuit_breakers = gateway.circuit_breakers.read().await;
+        assert_eq!(circuit_breakers.get("binance").unwrap().get_failure_count(), 1);
+        assert_eq!(circuit_breakers.get("coinbase").unwrap().get_failure_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_best_price_routing_picks_better_quote() {
+        let config = GatewayConfig {
+            routing_strategy: RoutingStrategy::BestPrice,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let default_adapter = MockExchangeAdapter::new().with_delay(0).with_quote(50010.0, 50020.0);
+        let default_orders = default_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(default_adapter)).await;
+        
+        let kraken_adapter = MockExchangeAdapter::new().with_delay(0).with_quote(49990.0, 50000.0);
+        let kraken_orders = kraken_adapter.placed_orders();
+        gateway.register_exchange_adapter("kraken".to_string(), Box::new(kraken_adapter)).await;
+        
+        // Buys go to the lowest ask
+        let order_decision = create_test_order_decision();
+        let client_id = Uuid::parse_str(&order_decision.decision_id).unwrap();
+        gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(kraken_orders.lock().unwrap().len(), 1);
+        assert!(default_orders.lock().unwrap().is_empty());
+        assert_eq!(gateway.active_orders.read().await.get(&client_id).unwrap().exchange, "kraken");
+        
+        // Sells go to the highest bid
+        let mut order_decision = create_test_order_decision();
+        order_decision.direction = Direction::Short;
+        order_decision.stop_loss = 51000.0;
+        order_decision.take_profit = Some(48000.0);
+        gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(default_orders.lock().unwrap().len(), 1);
+        assert_eq!(kraken_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_health_reports_open_breakers() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        gateway.register_exchange_adapter("default".to_string(), Box::new(MockExchangeAdapter::new().with_delay(0))).await;
+        gateway.register_exchange_adapter("kraken".to_string(), Box::new(MockExchangeAdapter::new().with_delay(0))).await;
+        
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        gateway.circuit_breakers.read().await.get("kraken").unwrap().force_open();
+        
+        let health = gateway.exchange_health().await;
+        assert_eq!(health.iter().map(|exchange| exchange.exchange.as_str()).collect::<Vec<_>>(), vec!["default", "kraken"]);
+        assert!(health[0].is_available());
+        assert!(health[0].last_success.is_some());
+        assert!(health[1].reachable);
+        assert!(health[1].breaker_open);
+        assert_eq!(health[1].breaker_state, CircuitBreakerState::Open);
+        assert!(!health[1].is_available());
+        assert!(health[1].last_success.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_routing_falls_back_when_best_venue_breaker_is_open() {
+        let config = GatewayConfig {
+            routing_strategy: RoutingStrategy::BestPrice,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let default_adapter = MockExchangeAdapter::new().with_delay(0).with_quote(50010.0, 50020.0);
+        let default_orders = default_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(default_adapter)).await;
+        
+        let kraken_adapter = MockExchangeAdapter::new().with_delay(0).with_quote(49990.0, 50000.0);
+        let kraken_orders = kraken_adapter.placed_orders();
+        gateway.register_exchange_adapter("kraken".to_string(), Box::new(kraken_adapter)).await;
+        gateway.circuit_breakers.read().await.get("kraken").unwrap().force_open();
+        
+        let result = gateway.place_order(create_test_order_decision()).await;
+        assert!(result.is_ok());
+        assert_eq!(default_orders.lock().unwrap().len(), 1);
+        assert!(kraken_orders.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lowest_fee_routing_picks_cheaper_venue() {
+        let config = GatewayConfig {
+            routing_strategy: RoutingStrategy::LowestFee,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let default_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_commission_model(CommissionModel::FlatBps(10.0));
+        gateway.register_exchange_adapter("default".to_string(), Box::new(default_adapter)).await;
+        
+        let kraken_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_commission_model(CommissionModel::MakerTaker { maker_bps: 2.0, taker_bps: 20.0 });
+        let kraken_orders = kraken_adapter.placed_orders();
+        gateway.register_exchange_adapter("kraken".to_string(), Box::new(kraken_adapter)).await;
+        
+        // Limit orders pay the maker rate, which is cheaper on kraken
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert_eq!(kraken_orders.lock().unwrap().len(), 1);
+        
+        // Market orders pay the taker rate, which is cheaper on the default venue
+        let mut order_decision = create_test_order_decision();
+        order_decision.order_type = RustOrderType::Market;
+        gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(kraken_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_order_rounded_and_validated_before_submission() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+        
+        // The REST adapter neither rounds nor checks exchange limits itself
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/exchange_info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&MockExchangeAdapter::new().exchange_info))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .and(body_partial_json(serde_json::json!({"quantity": 0.1, "price": 50000.12})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "order_id": "ex-1",
+                "status": "filled",
+                "filled_quantity": 0.1,
+                "average_price": 50000.12,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        
+        let config = GatewayConfig {
+            max_price_deviation_pct: None,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let rest_adapter = RestExchangeAdapter::new(&server.uri(), "test-key", "test-secret", reqwest::Client::new());
+        gateway.register_exchange_adapter("default".to_string(), Box::new(rest_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.risk_adjusted_quantity = 0.10049;
+        order_decision.entry_price = 50000.123;
+        let result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(result.status, rust_common::OrderStatus::Filled);
+        
+        // An order below the minimum size never reaches the exchange and isn't retried
+        let mut order_decision = create_test_order_decision();
+        order_decision.risk_adjusted_quantity = 0.0004;
+        let err = gateway.place_order(order_decision).await.unwrap_err();
+        assert!(err.to_string().contains("Invalid order: Order size 0.0004 rounds to zero"));
+        let circuit_breakers = gateway.circuit_breakers.read().await;
+        assert_eq!(circuit_breakers.get("default").unwrap().get_failure_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_retry_waits_for_exchange_retry_after() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+        
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/exchange_info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&MockExchangeAdapter::new().exchange_info))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "order_id": "ex-1",
+                "status": "filled",
+                "filled_quantity": 0.1,
+                "average_price": 50000.0,
+            })))
+            .mount(&server)
+            .await;
+        
+        // The computed backoff is at most 10ms, far below the exchange's one second, while
+        // the rate-limit ceiling leaves room to honour it
+        let config = GatewayConfig {
+            max_retries: 1,
+            base_retry_delay_ms: 10,
+            max_retry_delay_ms: 10,
+            retry_class_schedules: HashMap::from([(
+                RetryErrorClass::RateLimited,
+                BackoffSchedule { base_delay_ms: 10, max_delay_ms: 2000 },
+            )]),
+            max_price_deviation_pct: None,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        let rest_adapter = RestExchangeAdapter::new(&server.uri(), "test-key", "test-secret", reqwest::Client::new());
+        gateway.register_exchange_adapter("default".to_string(), Box::new(rest_adapter)).await;
+        
+        let start = std::time::Instant::now();
+        let result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        let elapsed = start.elapsed();
+        
+        assert_eq!(result.status, rust_common::OrderStatus::Filled);
+        assert_eq!(result.retry_count, 1);
+        assert!(elapsed >= Duration::from_millis(1000), "retry ignored Retry-After: {:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_order_to_unregistered_exchange_fails() {
+        let config = GatewayConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut order_decision = create_test_order_decision();
+        order_decision.exchange = Some("kraken".to_string());
+        
+        let result = gateway.place_order(order_decision).await;
+        assert!(result.unwrap_err().to_string().contains("Exchange adapter not found: kraken"));
+    }
+
+    #[tokio::test]
+    async fn test_order_cancellation() {
+        let config = GatewayConfig::default();
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let result = gateway.cancel_order("test_order_id").await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_close_position_by_symbol() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_positions(vec![Position {
+            symbol: "BTCUSD".to_string(),
+            side: "long".to_string(),
+            size: 0.25,
+            entry_price: 50000.0,
+            current_price: 51000.0,
+            unrealized_pnl: 250.0,
+            margin_used: 12500.0,
+            funding_paid: 0.0,
+        }]);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let result = gateway.close_position("BTCUSD", "default").await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().filled_quantity, 0.25);
+        
+        let placed_orders = placed_orders.lock().unwrap();
+        assert_eq!(placed_orders.len(), 1);
+        assert!(matches!(placed_orders[0].side, OrderSide::Sell));
+        assert_eq!(placed_orders[0].size, 0.25);
+        assert!(placed_orders[0].reduce_only);
+        assert!(placed_orders[0].price.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_close_position_is_tracked_like_other_orders() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_positions(vec![Position {
+            symbol: "BTCUSD".to_string(),
+            side: "short".to_string(),
+            size: -0.25,
+            entry_price: 50000.0,
+            current_price: 49000.0,
+            unrealized_pnl: 250.0,
+            margin_used: 12500.0,
+            funding_paid: 0.0,
+        }]);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // Flattening stays possible while new risk is halted
+        gateway.set_trading_halted(true);
+        let result = gateway.close_position("btc-usd", "default").await.unwrap();
+
+        let detail = gateway.get_order_detail(&result.order_id).await.unwrap();
+        assert_eq!(detail.lifecycle.symbol, "BTCUSD");
+        assert!(matches!(detail.execution.unwrap().status, OrderExecutionStatus::Filled));
+        assert!(gateway.exposure_reservations.read().await.is_empty());
+
+        let placed_orders = placed_orders.lock().unwrap();
+        assert_eq!(placed_orders.len(), 1);
+        assert!(matches!(placed_orders[0].side, OrderSide::Buy));
+        assert_eq!(placed_orders[0].symbol, "BTCUSD");
+        assert!(placed_orders[0].reduce_only);
+    }
+
+    #[tokio::test]
+    async fn test_close_position_without_position() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let result = gateway.close_position("ETHUSD", "default").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_order_status_query() {
+        let config = GatewayConfig::default();
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let result = gateway.get_order_status("test_order_id").await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), OrderExecutionStatus::Filled);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_completed_orders() {
+        let config = GatewayConfig::default();
+        let gateway = ExecutionGateway::new(config);
+        
+        let mock_adapter = MockExchangeAdapter::new();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        // Place an order
+        let order_decision = create_test_order_decision();
+        let result = gateway.place_order(order_decision).await.unwrap();
+        
+        assert_eq!(gateway.get_active_orders_count().await, 1);
+        
+        // Cleanup should not remove recent orders
+        assert_eq!(gateway.cleanup_completed_orders(24).await, 0);
+        assert_eq!(gateway.get_active_orders_count().await, 1);
+        assert!(gateway.order_manager.get_order(&result.order_id).await.is_some());
+        
+        // Cleanup with 0 hours should remove the filled order and its lifecycle
+        assert_eq!(gateway.cleanup_completed_orders(0).await, 1);
+        assert_eq!(gateway.get_active_orders_count().await, 0);
+        assert!(gateway.order_manager.get_order(&result.order_id).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_order_placement() {
+        let config = GatewayConfig::default();
+        let gateway = std::sync::Arc::new(ExecutionGateway::new(config));
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(50);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut handles = Vec::new();
+        
+        // Place multiple orders concurrently
+        for i in 0..5 {
+            let gateway_clone = gateway.clone();
+            let handle = tokio::spawn(async move {
+                let mut order_decision = create_test_order_decision();
+                order_decision.decision_id = format!("test_order_{}", i);
+                gateway_clone.place_order(order_decision).await
+            });
+            handles.push(handle);
+        }
+        
+        // Wait for all orders to complete
+        let mut success_count = 0;
+        for handle in handles {
+            if handle.await.unwrap().is_ok() {
+                success_count += 1;
+            }
+        }
+        
+        assert_eq!(success_count, 5);
+        assert_eq!(gateway.get_active_orders_count().await, 5);
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_caps_retries_across_failing_orders() {
+        let config = GatewayConfig {
+            max_retries: 5,
+            base_retry_delay_ms: 1,
+            max_retry_delay_ms: 2,
+            circuit_breaker_failure_threshold: u32::MAX,
+            max_concurrent_orders: 100,
+            retry_budget_per_sec: Some(1.0),
+            retry_budget_burst: 10,
+            ..Default::default()
+        };
+        let gateway = std::sync::Arc::new(ExecutionGateway::new(config));
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_failing_symbol("BTCUSD");
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let start = std::time::Instant::now();
+        let handles: Vec<_> = (0..50)
+            .map(|_| {
+                let gateway = gateway.clone();
+                // Fifty orders working at once have to fit the portfolio risk budget together
+                let mut order_decision = create_test_order_decision();
+                order_decision.portfolio_value = 1_000_000.0;
+                tokio::spawn(async move { gateway.place_order(order_decision).await })
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.await.unwrap().is_err());
+        }
+        let elapsed = start.elapsed();
+        
+        // Without the budget this would be 50 * 5 = 250 retries
+        let retries = placed_orders.lock().unwrap().len() - 50;
+        let allowed = 10 + elapsed.as_secs_f64().ceil() as usize;
+        assert!(retries >= 10, "burst was not used: {} retries", retries);
+        assert!(retries <= allowed, "{} retries exceeded the budget of {}", retries, allowed);
+    }
+
+    #[tokio::test]
+    async fn test_each_order_type_converts_end_to_end() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let submitted = [
+            RustOrderType::Market,
+            RustOrderType::Limit,
+            RustOrderType::Stop,
+            RustOrderType::StopLimit,
+            RustOrderType::StopLoss,
+            RustOrderType::TakeProfit,
+        ];
+        for order_type in submitted {
+            let mut decision = create_test_order_decision();
+            decision.order_type = order_type;
+            gateway.place_order(decision).await.unwrap();
+            
+            let placed = placed_orders.lock().unwrap().last().cloned().unwrap();
+            assert_eq!(placed.order_type, order_type);
+            let round_trip: OrderRequest = serde_json::from_str(&serde_json::to_string(&placed).unwrap()).unwrap();
+            assert_eq!(round_trip.order_type, order_type);
+        }
+        assert_eq!(placed_orders.lock().unwrap().len(), submitted.len());
+        
+        // Trailing stops are held by the gateway and only ever sent as a stop loss
+        let decision = create_trailing_stop_decision(1.0);
+        let order_request = gateway.convert_decision_to_request(&decision, &Uuid::new_v4().to_string()).unwrap();
+        assert_eq!(order_request.order_type, RustOrderType::StopLoss);
+    }
+
+    fn create_trailing_stop_decision(trail_pct: f64) -> OrderDecision {
+        let mut decision = create_test_order_decision();
+        decision.order_type = RustOrderType::TrailingStop;
+        decision.trail_pct = Some(trail_pct);
+        decision
+    }
+
+    #[tokio::test]
+    async fn test_trailing_stop_follows_price_up_and_triggers_exit() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_price_path(vec![50000.0, 51000.0, 50500.0, 52000.0, 50900.0]);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let execution_result = gateway.place_order(create_trailing_stop_decision(2.0)).await.unwrap();
+        assert_eq!(execution_result.status, rust_common::OrderStatus::Pending);
+        let order_id = execution_result.order_id;
+        assert!(placed_orders.lock().unwrap().is_empty());
+        
+        let stop = gateway.get_trailing_stop(&order_id).await.unwrap();
+        assert!((stop.stop_price - 49000.0).abs() < 1e-6);
+        
+        // Each poll serves the next scripted price; the stop only ever moves up
+        let mut stop_prices = Vec::new();
+        for _ in 0..3 {
+            gateway.poll_trailing_stops().await;
+            stop_prices.push(gateway.get_trailing_stop(&order_id).await.unwrap().stop_price);
+        }
+        assert!((stop_prices[0] - 49980.0).abs() < 1e-6);
+        assert!((stop_prices[1] - 49980.0).abs() < 1e-6); // 50500 pullback leaves it in place
+        assert!((stop_prices[2] - 50960.0).abs() < 1e-6);
+        
+        // 50900 falls through the 50960 stop
+        gateway.poll_trailing_stops().await;
+        assert!(gateway.get_trailing_stop(&order_id).await.is_none());
+        
+        {
+            let placed_orders = placed_orders.lock().unwrap();
+            assert_eq!(placed_orders.len(), 1);
+            assert!(matches!(placed_orders[0].side, OrderSide::Sell));
+            assert!(placed_orders[0].reduce_only);
+            assert!(placed_orders[0].price.is_none());
+        }
+        
+        let final_result = gateway.get_order_result(&order_id).await.unwrap();
+        assert_eq!(final_result.status, rust_common::OrderStatus::Filled);
+        assert_eq!(final_result.average_price, Some(50900.0));
+        let lifecycle = gateway.order_manager.get_order(&order_id).await.unwrap();
+        assert_eq!(lifecycle.state, OrderLifecycleState::Filled);
+    }
+
+    #[tokio::test]
+    async fn test_trailing_stop_only_follows_its_own_exchange() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_mark_price(50000.0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        gateway.register_exchange_adapter("other".to_string(), Box::new(MockExchangeAdapter::new().with_delay(0))).await;
+
+        let order_id = gateway.place_order(create_trailing_stop_decision(2.0)).await.unwrap().order_id;
+
+        // A crash on another venue neither moves nor fires the stop
+        gateway.update_exchange_mark_price("other", "BTCUSD", 40000.0).await;
+        let stop = gateway.get_trailing_stop(&order_id).await.unwrap();
+        assert!((stop.stop_price - 49000.0).abs() < 1e-6);
+        assert!(placed_orders.lock().unwrap().is_empty());
+
+        gateway.update_exchange_mark_price("default", "BTCUSD", 48000.0).await;
+        assert!(gateway.get_trailing_stop(&order_id).await.is_none());
+        assert_eq!(placed_orders.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trailing_stop_rearmed_when_exit_fails() {
+        let gateway = ExecutionGateway::new(GatewayConfig {
+            max_retries: 1,
+            base_retry_delay_ms: 1,
+            max_retry_delay_ms: 1,
+            ..Default::default()
+        });
+
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_mark_price(50000.0)
+            .with_failing_symbol("BTCUSD");
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let order_id = gateway.place_order(create_trailing_stop_decision(2.0)).await.unwrap().order_id;
+        gateway.update_mark_price("BTCUSD", 48000.0).await;
+
+        // The exit was retried, then the stop went back on watch with the position still open
+        assert_eq!(placed_orders.lock().unwrap().len(), 2);
+        assert!(gateway.get_trailing_stop(&order_id).await.is_some());
+        let lifecycle = gateway.order_manager.get_order(&order_id).await.unwrap();
+        assert_ne!(lifecycle.state, OrderLifecycleState::Failed);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_armed_trailing_stop() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_mark_price(50000.0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let order_id = gateway.place_order(create_trailing_stop_decision(1.0)).await.unwrap().order_id;
+        gateway.cancel_order(&order_id).await.unwrap();
+        
+        assert!(gateway.get_trailing_stop(&order_id).await.is_none());
+        let final_result = gateway.get_order_result(&order_id).await.unwrap();
+        assert_eq!(final_result.status, rust_common::OrderStatus::Cancelled);
+        
+        // A later collapse in price must not fire the cancelled stop
+        gateway.update_mark_price("BTCUSD", 40000.0).await;
+        assert!(placed_orders.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_orders_rejected_outside_trading_hours() {
+        use chrono::TimeZone;
+        
+        // Friday 18:00 through Saturday 02:00 New York, checked at 01:00 UTC Saturday
+        // (21:00 Friday in New York) and 07:00 UTC Saturday (03:00 Saturday, after the close)
+        let overnight_window = TradingHours {
+            day_of_week: 5,
+            open_time: "18:00:00".to_string(),
+            close_time: "02:00:00".to_string(),
+            timezone: "America/New_York".to_string(),
+        };
+        let gateway_at = |hour: u32| {
+            let now = Utc.with_ymd_and_hms(2024, 7, 20, hour, 0, 0).unwrap();
+            let clock = SessionClock::utc_midnight().with_clock(Arc::new(move || now));
+            ExecutionGateway::with_session_clock(GatewayConfig::default(), clock)
+        };
+        
+        let open_gateway = gateway_at(1);
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_trading_hours(vec![overnight_window.clone()]);
+        open_gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        assert!(open_gateway.place_order(create_test_order_decision()).await.is_ok());
+        
+        let closed_gateway = gateway_at(7);
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_trading_hours(vec![overnight_window]);
+        let placed_orders = mock_adapter.placed_orders();
+        closed_gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let error = closed_gateway.place_order(create_test_order_decision()).await.unwrap_err();
+        assert!(error.to_string().contains("Market closed for BTCUSD"));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
+        assert!(placed_orders.lock().unwrap().is_empty());
+        assert_eq!(closed_gateway.get_active_orders_count().await, 0);
+    }
+
+    #[test]
+    fn test_invalid_session_config_refused() {
+        let config = GatewayConfig { session_timezone: "Mars/Olympus_Mons".to_string(), ..Default::default() };
+        assert!(ExecutionGateway::try_new(config).is_err());
+        
+        let config = GatewayConfig { session_boundary_time: "25:00:00".to_string(), ..Default::default() };
+        assert!(ExecutionGateway::try_new(config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_daily_loss_rolls_over_at_session_boundary() {
+        use chrono::TimeZone;
+        
+        // 16:00 New York, an hour before the 17:00 session boundary
+        let now = Arc::new(std::sync::Mutex::new(Utc.with_ymd_and_hms(2024, 7, 19, 20, 0, 0).unwrap()));
+        let clock_now = now.clone();
+        let clock = SessionClock::new("17:00:00", "America/New_York")
+            .unwrap()
+            .with_clock(Arc::new(move || *clock_now.lock().unwrap()));
+        let config = GatewayConfig { max_daily_loss: Some(100.0), ..Default::default() };
+        let gateway = ExecutionGateway::with_session_clock(config, clock);
+        
+        gateway.loss_limit_guard.record_realized_pnl(-150.0);
+        assert!(gateway.get_daily_loss_stats().breached);
+        
+        // Same UTC day, but a new trading session
+        *now.lock().unwrap() = Utc.with_ymd_and_hms(2024, 7, 19, 21, 30, 0).unwrap();
+        let stats = gateway.get_daily_loss_stats();
+        assert!(!stats.breached);
+        assert_eq!(stats.day, gateway.session_clock.current_session_id());
+    }
+
+    #[tokio::test]
+    async fn test_replayed_decision_deduplicated_after_restart() {
+        let order_store: Arc<dyn OrderStore> = Arc::new(InMemoryOrderStore::new());
+        let order_decision = create_test_order_decision();
+        
+        let original_result = {
+            let gateway = ExecutionGateway::new(GatewayConfig::default()).with_order_store(order_store.clone());
+            let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+            gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+            gateway.place_order(order_decision.clone()).await.unwrap()
+        };
+        
+        // A fresh gateway over the same store picks up where the first left off
+        let gateway = ExecutionGateway::new(GatewayConfig::default()).with_order_store(order_store);
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        assert_eq!(gateway.restore_from_store().await.unwrap(), 1);
+        assert_eq!(gateway.get_active_orders_count().await, 1);
+        let restored = gateway.get_order_update(&original_result.order_id).await.unwrap();
+        assert!(matches!(restored.status, OrderExecutionStatus::Filled));
+        
+        let replayed_result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(replayed_result.order_id, original_result.order_id);
+        assert_eq!(replayed_result.filled_quantity, original_result.filled_quantity);
+        assert!(placed_orders.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_working_order_reservation_and_lifecycle_restored() {
+        let order_store: Arc<dyn OrderStore> = Arc::new(InMemoryOrderStore::new());
+        let order_decision = create_test_order_decision();
+        let client_id = Uuid::parse_str(&order_decision.decision_id).unwrap();
+        
+        let original_result = {
+            let gateway = ExecutionGateway::new(GatewayConfig::default()).with_order_store(order_store.clone());
+            let mock_adapter = MockExchangeAdapter::new()
+                .with_delay(0)
+                .with_fill_model(FillModel::Probabilistic { fill_prob: 0.0 });
+            gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+            gateway.place_order(order_decision.clone()).await.unwrap()
+        };
+        
+        let gateway = ExecutionGateway::new(GatewayConfig::default()).with_order_store(order_store);
+        assert_eq!(gateway.restore_from_store().await.unwrap(), 1);
+        
+        // The resting order still counts against the limits and can move through its lifecycle
+        let reservation = gateway.exposure_reservations.read().await.get(&client_id).cloned().unwrap();
+        assert!((reservation.signed_quantity - order_decision.risk_adjusted_quantity).abs() < 1e-9);
+        assert!((reservation.risk_amount - order_decision.risk_amount).abs() < 1e-9);
+        let lifecycle = gateway.order_manager.get_order(&original_result.order_id).await.unwrap();
+        assert_eq!(lifecycle.state, OrderLifecycleState::Acknowledged);
+    }
+
+    /// Order store whose idempotency writes always fail
+    #[derive(Default)]
+    struct DedupFailingOrderStore {
+        inner: InMemoryOrderStore,
+    }
+
+    #[async_trait::async_trait]
+    impl OrderStore for DedupFailingOrderStore {
+        async fn save_order(&self, order: &OrderExecution) -> Result<(), TradingError> {
+            self.inner.save_order(order).await
+        }
+
+        async fn load_order(&self, client_id: &Uuid) -> Result<Option<OrderExecution>, TradingError> {
+            self.inner.load_order(client_id).await
+        }
+
+        async fn load_active(&self) -> Result<Vec<OrderExecution>, TradingError> {
+            self.inner.load_active().await
+        }
+
+        async fn save_dedup_mapping(&self, _client_id: Uuid, _order_id: &str) -> Result<(), TradingError> {
+            Err(TradingError::Other("disk full".to_string()))
+        }
+
+        async fn load_dedup_mappings(&self) -> Result<HashMap<Uuid, String>, TradingError> {
+            self.inner.load_dedup_mappings().await
+        }
+
+        async fn save_result(&self, result: &ExecutionResult) -> Result<(), TradingError> {
+            self.inner.save_result(result).await
+        }
+
+        async fn load_result(&self, order_id: &str) -> Result<Option<ExecutionResult>, TradingError> {
+            self.inner.load_result(order_id).await
+        }
+
+        async fn remove_order(&self, client_id: &Uuid) -> Result<(), TradingError> {
+            self.inner.remove_order(client_id).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_placement_refused_when_order_store_fails() {
+        let order_store = Arc::new(DedupFailingOrderStore::default());
+        let gateway = ExecutionGateway::new(GatewayConfig::default()).with_order_store(order_store.clone());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        assert!(gateway.place_order(create_test_order_decision()).await.is_err());
+        assert!(placed_orders.lock().unwrap().is_empty());
+        assert_eq!(gateway.get_active_orders_count().await, 0);
+        assert!(gateway.exposure_reservations.read().await.is_empty());
+        assert!(order_store.load_active().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_order_lifecycles_replayed_from_event_log_after_restart() {
+        let path = std::env::temp_dir().join(format!("gateway_events_{}.jsonl", Uuid::new_v4()));
+        
+        let result = {
+            let gateway = ExecutionGateway::new(GatewayConfig::default())
+                .with_event_log(Arc::new(EventLog::open(&path).await.unwrap()))
+                .await
+                .unwrap();
+            let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+            gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+            gateway.place_order(create_test_order_decision()).await.unwrap()
+        };
+        
+        let gateway = ExecutionGateway::new(GatewayConfig::default())
+            .with_event_log(Arc::new(EventLog::open(&path).await.unwrap()))
+            .await
+            .unwrap();
+        let detail = gateway.get_order_detail(&result.order_id).await.unwrap();
+        assert_eq!(detail.lifecycle.state, OrderLifecycleState::Filled);
+        assert_eq!(gateway.get_order_statistics().await.total_orders, 1);
+        
+        tokio::fs::remove_file(&path).await.unwrap();
     }
 
-    /// Clean up completed orders (should be called periodically)
-    pub async fn cleanup_completed_orders(&self, max_age_hours: i64) {
-        let cutoff_time = Utc::now() - Duration::hours(max_age_hours);
+    #[tokio::test]
+    async fn test_oco_fill_cancels_sibling_leg() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
         
-        let mut active_orders = self.active_orders.write().await;
-        let mut dedup_map = self.order_deduplication.write().await;
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let resting_orders = mock_adapter.resting_orders();
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let mut to_remove = Vec::new();
+        let oco_result = gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+        let take_profit_id = oco_result.take_profit.order_id;
+        let stop_loss_id = oco_result.stop_loss.order_id;
+        assert_eq!(oco_result.take_profit.status, rust_common::OrderStatus::Pending);
+        assert_eq!(oco_result.stop_loss.status, rust_common::OrderStatus::Pending);
         
-        for (client_id, order_execution) in active_orders.iter() {
-            if order_execution.updated_at < cutoff_time {
-                match order_execution.status {
-                    OrderExecutionStatus::Filled | 
-                    OrderExecutionStatus::Cancelled | 
-                    OrderExecutionStatus::Rejected | 
-                    OrderExecutionStatus::Failed => {
-                        to_remove.push(*client_id);
-                    }
-                    _ => {} // Keep pending and partially filled orders
-                }
-            }
+        {
+            let placed_orders = placed_orders.lock().unwrap();
+            assert_eq!(placed_orders.len(), 2);
+            assert!(placed_orders.iter().all(|order| matches!(order.side, OrderSide::Sell) && order.reduce_only));
+            assert_eq!(placed_orders[0].price, Some(52000.0));
+            assert_eq!(placed_orders[1].price, Some(49000.0));
         }
         
-        for client_id in to_remove {
-            active_orders.remove(&client_id);
-            dedup_map.remove(&client_id);
-        }
+        // Price reaches the take profit
+        resting_orders.lock().unwrap().insert(take_profit_id.clone(), rust_common::OrderStatus::Filled);
+        gateway.poll_linked_orders().await;
+        
+        let take_profit = gateway.get_order_update(&take_profit_id).await.unwrap();
+        assert!(matches!(take_profit.status, OrderExecutionStatus::Filled));
+        let stop_loss = gateway.get_order_update(&stop_loss_id).await.unwrap();
+        assert!(matches!(stop_loss.status, OrderExecutionStatus::Cancelled));
+        assert_eq!(resting_orders.lock().unwrap()[&stop_loss_id], rust_common::OrderStatus::Cancelled);
+        
+        let lifecycle = gateway.order_manager.get_order(&stop_loss_id).await.unwrap();
+        assert_eq!(lifecycle.state, OrderLifecycleState::Cancelled);
+        let stop_loss_result = gateway.get_order_result(&stop_loss_id).await.unwrap();
+        assert_eq!(stop_loss_result.status, rust_common::OrderStatus::Cancelled);
     }
-}
 
-impl Default for ExecutionGateway {
-    fn default() -> Self {
-        Self::new(GatewayConfig::default())
+    #[tokio::test]
+    async fn test_polled_limit_fill_updates_position() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_fill_model(FillModel::Probabilistic { fill_prob: 0.0 });
+        let resting_orders = mock_adapter.resting_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        let result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert!(gateway.get_position("BTCUSD").await.is_none());
+
+        // The limit fills on the exchange and is picked up by polling
+        resting_orders.lock().unwrap().insert(result.order_id.clone(), rust_common::OrderStatus::Filled);
+        gateway.get_order_status(&result.order_id).await.unwrap();
+        let position = gateway.get_position("BTCUSD").await.unwrap();
+        assert!((position.net_size - 0.1).abs() < 1e-9);
+        assert!((position.average_entry_price - 50000.0).abs() < 1e-9);
+
+        // Seeing the same fill again does not add to the position
+        gateway.get_order_status(&result.order_id).await.unwrap();
+        assert!((gateway.get_position("BTCUSD").await.unwrap().net_size - 0.1).abs() < 1e-9);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rust_common::{OrderSide, OrderType, Direction, OrderType as RustOrderType};
-    use uuid::Uuid;
-    use chrono::Utc;
-    use tokio_test;
-    use std::time::Duration;
+    #[tokio::test]
+    async fn test_symbol_spellings_share_one_position() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
 
-    fn create_test_order_decision() -> OrderDecision {
-        let mut decision = OrderDecision::new("test_signal".to_string(), "BTCUSD".to_string());
-        decision.direction = Direction::Long;
-        decision.order_type = RustOrderType::Limit;
-        decision.risk_adjusted_quantity = 0.1;
-        decision.entry_price = 50000.0;
-        decision.stop_loss = 49000.0;
-        decision.take_profit = Some(52000.0);
-        decision.risk_amount = 100.0;
-        decision.risk_percentage = 1.0;
-        decision.leverage = 1.0;
-        decision.portfolio_value = 10000.0;
-        decision.available_margin = 5000.0;
-        decision.current_exposure = 0.1;
-        decision.confidence_score = 0.8;
-        decision.confluence_score = 75.0;
-        decision.risk_reward_ratio = 2.0;
-        decision
+        // gRPC builds decisions field by field, so the symbol arrives as the caller spelled it
+        let mut decision = create_test_order_decision();
+        decision.symbol = "btc-usd".to_string();
+        gateway.place_order(decision).await.unwrap();
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+
+        assert!((gateway.get_position("BTCUSD").await.unwrap().net_size - 0.2).abs() < 1e-9);
+        
+        // Filters and price feeds may spell the symbol either way too
+        let (orders, total) = gateway.list_orders(None, Some("btc-usd"), 10, 0).await;
+        assert_eq!((orders.len(), total), (2, 2));
+        gateway.update_mark_price("btc/usd", 51000.0).await;
+        assert_eq!(gateway.get_position("BTCUSD").await.unwrap().mark_price, Some(51000.0));
     }
 
     #[tokio::test]
-    async fn test_gateway_creation() {
-        let config = GatewayConfig::default();
-        let gateway = ExecutionGateway::new(config);
+    async fn test_oco_requires_take_profit() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let mut decision = create_test_order_decision();
+        decision.take_profit = None;
+        let error = gateway.place_oco_order(decision).await.unwrap_err();
+        assert!(matches!(error, TradingError::ValidationError { ref field, .. } if field == "take_profit"));
         assert_eq!(gateway.get_active_orders_count().await, 0);
     }
 
     #[tokio::test]
-    async fn test_register_exchange_adapter() {
-        let config = GatewayConfig::default();
-        let gateway = ExecutionGateway::new(config);
+    async fn test_oco_rejected_early_by_exchange_without_oco() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_capabilities(AdapterCapabilities { supports_oco: false, ..AdapterCapabilities::default() });
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let mock_adapter = MockExchangeAdapter::new();
-        gateway.register_exchange_adapter("test_exchange".to_string(), Box::new(mock_adapter)).await;
+        let order_decision = create_test_order_decision();
+        let error = gateway.place_oco_order(order_decision.clone()).await.unwrap_err();
+        assert!(matches!(error, TradingError::OrderRejected { reason: RejectReason::Unsupported, .. }));
+        assert!(error.to_string().contains("does not support OCO orders"));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
+        assert!(placed_orders.lock().unwrap().is_empty());
+        assert_eq!(gateway.get_active_orders_count().await, 0);
         
-        // Verify adapter is registered by checking circuit breaker exists
-        let circuit_breakers = gateway.circuit_breakers.read().await;
-        assert!(circuit_breakers.contains_key("test_exchange"));
+        // Plain orders still go through
+        assert!(gateway.place_order(order_decision).await.is_ok());
     }
 
     #[tokio::test]
-    async fn test_place_order_idempotency() {
-        let config = GatewayConfig::default();
-        let gateway = ExecutionGateway::new(config);
-        
-        let mock_adapter = MockExchangeAdapter::new();
+    async fn test_oco_replay_returns_both_legs() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
-        
+
         let order_decision = create_test_order_decision();
+        let original = gateway.place_oco_order(order_decision.clone()).await.unwrap();
+        let replayed = gateway.place_oco_order(order_decision.clone()).await.unwrap();
+        assert_eq!(replayed.take_profit, original.take_profit);
+        assert_eq!(replayed.stop_loss, original.stop_loss);
+
+        // A plain placement of the same decision replays the take profit leg
+        let replayed_order = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(replayed_order, original.take_profit);
+        assert_eq!(placed_orders.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_oco_keeps_take_profit_filled_before_stop_loss_failed() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(50);
+        let resting_orders = mock_adapter.resting_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // The take profit triggers while the stop loss, priced at zero, is on its way to being refused
+        let trigger = tokio::spawn(async move {
+            loop {
+                if let Some(status) = resting_orders.lock().unwrap().values_mut().next() {
+                    *status = rust_common::OrderStatus::Filled;
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        });
+        let mut order_decision = create_test_order_decision();
+        order_decision.stop_loss = 0.0;
+        assert!(gateway.place_oco_order(order_decision).await.is_err());
+        trigger.await.unwrap();
+
+        let take_profit = gateway.active_orders.read().await.values()
+            .find(|order_execution| order_execution.reference_price == Some(52000.0))
+            .cloned()
+            .unwrap();
+        assert!(matches!(take_profit.status, OrderExecutionStatus::Filled));
+        let exec_result = gateway.get_order_result(&take_profit.order_id).await.unwrap();
+        assert_eq!(exec_result.status, rust_common::OrderStatus::Filled);
+        assert!((exec_result.filled_quantity - 0.1).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_amend_order_checks_exchange_increments() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        // Place the same order twice
-        let result1 = gateway.place_order(order_decision.clone()).await;
-        let result2 = gateway.place_order(order_decision).await;
+        let oco_result = gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+        let take_profit_id = oco_result.take_profit.order_id;
         
-        assert!(result1.is_ok());
-        assert!(result2.is_ok());
+        let order_update = gateway.amend_order(&take_profit_id, Some(52500.5), Some(0.05)).await.unwrap();
+        assert!(matches!(order_update.status, OrderExecutionStatus::Pending));
+        let amended = gateway.active_orders.read().await.values()
+            .find(|order_execution| order_execution.order_id == take_profit_id)
+            .map(|order_execution| order_execution.requested_quantity);
+        assert_eq!(amended, Some(0.05));
+        let lifecycle = gateway.order_manager.get_order(&take_profit_id).await.unwrap();
+        assert_eq!(lifecycle.metadata["amended_price"], serde_json::json!(52500.5));
+        assert_eq!(lifecycle.metadata["amended_quantity"], serde_json::json!(0.05));
         
-        // Should have only one active order due to idempotency
-        assert_eq!(gateway.get_active_orders_count().await, 1);
+        for (price, quantity, expected_field) in [
+            (Some(52500.005), None, "new_price"),
+            (None, Some(0.0005), "new_quantity"),
+            (None, Some(0.0505), "new_quantity"),
+            (None, None, "amendment"),
+        ] {
+            match gateway.amend_order(&take_profit_id, price, quantity).await {
+                Err(TradingError::ValidationError { field, .. }) => assert_eq!(field, expected_field),
+                other => panic!("expected a validation error, got {:?}", other),
+            }
+        }
+        
+        let err = gateway.amend_order("unknown", Some(1.0), None).await.unwrap_err();
+        assert!(err.to_string().contains("not found"));
     }
 
     #[tokio::test]
-    async fn test_place_order_with_retry() {
-        let config = GatewayConfig {
-            max_retries: 2,
-            base_retry_delay_ms: 10,
-            max_retry_delay_ms: 100,
-            ..Default::default()
-        };
-        let gateway = ExecutionGateway::new(config);
-        
-        // First adapter fails, then succeeds
-        let mut mock_adapter = MockExchangeAdapter::new().with_failure(true);
+    async fn test_expire_orders_cancels_orders_past_max_execution_time() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(200);
+        let resting_orders = mock_adapter.resting_orders();
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let order_decision = create_test_order_decision();
-        let result = gateway.place_order(order_decision).await;
+        let mut order_decision = create_test_order_decision();
+        order_decision.max_execution_time = 1;
+        let legs = gateway.place_oco_order(order_decision).await.unwrap();
+        assert_eq!(gateway.expire_orders().await, 0);
         
-        // Should fail after retries
-        assert!(result.is_err());
+        tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+        assert_eq!(gateway.expire_orders().await, 2);
+        for order_id in [&legs.take_profit.order_id, &legs.stop_loss.order_id] {
+            let update = gateway.get_order_update(order_id).await.unwrap();
+            assert!(matches!(update.status, OrderExecutionStatus::Expired));
+            assert_eq!(resting_orders.lock().unwrap()[order_id], rust_common::OrderStatus::Cancelled);
+            let lifecycle = gateway.order_manager.get_order(order_id).await.unwrap();
+            assert_eq!(lifecycle.state, OrderLifecycleState::Expired);
+        }
+        assert_eq!(gateway.expire_orders().await, 0);
     }
 
     #[tokio::test]
-    async fn test_circuit_breaker_functionality() {
-        let config = GatewayConfig {
-            circuit_breaker_failure_threshold: 2,
-            circuit_breaker_recovery_timeout_ms: 100,
-            ..Default::default()
-        };
-        let gateway = ExecutionGateway::new(config);
-        
-        let mock_adapter = MockExchangeAdapter::new().with_failure(true);
+    async fn test_sweep_stale_orders_cancels_old_open_orders() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let resting_orders = mock_adapter.resting_orders();
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let order_decision = create_test_order_decision();
+        let legs = gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+        let filled = gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert_eq!(gateway.sweep_stale_orders(chrono::Duration::hours(1)).await, 0);
         
-        // Trigger circuit breaker with failures
-        for _ in 0..3 {
-            let _ = gateway.place_order(order_decision.clone()).await;
+        for order_execution in gateway.active_orders.write().await.values_mut() {
+            order_execution.created_at -= chrono::Duration::hours(2);
         }
-        
-        // Circuit breaker should be open
-        let circuit_breakers = gateway.circuit_breakers.read().await;
-        let cb = circuit_breakers.get("default").unwrap();
-        assert!(cb.is_open());
+        assert_eq!(gateway.sweep_stale_orders(chrono::Duration::hours(1)).await, 2);
+        for order_id in [&legs.take_profit.order_id, &legs.stop_loss.order_id] {
+            let update = gateway.get_order_update(order_id).await.unwrap();
+            assert!(matches!(update.status, OrderExecutionStatus::Cancelled));
+            assert_eq!(resting_orders.lock().unwrap()[order_id], rust_common::OrderStatus::Cancelled);
+            let lifecycle = gateway.order_manager.get_order(order_id).await.unwrap();
+            assert_eq!(lifecycle.state, OrderLifecycleState::Cancelled);
+        }
+        // Finished orders are left for the completed-order cleanup
+        let update = gateway.get_order_update(&filled.order_id).await.unwrap();
+        assert!(matches!(update.status, OrderExecutionStatus::Filled));
+        assert_eq!(gateway.sweep_stale_orders(chrono::Duration::hours(1)).await, 0);
     }
 
     #[tokio::test]
-    async fn test_partial_fill_handling() {
-        let config = GatewayConfig {
-            enable_partial_fills: true,
-            ..Default::default()
-        };
-        let gateway = ExecutionGateway::new(config);
+    async fn test_drain_waits_for_in_flight_orders() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(300);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let mock_adapter = MockExchangeAdapter::new().with_partial_fills(0.5);
+        let slow_gateway = gateway.clone();
+        let slow_order = tokio::spawn(async move { slow_gateway.place_order(create_test_order_decision()).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        
+        let start = std::time::Instant::now();
+        assert_eq!(gateway.drain(Duration::from_secs(5)).await, 0);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        
+        // The in-flight order finished before drain returned
+        assert!(slow_order.is_finished());
+        let execution_result = slow_order.await.unwrap().unwrap();
+        assert_eq!(execution_result.status, rust_common::OrderStatus::Filled);
+        
+        let err = gateway.place_order(create_test_order_decision()).await.unwrap_err();
+        assert!(err.to_string().contains("not accepting new orders"));
+    }
+
+    #[tokio::test]
+    async fn test_drain_gives_up_at_timeout() {
+        let gateway = Arc::new(ExecutionGateway::new(GatewayConfig::default()));
+        let mock_adapter = MockExchangeAdapter::new().with_delay(1000);
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let order_decision = create_test_order_decision();
-        let result = gateway.place_order(order_decision).await;
+        let slow_gateway = gateway.clone();
+        tokio::spawn(async move { slow_gateway.place_order(create_test_order_decision()).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
         
-        assert!(result.is_ok());
-        let execution_result = result.unwrap();
-        assert_eq!(execution_result.status, rust_common::OrderStatus::PartiallyFilled);
-        assert_eq!(execution_result.filled_quantity, 0.05); // 50% of 0.1
+        let start = std::time::Instant::now();
+        assert_eq!(gateway.drain(Duration::from_millis(100)).await, 1);
+        assert!(start.elapsed() < Duration::from_millis(900));
     }
 
     #[tokio::test]
-    async fn test_order_cancellation() {
-        let config = GatewayConfig::default();
-        let gateway = ExecutionGateway::new(config);
+    async fn test_ioc_order_cancels_unfilled_remainder() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let mock_adapter = MockExchangeAdapter::new();
+        let mut order_decision = create_test_order_decision();
+        order_decision.time_in_force = TimeInForce::Ioc;
+        let result = gateway.place_order(order_decision).await.unwrap();
+        
+        assert_eq!(placed_orders.lock().unwrap()[0].time_in_force, TimeInForce::Ioc);
+        assert_eq!(result.status, rust_common::OrderStatus::Cancelled);
+        assert!((result.filled_quantity - 0.05).abs() < 1e-9);
+        let lifecycle = gateway.order_manager.get_order(&result.order_id).await.unwrap();
+        assert_eq!(lifecycle.state, OrderLifecycleState::PartiallyFilledCancelled);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_after_partial_fill_keeps_filled_quantity() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let result = gateway.cancel_order("test_order_id").await;
-        assert!(result.is_ok());
+        let result = gateway.place_order(create_test_order_decision()).await.unwrap();
+        assert_eq!(result.status, rust_common::OrderStatus::PartiallyFilled);
+        
+        gateway.cancel_tracked_order(&result.order_id, "Cancelled by user").await.unwrap();
+        
+        let order_update = gateway.get_order_update(&result.order_id).await.unwrap();
+        assert!(matches!(order_update.status, OrderExecutionStatus::PartiallyFilledCancelled));
+        assert!(order_update.status.is_terminal());
+        assert!((order_update.total_filled - 0.05).abs() < 1e-9);
+        let lifecycle = gateway.order_manager.get_order(&result.order_id).await.unwrap();
+        assert_eq!(lifecycle.state, OrderLifecycleState::PartiallyFilledCancelled);
+        assert_eq!(gateway.order_manager.get_statistics().await.partially_filled_cancelled, 1);
+        
+        // A cancellation with nothing filled stays a plain cancellation
+        assert!(matches!(OrderExecutionStatus::Cancelled.with_fills(0.0), OrderExecutionStatus::Cancelled));
+        assert_eq!(OrderLifecycleState::Cancelled.with_fills(0.0), OrderLifecycleState::Cancelled);
     }
 
     #[tokio::test]
-    async fn test_order_status_query() {
-        let config = GatewayConfig::default();
-        let gateway = ExecutionGateway::new(config);
+    async fn test_fok_order_rejected_unless_fully_fillable() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let mock_adapter = MockExchangeAdapter::new();
+        let mut order_decision = create_test_order_decision();
+        order_decision.time_in_force = TimeInForce::Fok;
+        let result = gateway.place_order(order_decision).await.unwrap();
+        
+        assert_eq!(result.status, rust_common::OrderStatus::Rejected);
+        assert_eq!(result.filled_quantity, 0.0);
+        let lifecycle = gateway.order_manager.get_order(&result.order_id).await.unwrap();
+        assert_eq!(lifecycle.state, OrderLifecycleState::Rejected);
+        assert!(gateway.get_positions().await.is_empty());
+        
+        // The same order fills when the book can take all of it
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let result = gateway.get_order_status("test_order_id").await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), OrderExecutionStatus::Filled);
+        let mut order_decision = create_test_order_decision();
+        order_decision.time_in_force = TimeInForce::Fok;
+        let result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(result.status, rust_common::OrderStatus::Filled);
+        assert!((result.filled_quantity - 0.1).abs() < 1e-9);
     }
 
     #[tokio::test]
-    async fn test_cleanup_completed_orders() {
-        let config = GatewayConfig::default();
-        let gateway = ExecutionGateway::new(config);
+    async fn test_trading_halt_rejects_new_orders_until_resumed() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        let resting = gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+
+        gateway.set_trading_halted(true);
+        for result in [
+            gateway.place_order(create_test_order_decision()).await.map(|_| ()),
+            gateway.place_oco_order(create_test_order_decision()).await.map(|_| ()),
+        ] {
+            match result {
+                Err(TradingError::RiskLimitError { limit, .. }) => assert_eq!(limit, "trading halted"),
+                other => panic!("expected a trading halt rejection, got {:?}", other),
+            }
+        }
+        gateway.cancel_order(&resting.stop_loss.order_id).await.unwrap();
+
+        gateway.set_trading_halted(false);
+        assert!(gateway.place_order(create_test_order_decision()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_poll_funding_rates_accrues_due_funding() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new()
+            .with_delay(0)
+            .with_funding_rate(0.001, Utc::now() - chrono::Duration::seconds(1));
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+
+        // No position yet, so nothing to poll
+        gateway.poll_funding_rates().await;
+        assert!(gateway.get_positions().await.is_empty());
+
+        gateway.place_order(create_test_order_decision()).await.unwrap();
+        gateway.poll_funding_rates().await;
+        let position = gateway.get_position("BTCUSD").await.unwrap();
+        let expected = 0.1 * position.average_entry_price * 0.001;
+        assert!((position.funding_paid - expected).abs() < 1e-9);
+        assert!((position.realized_pnl - -expected).abs() < 1e-9);
+
+        // The same funding time is only charged once
+        gateway.poll_funding_rates().await;
+        let position = gateway.get_position("BTCUSD").await.unwrap();
+        assert!((position.funding_paid - expected).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_gtd_order_expires_at_its_date() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0).with_partial_fills(0.5);
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let mock_adapter = MockExchangeAdapter::new();
+        let mut order_decision = create_test_order_decision();
+        order_decision.time_in_force = TimeInForce::Gtd(Utc::now() + chrono::Duration::seconds(1));
+        let result = gateway.place_order(order_decision).await.unwrap();
+        assert_eq!(result.status, rust_common::OrderStatus::PartiallyFilled);
+        assert_eq!(gateway.expire_orders().await, 0);
+        
+        tokio::time::sleep(std::time::Duration::from_millis(1200)).await;
+        assert_eq!(gateway.expire_orders().await, 1);
+        let update = gateway.get_order_update(&result.order_id).await.unwrap();
+        assert!(matches!(update.status, OrderExecutionStatus::Expired));
+        let lifecycle = gateway.order_manager.get_order(&result.order_id).await.unwrap();
+        assert_eq!(lifecycle.state, OrderLifecycleState::Expired);
+    }
+
+    #[tokio::test]
+    async fn test_gtd_order_with_past_date_is_rejected() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let placed_orders = mock_adapter.placed_orders();
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        // Place an order
-        let order_decision = create_test_order_decision();
-        let _ = gateway.place_order(order_decision).await;
+        let mut order_decision = create_test_order_decision();
+        order_decision.time_in_force = TimeInForce::Gtd(Utc::now() - chrono::Duration::seconds(1));
+        let err = gateway.place_order(order_decision).await.unwrap_err();
+        assert!(err.to_string().contains("good-till-date"));
+        assert!(placed_orders.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_by_symbol_tolerates_failures() {
+        let gateway = ExecutionGateway::new(GatewayConfig::default());
+        let mock_adapter = MockExchangeAdapter::new().with_delay(0);
+        let resting_orders = mock_adapter.resting_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        assert_eq!(gateway.get_active_orders_count().await, 1);
+        let btc_legs = gateway.place_oco_order(create_test_order_decision()).await.unwrap();
+        let mut eth_decision = create_test_order_decision();
+        eth_decision.symbol = "ETHUSD".to_string();
+        let eth_legs = gateway.place_oco_order(eth_decision).await.unwrap();
         
-        // Cleanup should not remove recent orders
-        gateway.cleanup_completed_orders(24).await;
-        assert_eq!(gateway.get_active_orders_count().await, 1);
+        // An order whose exchange has gone away can't be cancelled
+        let orphan_id = Uuid::new_v4();
+        gateway.active_orders.write().await.insert(orphan_id, OrderExecution {
+            order_id: orphan_id.to_string(),
+            client_id: orphan_id,
+            exchange: "delisted".to_string(),
+            status: OrderExecutionStatus::Submitted,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            retry_count: 0,
+            partial_fills: Vec::new(),
+            total_filled: 0.0,
+            average_price: None,
+            linked_order_id: None,
+            requested_quantity: 0.0,
+            direction: None,
+            reference_price: None,
+            symbol: String::new(),
+            reserved_risk_amount: None,
+        });
+        
+        let summary = gateway.cancel_all_orders(Some("ETHUSD")).await;
+        assert_eq!(summary.cancelled.len(), 2);
+        assert!(summary.failed.is_empty());
+        for order_id in [&eth_legs.take_profit.order_id, &eth_legs.stop_loss.order_id] {
+            let update = gateway.get_order_update(order_id).await.unwrap();
+            assert!(matches!(update.status, OrderExecutionStatus::Cancelled));
+        }
+        let take_profit = gateway.get_order_update(&btc_legs.take_profit.order_id).await.unwrap();
+        assert!(matches!(take_profit.status, OrderExecutionStatus::Pending));
         
-        // Cleanup with 0 hours should remove completed orders
-        gateway.cleanup_completed_orders(0).await;
-        // Order might still be there if not in terminal state
+        let summary = gateway.cancel_all_orders(None).await;
+        assert_eq!(summary.cancelled.len(), 2);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0].order_id, orphan_id.to_string());
+        for order_id in [&btc_legs.take_profit.order_id, &btc_legs.stop_loss.order_id] {
+            let update = gateway.get_order_update(order_id).await.unwrap();
+            assert!(matches!(update.status, OrderExecutionStatus::Cancelled));
+            assert_eq!(resting_orders.lock().unwrap()[order_id], rust_common::OrderStatus::Cancelled);
+            let lifecycle = gateway.order_manager.get_order(order_id).await.unwrap();
+            assert_eq!(lifecycle.state, OrderLifecycleState::Cancelled);
+        }
     }
 
     #[tokio::test]
-    async fn test_concurrent_order_placement() {
-        let config = GatewayConfig::default();
+    async fn test_max_concurrent_orders_bounds_placement() {
+        let config = GatewayConfig {
+            max_concurrent_orders: 1,
+            ..Default::default()
+        };
         let gateway = std::sync::Arc::new(ExecutionGateway::new(config));
         
         let mock_adapter = MockExchangeAdapter::new().with_delay(50);
         gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
         
-        let mut handles = Vec::new();
-        
-        // Place multiple orders concurrently
-        for i in 0..5 {
-            let gateway_clone = gateway.clone();
-            let handle = tokio::spawn(async move {
-                let mut order_decision = create_test_order_decision();
-                order_decision.decision_id = format!("test_order_{}", i);
-                gateway_clone.place_order(order_decision).await
-            });
-            handles.push(handle);
+        let started = std::time::Instant::now();
+        let handles: Vec<_> = (0..3)
+            .map(|_| {
+                let gateway = gateway.clone();
+                tokio::spawn(async move { gateway.place_order(create_test_order_decision()).await })
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.await.unwrap().is_ok());
         }
         
-        // Wait for all orders to complete
-        let mut success_count = 0;
+        // One permit means the three 50ms placements run one after another
+        assert!(started.elapsed() >= std::time::Duration::from_millis(150));
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_orders_rejects_when_configured() {
+        let config = GatewayConfig {
+            max_concurrent_orders: 2,
+            concurrency_limit_mode: ConcurrencyLimitMode::Reject,
+            ..Default::default()
+        };
+        let gateway = std::sync::Arc::new(ExecutionGateway::new(config));
+        
+        let mock_adapter = MockExchangeAdapter::new().with_delay(100);
+        let placed_orders = mock_adapter.placed_orders();
+        gateway.register_exchange_adapter("default".to_string(), Box::new(mock_adapter)).await;
+        
+        let handles: Vec<_> = (0..5)
+            .map(|_| {
+                let gateway = gateway.clone();
+                tokio::spawn(async move { gateway.place_order(create_test_order_decision()).await })
+            })
+            .collect();
+        let mut rejected = 0;
         for handle in handles {
-            if handle.await.unwrap().is_ok() {
-                success_count += 1;
+            if let Err(e) = handle.await.unwrap() {
+                assert!(e.to_string().contains("concurrency limit reached"));
+                rejected += 1;
             }
         }
         
-        assert_eq!(success_count, 5);
-        assert_eq!(gateway.get_active_orders_count().await, 5);
+        assert_eq!(rejected, 3);
+        assert_eq!(placed_orders.lock().unwrap().len(), 2);
+        
+        // Permits come back once the placements finish
+        assert!(gateway.place_order(create_test_order_decision()).await.is_ok());
     }
 
     // Property-based tests
@@ -0,0 +1,504 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use rust_common::{Direction, OrderType, TimeInForce, Timeframe, TradingError};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::service::interceptor::InterceptedService;
+use tonic::service::Interceptor;
+use tonic::{Code, Request, Response, Status};
+use tracing::{error, info};
+
+use crate::api::{validate_and_place, ErrorResponse};
+use crate::{ApiKeyAuth, ExecutionGateway, OrderExecutionStatus, RateLimiter};
+
+/// Types and client/server stubs generated from `proto/order_service.proto`
+pub mod proto {
+    tonic::include_proto!("execution_gateway.v1");
+}
+
+use proto::order_service_server::{OrderService, OrderServiceServer};
+
+/// gRPC order service backed by the same gateway as the HTTP API
+pub struct GrpcOrderService {
+    gateway: Arc<ExecutionGateway>,
+}
+
+impl GrpcOrderService {
+    pub fn new(gateway: Arc<ExecutionGateway>) -> Self {
+        Self { gateway }
+    }
+}
+
+/// Applies the gateway's API keys and rate limit to gRPC calls, as the HTTP router does
+#[derive(Clone)]
+pub struct GrpcAuthInterceptor {
+    auth: Option<Arc<ApiKeyAuth>>,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl GrpcAuthInterceptor {
+    pub fn new(gateway: &ExecutionGateway) -> Self {
+        let config = gateway.config();
+        Self {
+            auth: (!config.api_keys.is_empty()).then(|| Arc::new(ApiKeyAuth::new(&config.api_keys))),
+            limiter: config
+                .api_rate_limit_per_sec
+                .map(|requests_per_sec| Arc::new(RateLimiter::new(requests_per_sec, config.api_rate_limit_burst))),
+        }
+    }
+}
+
+impl Interceptor for GrpcAuthInterceptor {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        if let (Some(limiter), Some(client)) = (&self.limiter, request.remote_addr()) {
+            if let Err(retry_after) = limiter.check(client.ip()) {
+                return Err(Status::resource_exhausted(format!(
+                    "Rate limit exceeded; retry in {} ms",
+                    retry_after.as_millis()
+                )));
+            }
+        }
+        if let Some(auth) = &self.auth {
+            let header = request.metadata().get("authorization").and_then(|value| value.to_str().ok());
+            if !auth.is_authorized_header(header) {
+                return Err(Status::unauthenticated("Missing or invalid API key"));
+            }
+        }
+        Ok(request)
+    }
+}
+
+/// Build the gRPC order service with authentication and rate limiting applied
+pub fn create_grpc_service(gateway: Arc<ExecutionGateway>) -> InterceptedService<OrderServiceServer<GrpcOrderService>, GrpcAuthInterceptor> {
+    let interceptor = GrpcAuthInterceptor::new(&gateway);
+    OrderServiceServer::with_interceptor(GrpcOrderService::new(gateway), interceptor)
+}
+
+#[tonic::async_trait]
+impl OrderService for GrpcOrderService {
+    async fn place_order(&self, request: Request<proto::PlaceOrderRequest>) -> Result<Response<proto::ExecutionResult>, Status> {
+        let request = request.into_inner();
+        let order_decision = request
+            .order_decision
+            .ok_or_else(|| Status::invalid_argument("order_decision is required"))?;
+        let order_decision = rust_common::OrderDecision::try_from(order_decision)?;
+        info!("Received gRPC place order request for symbol: {}", order_decision.symbol);
+
+        let idempotency_key = request.idempotency_key.as_deref().map(str::trim).filter(|key| !key.is_empty());
+        match validate_and_place(&self.gateway, order_decision, idempotency_key).await {
+            Ok(execution_result) => Ok(Response::new(execution_result.into())),
+            Err((status_code, error_response)) => Err(error_status(status_code, &error_response)),
+        }
+    }
+
+    async fn cancel_order(&self, request: Request<proto::CancelOrderRequest>) -> Result<Response<proto::CancelOrderResponse>, Status> {
+        let order_id = request.into_inner().order_id;
+        info!("Cancelling order over gRPC: {}", order_id);
+
+        match self.gateway.cancel_order(&order_id).await {
+            Ok(()) => Ok(Response::new(proto::CancelOrderResponse { order_id, cancelled: true })),
+            Err(e) => {
+                error!("Failed to cancel order: {}", e);
+                Err(match &e {
+                    TradingError::ExecutionError { message } if message.contains("not found") => Status::not_found(e.to_string()),
+                    _ => Status::internal(e.to_string()),
+                })
+            }
+        }
+    }
+
+    async fn get_order_status(&self, request: Request<proto::GetOrderStatusRequest>) -> Result<Response<proto::OrderStatusResponse>, Status> {
+        let order_id = request.into_inner().order_id;
+        let status = self.gateway.get_order_status(&order_id).await.map_err(|e| Status::not_found(e.to_string()))?;
+        Ok(Response::new(proto::OrderStatusResponse {
+            order_id,
+            status: proto::ExecutionStatus::from(status).into(),
+        }))
+    }
+
+    type StreamOrderUpdatesStream = Pin<Box<dyn Stream<Item = Result<proto::OrderUpdate, Status>> + Send>>;
+
+    async fn stream_order_updates(
+        &self,
+        _request: Request<proto::StreamOrderUpdatesRequest>,
+    ) -> Result<Response<Self::StreamOrderUpdatesStream>, Status> {
+        let updates = UnboundedReceiverStream::new(self.gateway.subscribe_coalesced_order_updates())
+            .map(proto::OrderUpdate::from)
+            .map(Ok);
+        Ok(Response::new(Box::pin(updates)))
+    }
+}
+
+/// gRPC status for an HTTP error reply, keeping the API error code in the message
+fn error_status(status_code: StatusCode, error_response: &ErrorResponse) -> Status {
+    let code = match status_code {
+        // Validation failures name the offending field
+        _ if error_response.field.is_some() => Code::InvalidArgument,
+        StatusCode::BAD_REQUEST => Code::InvalidArgument,
+        StatusCode::UNAUTHORIZED => Code::Unauthenticated,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        StatusCode::NOT_FOUND => Code::NotFound,
+        StatusCode::CONFLICT => Code::AlreadyExists,
+        StatusCode::UNPROCESSABLE_ENTITY => Code::FailedPrecondition,
+        StatusCode::TOO_MANY_REQUESTS => Code::ResourceExhausted,
+        StatusCode::SERVICE_UNAVAILABLE | StatusCode::BAD_GATEWAY => Code::Unavailable,
+        StatusCode::GATEWAY_TIMEOUT => Code::DeadlineExceeded,
+        _ => Code::Internal,
+    };
+    Status::new(code, format!("{}: {}", error_response.code, error_response.error))
+}
+
+fn from_millis(field: &str, millis: i64) -> Result<DateTime<Utc>, String> {
+    DateTime::from_timestamp_millis(millis).ok_or_else(|| format!("{} is out of range", field))
+}
+
+impl TryFrom<proto::OrderDecision> for rust_common::OrderDecision {
+    type Error = Status;
+
+    fn try_from(decision: proto::OrderDecision) -> Result<Self, Status> {
+        let direction = match decision.direction() {
+            proto::Direction::Long => Direction::Long,
+            proto::Direction::Short => Direction::Short,
+            proto::Direction::Unspecified => return Err(Status::invalid_argument("direction is required")),
+        };
+        let order_type = match decision.order_type() {
+            proto::OrderType::Market => OrderType::Market,
+            proto::OrderType::Limit => OrderType::Limit,
+            proto::OrderType::Stop => OrderType::Stop,
+            proto::OrderType::StopLimit => OrderType::StopLimit,
+            proto::OrderType::StopLoss => OrderType::StopLoss,
+            proto::OrderType::TakeProfit => OrderType::TakeProfit,
+            proto::OrderType::TrailingStop => OrderType::TrailingStop,
+            proto::OrderType::Unspecified => return Err(Status::invalid_argument("order_type is required")),
+        };
+        let time_in_force = match decision.time_in_force() {
+            proto::TimeInForce::Gtc => TimeInForce::Gtc,
+            proto::TimeInForce::Ioc => TimeInForce::Ioc,
+            proto::TimeInForce::Fok => TimeInForce::Fok,
+            proto::TimeInForce::Gtd => {
+                let expire_time_ms = decision
+                    .gtd_expire_time_ms
+                    .ok_or_else(|| Status::invalid_argument("gtd_expire_time_ms is required for GTD orders"))?;
+                TimeInForce::Gtd(from_millis("gtd_expire_time_ms", expire_time_ms).map_err(Status::invalid_argument)?)
+            }
+        };
+        let timeframe_context = Timeframe::from_str(&decision.timeframe_context).ok_or_else(|| {
+            Status::invalid_argument(format!("Unknown timeframe_context: {}", decision.timeframe_context))
+        })?;
+        let market_conditions = decision
+            .market_conditions
+            .into_iter()
+            .map(|(key, value)| {
+                serde_json::from_str(&value)
+                    .map(|value| (key.clone(), value))
+                    .map_err(|e| format!("market_conditions[{}] is not JSON: {}", key, e))
+            })
+            .collect::<Result<HashMap<_, _>, String>>()
+            .map_err(Status::invalid_argument)?;
+
+        Ok(Self {
+            decision_id: decision.decision_id,
+            signal_id: decision.signal_id,
+            symbol: decision.symbol,
+            timestamp: from_millis("timestamp_ms", decision.timestamp_ms).map_err(Status::invalid_argument)?,
+            direction,
+            order_type,
+            trail_pct: decision.trail_pct,
+            base_quantity: decision.base_quantity,
+            risk_adjusted_quantity: decision.risk_adjusted_quantity,
+            max_position_value: decision.max_position_value,
+            entry_price: decision.entry_price,
+            stop_loss: decision.stop_loss,
+            take_profit: decision.take_profit,
+            risk_amount: decision.risk_amount,
+            risk_percentage: decision.risk_percentage,
+            leverage: decision.leverage,
+            portfolio_value: decision.portfolio_value,
+            available_margin: decision.available_margin,
+            current_exposure: decision.current_exposure,
+            confidence_score: decision.confidence_score,
+            confluence_score: decision.confluence_score,
+            risk_reward_ratio: decision.risk_reward_ratio,
+            slippage_tolerance: decision.slippage_tolerance,
+            max_execution_time: decision.max_execution_time,
+            partial_fill_acceptable: decision.partial_fill_acceptable,
+            time_in_force,
+            post_only: decision.post_only,
+            reduce_only: decision.reduce_only,
+            partial_retry_policy: decision.partial_retry_policy.map(|policy| rust_common::PartialRetryPolicy {
+                max_attempts: policy.max_attempts,
+                delay_ms: policy.delay_ms,
+            }),
+            exchange: decision.exchange,
+            decision_reason: decision.decision_reason,
+            risk_factors: decision.risk_factors,
+            supporting_factors: decision.supporting_factors,
+            timeframe_context,
+            market_conditions,
+        })
+    }
+}
+
+impl From<rust_common::OrderDecision> for proto::OrderDecision {
+    fn from(decision: rust_common::OrderDecision) -> Self {
+        let (time_in_force, gtd_expire_time_ms) = match decision.time_in_force {
+            TimeInForce::Gtc => (proto::TimeInForce::Gtc, None),
+            TimeInForce::Gtd(expire_time) => (proto::TimeInForce::Gtd, Some(expire_time.timestamp_millis())),
+            TimeInForce::Ioc => (proto::TimeInForce::Ioc, None),
+            TimeInForce::Fok => (proto::TimeInForce::Fok, None),
+        };
+        let direction = match decision.direction {
+            Direction::Long => proto::Direction::Long,
+            Direction::Short => proto::Direction::Short,
+        };
+        let order_type = match decision.order_type {
+            OrderType::Market => proto::OrderType::Market,
+            OrderType::Limit => proto::OrderType::Limit,
+            OrderType::Stop => proto::OrderType::Stop,
+            OrderType::StopLimit => proto::OrderType::StopLimit,
+            OrderType::StopLoss => proto::OrderType::StopLoss,
+            OrderType::TakeProfit => proto::OrderType::TakeProfit,
+            OrderType::TrailingStop => proto::OrderType::TrailingStop,
+        };
+
+        Self {
+            decision_id: decision.decision_id,
+            signal_id: decision.signal_id,
+            symbol: decision.symbol,
+            timestamp_ms: decision.timestamp.timestamp_millis(),
+            direction: direction.into(),
+            order_type: order_type.into(),
+            trail_pct: decision.trail_pct,
+            base_quantity: decision.base_quantity,
+            risk_adjusted_quantity: decision.risk_adjusted_quantity,
+            max_position_value: decision.max_position_value,
+            entry_price: decision.entry_price,
+            stop_loss: decision.stop_loss,
+            take_profit: decision.take_profit,
+            risk_amount: decision.risk_amount,
+            risk_percentage: decision.risk_percentage,
+            leverage: decision.leverage,
+            portfolio_value: decision.portfolio_value,
+            available_margin: decision.available_margin,
+            current_exposure: decision.current_exposure,
+            confidence_score: decision.confidence_score,
+            confluence_score: decision.confluence_score,
+            risk_reward_ratio: decision.risk_reward_ratio,
+            slippage_tolerance: decision.slippage_tolerance,
+            max_execution_time: decision.max_execution_time,
+            partial_fill_acceptable: decision.partial_fill_acceptable,
+            time_in_force: time_in_force.into(),
+            gtd_expire_time_ms,
+            post_only: decision.post_only,
+            reduce_only: decision.reduce_only,
+            partial_retry_policy: decision.partial_retry_policy.map(|policy| proto::PartialRetryPolicy {
+                max_attempts: policy.max_attempts,
+                delay_ms: policy.delay_ms,
+            }),
+            exchange: decision.exchange,
+            decision_reason: decision.decision_reason,
+            risk_factors: decision.risk_factors,
+            supporting_factors: decision.supporting_factors,
+            timeframe_context: decision.timeframe_context.as_str().to_string(),
+            market_conditions: decision
+                .market_conditions
+                .into_iter()
+                .map(|(key, value)| (key, value.to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl From<rust_common::ExecutionResult> for proto::ExecutionResult {
+    fn from(result: rust_common::ExecutionResult) -> Self {
+        let status = match result.status {
+            rust_common::OrderStatus::Pending => proto::OrderStatus::Pending,
+            rust_common::OrderStatus::Open => proto::OrderStatus::Open,
+            rust_common::OrderStatus::Filled => proto::OrderStatus::Filled,
+            rust_common::OrderStatus::PartiallyFilled => proto::OrderStatus::PartiallyFilled,
+            rust_common::OrderStatus::Cancelled => proto::OrderStatus::Cancelled,
+            rust_common::OrderStatus::Rejected => proto::OrderStatus::Rejected,
+            rust_common::OrderStatus::Expired => proto::OrderStatus::Expired,
+        };
+
+        Self {
+            execution_id: result.execution_id,
+            decision_id: result.decision_id,
+            order_id: result.order_id,
+            status: status.into(),
+            filled_quantity: result.filled_quantity,
+            average_price: result.average_price,
+            submitted_at_ms: result.submitted_at.timestamp_millis(),
+            filled_at_ms: result.filled_at.map(|filled_at| filled_at.timestamp_millis()),
+            commission: result.commission,
+            slippage: result.slippage,
+            execution_time_ms: result.execution_time_ms,
+            decision_to_submit_ms: result.decision_to_submit_ms,
+            error_message: result.error_message,
+            reject_reason: result
+                .reject_reason
+                .and_then(|reason| serde_json::to_value(reason).ok())
+                .and_then(|reason| reason.as_str().map(str::to_string)),
+            retry_count: result.retry_count,
+        }
+    }
+}
+
+impl From<OrderExecutionStatus> for proto::ExecutionStatus {
+    fn from(status: OrderExecutionStatus) -> Self {
+        match status {
+            OrderExecutionStatus::Pending => Self::Pending,
+            OrderExecutionStatus::Submitted => Self::Submitted,
+            OrderExecutionStatus::PartiallyFilled => Self::PartiallyFilled,
+            OrderExecutionStatus::Filled => Self::Filled,
+            OrderExecutionStatus::Cancelled => Self::Cancelled,
+            OrderExecutionStatus::PartiallyFilledCancelled => Self::PartiallyFilledCancelled,
+            OrderExecutionStatus::Rejected => Self::Rejected,
+            OrderExecutionStatus::Failed => Self::Failed,
+            OrderExecutionStatus::Expired => Self::Expired,
+        }
+    }
+}
+
+impl From<crate::OrderUpdate> for proto::OrderUpdate {
+    fn from(update: crate::OrderUpdate) -> Self {
+        Self {
+            order_id: update.order_id,
+            client_id: update.client_id,
+            exchange: update.exchange,
+            status: proto::ExecutionStatus::from(update.status).into(),
+            total_filled: update.total_filled,
+            average_price: update.average_price,
+            fill_count: update.fill_count as u64,
+            updated_at_ms: update.updated_at.timestamp_millis(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::proto::order_service_client::OrderServiceClient;
+    use super::*;
+    use crate::{GatewayConfig, MockExchangeAdapter};
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::{Channel, Server};
+
+    fn create_test_order_decision() -> rust_common::OrderDecision {
+        let mut decision = rust_common::OrderDecision::new("test_signal".to_string(), "BTCUSD".to_string());
+        decision.direction = Direction::Long;
+        decision.order_type = OrderType::Limit;
+        decision.base_quantity = 0.1;
+        decision.risk_adjusted_quantity = 0.1;
+        decision.max_position_value = 5000.0;
+        decision.entry_price = 50000.0;
+        decision.stop_loss = 49000.0;
+        decision.take_profit = Some(52000.0);
+        decision.risk_amount = 100.0;
+        decision.risk_percentage = 1.0;
+        decision.leverage = 1.0;
+        decision.portfolio_value = 10000.0;
+        decision.available_margin = 5000.0;
+        decision.current_exposure = 0.1;
+        decision.confidence_score = 0.8;
+        decision.confluence_score = 75.0;
+        decision.risk_reward_ratio = 2.0;
+        decision
+    }
+
+    async fn serve(config: GatewayConfig, adapter: MockExchangeAdapter) -> OrderServiceClient<Channel> {
+        let gateway = Arc::new(ExecutionGateway::new(config));
+        gateway.register_exchange_adapter("default".to_string(), Box::new(adapter)).await;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(create_grpc_service(gateway))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        OrderServiceClient::connect(format!("http://{}", addr)).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_grpc_place_status_cancel_and_stream() {
+        let mut client = serve(GatewayConfig::default(), MockExchangeAdapter::new().with_delay(0)).await;
+        let mut updates = client
+            .stream_order_updates(proto::StreamOrderUpdatesRequest {})
+            .await
+            .unwrap()
+            .into_inner();
+
+        let decision = create_test_order_decision();
+        let request = proto::PlaceOrderRequest {
+            order_decision: Some(decision.clone().into()),
+            idempotency_key: None,
+        };
+        let result = client.place_order(request.clone()).await.unwrap().into_inner();
+        assert_eq!(result.decision_id, decision.decision_id);
+        assert_eq!(result.status(), proto::OrderStatus::Filled);
+        assert_eq!(result.filled_quantity, 0.1);
+
+        // Replaying the decision returns the original execution
+        let replay = client.place_order(request).await.unwrap().into_inner();
+        assert_eq!(replay.order_id, result.order_id);
+
+        let status = client
+            .get_order_status(proto::GetOrderStatusRequest { order_id: result.order_id.clone() })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(status.status(), proto::ExecutionStatus::Filled);
+
+        let update = tokio::time::timeout(std::time::Duration::from_secs(5), updates.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(update.order_id, result.order_id);
+
+        // Cancels are forwarded to the exchange, as over REST
+        let cancelled = client
+            .cancel_order(proto::CancelOrderRequest { order_id: "test_order_id".to_string() })
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(cancelled.cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_rejections_map_to_status_codes() {
+        let config = GatewayConfig {
+            api_keys: vec!["secret".to_string()],
+            ..Default::default()
+        };
+        let mut client = serve(config, MockExchangeAdapter::new().with_delay(0)).await;
+        let request = |decision: rust_common::OrderDecision, key: &str| {
+            let mut request = Request::new(proto::PlaceOrderRequest {
+                order_decision: Some(decision.into()),
+                idempotency_key: None,
+            });
+            request.metadata_mut().insert("authorization", format!("Bearer {}", key).parse().unwrap());
+            request
+        };
+
+        let unauthenticated = client.place_order(request(create_test_order_decision(), "wrong")).await.unwrap_err();
+        assert_eq!(unauthenticated.code(), Code::Unauthenticated);
+
+        let mut invalid = create_test_order_decision();
+        invalid.risk_adjusted_quantity = -1.0;
+        let invalid = client.place_order(request(invalid, "secret")).await.unwrap_err();
+        assert_eq!(invalid.code(), Code::InvalidArgument);
+        assert!(invalid.message().starts_with("VALIDATION_ERROR"));
+
+        let mut missing_direction = proto::OrderDecision::from(create_test_order_decision());
+        missing_direction.direction = proto::Direction::Unspecified.into();
+        let mut request_without_direction = request(create_test_order_decision(), "secret");
+        request_without_direction.get_mut().order_decision = Some(missing_direction);
+        assert_eq!(client.place_order(request_without_direction).await.unwrap_err().code(), Code::InvalidArgument);
+
+        assert!(client.place_order(request(create_test_order_decision(), "secret")).await.is_ok());
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Upper bounds (inclusive, in ms) of the latency histogram buckets
+pub const LATENCY_BUCKETS_MS: [u64; 8] = [10, 50, 100, 250, 500, 1000, 5000, 30000];
+
+/// Tracks signal-to-execution latency against an SLO budget
+pub struct LatencyTracker {
+    budget_ms: u64,
+    count: AtomicU64,
+    sum_ms: AtomicU64,
+    max_ms: AtomicU64,
+    slo_violations_total: AtomicU64,
+    buckets: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1], // last bucket is +Inf
+}
+
+/// Aggregated latency statistics
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencyStats {
+    pub budget_ms: u64,
+    pub count: u64,
+    pub mean_ms: f64,
+    pub max_ms: u64,
+    pub slo_violations_total: u64,
+    pub buckets: Vec<LatencyBucket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyBucket {
+    pub le_ms: Option<u64>, // None = +Inf
+    pub count: u64,
+}
+
+impl LatencyTracker {
+    pub fn new(budget_ms: u64) -> Self {
+        Self {
+            budget_ms,
+            count: AtomicU64::new(0),
+            sum_ms: AtomicU64::new(0),
+            max_ms: AtomicU64::new(0),
+            slo_violations_total: AtomicU64::new(0),
+            buckets: Default::default(),
+        }
+    }
+
+    /// Record a latency sample, returning true if it violated the SLO budget
+    pub fn record(&self, latency_ms: u64) -> bool {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_ms.fetch_add(latency_ms, Ordering::Relaxed);
+        self.max_ms.fetch_max(latency_ms, Ordering::Relaxed);
+
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|le| latency_ms <= *le)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+
+        let violated = latency_ms > self.budget_ms;
+        if violated {
+            self.slo_violations_total.fetch_add(1, Ordering::Relaxed);
+        }
+        violated
+    }
+
+    /// Get total number of SLO violations
+    pub fn slo_violations_total(&self) -> u64 {
+        self.slo_violations_total.load(Ordering::Relaxed)
+    }
+
+    /// Get a snapshot of the aggregated statistics
+    pub fn get_stats(&self) -> LatencyStats {
+        let count = self.count.load(Ordering::Relaxed);
+        let sum_ms = self.sum_ms.load(Ordering::Relaxed);
+
+        let buckets = self.buckets
+            .iter()
+            .enumerate()
+            .map(|(i, bucket)| LatencyBucket {
+                le_ms: LATENCY_BUCKETS_MS.get(i).copied(),
+                count: bucket.load(Ordering::Relaxed),
+            })
+            .collect();
+
+        LatencyStats {
+            budget_ms: self.budget_ms,
+            count,
+            mean_ms: if count > 0 { sum_ms as f64 / count as f64 } else { 0.0 },
+            max_ms: self.max_ms.load(Ordering::Relaxed),
+            slo_violations_total: self.slo_violations_total(),
+            buckets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_tracker_records_samples() {
+        let tracker = LatencyTracker::new(100);
+
+        assert!(!tracker.record(20));
+        assert!(!tracker.record(100));
+        assert!(tracker.record(400));
+
+        let stats = tracker.get_stats();
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.max_ms, 400);
+        assert_eq!(stats.slo_violations_total, 1);
+        assert!((stats.mean_ms - 173.333).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_latency_tracker_buckets() {
+        let tracker = LatencyTracker::new(1000);
+
+        tracker.record(5);
+        tracker.record(60);
+        tracker.record(60000);
+
+        let stats = tracker.get_stats();
+        assert_eq!(stats.buckets.len(), LATENCY_BUCKETS_MS.len() + 1);
+        assert_eq!(stats.buckets[0].count, 1); // <= 10ms
+        assert_eq!(stats.buckets[2].count, 1); // <= 100ms
+        assert_eq!(stats.buckets.last().unwrap().le_ms, None);
+        assert_eq!(stats.buckets.last().unwrap().count, 1);
+    }
+}
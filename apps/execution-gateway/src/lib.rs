@@ -1,5 +1,19 @@
 pub mod gateway;
 pub mod api;
+pub mod algos;
+pub mod analytics;
+pub mod backtest;
+pub mod conditional_orders;
+pub mod fix;
+pub mod grpc;
+pub mod auth;
+pub mod rate_limit;
 
 pub use gateway::*;
-pub use api::*;
\ No newline at end of file
+pub use api::*;
+pub use algos::*;
+pub use backtest::*;
+pub use conditional_orders::*;
+pub use grpc::{create_grpc_service, GrpcAuthInterceptor, GrpcOrderService};
+pub use auth::*;
+pub use rate_limit::*;
\ No newline at end of file
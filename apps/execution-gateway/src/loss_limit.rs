@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+use super::SessionClock;
+
+/// Realized PnL for the current day and the loss limit it is held against
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyLossStats {
+    /// UTC date the PnL was realized on ("2024-03-05")
+    pub day: String,
+    /// Realized PnL net of commission; negative is a loss
+    pub realized_pnl: f64,
+    pub max_daily_loss: Option<f64>,
+    pub breached: bool,
+}
+
+/// Tracks realized PnL per day and trips once the day's loss reaches `max_daily_loss`.
+///
+/// The day rolls over at the clock's session boundary, so the guard clears itself at the
+/// start of the next day rather than needing a manual reset.
+pub struct LossLimitGuard {
+    max_daily_loss: Option<f64>,
+    clock: Arc<SessionClock>,
+    day: Mutex<(String, f64)>, // (session ID, realized PnL)
+}
+
+impl LossLimitGuard {
+    /// Guard rolling over at UTC midnight; `None` never trips
+    pub fn new(max_daily_loss: Option<f64>) -> Self {
+        Self::with_clock(max_daily_loss, Arc::new(SessionClock::utc_midnight()))
+    }
+
+    pub fn with_clock(max_daily_loss: Option<f64>, clock: Arc<SessionClock>) -> Self {
+        let day = (clock.current_session_id(), 0.0);
+        Self {
+            max_daily_loss,
+            clock,
+            day: Mutex::new(day),
+        }
+    }
+
+    /// Add realized PnL to the current day
+    pub fn record_realized_pnl(&self, pnl: f64) {
+        let mut day = self.day.lock().unwrap();
+        self.roll_day(&mut day);
+        day.1 += pnl;
+    }
+
+    pub fn daily_realized_pnl(&self) -> f64 {
+        let mut day = self.day.lock().unwrap();
+        self.roll_day(&mut day);
+        day.1
+    }
+
+    /// Whether the day's realized loss has reached the limit
+    pub fn is_breached(&self) -> bool {
+        self.max_daily_loss
+            .is_some_and(|max_daily_loss| self.daily_realized_pnl() <= -max_daily_loss)
+    }
+
+    pub fn snapshot(&self) -> DailyLossStats {
+        let mut day = self.day.lock().unwrap();
+        self.roll_day(&mut day);
+        DailyLossStats {
+            day: day.0.clone(),
+            realized_pnl: day.1,
+            max_daily_loss: self.max_daily_loss,
+            breached: self.max_daily_loss.is_some_and(|max_daily_loss| day.1 <= -max_daily_loss),
+        }
+    }
+
+    fn roll_day(&self, day: &mut (String, f64)) {
+        let session_id = self.clock.current_session_id();
+        if day.0 != session_id {
+            *day = (session_id, 0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Clock;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[test]
+    fn test_guard_trips_at_limit_and_resets_at_utc_midnight() {
+        let now = Arc::new(Mutex::new(Utc.with_ymd_and_hms(2024, 3, 5, 23, 0, 0).unwrap()));
+        let clock_now = now.clone();
+        let clock: Clock = Arc::new(move || -> DateTime<Utc> { *clock_now.lock().unwrap() });
+        let guard = LossLimitGuard::with_clock(Some(100.0), Arc::new(SessionClock::utc_midnight().with_clock(clock)));
+
+        guard.record_realized_pnl(-60.0);
+        assert!(!guard.is_breached());
+        guard.record_realized_pnl(20.0);
+        guard.record_realized_pnl(-60.0);
+        assert!(guard.is_breached());
+        let stats = guard.snapshot();
+        assert_eq!(stats.day, "2024-03-05");
+        assert_eq!(stats.realized_pnl, -100.0);
+        assert!(stats.breached);
+
+        *now.lock().unwrap() = Utc.with_ymd_and_hms(2024, 3, 6, 0, 0, 0).unwrap();
+        assert!(!guard.is_breached());
+        assert_eq!(guard.daily_realized_pnl(), 0.0);
+
+        // Without a limit the guard only reports
+        let guard = LossLimitGuard::new(None);
+        guard.record_realized_pnl(-1_000_000.0);
+        assert!(!guard.is_breached());
+    }
+}
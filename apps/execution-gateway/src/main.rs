@@ -1,6 +1,7 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
-use tracing::info;
-use execution_gateway::{ExecutionGateway, GatewayConfig, MockExchangeAdapter, create_router};
+use tracing::{info, warn};
+use execution_gateway::{BackgroundTasks, EventLog, ExecutionGateway, FileOrderStore, GatewayConfig, MockExchangeAdapter, create_grpc_service, create_router};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -18,8 +19,44 @@ async fn main() -> anyhow::Result<()> {
     
     info!("Starting Execution Gateway");
     
-    let config = GatewayConfig::default();
-    let gateway = Arc::new(ExecutionGateway::new(config));
+    let mut config = GatewayConfig::default();
+    if let Ok(api_keys) = std::env::var("GATEWAY_API_KEYS") {
+        config.api_keys = api_keys
+            .split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+    if config.api_keys.is_empty() {
+        warn!("GATEWAY_API_KEYS is not set; the HTTP API is unauthenticated");
+    }
+    if let Ok(event_log_path) = std::env::var("GATEWAY_EVENT_LOG_PATH") {
+        config.event_log_path = Some(event_log_path);
+    }
+    if let Ok(order_store_path) = std::env::var("GATEWAY_ORDER_STORE_PATH") {
+        config.order_store_path = Some(order_store_path);
+    }
+    let mut gateway = ExecutionGateway::try_new(config.clone())?;
+    #[cfg(feature = "nats")]
+    if let Ok(nats_url) = std::env::var("GATEWAY_NATS_URL") {
+        let subject_prefix = std::env::var("GATEWAY_NATS_SUBJECT_PREFIX").unwrap_or_else(|_| "execution".to_string());
+        let event_publisher = execution_gateway::NatsEventPublisher::connect(&nats_url, subject_prefix).await?;
+        gateway = gateway.with_event_publisher(Arc::new(event_publisher));
+        info!("Publishing execution events to NATS at {}", nats_url);
+    }
+    if let Some(event_log_path) = &config.event_log_path {
+        let event_log = EventLog::open(event_log_path).await?;
+        gateway = gateway.with_event_log(Arc::new(event_log)).await?;
+        info!("Appending order lifecycle events to {}", event_log_path);
+    }
+    if let Some(order_store_path) = &config.order_store_path {
+        let order_store = FileOrderStore::open(order_store_path).await?;
+        gateway = gateway.with_order_store(Arc::new(order_store));
+        gateway.restore_from_store().await?;
+        info!("Persisting orders to {}", order_store_path);
+    }
+    let gateway = Arc::new(gateway);
     
     // Register a mock exchange adapter for testing
     let mock_adapter = MockExchangeAdapter::new();
@@ -34,28 +71,131 @@ async fn main() -> anyhow::Result<()> {
     info!("Starting HTTP server on http://0.0.0.0:8080");
     info!("API endpoints:");
     info!("  GET  /health - Health check");
+    info!("  GET  /v1/stats - Gateway and order lifecycle statistics");
     info!("  POST /v1/orders - Place order (idempotent)");
+    info!("  POST /v1/orders/batch - Place a batch of orders (multi-status)");
+    info!("  POST /v1/orders/oco - Place a linked take-profit/stop-loss pair");
+    info!("  GET  /v1/orders - List tracked orders (?state=, ?symbol=, ?limit=, ?offset=)");
     info!("  GET  /v1/orders/:id/status - Get order status");
+    info!("  GET  /v1/orders/:id/detail - Order lifecycle history and fills");
+    info!("  GET  /v1/orders/:id/stream - Single order updates (WebSocket)");
     info!("  DELETE /v1/orders/:id - Cancel order");
+    info!("  GET  /v1/positions - Positions and PnL from gateway fills");
+    info!("  POST /v1/positions/:symbol/close - Close position");
+    info!("  POST /v1/halt - Halt trading; new orders are rejected");
+    info!("  POST /v1/resume - Resume trading after a halt");
+    info!("  GET  /v1/risk-limits - Portfolio risk limits");
+    info!("  POST /v1/risk-limits - Replace portfolio risk limits");
+    info!("  GET  /v1/ws/orders - Order updates (WebSocket)");
+
+    // gRPC mirror of the order API on its own port
+    let grpc_port: u16 = match std::env::var("GATEWAY_GRPC_PORT") {
+        Ok(port) => port.parse()?,
+        Err(_) => 50051,
+    };
+    let grpc_addr = SocketAddr::from(([0, 0, 0, 0], grpc_port));
+    let grpc_server = tonic::transport::Server::builder()
+        .add_service(create_grpc_service(gateway.clone()))
+        .serve_with_shutdown(grpc_addr, shutdown_signal());
+    let grpc_handle = tokio::spawn(grpc_server);
+    info!("Starting gRPC server on {}", grpc_addr);
+    info!("  execution_gateway.v1.OrderService: PlaceOrder, CancelOrder, GetOrderStatus, StreamOrderUpdates");
     
-    // Start cleanup task
+    // Start staggered background tasks
+    let mut background_tasks = BackgroundTasks::new(
+        gateway.config().background_task_max_offset_ms,
+        gateway.config().background_task_jitter_pct,
+    );
+
     let gateway_cleanup = gateway.clone();
-    tokio::spawn(async move {
-        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600)); // 1 hour
-        loop {
-            interval.tick().await;
-            let cleaned = gateway_cleanup.cleanup_completed_orders(24).await;
-            if cleaned > 0 {
-                info!("Cleaned up {} completed orders", cleaned);
+    background_tasks.spawn(
+        "cleanup",
+        std::time::Duration::from_secs(gateway.config().cleanup_interval_secs),
+        move || {
+            let gateway_cleanup = gateway_cleanup.clone();
+            async move {
+                if let Some(max_age_secs) = gateway_cleanup.config().max_order_age_secs {
+                    let cancelled = gateway_cleanup
+                        .sweep_stale_orders(chrono::Duration::seconds(max_age_secs as i64))
+                        .await;
+                    if cancelled > 0 {
+                        info!("Cancelled {} stale open orders", cancelled);
+                    }
+                }
+                let cleaned = gateway_cleanup.cleanup_completed_orders(24).await;
+                if cleaned > 0 {
+                    info!("Cleaned up {} completed orders", cleaned);
+                }
             }
-        }
-    });
+        },
+    );
+
+    let gateway_trailing = gateway.clone();
+    background_tasks.spawn(
+        "trailing_stops",
+        std::time::Duration::from_millis(gateway.config().trailing_stop_poll_interval_ms),
+        move || {
+            let gateway_trailing = gateway_trailing.clone();
+            async move {
+                gateway_trailing.poll_trailing_stops().await;
+            }
+        },
+    );
+
+    let gateway_funding = gateway.clone();
+    background_tasks.spawn(
+        "funding_rates",
+        std::time::Duration::from_millis(gateway.config().funding_rate_poll_interval_ms),
+        move || {
+            let gateway_funding = gateway_funding.clone();
+            async move {
+                gateway_funding.poll_funding_rates().await;
+            }
+        },
+    );
+
+    let gateway_linked = gateway.clone();
+    background_tasks.spawn(
+        "linked_orders",
+        std::time::Duration::from_millis(gateway.config().linked_order_poll_interval_ms),
+        move || {
+            let gateway_linked = gateway_linked.clone();
+            async move {
+                gateway_linked.poll_linked_orders().await;
+            }
+        },
+    );
+
+    let gateway_expiry = gateway.clone();
+    background_tasks.spawn(
+        "order_expiry",
+        std::time::Duration::from_millis(gateway.config().order_expiry_poll_interval_ms),
+        move || {
+            let gateway_expiry = gateway_expiry.clone();
+            async move {
+                let expired = gateway_expiry.expire_orders().await;
+                if expired > 0 {
+                    info!("Expired {} orders past their max execution time", expired);
+                }
+            }
+        },
+    );
     
     // Start the server
-    axum::serve(listener, app)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal())
         .await?;
+    if let Err(e) = grpc_handle.await? {
+        warn!("gRPC server stopped with an error: {}", e);
+    }
     
+    // Let in-flight orders reach the exchange's answer before the process exits
+    let drain_timeout = std::time::Duration::from_millis(gateway.config().shutdown_drain_timeout_ms);
+    let still_in_flight = gateway.drain(drain_timeout).await;
+    if still_in_flight > 0 {
+        warn!("{} orders were still in flight when the drain timed out", still_in_flight);
+    }
+    background_tasks.shutdown();
     info!("Execution Gateway shut down");
     Ok(())
 }
@@ -0,0 +1,142 @@
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use rust_common::ExecutionResult;
+
+use super::{CircuitBreakerState, OrderExecutionStatus};
+
+/// Execution latency histogram buckets, in milliseconds
+const LATENCY_BUCKETS_MS: &[f64] = &[1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0];
+
+/// Prometheus metrics for order throughput, latency, retries and circuit breaker state.
+///
+/// Each gateway owns its own registry, so several gateways (e.g. in tests) don't collide.
+pub struct GatewayMetrics {
+    registry: Registry,
+    order_status_total: IntCounterVec,
+    execution_latency_ms: HistogramVec,
+    order_retries_total: IntCounterVec,
+    circuit_breaker_state: IntGaugeVec,
+}
+
+impl GatewayMetrics {
+    pub fn new() -> Self {
+        let order_status_total = IntCounterVec::new(
+            Opts::new("gateway_order_status_total", "Order status transitions by resulting status"),
+            &["status"],
+        )
+        .expect("valid order status metric");
+        let execution_latency_ms = HistogramVec::new(
+            HistogramOpts::new("gateway_execution_latency_ms", "Order execution time including retries, in milliseconds")
+                .buckets(LATENCY_BUCKETS_MS.to_vec()),
+            &["exchange"],
+        )
+        .expect("valid execution latency metric");
+        let order_retries_total = IntCounterVec::new(
+            Opts::new("gateway_order_retries_total", "Order submission retries"),
+            &["exchange"],
+        )
+        .expect("valid retry metric");
+        let circuit_breaker_state = IntGaugeVec::new(
+            Opts::new("gateway_circuit_breaker_state", "Circuit breaker state (0=closed, 1=half-open, 2=open)"),
+            &["exchange"],
+        )
+        .expect("valid circuit breaker metric");
+
+        let registry = Registry::new();
+        for collector in [
+            Box::new(order_status_total.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(execution_latency_ms.clone()),
+            Box::new(order_retries_total.clone()),
+            Box::new(circuit_breaker_state.clone()),
+        ] {
+            registry.register(collector).expect("metric registered once");
+        }
+
+        Self {
+            registry,
+            order_status_total,
+            execution_latency_ms,
+            order_retries_total,
+            circuit_breaker_state,
+        }
+    }
+
+    /// Count an order moving to `status`
+    pub fn record_order_status(&self, status: &OrderExecutionStatus) {
+        let status = match status {
+            OrderExecutionStatus::Pending => "pending",
+            OrderExecutionStatus::Submitted => "submitted",
+            OrderExecutionStatus::PartiallyFilled => "partially_filled",
+            OrderExecutionStatus::Filled => "filled",
+            OrderExecutionStatus::Cancelled => "cancelled",
+            OrderExecutionStatus::PartiallyFilledCancelled => "partially_filled_cancelled",
+            OrderExecutionStatus::Rejected => "rejected",
+            OrderExecutionStatus::Failed => "failed",
+            OrderExecutionStatus::Expired => "expired",
+        };
+        self.order_status_total.with_label_values(&[status]).inc();
+    }
+
+    /// Observe the execution time of a completed order
+    pub fn record_execution(&self, exchange: &str, result: &ExecutionResult) {
+        if let Some(execution_time_ms) = result.execution_time_ms {
+            self.execution_latency_ms
+                .with_label_values(&[exchange])
+                .observe(f64::from(execution_time_ms));
+        }
+    }
+
+    /// Count one resubmission of an order
+    pub fn record_retry(&self, exchange: &str) {
+        self.order_retries_total.with_label_values(&[exchange]).inc();
+    }
+
+    pub fn set_circuit_breaker_state(&self, exchange: &str, state: CircuitBreakerState) {
+        let value = match state {
+            CircuitBreakerState::Closed => 0,
+            CircuitBreakerState::HalfOpen => 1,
+            CircuitBreakerState::Open => 2,
+        };
+        self.circuit_breaker_state.with_label_values(&[exchange]).set(value);
+    }
+
+    /// All metrics in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            tracing::warn!("Failed to encode metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+impl Default for GatewayMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_includes_recorded_values() {
+        let metrics = GatewayMetrics::new();
+        metrics.record_order_status(&OrderExecutionStatus::Filled);
+        metrics.record_order_status(&OrderExecutionStatus::Filled);
+        metrics.record_retry("binance");
+        metrics.set_circuit_breaker_state("binance", CircuitBreakerState::Open);
+
+        let mut result = ExecutionResult::new("decision".to_string(), "order".to_string());
+        result.execution_time_ms = Some(42);
+        metrics.record_execution("binance", &result);
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("gateway_order_status_total{status=\"filled\"} 2"));
+        assert!(rendered.contains("gateway_order_retries_total{exchange=\"binance\"} 1"));
+        assert!(rendered.contains("gateway_circuit_breaker_state{exchange=\"binance\"} 2"));
+        assert!(rendered.contains("gateway_execution_latency_ms_count{exchange=\"binance\"} 1"));
+    }
+}
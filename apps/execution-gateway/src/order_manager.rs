@@ -6,6 +6,8 @@ use chrono::{DateTime, Utc, Duration};
 use serde::{Deserialize, Serialize};
 use rust_common::{TradingError, OrderStatus};
 
+use super::{EventLog, OrderEventRecord};
+
 /// Order lifecycle states
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum OrderLifecycleState {
@@ -16,11 +18,36 @@ pub enum OrderLifecycleState {
     PartiallyFilled,
     Filled,
     Cancelled,
+    /// Cancelled after part of the order filled
+    PartiallyFilledCancelled,
     Rejected,
     Expired,
     Failed,
 }
 
+impl OrderLifecycleState {
+    /// Whether no further transitions are allowed
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderLifecycleState::Filled
+                | OrderLifecycleState::Cancelled
+                | OrderLifecycleState::PartiallyFilledCancelled
+                | OrderLifecycleState::Rejected
+                | OrderLifecycleState::Expired
+                | OrderLifecycleState::Failed
+        )
+    }
+
+    /// Tell a cancellation that left fills behind apart from one that left none
+    pub fn with_fills(self, filled_quantity: f64) -> Self {
+        match self {
+            OrderLifecycleState::Cancelled if filled_quantity > 0.0 => OrderLifecycleState::PartiallyFilledCancelled,
+            state => state,
+        }
+    }
+}
+
 /// Order lifecycle tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderLifecycle {
@@ -48,6 +75,7 @@ pub struct StateTransition {
 pub struct OrderManager {
     orders: Arc<RwLock<HashMap<String, OrderLifecycle>>>,
     client_id_mapping: Arc<RwLock<HashMap<Uuid, String>>>, // client_id -> order_id
+    event_log: Option<Arc<EventLog>>,
 }
 
 impl OrderManager {
@@ -55,9 +83,61 @@ impl OrderManager {
         Self {
             orders: Arc::new(RwLock::new(HashMap::new())),
             client_id_mapping: Arc::new(RwLock::new(HashMap::new())),
+            event_log: None,
         }
     }
 
+    /// Record every order creation and state transition in `event_log`
+    pub fn with_event_log(mut self, event_log: Arc<EventLog>) -> Self {
+        self.event_log = Some(event_log);
+        self
+    }
+
+    pub fn event_log(&self) -> Option<&Arc<EventLog>> {
+        self.event_log.as_ref()
+    }
+
+    /// Reconstruct order state by replaying `event_log`, which the rebuilt manager keeps appending to.
+    /// Orders compacted out of the log are not restored.
+    pub async fn rebuild_from_log(event_log: Arc<EventLog>) -> Result<Self, TradingError> {
+        let manager = Self::new();
+        {
+            let mut orders = manager.orders.write().await;
+            let mut client_mapping = manager.client_id_mapping.write().await;
+
+            for entry in event_log.entries().await {
+                match entry.record {
+                    OrderEventRecord::Created { client_id, symbol, created_at, expires_at } => {
+                        client_mapping.insert(client_id, entry.order_id.clone());
+                        orders.insert(entry.order_id.clone(), OrderLifecycle {
+                            order_id: entry.order_id,
+                            client_id,
+                            symbol,
+                            state: OrderLifecycleState::Created,
+                            state_history: Vec::new(),
+                            created_at,
+                            updated_at: created_at,
+                            expires_at,
+                            metadata: HashMap::new(),
+                        });
+                    }
+                    OrderEventRecord::Transition(transition) => {
+                        let lifecycle = orders.get_mut(&entry.order_id)
+                            .ok_or_else(|| TradingError::ExecutionError {
+                                message: format!("Event log entry {} references unknown order {}", entry.sequence, entry.order_id),
+                            })?;
+                        manager.validate_state_transition(&lifecycle.state, &transition.to_state)?;
+                        lifecycle.state = transition.to_state.clone();
+                        lifecycle.updated_at = transition.timestamp;
+                        lifecycle.state_history.push(transition);
+                    }
+                }
+            }
+        }
+
+        Ok(manager.with_event_log(event_log))
+    }
+
     /// Create a new order lifecycle
     pub async fn create_order(
         &self,
@@ -83,6 +163,14 @@ impl OrderManager {
         let mut orders = self.orders.write().await;
         let mut client_mapping = self.client_id_mapping.write().await;
         
+        if let Some(event_log) = &self.event_log {
+            event_log.append(&order_id, OrderEventRecord::Created {
+                client_id,
+                symbol: lifecycle.symbol.clone(),
+                created_at: lifecycle.created_at,
+                expires_at,
+            }).await?;
+        }
         orders.insert(order_id.clone(), lifecycle);
         client_mapping.insert(client_id, order_id);
 
@@ -115,9 +203,12 @@ impl OrderManager {
             metadata: metadata.unwrap_or_default(),
         };
 
+        if let Some(event_log) = &self.event_log {
+            event_log.append(order_id, OrderEventRecord::Transition(transition.clone())).await?;
+        }
+        lifecycle.updated_at = transition.timestamp;
         lifecycle.state_history.push(transition);
         lifecycle.state = new_state;
-        lifecycle.updated_at = Utc::now();
 
         Ok(())
     }
@@ -154,6 +245,31 @@ impl OrderManager {
             .collect()
     }
 
+    /// List orders, optionally filtered by state and symbol, oldest first.
+    /// Returns one page of results and the total number of matches.
+    pub async fn list_orders(
+        &self,
+        state: Option<OrderLifecycleState>,
+        symbol: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> (Vec<OrderLifecycle>, usize) {
+        let mut matching = match state {
+            Some(state) => self.get_orders_by_state(state).await,
+            None => self.orders.read().await.values().cloned().collect(),
+        };
+        if let Some(symbol) = symbol {
+            matching.retain(|order| order.symbol == symbol);
+        }
+        matching.sort_by(|a, b| {
+            a.created_at.cmp(&b.created_at).then_with(|| a.order_id.cmp(&b.order_id))
+        });
+
+        let total = matching.len();
+        let page = matching.into_iter().skip(offset).take(limit).collect();
+        (page, total)
+    }
+
     /// Get expired orders
     pub async fn get_expired_orders(&self) -> Vec<OrderLifecycle> {
         let now = Utc::now();
@@ -231,6 +347,7 @@ impl OrderManager {
                 OrderLifecycleState::PartiallyFilled => stats.partially_filled += 1,
                 OrderLifecycleState::Filled => stats.filled += 1,
                 OrderLifecycleState::Cancelled => stats.cancelled += 1,
+                OrderLifecycleState::PartiallyFilledCancelled => stats.partially_filled_cancelled += 1,
                 OrderLifecycleState::Rejected => stats.rejected += 1,
                 OrderLifecycleState::Expired => stats.expired += 1,
                 OrderLifecycleState::Failed => stats.failed += 1,
@@ -252,9 +369,10 @@ impl OrderManager {
             Created => vec![Validated, Rejected, Failed],
             Validated => vec![Submitted, Rejected, Failed],
             Submitted => vec![Acknowledged, Rejected, Failed, Expired],
-            Acknowledged => vec![PartiallyFilled, Filled, Cancelled, Rejected, Failed, Expired],
-            PartiallyFilled => vec![Filled, Cancelled, Failed, Expired],
-            Filled | Cancelled | Rejected | Expired | Failed => vec![], // Terminal states
+            // Fills may first be reported alongside the cancellation
+            Acknowledged => vec![PartiallyFilled, Filled, Cancelled, PartiallyFilledCancelled, Rejected, Failed, Expired],
+            PartiallyFilled => vec![Filled, Cancelled, PartiallyFilledCancelled, Failed, Expired],
+            Filled | Cancelled | PartiallyFilledCancelled | Rejected | Expired | Failed => vec![], // Terminal states
         };
 
         if valid_transitions.contains(to_state) {
@@ -271,14 +389,7 @@ impl OrderManager {
 
     /// Check if state is terminal (no further transitions allowed)
     fn is_terminal_state(&self, state: &OrderLifecycleState) -> bool {
-        matches!(
-            state,
-            OrderLifecycleState::Filled
-                | OrderLifecycleState::Cancelled
-                | OrderLifecycleState::Rejected
-                | OrderLifecycleState::Expired
-                | OrderLifecycleState::Failed
-        )
+        state.is_terminal()
     }
 }
 
@@ -298,6 +409,7 @@ pub struct OrderStatistics {
     pub partially_filled: usize,
     pub filled: usize,
     pub cancelled: usize,
+    pub partially_filled_cancelled: usize,
     pub rejected: usize,
     pub expired: usize,
     pub failed: usize,
@@ -312,6 +424,8 @@ impl From<OrderStatus> for OrderLifecycleState {
             OrderStatus::Filled => OrderLifecycleState::Filled,
             OrderStatus::Cancelled => OrderLifecycleState::Cancelled,
             OrderStatus::Rejected => OrderLifecycleState::Rejected,
+            OrderStatus::Open => OrderLifecycleState::Acknowledged,
+            OrderStatus::Expired => OrderLifecycleState::Expired,
         }
     }
 }
@@ -485,4 +599,86 @@ mod tests {
         let expired_orders = manager.get_expired_orders().await;
         assert_eq!(expired_orders.len(), 1);
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_list_orders_filters_and_paginates() {
+        let manager = OrderManager::new();
+        for i in 0..5 {
+            let symbol = if i % 2 == 0 { "BTCUSD" } else { "ETHUSD" };
+            manager.create_order(format!("order_{}", i), Uuid::new_v4(), symbol.to_string(), None).await.unwrap();
+        }
+        manager.transition_state("order_0", OrderLifecycleState::Validated, "Valid".to_string(), None).await.unwrap();
+        manager.transition_state("order_1", OrderLifecycleState::Validated, "Valid".to_string(), None).await.unwrap();
+        
+        let (orders, total) = manager.list_orders(None, None, 100, 0).await;
+        assert_eq!(total, 5);
+        assert_eq!(orders.len(), 5);
+        
+        let (orders, total) = manager.list_orders(Some(OrderLifecycleState::Validated), None, 100, 0).await;
+        assert_eq!(total, 2);
+        assert!(orders.iter().all(|order| order.state == OrderLifecycleState::Validated));
+        
+        let (orders, total) = manager.list_orders(Some(OrderLifecycleState::Validated), Some("BTCUSD"), 100, 0).await;
+        assert_eq!(total, 1);
+        assert_eq!(orders[0].order_id, "order_0");
+        
+        let (orders, total) = manager.list_orders(None, Some("BTCUSD"), 2, 0).await;
+        assert_eq!(total, 3);
+        assert_eq!(orders.len(), 2);
+        
+        let (last_page, _) = manager.list_orders(None, Some("BTCUSD"), 2, 2).await;
+        assert_eq!(last_page.len(), 1);
+        assert!(orders.iter().all(|order| order.order_id != last_page[0].order_id));
+        
+        let (orders, total) = manager.list_orders(None, None, 2, 10).await;
+        assert_eq!(total, 5);
+        assert!(orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_from_event_log() {
+        use OrderLifecycleState::*;
+
+        let event_log = Arc::new(EventLog::new());
+        let manager = OrderManager::new().with_event_log(event_log.clone());
+        manager.create_order("filled".to_string(), Uuid::new_v4(), "BTCUSD".to_string(), Some(3600)).await.unwrap();
+        manager.create_order("working".to_string(), Uuid::new_v4(), "ETHUSD".to_string(), None).await.unwrap();
+        for state in [Validated, Submitted, Acknowledged, PartiallyFilled, Filled] {
+            manager.transition_state("filled", state, "Exchange update".to_string(), None).await.unwrap();
+        }
+        for state in [Validated, Submitted, Acknowledged] {
+            manager.transition_state("working", state, "Exchange update".to_string(), None).await.unwrap();
+        }
+        // Rejected transitions are not logged
+        assert!(manager.transition_state("working", Created, "Invalid".to_string(), None).await.is_err());
+
+        let entries = event_log.entries().await;
+        assert_eq!(entries.len(), 10);
+        assert!(entries.windows(2).all(|pair| pair[0].sequence < pair[1].sequence));
+
+        let rebuilt = OrderManager::rebuild_from_log(event_log.clone()).await.unwrap();
+        for order_id in ["filled", "working"] {
+            let original = manager.get_order(order_id).await.unwrap();
+            let replayed = rebuilt.get_order(order_id).await.unwrap();
+            assert_eq!(replayed.state, original.state);
+            assert_eq!(replayed.client_id, original.client_id);
+            assert_eq!(replayed.symbol, original.symbol);
+            assert_eq!(replayed.created_at, original.created_at);
+            assert_eq!(replayed.updated_at, original.updated_at);
+            assert_eq!(replayed.expires_at, original.expires_at);
+            let states = |order: &OrderLifecycle| order.state_history.iter().map(|t| t.to_state.clone()).collect::<Vec<_>>();
+            assert_eq!(states(&replayed), states(&original));
+        }
+        let working = manager.get_order("working").await.unwrap();
+        assert!(rebuilt.order_exists_by_client_id(&working.client_id).await);
+
+        // The rebuilt manager keeps appending to the same log
+        rebuilt.transition_state("working", Filled, "Exchange update".to_string(), None).await.unwrap();
+        assert_eq!(event_log.last_sequence().await, 11);
+
+        // Compaction drops both now-terminal orders entirely
+        assert_eq!(event_log.compact().await.unwrap(), 11);
+        let rebuilt = OrderManager::rebuild_from_log(event_log).await.unwrap();
+        assert_eq!(rebuilt.get_statistics().await.total_orders, 0);
+    }
+}
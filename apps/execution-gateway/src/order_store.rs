@@ -0,0 +1,372 @@
+use async_trait::async_trait;
+use rust_common::{ExecutionResult, TradingError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::warn;
+use uuid::Uuid;
+
+use super::event_log::sync_parent_dir;
+use super::OrderExecution;
+
+/// Durable storage for order state, so tracking and idempotency survive restarts
+#[async_trait]
+pub trait OrderStore: Send + Sync {
+    /// Insert or replace an order, keyed by client ID
+    async fn save_order(&self, order: &OrderExecution) -> Result<(), TradingError>;
+
+    async fn load_order(&self, client_id: &Uuid) -> Result<Option<OrderExecution>, TradingError>;
+
+    /// Every order not yet removed, mirroring the gateway's active order map
+    async fn load_active(&self) -> Result<Vec<OrderExecution>, TradingError>;
+
+    /// Record that a decision's client ID was placed as `order_id`
+    async fn save_dedup_mapping(&self, client_id: Uuid, order_id: &str) -> Result<(), TradingError>;
+
+    async fn load_dedup_mappings(&self) -> Result<HashMap<Uuid, String>, TradingError>;
+
+    /// Insert or replace the final result of an order, keyed by order ID
+    async fn save_result(&self, result: &ExecutionResult) -> Result<(), TradingError>;
+
+    async fn load_result(&self, order_id: &str) -> Result<Option<ExecutionResult>, TradingError>;
+
+    /// Drop an order together with its dedup mapping and result
+    async fn remove_order(&self, client_id: &Uuid) -> Result<(), TradingError>;
+}
+
+/// Everything an order store holds
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct StoreState {
+    orders: HashMap<Uuid, OrderExecution>,
+    dedup: HashMap<Uuid, String>,
+    results: HashMap<String, ExecutionResult>,
+}
+
+impl StoreState {
+    fn remove_order(&mut self, client_id: &Uuid) {
+        if let Some(order) = self.orders.remove(client_id) {
+            self.results.remove(&order.order_id);
+        }
+        self.dedup.remove(client_id);
+    }
+}
+
+/// Order store that lives only as long as the process; the gateway's default
+#[derive(Default)]
+pub struct InMemoryOrderStore {
+    state: Mutex<StoreState>,
+}
+
+impl InMemoryOrderStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OrderStore for InMemoryOrderStore {
+    async fn save_order(&self, order: &OrderExecution) -> Result<(), TradingError> {
+        self.state.lock().await.orders.insert(order.client_id, order.clone());
+        Ok(())
+    }
+
+    async fn load_order(&self, client_id: &Uuid) -> Result<Option<OrderExecution>, TradingError> {
+        Ok(self.state.lock().await.orders.get(client_id).cloned())
+    }
+
+    async fn load_active(&self) -> Result<Vec<OrderExecution>, TradingError> {
+        Ok(self.state.lock().await.orders.values().cloned().collect())
+    }
+
+    async fn save_dedup_mapping(&self, client_id: Uuid, order_id: &str) -> Result<(), TradingError> {
+        self.state.lock().await.dedup.insert(client_id, order_id.to_string());
+        Ok(())
+    }
+
+    async fn load_dedup_mappings(&self) -> Result<HashMap<Uuid, String>, TradingError> {
+        Ok(self.state.lock().await.dedup.clone())
+    }
+
+    async fn save_result(&self, result: &ExecutionResult) -> Result<(), TradingError> {
+        self.state.lock().await.results.insert(result.order_id.clone(), result.clone());
+        Ok(())
+    }
+
+    async fn load_result(&self, order_id: &str) -> Result<Option<ExecutionResult>, TradingError> {
+        Ok(self.state.lock().await.results.get(order_id).cloned())
+    }
+
+    async fn remove_order(&self, client_id: &Uuid) -> Result<(), TradingError> {
+        self.state.lock().await.remove_order(client_id);
+        Ok(())
+    }
+}
+
+/// One line of a [`FileOrderStore`] journal
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StoreChange {
+    /// Written first by compaction; everything held at that point
+    Snapshot(StoreState),
+    Order(OrderExecution),
+    Dedup { client_id: Uuid, order_id: String },
+    Result(ExecutionResult),
+    Remove { client_id: Uuid },
+}
+
+impl StoreChange {
+    fn apply(self, state: &mut StoreState) {
+        match self {
+            StoreChange::Snapshot(snapshot) => *state = snapshot,
+            StoreChange::Order(order) => {
+                state.orders.insert(order.client_id, order);
+            }
+            StoreChange::Dedup { client_id, order_id } => {
+                state.dedup.insert(client_id, order_id);
+            }
+            StoreChange::Result(result) => {
+                state.results.insert(result.order_id.clone(), result);
+            }
+            StoreChange::Remove { client_id } => state.remove_order(&client_id),
+        }
+    }
+}
+
+/// Journal lines below which a store is never compacted
+const MIN_COMPACTION_LINES: usize = 1024;
+
+struct Journal {
+    state: StoreState,
+    file: File,
+    /// Bytes of whole lines in the file, so a failed append can be cut off again
+    len: u64,
+    lines: usize,
+}
+
+/// Order store persisted as an append-only journal of JSON lines.
+///
+/// Each change is written and synced to disk before it is applied in memory, so a
+/// change the caller saw succeed survives a crash. The journal is rewritten as a
+/// single snapshot on open and whenever it has grown to twice the state it holds.
+pub struct FileOrderStore {
+    path: PathBuf,
+    journal: Mutex<Journal>,
+}
+
+impl FileOrderStore {
+    /// Open the store at `path`, loading any state saved by a previous run.
+    ///
+    /// A torn final line left by a crash mid-append is dropped; any other unreadable line is an error.
+    pub async fn open(path: impl AsRef<Path>) -> Result<Self, TradingError> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(io_error("read", &path, e)),
+        };
+
+        let mut state = StoreState::default();
+        let lines: Vec<&str> = contents.lines().filter(|line| !line.trim().is_empty()).collect();
+        for (index, line) in lines.iter().enumerate() {
+            match serde_json::from_str::<StoreChange>(line) {
+                Ok(change) => change.apply(&mut state),
+                Err(e) if index + 1 == lines.len() && !contents.ends_with('\n') => {
+                    warn!("Dropping torn last change of order store {}: {}", path.display(), e);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        // Start from a compacted journal so it stays bounded across restarts
+        let (file, len) = Self::write_snapshot(&path, &state).await?;
+        Ok(Self {
+            path,
+            journal: Mutex::new(Journal { state, file, len, lines: 1 }),
+        })
+    }
+
+    /// Replace the file at `path` with a single snapshot line and open it for appending
+    async fn write_snapshot(path: &Path, state: &StoreState) -> Result<(File, u64), TradingError> {
+        let mut contents = serde_json::to_vec(&StoreChange::Snapshot(state.clone()))?;
+        contents.push(b'\n');
+
+        let temp_path = path.with_extension("tmp");
+        let mut temp_file = File::create(&temp_path).await.map_err(|e| io_error("write", &temp_path, e))?;
+        temp_file.write_all(&contents).await.map_err(|e| io_error("write", &temp_path, e))?;
+        temp_file.sync_all().await.map_err(|e| io_error("sync", &temp_path, e))?;
+        tokio::fs::rename(&temp_path, path).await.map_err(|e| io_error("replace", path, e))?;
+        sync_parent_dir(path).await.map_err(|e| io_error("sync the directory of", path, e))?;
+
+        let file = OpenOptions::new()
+            .append(true)
+            .open(path)
+            .await
+            .map_err(|e| io_error("open", path, e))?;
+        Ok((file, contents.len() as u64))
+    }
+
+    /// Append a change to the journal and apply it once it is on disk
+    async fn update(&self, change: StoreChange) -> Result<(), TradingError> {
+        let mut journal = self.journal.lock().await;
+        let mut line = serde_json::to_vec(&change)?;
+        line.push(b'\n');
+
+        let written = async {
+            journal.file.write_all(&line).await?;
+            journal.file.sync_data().await
+        }
+        .await;
+        if let Err(e) = written {
+            // Later appends must not land after a partial line
+            if let Err(truncate_error) = journal.file.set_len(journal.len).await {
+                warn!("Failed to cut a partial change off order store {}: {}", self.path.display(), truncate_error);
+            }
+            return Err(io_error("append to", &self.path, e));
+        }
+        journal.len += line.len() as u64;
+        journal.lines += 1;
+        change.apply(&mut journal.state);
+
+        let live = journal.state.orders.len() + journal.state.dedup.len() + journal.state.results.len();
+        if journal.lines > MIN_COMPACTION_LINES && journal.lines > 2 * live {
+            // The change is already durable, so a failed compaction only leaves a longer journal
+            match Self::write_snapshot(&self.path, &journal.state).await {
+                Ok((file, len)) => {
+                    journal.file = file;
+                    journal.len = len;
+                    journal.lines = 1;
+                }
+                Err(e) => warn!("Failed to compact order store {}: {}", self.path.display(), e),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OrderStore for FileOrderStore {
+    async fn save_order(&self, order: &OrderExecution) -> Result<(), TradingError> {
+        self.update(StoreChange::Order(order.clone())).await
+    }
+
+    async fn load_order(&self, client_id: &Uuid) -> Result<Option<OrderExecution>, TradingError> {
+        Ok(self.journal.lock().await.state.orders.get(client_id).cloned())
+    }
+
+    async fn load_active(&self) -> Result<Vec<OrderExecution>, TradingError> {
+        Ok(self.journal.lock().await.state.orders.values().cloned().collect())
+    }
+
+    async fn save_dedup_mapping(&self, client_id: Uuid, order_id: &str) -> Result<(), TradingError> {
+        self.update(StoreChange::Dedup { client_id, order_id: order_id.to_string() }).await
+    }
+
+    async fn load_dedup_mappings(&self) -> Result<HashMap<Uuid, String>, TradingError> {
+        Ok(self.journal.lock().await.state.dedup.clone())
+    }
+
+    async fn save_result(&self, result: &ExecutionResult) -> Result<(), TradingError> {
+        self.update(StoreChange::Result(result.clone())).await
+    }
+
+    async fn load_result(&self, order_id: &str) -> Result<Option<ExecutionResult>, TradingError> {
+        Ok(self.journal.lock().await.state.results.get(order_id).cloned())
+    }
+
+    async fn remove_order(&self, client_id: &Uuid) -> Result<(), TradingError> {
+        self.update(StoreChange::Remove { client_id: *client_id }).await
+    }
+}
+
+fn io_error(action: &str, path: &Path, e: std::io::Error) -> TradingError {
+    TradingError::ExecutionError {
+        message: format!("Failed to {} order store {}: {}", action, path.display(), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OrderExecutionStatus;
+    use chrono::Utc;
+
+    fn create_order() -> OrderExecution {
+        OrderExecution {
+            order_id: Uuid::new_v4().to_string(),
+            client_id: Uuid::new_v4(),
+            exchange: "default".to_string(),
+            status: OrderExecutionStatus::Pending,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            retry_count: 0,
+            partial_fills: Vec::new(),
+            total_filled: 0.0,
+            average_price: None,
+            linked_order_id: None,
+            requested_quantity: 0.0,
+            direction: None,
+            reference_price: None,
+            symbol: "BTCUSD".to_string(),
+            reserved_risk_amount: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_store_reloads_saved_state() {
+        let path = std::env::temp_dir().join(format!("order_store_{}.json", Uuid::new_v4()));
+        let order = create_order();
+        let mut result = ExecutionResult::new(order.client_id.to_string(), order.order_id.clone());
+        result.filled_quantity = 0.1;
+
+        {
+            let store = FileOrderStore::open(&path).await.unwrap();
+            store.save_order(&order).await.unwrap();
+            store.save_dedup_mapping(order.client_id, &order.order_id).await.unwrap();
+            store.save_result(&result).await.unwrap();
+        }
+
+        let store = FileOrderStore::open(&path).await.unwrap();
+        assert_eq!(store.load_order(&order.client_id).await.unwrap().unwrap().order_id, order.order_id);
+        assert_eq!(store.load_active().await.unwrap().len(), 1);
+        assert_eq!(store.load_dedup_mappings().await.unwrap()[&order.client_id], order.order_id);
+        assert_eq!(store.load_result(&order.order_id).await.unwrap().unwrap().filled_quantity, 0.1);
+
+        store.remove_order(&order.client_id).await.unwrap();
+        let store = FileOrderStore::open(&path).await.unwrap();
+        assert!(store.load_active().await.unwrap().is_empty());
+        assert!(store.load_dedup_mappings().await.unwrap().is_empty());
+        assert!(store.load_result(&order.order_id).await.unwrap().is_none());
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_file_store_journal_drops_torn_change() {
+        let path = std::env::temp_dir().join(format!("order_store_{}.json", Uuid::new_v4()));
+        let order = create_order();
+        let second = create_order();
+
+        let store = FileOrderStore::open(&path).await.unwrap();
+        store.save_order(&order).await.unwrap();
+        store.save_order(&second).await.unwrap();
+        drop(store);
+
+        // A crash mid-append leaves a torn line that is dropped on the next open
+        let mut contents = tokio::fs::read_to_string(&path).await.unwrap();
+        assert_eq!(contents.lines().count(), 3);
+        contents.push_str("{\"type\":\"remove\",\"client_id\":\"");
+        tokio::fs::write(&path, contents).await.unwrap();
+        let store = FileOrderStore::open(&path).await.unwrap();
+        assert_eq!(store.load_active().await.unwrap().len(), 2);
+        store.remove_order(&order.client_id).await.unwrap();
+        drop(store);
+
+        let store = FileOrderStore::open(&path).await.unwrap();
+        assert_eq!(store.load_active().await.unwrap()[0].client_id, second.client_id);
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
@@ -0,0 +1,245 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, RwLock};
+use tracing::warn;
+use uuid::Uuid;
+
+use super::{OrderExecution, OrderExecutionStatus};
+
+/// Latest known state of an order, pushed to subscribers on every change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderUpdate {
+    pub order_id: String,
+    pub client_id: String,
+    pub exchange: String,
+    pub status: OrderExecutionStatus,
+    pub total_filled: f64,
+    pub average_price: Option<f64>,
+    pub fill_count: usize,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&OrderExecution> for OrderUpdate {
+    fn from(order_execution: &OrderExecution) -> Self {
+        Self {
+            order_id: order_execution.order_id.clone(),
+            client_id: order_execution.client_id.to_string(),
+            exchange: order_execution.exchange.clone(),
+            status: order_execution.status.clone(),
+            total_filled: order_execution.total_filled,
+            average_price: order_execution.average_price,
+            fill_count: order_execution.partial_fills.len(),
+            updated_at: order_execution.updated_at,
+        }
+    }
+}
+
+/// Updates collected during one coalescing window, latest state per order
+#[derive(Default)]
+struct CoalescedBatch {
+    updates: Vec<OrderUpdate>,
+    positions: HashMap<String, usize>,
+}
+
+impl CoalescedBatch {
+    fn push(&mut self, update: OrderUpdate) {
+        match self.positions.get(&update.order_id) {
+            Some(&position) => self.updates[position] = update,
+            None => {
+                self.positions.insert(update.order_id.clone(), self.updates.len());
+                self.updates.push(update);
+            }
+        }
+    }
+
+    fn flush(self, output: &mpsc::UnboundedSender<OrderUpdate>) -> bool {
+        self.updates.into_iter().all(|update| output.send(update).is_ok())
+    }
+}
+
+/// Merge updates for the same order arriving within `window` into a single
+/// latest-state message. If the subscriber falls behind, every order in
+/// `orders` changed since the last update seen is re-sent from its current
+/// state. The stream ends when the source closes or the returned receiver
+/// is dropped.
+pub fn coalesce_order_updates(
+    updates: broadcast::Receiver<OrderUpdate>,
+    orders: Arc<RwLock<HashMap<Uuid, OrderExecution>>>,
+    window: Duration,
+) -> mpsc::UnboundedReceiver<OrderUpdate> {
+    let (output, coalesced) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let mut source = UpdateSource { updates, orders, last_seen: None };
+
+        // The first updates of each batch open the window
+        while let Some(first) = source.next().await {
+            let mut batch = CoalescedBatch::default();
+            first.into_iter().for_each(|update| batch.push(update));
+
+            let deadline = tokio::time::Instant::now() + window;
+            let mut source_closed = false;
+            loop {
+                match tokio::time::timeout_at(deadline, source.next()).await {
+                    Ok(Some(updates)) => updates.into_iter().for_each(|update| batch.push(update)),
+                    Ok(None) => {
+                        source_closed = true;
+                        break;
+                    }
+                    Err(_) => break, // Window elapsed
+                }
+            }
+
+            if !batch.flush(&output) || source_closed {
+                return;
+            }
+        }
+    });
+
+    coalesced
+}
+
+/// Broadcast updates, backfilled from the tracked orders when the subscriber lags
+struct UpdateSource {
+    updates: broadcast::Receiver<OrderUpdate>,
+    orders: Arc<RwLock<HashMap<Uuid, OrderExecution>>>,
+    /// Change time of the last update received; `None` until the first arrives
+    last_seen: Option<DateTime<Utc>>,
+}
+
+impl UpdateSource {
+    /// The next update, or a snapshot of every order that may have changed unseen
+    async fn next(&mut self) -> Option<Vec<OrderUpdate>> {
+        loop {
+            match self.updates.recv().await {
+                Ok(update) => {
+                    self.last_seen = Some(update.updated_at);
+                    return Some(vec![update]);
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    // A skipped update may have been an order's last, so re-read what changed
+                    warn!("Order update subscriber lagged, skipped {} updates; resending current state", skipped);
+                    let snapshot: Vec<OrderUpdate> = self.orders.read().await.values()
+                        .filter(|order| self.last_seen.map_or(true, |last_seen| order.updated_at >= last_seen))
+                        .map(OrderUpdate::from)
+                        .collect();
+                    if !snapshot.is_empty() {
+                        return Some(snapshot);
+                    }
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_update(order_id: &str, total_filled: f64, status: OrderExecutionStatus) -> OrderUpdate {
+        OrderUpdate {
+            order_id: order_id.to_string(),
+            client_id: uuid::Uuid::new_v4().to_string(),
+            exchange: "default".to_string(),
+            status,
+            total_filled,
+            average_price: Some(50000.0),
+            fill_count: 0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rapid_partial_fills_are_coalesced() {
+        let (sender, receiver) = broadcast::channel(100);
+        let mut coalesced = coalesce_order_updates(receiver, Arc::default(), Duration::from_millis(50));
+
+        let fill_count = 20;
+        for i in 1..=fill_count {
+            let status = if i == fill_count {
+                OrderExecutionStatus::Filled
+            } else {
+                OrderExecutionStatus::PartiallyFilled
+            };
+            sender.send(create_update("order-1", i as f64 * 0.01, status)).unwrap();
+        }
+        drop(sender);
+
+        let mut messages = Vec::new();
+        while let Some(update) = coalesced.recv().await {
+            messages.push(update);
+        }
+
+        assert!(!messages.is_empty());
+        assert!(messages.len() < fill_count);
+
+        let final_state = messages.last().unwrap();
+        assert!(matches!(final_state.status, OrderExecutionStatus::Filled));
+        assert!((final_state.total_filled - 0.2).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_coalescing_keeps_distinct_orders() {
+        let (sender, receiver) = broadcast::channel(100);
+        let mut coalesced = coalesce_order_updates(receiver, Arc::default(), Duration::from_millis(50));
+
+        sender.send(create_update("order-1", 0.05, OrderExecutionStatus::PartiallyFilled)).unwrap();
+        sender.send(create_update("order-2", 0.10, OrderExecutionStatus::Filled)).unwrap();
+        sender.send(create_update("order-1", 0.10, OrderExecutionStatus::Filled)).unwrap();
+        drop(sender);
+
+        let mut messages = Vec::new();
+        while let Some(update) = coalesced.recv().await {
+            messages.push(update);
+        }
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].order_id, "order-1");
+        assert!((messages[0].total_filled - 0.10).abs() < 1e-9);
+        assert_eq!(messages[1].order_id, "order-2");
+    }
+
+    #[tokio::test]
+    async fn test_lagged_subscriber_receives_skipped_final_state() {
+        let (sender, receiver) = broadcast::channel(2);
+        let filled = OrderExecution {
+            order_id: "order-2".to_string(),
+            client_id: uuid::Uuid::new_v4(),
+            exchange: "default".to_string(),
+            status: OrderExecutionStatus::Filled,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            retry_count: 0,
+            partial_fills: Vec::new(),
+            total_filled: 0.1,
+            average_price: Some(50000.0),
+            linked_order_id: None,
+            requested_quantity: 0.1,
+            direction: None,
+            reference_price: None,
+            symbol: "BTCUSD".to_string(),
+            reserved_risk_amount: None,
+        };
+        let orders = Arc::new(RwLock::new(HashMap::from([(filled.client_id, filled)])));
+
+        // order-2's only update is pushed out of the buffer before the subscriber reads it
+        sender.send(create_update("order-2", 0.1, OrderExecutionStatus::Filled)).unwrap();
+        sender.send(create_update("order-1", 0.05, OrderExecutionStatus::PartiallyFilled)).unwrap();
+        sender.send(create_update("order-1", 0.10, OrderExecutionStatus::PartiallyFilled)).unwrap();
+        let mut coalesced = coalesce_order_updates(receiver, orders, Duration::from_millis(50));
+        drop(sender);
+
+        let mut messages = Vec::new();
+        while let Some(update) = coalesced.recv().await {
+            messages.push(update);
+        }
+
+        let order_2 = messages.iter().find(|update| update.order_id == "order-2").expect("Skipped order was not resent");
+        assert!(matches!(order_2.status, OrderExecutionStatus::Filled));
+        assert!(messages.iter().any(|update| update.order_id == "order-1"));
+    }
+}
@@ -0,0 +1,235 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_common::{round_f64_to_increment, OrderRequest, OrderSide, OrderStatus, OrderType, RejectReason, RoundingMode, TradingError};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::{AccountInfo, AdapterCapabilities, AdapterOrderResult, CommissionModel, ExchangeAdapter, ExchangeInfo};
+
+/// Simulated exchange that fills orders against a price set by the caller.
+///
+/// Market orders fill in full at the current price. Limit orders fill at the current
+/// price when marketable and otherwise rest as `Pending`; resting orders never fill later.
+pub struct PaperExchangeAdapter {
+    pub exchange_info: ExchangeInfo,
+    pub commission_model: CommissionModel,
+    pub balance: f64,
+    price: Mutex<Option<f64>>,
+    orders: Mutex<HashMap<String, OrderStatus>>,
+}
+
+impl PaperExchangeAdapter {
+    pub fn new() -> Self {
+        Self {
+            exchange_info: ExchangeInfo {
+                name: "PaperExchange".to_string(),
+                tick_size: 0.01,
+                lot_size: 0.001,
+                min_order_size: 0.001,
+                max_order_size: 1_000_000.0,
+                min_price: 0.01,
+                max_price: 10_000_000.0,
+                trading_hours: Vec::new(),
+                supported_order_types: vec!["market".to_string(), "limit".to_string()],
+            },
+            commission_model: CommissionModel::default(),
+            balance: 100_000.0,
+            price: Mutex::new(None),
+            orders: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_commission_model(mut self, commission_model: CommissionModel) -> Self {
+        self.commission_model = commission_model;
+        self
+    }
+
+    pub fn with_balance(mut self, balance: f64) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    /// Set the price the next orders fill at
+    pub fn set_price(&self, price: f64) {
+        *self.price.lock().unwrap() = Some(price);
+    }
+
+    pub fn price(&self) -> Option<f64> {
+        *self.price.lock().unwrap()
+    }
+}
+
+impl Default for PaperExchangeAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ExchangeAdapter for PaperExchangeAdapter {
+    async fn ping(&self) -> Result<(), TradingError> {
+        Ok(())
+    }
+
+    async fn get_exchange_info(&self, _symbol: &str) -> Result<ExchangeInfo, TradingError> {
+        Ok(self.exchange_info.clone())
+    }
+
+    async fn place_order(&self, order: OrderRequest) -> Result<AdapterOrderResult, TradingError> {
+        self.validate_order(&order).await?;
+        let price = self.price().ok_or_else(|| TradingError::ExecutionError {
+            message: format!("No paper price set for {}", order.symbol),
+        })?;
+
+        let marketable = match (order.order_type, order.price) {
+            (OrderType::Market, _) => true,
+            (OrderType::Limit, Some(limit)) => match order.side {
+                OrderSide::Buy => limit >= price,
+                OrderSide::Sell => limit <= price,
+            },
+            (order_type, _) => {
+                return Err(TradingError::OrderRejected {
+                    reason: RejectReason::Unknown,
+                    message: format!("Paper exchange does not support {:?} orders", order_type),
+                });
+            }
+        };
+
+        let order_id = order.id.to_string();
+        let result = if marketable {
+            AdapterOrderResult {
+                order_id: order_id.clone(),
+                status: OrderStatus::Filled,
+                filled_quantity: order.size,
+                average_price: Some(price),
+                commission: self.commission_model.commission(order.size, price, false),
+                filled_at: Some(Utc::now()),
+                partial_fills: Vec::new(),
+            }
+        } else {
+            AdapterOrderResult {
+                order_id: order_id.clone(),
+                status: OrderStatus::Pending,
+                filled_quantity: 0.0,
+                average_price: None,
+                commission: 0.0,
+                filled_at: None,
+                partial_fills: Vec::new(),
+            }
+        };
+        self.orders.lock().unwrap().insert(order_id, result.status);
+        Ok(result)
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> Result<(), TradingError> {
+        let mut orders = self.orders.lock().unwrap();
+        match orders.get_mut(order_id) {
+            Some(status) if *status == OrderStatus::Pending => {
+                *status = OrderStatus::Cancelled;
+                Ok(())
+            }
+            Some(status) => Err(TradingError::ExecutionError {
+                message: format!("Cannot cancel paper order {} in status {:?}", order_id, status),
+            }),
+            None => Err(TradingError::ExecutionError {
+                message: format!("Unknown paper order: {}", order_id),
+            }),
+        }
+    }
+
+    async fn get_order_status(&self, order_id: &str) -> Result<OrderStatus, TradingError> {
+        self.orders.lock().unwrap().get(order_id).copied().ok_or_else(|| TradingError::ExecutionError {
+            message: format!("Unknown paper order: {}", order_id),
+        })
+    }
+
+    async fn amend_order(&self, _order_id: &str, _new_price: Option<f64>, _new_quantity: Option<f64>) -> Result<(), TradingError> {
+        Err(TradingError::ExecutionError {
+            message: "Paper exchange does not support amendments".to_string(),
+        })
+    }
+
+    async fn get_account_info(&self) -> Result<AccountInfo, TradingError> {
+        Ok(AccountInfo {
+            account_id: "paper".to_string(),
+            total_balance: self.balance,
+            available_balance: self.balance,
+            margin_used: 0.0,
+            margin_available: self.balance,
+            positions: Vec::new(),
+        })
+    }
+
+    async fn get_mark_price(&self, _symbol: &str) -> Result<Option<f64>, TradingError> {
+        Ok(self.price())
+    }
+
+    fn commission_model(&self) -> CommissionModel {
+        self.commission_model
+    }
+
+    /// Market and limit orders only, with nothing resting to amend or link
+    fn capabilities(&self) -> AdapterCapabilities {
+        AdapterCapabilities {
+            order_types: [OrderType::Market, OrderType::Limit].into_iter().collect(),
+            supports_amend: false,
+            supports_oco: false,
+            supports_post_only: false,
+            ..AdapterCapabilities::default()
+        }
+    }
+
+    async fn validate_order(&self, order: &OrderRequest) -> Result<(), TradingError> {
+        if !order.size.is_finite() || order.size <= 0.0 {
+            return Err(TradingError::OrderRejected {
+                reason: RejectReason::SizeOutOfBounds,
+                message: format!("Invalid order size: {}", order.size),
+            });
+        }
+        Ok(())
+    }
+
+    fn round_price(&self, price: f64, tick_size: f64) -> f64 {
+        round_f64_to_increment(price, tick_size, RoundingMode::HalfEven)
+    }
+
+    fn round_quantity(&self, quantity: f64, lot_size: f64) -> f64 {
+        let snapped = round_f64_to_increment(quantity, 1e-9, RoundingMode::HalfEven);
+        round_f64_to_increment(snapped, lot_size, RoundingMode::Down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_common::TimeInForce;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_paper_limit_order_rests_until_marketable() {
+        let adapter = PaperExchangeAdapter::new();
+        adapter.set_price(100.0);
+        let order = |price: f64| OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size: 1.0,
+            price: Some(price),
+            order_type: OrderType::Limit,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        };
+
+        let resting = adapter.place_order(order(95.0)).await.unwrap();
+        assert_eq!(resting.status, OrderStatus::Pending);
+        adapter.cancel_order(&resting.order_id).await.unwrap();
+        assert_eq!(adapter.get_order_status(&resting.order_id).await.unwrap(), OrderStatus::Cancelled);
+
+        let filled = adapter.place_order(order(101.0)).await.unwrap();
+        assert_eq!(filled.status, OrderStatus::Filled);
+        assert_eq!(filled.average_price, Some(100.0));
+        assert!(adapter.cancel_order(&filled.order_id).await.is_err());
+    }
+}
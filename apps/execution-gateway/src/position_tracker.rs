@@ -0,0 +1,333 @@
+use chrono::{DateTime, Utc};
+use rust_common::{FundingRate, OrderSide, Symbol};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::PartialFill;
+
+/// Size below which a position is considered flat
+const FLAT_EPSILON: f64 = 1e-9;
+
+/// Net position in a symbol built up from fills
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedPosition {
+    /// Symbol as first traded; fills in any equivalent spelling net into the same position
+    pub symbol: String,
+    /// Signed size: positive is long, negative is short
+    pub net_size: f64,
+    pub average_entry_price: f64,
+    pub realized_pnl: f64,
+    pub mark_price: Option<f64>,
+    /// Unrealized PnL at `mark_price`, if one is known
+    pub unrealized_pnl: Option<f64>,
+    /// Net funding paid on a perpetual, already included in `realized_pnl`; negative when received
+    pub funding_paid: f64,
+    pub fill_count: usize,
+}
+
+impl TrackedPosition {
+    fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            net_size: 0.0,
+            average_entry_price: 0.0,
+            realized_pnl: 0.0,
+            mark_price: None,
+            unrealized_pnl: None,
+            funding_paid: 0.0,
+            fill_count: 0,
+        }
+    }
+
+    fn apply(&mut self, signed_quantity: f64, price: f64) {
+        let new_size = self.net_size + signed_quantity;
+
+        if self.net_size.abs() < FLAT_EPSILON || self.net_size.signum() == signed_quantity.signum() {
+            // Opening or adding: blend the entry price
+            let total = self.net_size.abs() + signed_quantity.abs();
+            self.average_entry_price =
+                (self.net_size.abs() * self.average_entry_price + signed_quantity.abs() * price) / total;
+        } else {
+            // Reducing: realize PnL on the closed part, flipping if the fill overshoots
+            let closed = signed_quantity.abs().min(self.net_size.abs());
+            self.realized_pnl += closed * (price - self.average_entry_price) * self.net_size.signum();
+            if new_size.abs() < FLAT_EPSILON {
+                self.average_entry_price = 0.0;
+            } else if new_size.signum() != self.net_size.signum() {
+                self.average_entry_price = price;
+            }
+        }
+
+        self.net_size = if new_size.abs() < FLAT_EPSILON { 0.0 } else { new_size };
+        self.fill_count += 1;
+        self.refresh_unrealized();
+    }
+
+    fn accrue_funding(&mut self, funding: &FundingRate) -> f64 {
+        if self.net_size.abs() < FLAT_EPSILON {
+            return 0.0;
+        }
+        let price = self.mark_price.unwrap_or(self.average_entry_price);
+        let payment = funding.payment(self.net_size, price);
+        self.realized_pnl += payment;
+        self.funding_paid -= payment;
+        payment
+    }
+
+    fn refresh_unrealized(&mut self) {
+        self.unrealized_pnl = self.mark_price
+            .map(|mark_price| self.net_size * (mark_price - self.average_entry_price));
+    }
+}
+
+/// Aggregates fills into per-symbol positions with realized and unrealized PnL.
+/// Symbols are keyed by their normalized form, so `BTC-USD` and `btcusd` net together.
+#[derive(Debug, Default)]
+pub struct PositionTracker {
+    positions: HashMap<Symbol, TrackedPosition>,
+    /// Latest funding rate per symbol, waiting for its funding time
+    pending_funding: HashMap<Symbol, FundingRate>,
+    /// Funding time last settled per symbol
+    last_funding_time: HashMap<Symbol, DateTime<Utc>>,
+}
+
+impl PositionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a fill on `side` to the position in `symbol`
+    pub fn apply_fill(&mut self, symbol: &str, side: &OrderSide, fill: &PartialFill) {
+        if fill.quantity <= 0.0 {
+            return;
+        }
+        let signed_quantity = match side {
+            OrderSide::Buy => fill.quantity,
+            OrderSide::Sell => -fill.quantity,
+        };
+        self.positions
+            .entry(Symbol::from(symbol))
+            .or_insert_with(|| TrackedPosition::new(symbol))
+            .apply(signed_quantity, fill.price);
+    }
+
+    /// Revalue the position in `symbol` at a new mark price
+    pub fn update_mark_price(&mut self, symbol: &str, mark_price: f64) {
+        if let Some(position) = self.positions.get_mut(&Symbol::from(symbol)) {
+            position.mark_price = Some(mark_price);
+            position.refresh_unrealized();
+        }
+    }
+
+    /// Record the latest funding rate for its symbol, settling any rate already due by `now`.
+    /// Returns the funding received, negative when paid.
+    pub fn update_funding_rate(&mut self, funding: FundingRate, now: DateTime<Utc>) -> f64 {
+        // The rate being replaced may have come due since it was recorded
+        let settled = self.settle_funding(now);
+        let symbol = Symbol::from(&funding.symbol);
+        let already_settled = self.last_funding_time
+            .get(&symbol)
+            .is_some_and(|settled| funding.next_funding_time <= *settled);
+        if already_settled {
+            return settled;
+        }
+        self.pending_funding.insert(symbol, funding);
+        settled + self.settle_funding(now)
+    }
+
+    /// Accrue every pending funding rate whose funding time has passed into realized PnL.
+    /// Returns the funding received, negative when paid.
+    pub fn settle_funding(&mut self, now: DateTime<Utc>) -> f64 {
+        let due: Vec<FundingRate> = self.pending_funding.values()
+            .filter(|funding| funding.next_funding_time <= now)
+            .cloned()
+            .collect();
+        let mut received = 0.0;
+        for funding in due {
+            let symbol = Symbol::from(&funding.symbol);
+            self.pending_funding.remove(&symbol);
+            self.last_funding_time.insert(symbol.clone(), funding.next_funding_time);
+            if let Some(position) = self.positions.get_mut(&symbol) {
+                received += position.accrue_funding(&funding);
+            }
+        }
+        received
+    }
+
+    /// Realized PnL summed over every symbol
+    pub fn total_realized_pnl(&self) -> f64 {
+        self.positions.values().map(|position| position.realized_pnl).sum()
+    }
+
+    pub fn get_position(&self, symbol: &str) -> Option<TrackedPosition> {
+        self.positions.get(&Symbol::from(symbol)).cloned()
+    }
+
+    /// All tracked positions, including flat ones with realized PnL, sorted by symbol
+    pub fn positions(&self) -> Vec<TrackedPosition> {
+        let mut positions: Vec<TrackedPosition> = self.positions.values().cloned().collect();
+        positions.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        positions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn fill(quantity: f64, price: f64) -> PartialFill {
+        PartialFill {
+            fill_id: "fill".to_string(),
+            quantity,
+            price,
+            timestamp: Utc::now(),
+            commission: 0.0,
+        }
+    }
+
+    fn funding(symbol: &str, rate: f64, next_funding_time: DateTime<Utc>) -> FundingRate {
+        FundingRate {
+            symbol: symbol.to_string(),
+            rate,
+            next_funding_time,
+            timestamp: next_funding_time - Duration::hours(8),
+        }
+    }
+
+    #[test]
+    fn test_open_and_add_blends_entry_price() {
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill("BTCUSD", &OrderSide::Buy, &fill(1.0, 100.0));
+        tracker.apply_fill("BTCUSD", &OrderSide::Buy, &fill(3.0, 120.0));
+
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert!((position.net_size - 4.0).abs() < 1e-9);
+        assert!((position.average_entry_price - 115.0).abs() < 1e-9);
+        assert_eq!(position.realized_pnl, 0.0);
+        assert!(position.unrealized_pnl.is_none());
+
+        tracker.update_mark_price("BTCUSD", 125.0);
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert!((position.unrealized_pnl.unwrap() - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_equivalent_symbols_net_into_one_position() {
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill("BTC-USD", &OrderSide::Buy, &fill(2.0, 100.0));
+        tracker.apply_fill("btcusd", &OrderSide::Sell, &fill(0.5, 110.0));
+        tracker.apply_fill("BTC/USD", &OrderSide::Sell, &fill(1.5, 120.0));
+        tracker.update_mark_price("btc_usd", 130.0);
+
+        let positions = tracker.positions();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].symbol, "BTC-USD");
+        assert_eq!(positions[0].net_size, 0.0);
+        assert!((positions[0].realized_pnl - 35.0).abs() < 1e-9);
+        assert_eq!(positions[0].mark_price, Some(130.0));
+        assert!(tracker.get_position("BTCUSD").is_some());
+    }
+
+    #[test]
+    fn test_partial_close_realizes_pnl_and_keeps_entry() {
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill("BTCUSD", &OrderSide::Buy, &fill(2.0, 100.0));
+        tracker.apply_fill("BTCUSD", &OrderSide::Sell, &fill(0.5, 110.0));
+
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert!((position.net_size - 1.5).abs() < 1e-9);
+        assert!((position.average_entry_price - 100.0).abs() < 1e-9);
+        assert!((position.realized_pnl - 5.0).abs() < 1e-9);
+
+        tracker.apply_fill("BTCUSD", &OrderSide::Sell, &fill(1.5, 90.0));
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert_eq!(position.net_size, 0.0);
+        assert!((position.realized_pnl - -10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flip_from_long_to_short_reopens_at_fill_price() {
+        let mut tracker = PositionTracker::new();
+        tracker.update_mark_price("BTCUSD", 100.0); // no position yet, ignored
+        tracker.apply_fill("BTCUSD", &OrderSide::Buy, &fill(1.0, 100.0));
+        tracker.apply_fill("BTCUSD", &OrderSide::Sell, &fill(3.0, 110.0));
+
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert!((position.net_size - -2.0).abs() < 1e-9);
+        assert!((position.average_entry_price - 110.0).abs() < 1e-9);
+        assert!((position.realized_pnl - 10.0).abs() < 1e-9);
+
+        // The short profits as the price falls
+        tracker.update_mark_price("BTCUSD", 100.0);
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert!((position.unrealized_pnl.unwrap() - 20.0).abs() < 1e-9);
+
+        // And flips back long
+        tracker.apply_fill("BTCUSD", &OrderSide::Buy, &fill(2.5, 105.0));
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert!((position.net_size - 0.5).abs() < 1e-9);
+        assert!((position.average_entry_price - 105.0).abs() < 1e-9);
+        assert!((position.realized_pnl - 20.0).abs() < 1e-9);
+        assert_eq!(position.fill_count, 3);
+    }
+
+    #[test]
+    fn test_funding_accrual_sign_for_long_and_short() {
+        let now = Utc::now();
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill("LONG", &OrderSide::Buy, &fill(2.0, 100.0));
+        tracker.apply_fill("SHORT", &OrderSide::Sell, &fill(2.0, 100.0));
+
+        // Positive rate: the long pays 2 * 100 * 0.01 and the short receives it
+        tracker.update_funding_rate(funding("LONG", 0.01, now), now);
+        tracker.update_funding_rate(funding("SHORT", 0.01, now), now);
+        let long = tracker.get_position("LONG").unwrap();
+        let short = tracker.get_position("SHORT").unwrap();
+        assert!((long.realized_pnl - -2.0).abs() < 1e-9);
+        assert!((long.funding_paid - 2.0).abs() < 1e-9);
+        assert!((short.realized_pnl - 2.0).abs() < 1e-9);
+        assert!((short.funding_paid - -2.0).abs() < 1e-9);
+
+        // Negative rate at the next funding time, valued at the mark: the short pays, the long receives
+        let next = now + Duration::hours(8);
+        tracker.update_mark_price("LONG", 110.0);
+        tracker.update_mark_price("SHORT", 110.0);
+        tracker.update_funding_rate(funding("LONG", -0.01, next), next);
+        tracker.update_funding_rate(funding("SHORT", -0.01, next), next);
+        let long = tracker.get_position("LONG").unwrap();
+        let short = tracker.get_position("SHORT").unwrap();
+        assert!((long.realized_pnl - 0.2).abs() < 1e-9);
+        assert!((long.funding_paid - -0.2).abs() < 1e-9);
+        assert!((short.realized_pnl - -0.2).abs() < 1e-9);
+        assert!((short.funding_paid - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_funding_settles_once_at_funding_time() {
+        let now = Utc::now();
+        let funding_time = now + Duration::hours(1);
+        let mut tracker = PositionTracker::new();
+        tracker.apply_fill("BTCUSD", &OrderSide::Buy, &fill(1.0, 100.0));
+
+        // Not yet due
+        tracker.update_funding_rate(funding("BTCUSD", 0.01, funding_time), now);
+        assert_eq!(tracker.get_position("BTCUSD").unwrap().funding_paid, 0.0);
+
+        tracker.settle_funding(funding_time);
+        assert!((tracker.get_position("BTCUSD").unwrap().funding_paid - 1.0).abs() < 1e-9);
+
+        // A stale rate for the same funding time is not charged again
+        tracker.update_funding_rate(funding("BTCUSD", 0.01, funding_time), funding_time + Duration::minutes(1));
+        tracker.settle_funding(funding_time + Duration::minutes(1));
+        let position = tracker.get_position("BTCUSD").unwrap();
+        assert!((position.funding_paid - 1.0).abs() < 1e-9);
+        assert!((position.realized_pnl - -1.0).abs() < 1e-9);
+
+        // Flat positions pay nothing
+        tracker.apply_fill("BTCUSD", &OrderSide::Sell, &fill(1.0, 100.0));
+        let later = funding_time + Duration::hours(8);
+        tracker.update_funding_rate(funding("BTCUSD", 0.01, later), later);
+        assert!((tracker.get_position("BTCUSD").unwrap().funding_paid - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of tracked clients above which idle buckets are pruned
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Per-client token bucket rate limiter
+pub struct RateLimiter {
+    requests_per_sec: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<IpAddr, TokenBucket>>,
+}
+
+impl RateLimiter {
+    /// Allow `requests_per_sec` sustained requests per client, with bursts of up to `burst`
+    pub fn new(requests_per_sec: f64, burst: u32) -> Self {
+        Self {
+            requests_per_sec: requests_per_sec.max(f64::MIN_POSITIVE),
+            burst: f64::from(burst.max(1)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take a token for `client`, or return how long until one is available
+    pub fn check(&self, client: IpAddr) -> Result<(), Duration> {
+        self.check_at(client, Instant::now())
+    }
+
+    fn check_at(&self, client: IpAddr, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(&client) {
+            self.prune_full_buckets(&mut buckets, now);
+        }
+
+        let bucket = buckets.entry(client).or_insert(TokenBucket {
+            tokens: self.burst,
+            last_refill: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.requests_per_sec).min(self.burst);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.requests_per_sec))
+        }
+    }
+
+    /// Forget clients whose bucket has refilled completely; they behave as new clients
+    fn prune_full_buckets(&self, buckets: &mut HashMap<IpAddr, TokenBucket>, now: Instant) {
+        buckets.retain(|_, bucket| {
+            let elapsed = now.saturating_duration_since(bucket.last_refill).as_secs_f64();
+            bucket.tokens + elapsed * self.requests_per_sec < self.burst
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_bucket_allows_burst_then_refills_per_client() {
+        let limiter = RateLimiter::new(2.0, 3);
+        let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let other = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.check_at(client, start).is_ok());
+        }
+        let retry_after = limiter.check_at(client, start).unwrap_err();
+        assert!((retry_after.as_secs_f64() - 0.5).abs() < 1e-6);
+
+        // Other clients have their own bucket
+        assert!(limiter.check_at(other, start).is_ok());
+
+        // Half a second at 2 req/s refills one token
+        assert!(limiter.check_at(client, start + Duration::from_millis(500)).is_ok());
+        assert!(limiter.check_at(client, start + Duration::from_millis(500)).is_err());
+    }
+}
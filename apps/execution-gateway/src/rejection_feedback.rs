@@ -0,0 +1,457 @@
+use rust_common::{
+    Direction, OrderDecision, Symbol, LEVERAGE_RISK_BUDGET, MAX_LEVERAGE, MAX_PORTFOLIO_RISK_PCT, MAX_RISK_PERCENTAGE,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::TrackedPosition;
+
+pub use rust_common::{RejectionFeedback, RejectionReason};
+
+/// Slack allowed over a risk limit so float drift in fills doesn't reject an order landing exactly on it
+const LIMIT_EPSILON: f64 = 1e-9;
+
+/// Portfolio-level limits checked against the gateway's tracked positions; `None` disables a limit
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RiskLimits {
+    /// Maximum notional of a single order, in the quote asset
+    #[serde(default)]
+    pub max_order_notional: Option<f64>,
+    /// Maximum absolute net position in any one symbol, in base units
+    #[serde(default)]
+    pub max_position_size: Option<f64>,
+    /// Per-symbol overrides of `max_position_size` ("BTCUSD" -> 5.0)
+    #[serde(default)]
+    pub symbol_max_position_size: HashMap<String, f64>,
+    /// Maximum combined notional of every position, in the quote asset
+    #[serde(default)]
+    pub max_total_notional: Option<f64>,
+}
+
+impl RiskLimits {
+    /// Every limit must be positive and finite
+    pub fn validate(&self) -> Result<(), String> {
+        let limits = [
+            ("max_order_notional", self.max_order_notional),
+            ("max_position_size", self.max_position_size),
+            ("max_total_notional", self.max_total_notional),
+        ];
+        for (name, limit) in limits {
+            if let Some(limit) = limit {
+                if !limit.is_finite() || limit <= 0.0 {
+                    return Err(format!("{} must be positive, got {}", name, limit));
+                }
+            }
+        }
+        for (symbol, limit) in &self.symbol_max_position_size {
+            if !limit.is_finite() || *limit <= 0.0 {
+                return Err(format!("max_position_size for {} must be positive, got {}", symbol, limit));
+            }
+        }
+        Ok(())
+    }
+
+    /// Position limit for `symbol`, preferring its override
+    pub fn max_position_size_for(&self, symbol: &str) -> Option<f64> {
+        self.symbol_max_position_size.get(symbol).copied().or(self.max_position_size)
+    }
+}
+
+/// Exposure held by a working order, counted against limits as if it had filled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OpenOrderExposure {
+    pub symbol: String,
+    /// Unfilled quantity, negative for sells
+    pub signed_quantity: f64,
+    pub price: f64,
+    /// Amount lost if the unfilled quantity fills and is stopped out
+    pub risk_amount: f64,
+}
+
+impl OpenOrderExposure {
+    /// Exposure of the whole decision, before anything has filled
+    pub fn for_decision(decision: &OrderDecision) -> Self {
+        let signed_quantity = match decision.direction {
+            Direction::Long => decision.risk_adjusted_quantity,
+            Direction::Short => -decision.risk_adjusted_quantity,
+        };
+        Self {
+            symbol: decision.symbol.clone(),
+            signed_quantity,
+            price: decision.entry_price,
+            risk_amount: decision.risk_amount,
+        }
+    }
+
+    /// Unfilled notional, in the quote asset
+    pub fn notional(&self) -> f64 {
+        self.signed_quantity.abs() * self.price
+    }
+
+    /// Shrink to `remaining` unfilled quantity, scaling the risk with it
+    pub fn set_remaining(&mut self, remaining: f64) {
+        if self.signed_quantity != 0.0 {
+            self.risk_amount *= remaining / self.signed_quantity.abs();
+        }
+        self.signed_quantity = remaining.copysign(self.signed_quantity);
+    }
+}
+
+/// Evaluate the decision-level risk rules, in order, returning every one that fails.
+///
+/// `open_risk_amount` is what orders still working stand to lose, which counts towards
+/// portfolio risk alongside `current_exposure`.
+pub fn evaluate_risk_rules(decision: &OrderDecision, open_risk_amount: f64) -> Vec<RejectionFeedback> {
+    let mut failures = Vec::new();
+    let open_risk_pct = if decision.portfolio_value > 0.0 {
+        open_risk_amount / decision.portfolio_value * 100.0
+    } else {
+        0.0
+    };
+
+    if decision.leverage > MAX_LEVERAGE {
+        failures.push(RejectionFeedback::new(
+            RejectionReason::ExcessiveLeverage,
+            "max_leverage",
+            format!("Leverage {}x exceeds maximum {}x", decision.leverage, MAX_LEVERAGE),
+            format!("Reduce leverage to {}x or less", MAX_LEVERAGE),
+        ));
+    }
+
+    if decision.risk_percentage > MAX_RISK_PERCENTAGE {
+        failures.push(RejectionFeedback::new(
+            RejectionReason::ExcessiveRiskPerTrade,
+            "max_risk_percentage",
+            format!("Risk {}% exceeds maximum {}% per trade", decision.risk_percentage, MAX_RISK_PERCENTAGE),
+            format!("Reduce risk percentage to {}% or less", MAX_RISK_PERCENTAGE),
+        ));
+    }
+
+    let committed_risk = decision.current_exposure * 100.0 + open_risk_pct;
+    let portfolio_risk = decision.risk_percentage + committed_risk;
+    if portfolio_risk > MAX_PORTFOLIO_RISK_PCT {
+        let headroom = (MAX_PORTFOLIO_RISK_PCT - committed_risk).max(0.0);
+        failures.push(RejectionFeedback::new(
+            RejectionReason::PortfolioRiskExceeded,
+            "max_portfolio_risk_pct",
+            format!("Total portfolio risk {:.2}% would exceed {}%", portfolio_risk, MAX_PORTFOLIO_RISK_PCT),
+            format!("Reduce risk percentage to {:.2}% or less given current exposure and working orders", headroom),
+        ));
+    }
+
+    if decision.leverage > 0.0 {
+        let max_risk_for_leverage = LEVERAGE_RISK_BUDGET / decision.leverage;
+        if decision.risk_percentage > max_risk_for_leverage {
+            failures.push(RejectionFeedback::new(
+                RejectionReason::RiskTooHighForLeverage,
+                "max_risk_for_leverage",
+                format!(
+                    "Risk {}% too high for {}x leverage (max {:.2}%)",
+                    decision.risk_percentage, decision.leverage, max_risk_for_leverage
+                ),
+                format!(
+                    "Reduce risk percentage to {:.2}% or leverage to {:.2}x",
+                    max_risk_for_leverage,
+                    LEVERAGE_RISK_BUDGET / decision.risk_percentage
+                ),
+            ));
+        }
+    }
+
+    failures
+}
+
+/// Check the decision's price against the mark, failing if it deviates more than `max_deviation_pct`
+pub fn check_price_deviation(
+    decision: &OrderDecision,
+    mark_price: f64,
+    max_deviation_pct: f64,
+) -> Option<RejectionFeedback> {
+    if mark_price <= 0.0 {
+        return None;
+    }
+
+    let deviation_pct = (decision.entry_price - mark_price).abs() / mark_price * 100.0;
+    if deviation_pct <= max_deviation_pct {
+        return None;
+    }
+
+    Some(RejectionFeedback::new(
+        RejectionReason::PriceDeviatesFromMark,
+        "max_price_deviation_pct",
+        format!(
+            "Fat-finger check: price {} deviates {:.2}% from mark {} (max {}%)",
+            decision.entry_price, deviation_pct, mark_price, max_deviation_pct
+        ),
+        format!("Price the order within {}% of the mark {}", max_deviation_pct, mark_price),
+    ))
+}
+
+/// Check a decision against portfolio limits given the current positions and working orders.
+///
+/// Positions are valued at their mark, or entry price without one, and the decision's
+/// symbol at its entry price. Working orders count as filled when they add to the side the
+/// decision trades, so two orders can't each pass against the same headroom. Orders that
+/// shrink a position or the total notional pass the position and total limits even when
+/// already over them.
+pub fn check_risk_limits(
+    decision: &OrderDecision,
+    limits: &RiskLimits,
+    positions: &[TrackedPosition],
+    open_orders: &[OpenOrderExposure],
+) -> Vec<RejectionFeedback> {
+    let mut failures = Vec::new();
+    let order_notional = decision.risk_adjusted_quantity * decision.entry_price;
+
+    if let Some(max_order_notional) = limits.max_order_notional {
+        if order_notional > max_order_notional + LIMIT_EPSILON {
+            failures.push(RejectionFeedback::new(
+                RejectionReason::OrderNotionalExceeded,
+                "max_order_notional",
+                format!("Order notional {:.2} exceeds maximum {:.2}", order_notional, max_order_notional),
+                format!("Reduce quantity to {} or less", max_order_notional / decision.entry_price),
+            ));
+        }
+    }
+
+    let signed_quantity = match decision.direction {
+        Direction::Long => decision.risk_adjusted_quantity,
+        Direction::Short => -decision.risk_adjusted_quantity,
+    };
+    let symbol = Symbol::from(&decision.symbol);
+    let working_size: f64 = open_orders.iter()
+        .filter(|open_order| Symbol::from(&open_order.symbol) == symbol)
+        .filter(|open_order| open_order.signed_quantity.signum() == signed_quantity.signum())
+        .map(|open_order| open_order.signed_quantity)
+        .sum();
+    let current_size = positions.iter()
+        .find(|position| Symbol::from(&position.symbol) == symbol)
+        .map_or(0.0, |position| position.net_size)
+        + working_size;
+    let resulting_size = current_size + signed_quantity;
+
+    if let Some(max_position_size) = limits.max_position_size_for(&decision.symbol) {
+        if resulting_size.abs() > max_position_size + LIMIT_EPSILON && resulting_size.abs() > current_size.abs() {
+            let headroom = (max_position_size - current_size * signed_quantity.signum()).max(0.0);
+            failures.push(RejectionFeedback::new(
+                RejectionReason::PositionLimitExceeded,
+                "max_position_size",
+                format!(
+                    "Position in {} would be {} (max {})",
+                    decision.symbol, resulting_size, max_position_size
+                ),
+                format!("Reduce quantity to {} or less", headroom),
+            ));
+        }
+    }
+
+    if let Some(max_total_notional) = limits.max_total_notional {
+        let others_notional: f64 = positions.iter()
+            .filter(|position| Symbol::from(&position.symbol) != symbol)
+            .map(|position| position.net_size.abs() * position.mark_price.unwrap_or(position.average_entry_price))
+            .sum::<f64>()
+            + open_orders.iter()
+                .filter(|open_order| Symbol::from(&open_order.symbol) != symbol)
+                .map(OpenOrderExposure::notional)
+                .sum::<f64>();
+        let current_total = others_notional + current_size.abs() * decision.entry_price;
+        let resulting_total = others_notional + resulting_size.abs() * decision.entry_price;
+        if resulting_total > max_total_notional + LIMIT_EPSILON && resulting_total > current_total {
+            failures.push(RejectionFeedback::new(
+                RejectionReason::TotalNotionalExceeded,
+                "max_total_notional",
+                format!(
+                    "Total notional would be {:.2} (max {:.2})",
+                    resulting_total, max_total_notional
+                ),
+                format!("Reduce order notional by {:.2}", resulting_total - max_total_notional),
+            ));
+        }
+    }
+
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_decision() -> OrderDecision {
+        let mut decision = OrderDecision::new("test_signal".to_string(), "BTCUSD".to_string());
+        decision.entry_price = 50000.0;
+        decision.risk_percentage = 1.0;
+        decision.leverage = 1.0;
+        decision.current_exposure = 0.1;
+        decision
+    }
+
+    #[test]
+    fn test_valid_decision_passes_all_rules() {
+        assert!(evaluate_risk_rules(&create_decision(), 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_all_failing_rules_are_reported() {
+        let mut decision = create_decision();
+        decision.leverage = 20.0;
+        decision.current_exposure = 0.5;
+
+        let reasons: Vec<_> = evaluate_risk_rules(&decision, 0.0).iter().map(|f| f.reason).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                RejectionReason::ExcessiveLeverage,
+                RejectionReason::PortfolioRiskExceeded,
+                RejectionReason::RiskTooHighForLeverage,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_price_deviation_feedback() {
+        let mut decision = create_decision();
+        assert!(check_price_deviation(&decision, 50000.0, 10.0).is_none());
+
+        decision.entry_price = 75000.0;
+        let feedback = check_price_deviation(&decision, 50000.0, 10.0).unwrap();
+        assert_eq!(feedback.reason, RejectionReason::PriceDeviatesFromMark);
+        assert_eq!(feedback.rule, "max_price_deviation_pct");
+    }
+
+    fn position(symbol: &str, net_size: f64, mark_price: f64) -> TrackedPosition {
+        TrackedPosition {
+            symbol: symbol.to_string(),
+            net_size,
+            average_entry_price: mark_price,
+            realized_pnl: 0.0,
+            mark_price: Some(mark_price),
+            unrealized_pnl: Some(0.0),
+            funding_paid: 0.0,
+            fill_count: 1,
+        }
+    }
+
+    #[test]
+    fn test_position_limit_rejects_growth_past_limit() {
+        let limits = RiskLimits {
+            max_position_size: Some(1.0),
+            symbol_max_position_size: HashMap::from([("BTCUSD".to_string(), 5.0)]),
+            ..RiskLimits::default()
+        };
+        let positions = vec![position("BTCUSD", 4.0, 50000.0)];
+        let mut decision = create_decision();
+
+        decision.risk_adjusted_quantity = 1.0;
+        assert!(check_risk_limits(&decision, &limits, &positions, &[]).is_empty());
+
+        decision.risk_adjusted_quantity = 1.5;
+        let failures = check_risk_limits(&decision, &limits, &positions, &[]);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].reason, RejectionReason::PositionLimitExceeded);
+        assert_eq!(failures[0].suggested_correction.as_deref(), Some("Reduce quantity to 1 or less"));
+
+        // Selling down an oversized position is always allowed
+        let positions = vec![position("BTCUSD", 8.0, 50000.0)];
+        decision.direction = Direction::Short;
+        decision.risk_adjusted_quantity = 2.0;
+        assert!(check_risk_limits(&decision, &limits, &positions, &[]).is_empty());
+
+        // Other symbols fall back to the default limit
+        decision.symbol = "ETHUSD".to_string();
+        assert_eq!(check_risk_limits(&decision, &limits, &positions, &[])[0].reason, RejectionReason::PositionLimitExceeded);
+    }
+
+    #[test]
+    fn test_notional_limits() {
+        let limits = RiskLimits {
+            max_order_notional: Some(60000.0),
+            max_total_notional: Some(150000.0),
+            ..RiskLimits::default()
+        };
+        let positions = vec![position("ETHUSD", 30.0, 3000.0)];
+        let mut decision = create_decision();
+
+        // 90k of ETH plus 50k of BTC fits
+        decision.risk_adjusted_quantity = 1.0;
+        assert!(check_risk_limits(&decision, &limits, &positions, &[]).is_empty());
+
+        decision.risk_adjusted_quantity = 1.5;
+        let reasons: Vec<_> = check_risk_limits(&decision, &limits, &positions, &[]).iter().map(|f| f.reason).collect();
+        assert_eq!(reasons, vec![RejectionReason::OrderNotionalExceeded, RejectionReason::TotalNotionalExceeded]);
+    }
+
+    #[test]
+    fn test_working_orders_count_against_limits() {
+        let limits = RiskLimits {
+            max_position_size: Some(2.0),
+            max_total_notional: Some(150000.0),
+            ..RiskLimits::default()
+        };
+        let positions = vec![position("BTCUSD", 1.0, 50000.0)];
+        let open_buy = OpenOrderExposure {
+            symbol: "BTC/USD".to_string(),
+            signed_quantity: 0.5,
+            price: 50000.0,
+            risk_amount: 500.0,
+        };
+        let mut decision = create_decision();
+        decision.risk_adjusted_quantity = 0.8;
+
+        // 1 held plus 0.5 working plus 0.8 more breaks the 2.0 limit
+        assert!(check_risk_limits(&decision, &limits, &positions, &[]).is_empty());
+        let failures = check_risk_limits(&decision, &limits, &positions, std::slice::from_ref(&open_buy));
+        assert_eq!(failures[0].reason, RejectionReason::PositionLimitExceeded);
+
+        // A working sell might never fill, so it frees no room for buys
+        let open_sell = OpenOrderExposure { signed_quantity: -0.5, ..open_buy.clone() };
+        assert!(check_risk_limits(&decision, &limits, &positions, &[open_sell]).is_empty());
+
+        // Orders working in other symbols use up the total notional
+        let open_eth = OpenOrderExposure {
+            symbol: "ETHUSD".to_string(),
+            signed_quantity: 21.0,
+            price: 3000.0,
+            risk_amount: 1000.0,
+        };
+        let reasons: Vec<_> = check_risk_limits(&decision, &limits, &positions, &[open_eth]).iter().map(|f| f.reason).collect();
+        assert_eq!(reasons, vec![RejectionReason::TotalNotionalExceeded]);
+    }
+
+    #[test]
+    fn test_working_risk_counts_towards_portfolio_risk() {
+        let mut decision = create_decision();
+        decision.portfolio_value = 100000.0;
+
+        // 10% held, 1% on this trade and 5% on working orders passes; 10% working takes it past 20%
+        assert!(evaluate_risk_rules(&decision, 5000.0).is_empty());
+        let failures = evaluate_risk_rules(&decision, 10000.0);
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].reason, RejectionReason::PortfolioRiskExceeded);
+        assert_eq!(
+            failures[0].suggested_correction.as_deref(),
+            Some("Reduce risk percentage to 0.00% or less given current exposure and working orders")
+        );
+    }
+
+    #[test]
+    fn test_partly_filled_order_holds_less_risk() {
+        let mut open_order = OpenOrderExposure::for_decision(&create_decision());
+        open_order.signed_quantity = -2.0;
+        open_order.risk_amount = 400.0;
+        open_order.set_remaining(0.5);
+        assert_eq!(open_order.signed_quantity, -0.5);
+        assert!((open_order.risk_amount - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_risk_limits_validation() {
+        assert!(RiskLimits::default().validate().is_ok());
+        let limits = RiskLimits { max_total_notional: Some(-1.0), ..RiskLimits::default() };
+        assert!(limits.validate().unwrap_err().contains("max_total_notional"));
+        let limits = RiskLimits {
+            symbol_max_position_size: HashMap::from([("BTCUSD".to_string(), f64::NAN)]),
+            ..RiskLimits::default()
+        };
+        assert!(limits.validate().is_err());
+    }
+}
@@ -0,0 +1,586 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{header::{HeaderMap, CONTENT_TYPE, RETRY_AFTER}, Method, StatusCode};
+use rust_common::{
+    round_f64_to_increment, FundingRate, OrderBook, OrderBookLevel, OrderRequest, OrderSide, OrderStatus, OrderType, Quote, RejectReason,
+    RoundingMode, TimeInForce, TradingError,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::{AccountInfo, AdapterOrderResult, ExchangeAdapter, ExchangeInfo};
+
+/// Exchange adapter for a signed JSON REST API.
+///
+/// Every request carries `X-API-KEY`, `X-TIMESTAMP` (unix millis) and `X-SIGNATURE`, the
+/// hex HMAC-SHA256 of `timestamp + method + path + body` keyed by the API secret.
+/// Rate limiting (429) surfaces as `TradingError::RateLimited`, carrying any `Retry-After`, and server errors (5xx) as
+/// `TradingError::NetworkError`, both of which the gateway backs off on; other 4xx responses
+/// are typed from the body's `code` and not retried.
+pub struct RestExchangeAdapter {
+    base_url: String,
+    api_key: String,
+    api_secret: String,
+    client: reqwest::Client,
+}
+
+/// Error body of a 4xx response; `code` uses the `RejectReason` names, e.g. `"insufficient_funds"`
+#[derive(Debug, Deserialize)]
+struct RestErrorBody {
+    #[serde(default)]
+    code: Option<RejectReason>,
+}
+
+#[derive(Debug, Serialize)]
+struct RestOrderPayload<'a> {
+    client_order_id: String,
+    symbol: &'a str,
+    side: &'static str,
+    order_type: OrderType,
+    quantity: f64,
+    price: Option<f64>,
+    reduce_only: bool,
+    time_in_force: TimeInForce,
+    post_only: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RestAmendPayload {
+    price: Option<f64>,
+    quantity: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RestOrderResponse {
+    order_id: String,
+    status: String,
+    #[serde(default)]
+    filled_quantity: f64,
+    average_price: Option<f64>,
+    #[serde(default)]
+    commission: f64,
+    filled_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RestOrderStatusResponse {
+    status: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RestMarkPriceResponse {
+    mark_price: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RestQuoteResponse {
+    bid: Option<f64>,
+    ask: Option<f64>,
+    #[serde(default)]
+    bid_size: f64,
+    #[serde(default)]
+    ask_size: f64,
+}
+
+/// Depth levels are `[price, size]` pairs, best first
+#[derive(Debug, Deserialize)]
+struct RestOrderBookResponse {
+    bids: Vec<(f64, f64)>,
+    asks: Vec<(f64, f64)>,
+}
+
+/// Spot symbols have no funding and return a null `rate`
+#[derive(Debug, Deserialize)]
+struct RestFundingRateResponse {
+    rate: Option<f64>,
+    next_funding_time: Option<DateTime<Utc>>,
+}
+
+impl RestExchangeAdapter {
+    pub fn new(base_url: &str, api_key: &str, api_secret: &str, client: reqwest::Client) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+            api_secret: api_secret.to_string(),
+            client,
+        }
+    }
+
+    /// Hex HMAC-SHA256 signature of a request
+    fn sign(&self, timestamp: &str, method: &Method, path: &str, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC accepts any key length");
+        mac.update(timestamp.as_bytes());
+        mac.update(method.as_str().as_bytes());
+        mac.update(path.as_bytes());
+        mac.update(body.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// Send a signed request and decode the JSON response body
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<T, TradingError> {
+        let response_body = self.send(method, path, body).await?;
+        Ok(serde_json::from_str(&response_body)?)
+    }
+
+    /// Send a signed request, returning the raw response body
+    async fn send(&self, method: Method, path: &str, body: Option<String>) -> Result<String, TradingError> {
+        let body = body.unwrap_or_default();
+        let timestamp = Utc::now().timestamp_millis().to_string();
+        let signature = self.sign(&timestamp, &method, path, &body);
+
+        let mut request = self.client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("X-API-KEY", &self.api_key)
+            .header("X-TIMESTAMP", &timestamp)
+            .header("X-SIGNATURE", signature);
+        if !body.is_empty() {
+            request = request.header(CONTENT_TYPE, "application/json").body(body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let retry_after_ms = Self::parse_retry_after(response.headers(), Utc::now());
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(TradingError::RateLimited {
+                message: response.text().await.unwrap_or_default(),
+                retry_after_ms,
+                rejection: None,
+            });
+        }
+        if status.is_client_error() {
+            let error_body = response.text().await.unwrap_or_default();
+            let message = format!("Exchange rejected invalid order request ({}): {}", status, error_body);
+            let code = serde_json::from_str::<RestErrorBody>(&error_body).ok().and_then(|body| body.code);
+            return Err(match code {
+                Some(RejectReason::InsufficientFunds) => TradingError::InsufficientFunds { message },
+                Some(RejectReason::RateLimited) => TradingError::RateLimited { message, retry_after_ms, rejection: None },
+                reason => TradingError::OrderRejected {
+                    reason: reason.unwrap_or(RejectReason::Unknown),
+                    message,
+                },
+            });
+        }
+
+        // 5xx become network errors, which the retry policy backs off on
+        Ok(response.error_for_status()?.text().await?)
+    }
+
+    /// Wait requested by a `Retry-After` header, given as delay-seconds or an HTTP date
+    fn parse_retry_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<u64> {
+        let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(seconds.saturating_mul(1000));
+        }
+        let retry_at = DateTime::parse_from_rfc2822(value).ok()?;
+        Some((retry_at.with_timezone(&Utc) - now).num_milliseconds().max(0) as u64)
+    }
+
+    fn parse_status(status: &str) -> Result<OrderStatus, TradingError> {
+        match status.to_lowercase().as_str() {
+            "new" | "pending" => Ok(OrderStatus::Pending),
+            "open" => Ok(OrderStatus::Open),
+            "partially_filled" => Ok(OrderStatus::PartiallyFilled),
+            "filled" => Ok(OrderStatus::Filled),
+            "canceled" | "cancelled" => Ok(OrderStatus::Cancelled),
+            "rejected" => Ok(OrderStatus::Rejected),
+            "expired" => Ok(OrderStatus::Expired),
+            other => Err(TradingError::Other(format!("Unknown order status from exchange: {}", other))),
+        }
+    }
+}
+
+#[async_trait]
+impl ExchangeAdapter for RestExchangeAdapter {
+    async fn ping(&self) -> Result<(), TradingError> {
+        self.send(Method::GET, "/v1/ping", None).await.map(|_| ())
+    }
+
+    async fn get_exchange_info(&self, symbol: &str) -> Result<ExchangeInfo, TradingError> {
+        self.request(Method::GET, &format!("/v1/exchange_info?symbol={}", symbol), None).await
+    }
+
+    async fn place_order(&self, order: OrderRequest) -> Result<AdapterOrderResult, TradingError> {
+        self.validate_order(&order).await?;
+
+        let payload = RestOrderPayload {
+            client_order_id: order.id.to_string(),
+            symbol: &order.symbol,
+            side: match order.side {
+                OrderSide::Buy => "buy",
+                OrderSide::Sell => "sell",
+            },
+            order_type: order.order_type,
+            quantity: order.size,
+            price: order.price,
+            reduce_only: order.reduce_only,
+            time_in_force: order.time_in_force,
+            post_only: order.post_only,
+        };
+        let response: RestOrderResponse = self
+            .request(Method::POST, "/v1/orders", Some(serde_json::to_string(&payload)?))
+            .await?;
+
+        Ok(AdapterOrderResult {
+            order_id: response.order_id,
+            status: Self::parse_status(&response.status)?,
+            filled_quantity: response.filled_quantity,
+            average_price: response.average_price,
+            commission: response.commission,
+            filled_at: response.filled_at,
+            partial_fills: Vec::new(),
+        })
+    }
+
+    async fn cancel_order(&self, order_id: &str) -> Result<(), TradingError> {
+        self.send(Method::DELETE, &format!("/v1/orders/{}", order_id), None).await?;
+        Ok(())
+    }
+
+    async fn get_order_status(&self, order_id: &str) -> Result<OrderStatus, TradingError> {
+        let response: RestOrderStatusResponse = self
+            .request(Method::GET, &format!("/v1/orders/{}", order_id), None)
+            .await?;
+        Self::parse_status(&response.status)
+    }
+
+    async fn amend_order(&self, order_id: &str, new_price: Option<f64>, new_quantity: Option<f64>) -> Result<(), TradingError> {
+        let payload = RestAmendPayload {
+            price: new_price,
+            quantity: new_quantity,
+        };
+        self.send(Method::PUT, &format!("/v1/orders/{}", order_id), Some(serde_json::to_string(&payload)?))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_account_info(&self) -> Result<AccountInfo, TradingError> {
+        self.request(Method::GET, "/v1/account", None).await
+    }
+
+    async fn get_mark_price(&self, symbol: &str) -> Result<Option<f64>, TradingError> {
+        let response: RestMarkPriceResponse = self
+            .request(Method::GET, &format!("/v1/mark_price?symbol={}", symbol), None)
+            .await?;
+        Ok(response.mark_price)
+    }
+
+    async fn get_quote(&self, symbol: &str) -> Result<Option<Quote>, TradingError> {
+        let response: RestQuoteResponse = self
+            .request(Method::GET, &format!("/v1/quote?symbol={}", symbol), None)
+            .await?;
+        Ok(response.bid.zip(response.ask).map(|(bid, ask)| Quote {
+            symbol: symbol.to_string(),
+            bid,
+            ask,
+            bid_size: response.bid_size,
+            ask_size: response.ask_size,
+            timestamp: Utc::now(),
+        }))
+    }
+
+    async fn get_order_book(&self, symbol: &str, depth: usize) -> Result<Option<OrderBook>, TradingError> {
+        let response: RestOrderBookResponse = self
+            .request(Method::GET, &format!("/v1/depth?symbol={}&depth={}", symbol, depth), None)
+            .await?;
+        let levels = |levels: Vec<(f64, f64)>| -> Vec<OrderBookLevel> {
+            levels.into_iter().take(depth).map(|(price, size)| OrderBookLevel { price, size }).collect()
+        };
+        Ok(Some(OrderBook {
+            symbol: symbol.to_string(),
+            bids: levels(response.bids),
+            asks: levels(response.asks),
+            timestamp: Utc::now(),
+        }))
+    }
+
+    async fn get_funding_rate(&self, symbol: &str) -> Result<Option<FundingRate>, TradingError> {
+        let response: RestFundingRateResponse = self
+            .request(Method::GET, &format!("/v1/funding?symbol={}", symbol), None)
+            .await?;
+        let (Some(rate), Some(next_funding_time)) = (response.rate, response.next_funding_time) else {
+            return Ok(None);
+        };
+        Ok(Some(FundingRate {
+            symbol: symbol.to_string(),
+            rate,
+            next_funding_time,
+            timestamp: Utc::now(),
+        }))
+    }
+
+    async fn validate_order(&self, order: &OrderRequest) -> Result<(), TradingError> {
+        // Exchange-specific limits are enforced server-side
+        if !order.size.is_finite() || order.size <= 0.0 {
+            return Err(TradingError::OrderRejected {
+                reason: RejectReason::SizeOutOfBounds,
+                message: format!("Invalid order size: {}", order.size),
+            });
+        }
+        if let Some(price) = order.price {
+            if !price.is_finite() || price <= 0.0 {
+                return Err(TradingError::OrderRejected {
+                    reason: RejectReason::PriceOutOfBounds,
+                    message: format!("Invalid order price: {}", price),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn round_price(&self, price: f64, tick_size: f64) -> f64 {
+        round_f64_to_increment(price, tick_size, RoundingMode::HalfEven)
+    }
+
+    fn round_quantity(&self, quantity: f64, lot_size: f64) -> f64 {
+        let snapped = round_f64_to_increment(quantity, 1e-9, RoundingMode::HalfEven);
+        round_f64_to_increment(snapped, lot_size, RoundingMode::Down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{determine_retry_policy, RetryPolicy};
+    use uuid::Uuid;
+    use wiremock::matchers::{body_partial_json, header_exists, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn create_adapter(server: &MockServer) -> RestExchangeAdapter {
+        RestExchangeAdapter::new(&server.uri(), "test-key", "test-secret", reqwest::Client::new())
+    }
+
+    fn create_order() -> OrderRequest {
+        OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size: 0.1,
+            price: Some(50000.0),
+            order_type: OrderType::Limit,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_place_order_success() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .and(header_exists("X-SIGNATURE"))
+            .and(body_partial_json(serde_json::json!({"symbol": "BTCUSD", "side": "buy", "order_type": "limit"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "order_id": "ex-1",
+                "status": "filled",
+                "filled_quantity": 0.1,
+                "average_price": 50000.0,
+                "commission": 5.0,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = create_adapter(&server).place_order(create_order()).await.unwrap();
+        assert_eq!(result.order_id, "ex-1");
+        assert_eq!(result.status, OrderStatus::Filled);
+        assert_eq!(result.filled_quantity, 0.1);
+        assert_eq!(result.average_price, Some(50000.0));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_is_retryable() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&server)
+            .await;
+
+        let error = create_adapter(&server).place_order(create_order()).await.unwrap_err();
+        assert!(matches!(error, TradingError::RateLimited { retry_after_ms: None, .. }));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::ExponentialBackoff));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_carries_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3"))
+            .mount(&server)
+            .await;
+
+        let error = create_adapter(&server).place_order(create_order()).await.unwrap_err();
+        assert!(matches!(error, TradingError::RateLimited { retry_after_ms: Some(3000), .. }));
+
+        let now: DateTime<Utc> = "2024-03-04T10:00:00Z".parse().unwrap();
+        let retry_after = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(RETRY_AFTER, value.parse().unwrap());
+            RestExchangeAdapter::parse_retry_after(&headers, now)
+        };
+        assert_eq!(retry_after("Mon, 04 Mar 2024 10:00:02 GMT"), Some(2000));
+        assert_eq!(retry_after("Mon, 04 Mar 2024 09:59:00 GMT"), Some(0));
+        assert_eq!(retry_after("soon"), None);
+    }
+
+    #[tokio::test]
+    async fn test_server_error_is_retryable_network_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/orders/ex-1"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let error = create_adapter(&server).get_order_status("ex-1").await.unwrap_err();
+        assert!(matches!(error, TradingError::NetworkError(_)));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::ExponentialBackoff));
+    }
+
+    #[tokio::test]
+    async fn test_client_error_is_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/orders/ex-1"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("unknown order"))
+            .mount(&server)
+            .await;
+
+        let error = create_adapter(&server).cancel_order("ex-1").await.unwrap_err();
+        assert!(matches!(error, TradingError::OrderRejected { reason: RejectReason::Unknown, .. }));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
+    }
+
+    #[tokio::test]
+    async fn test_client_error_code_is_typed() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/orders"))
+            .respond_with(ResponseTemplate::new(400).set_body_string(r#"{"code":"insufficient_funds","msg":"margin call"}"#))
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/v1/orders/ex-1"))
+            .respond_with(ResponseTemplate::new(409).set_body_string(r#"{"code":"duplicate_order"}"#))
+            .mount(&server)
+            .await;
+
+        let adapter = create_adapter(&server);
+        let error = adapter.place_order(create_order()).await.unwrap_err();
+        assert!(matches!(error, TradingError::InsufficientFunds { .. }));
+        assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
+
+        let error = adapter.cancel_order("ex-1").await.unwrap_err();
+        assert!(matches!(error, TradingError::OrderRejected { reason: RejectReason::DuplicateOrder, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_get_account_info_and_order_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/account"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "account_id": "acct-1",
+                "total_balance": 10000.0,
+                "available_balance": 8000.0,
+                "margin_used": 2000.0,
+                "margin_available": 8000.0,
+                "positions": [],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/orders/ex-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"status": "partially_filled"})))
+            .mount(&server)
+            .await;
+
+        let adapter = create_adapter(&server);
+        let account = adapter.get_account_info().await.unwrap();
+        assert_eq!(account.account_id, "acct-1");
+        assert_eq!(account.available_balance, 8000.0);
+        assert_eq!(adapter.get_order_status("ex-1").await.unwrap(), OrderStatus::PartiallyFilled);
+    }
+
+    #[tokio::test]
+    async fn test_get_quote_and_order_book() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/quote"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "bid": 49995.0, "ask": 50005.0, "bid_size": 1.5, "ask_size": 2.0,
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/depth"))
+            .and(query_param("depth", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "bids": [[49995.0, 1.5], [49990.0, 3.0]],
+                "asks": [[50005.0, 2.0], [50010.0, 4.0]],
+            })))
+            .mount(&server)
+            .await;
+
+        let adapter = create_adapter(&server);
+        let quote = adapter.get_quote("BTCUSD").await.unwrap().unwrap();
+        assert_eq!(quote.symbol, "BTCUSD");
+        assert_eq!((quote.bid_size, quote.ask_size), (1.5, 2.0));
+        assert!((quote.spread_bps() - 2.0).abs() < 1e-9);
+
+        let order_book = adapter.get_order_book("BTCUSD", 2).await.unwrap().unwrap();
+        assert_eq!(order_book.bids, vec![OrderBookLevel { price: 49995.0, size: 1.5 }, OrderBookLevel { price: 49990.0, size: 3.0 }]);
+        assert_eq!(order_book.asks[1].price, 50010.0);
+        assert!(order_book.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_funding_rate() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/funding"))
+            .and(query_param("symbol", "BTC-PERP"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "rate": -0.0001, "next_funding_time": "2024-01-01T08:00:00Z",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/funding"))
+            .and(query_param("symbol", "BTCUSD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "rate": null, "next_funding_time": null,
+            })))
+            .mount(&server)
+            .await;
+
+        let adapter = create_adapter(&server);
+        let funding = adapter.get_funding_rate("BTC-PERP").await.unwrap().unwrap();
+        assert_eq!(funding.symbol, "BTC-PERP");
+        assert_eq!(funding.rate, -0.0001);
+        assert_eq!(funding.next_funding_time.to_rfc3339(), "2024-01-01T08:00:00+00:00");
+
+        assert!(adapter.get_funding_rate("BTCUSD").await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_signature_covers_every_request_part() {
+        let adapter = RestExchangeAdapter::new("http://localhost", "key", "secret", reqwest::Client::new());
+        let signature = adapter.sign("1700000000000", &Method::POST, "/v1/orders", "{}");
+        assert_eq!(signature.len(), 64);
+        assert_eq!(signature, adapter.sign("1700000000000", &Method::POST, "/v1/orders", "{}"));
+        assert_ne!(signature, adapter.sign("1700000000001", &Method::POST, "/v1/orders", "{}"));
+        assert_ne!(signature, adapter.sign("1700000000000", &Method::DELETE, "/v1/orders", "{}"));
+        assert_ne!(signature, adapter.sign("1700000000000", &Method::POST, "/v1/orders", ""));
+    }
+}
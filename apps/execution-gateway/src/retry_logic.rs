@@ -1,43 +1,161 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_common::RejectReason;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// How randomness is applied to the exponential backoff, following the
+/// "Exponential Backoff And Jitter" strategies
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JitterStrategy {
+    /// Uniform in `[0, backoff]`; spreads concurrent retries the most
+    #[default]
+    Full,
+    /// Uniform in `[backoff / 2, backoff]`; always waits at least half the backoff
+    Equal,
+    /// Uniform in `[base, previous * 3]`, capped; grows from the previous delay rather than the attempt
+    Decorrelated,
+}
+
+/// Kind of retriable failure, each of which may back off on its own schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RetryErrorClass {
+    /// The exchange is shedding load and wants callers to slow down
+    RateLimited,
+    Timeout,
+    Network,
+    Other,
+}
+
+impl RetryErrorClass {
+    pub fn of(error: &rust_common::TradingError) -> Self {
+        match error {
+            rust_common::TradingError::RateLimited { .. } => RetryErrorClass::RateLimited,
+            rust_common::TradingError::Timeout { .. } => RetryErrorClass::Timeout,
+            rust_common::TradingError::NetworkError(_) => RetryErrorClass::Network,
+            _ => RetryErrorClass::Other,
+        }
+    }
+}
+
+/// Base and maximum delays of an exponential backoff schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackoffSchedule {
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl BackoffSchedule {
+    /// Exponential backoff for an attempt before jitter: base_delay * 2^(attempt-1), capped
+    fn capped_backoff(&self, attempt: u32) -> u64 {
+        2_u64
+            .checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.base_delay_ms.checked_mul(factor))
+            .unwrap_or(u64::MAX)
+            .min(self.max_delay_ms)
+    }
+}
 
 /// Retry logic with exponential backoff and jitter
 pub struct RetryLogic {
     max_retries: u32,
     base_delay_ms: u64,
     max_delay_ms: u64,
+    jitter: JitterStrategy,
+    class_schedules: HashMap<RetryErrorClass, BackoffSchedule>, // overrides of the default schedule
+    rng: Mutex<StdRng>, // jitter source, entropy-seeded unless pinned with `with_seed`
 }
 
 impl RetryLogic {
-    pub fn new(max_retries: u32, base_delay_ms: u64, max_delay_ms: u64) -> Self {
+    pub fn new(max_retries: u32, base_delay_ms: u64, max_delay_ms: u64, jitter: JitterStrategy) -> Self {
         Self {
             max_retries,
             base_delay_ms,
             max_delay_ms,
+            jitter,
+            class_schedules: HashMap::new(),
+            rng: Mutex::new(StdRng::from_entropy()),
         }
     }
 
-    /// Calculate delay for retry attempt with exponential backoff and jitter
+    /// Seed the jitter so the same seed always yields the same delay sequence
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Back off on `schedule` instead of the default one for errors of `class`
+    pub fn with_class_schedule(mut self, class: RetryErrorClass, schedule: BackoffSchedule) -> Self {
+        self.class_schedules.insert(class, schedule);
+        self
+    }
+
+    /// Schedule used for errors of `class`
+    pub fn schedule_for(&self, class: RetryErrorClass) -> BackoffSchedule {
+        self.class_schedules.get(&class).copied().unwrap_or_else(|| self.default_schedule())
+    }
+
+    fn default_schedule(&self) -> BackoffSchedule {
+        BackoffSchedule {
+            base_delay_ms: self.base_delay_ms,
+            max_delay_ms: self.max_delay_ms,
+        }
+    }
+
+    /// Calculate delay for retry attempt with exponential backoff and jitter.
+    ///
+    /// Decorrelated jitter grows from the previous delay; here that is taken to be the
+    /// un-jittered backoff of the previous attempt. Use `next_delay` to chain actual delays.
     pub fn calculate_delay(&self, attempt: u32) -> u64 {
+        let schedule = self.default_schedule();
+        let previous_delay_ms = schedule.capped_backoff(attempt.saturating_sub(1)).max(schedule.base_delay_ms);
+        self.next_delay(attempt, previous_delay_ms)
+    }
+
+    /// Delay before `attempt`, given the delay actually waited before the previous attempt
+    pub fn next_delay(&self, attempt: u32, previous_delay_ms: u64) -> u64 {
+        self.delay_on(self.default_schedule(), attempt, previous_delay_ms)
+    }
+
+    /// Like `next_delay`, but on the schedule configured for errors of `class`
+    pub fn next_delay_for(&self, class: RetryErrorClass, attempt: u32, previous_delay_ms: u64) -> u64 {
+        self.delay_on(self.schedule_for(class), attempt, previous_delay_ms)
+    }
+
+    /// Delay before retrying after `error`: the backoff for its class, or the exchange's
+    /// requested wait when a rate limit asks for longer, capped at the class's `max_delay_ms`
+    pub fn next_delay_after(&self, error: &rust_common::TradingError, attempt: u32, previous_delay_ms: u64) -> u64 {
+        let class = RetryErrorClass::of(error);
+        let backoff = self.next_delay_for(class, attempt, previous_delay_ms);
+        match error {
+            rust_common::TradingError::RateLimited { retry_after_ms: Some(retry_after_ms), .. } => {
+                backoff.max((*retry_after_ms).min(self.schedule_for(class).max_delay_ms))
+            }
+            _ => backoff,
+        }
+    }
+
+    fn delay_on(&self, schedule: BackoffSchedule, attempt: u32, previous_delay_ms: u64) -> u64 {
         if attempt == 0 {
             return 0;
         }
 
-        // Exponential backoff: base_delay * 2^(attempt-1)
-        let exponential_delay = self.base_delay_ms * (2_u64.pow(attempt.saturating_sub(1)));
-        
-        // Cap at max delay
-        let capped_delay = exponential_delay.min(self.max_delay_ms);
-        
-        // Add jitter (±25% of the delay)
-        let jitter_range = capped_delay / 4; // 25% of delay
-        let mut rng = rand::thread_rng();
-        let jitter = rng.gen_range(0..=jitter_range * 2); // 0 to 50% of delay
-        
-        // Apply jitter (subtract half the range to center around original delay)
-        if capped_delay >= jitter_range {
-            capped_delay - jitter_range + jitter
-        } else {
-            capped_delay + jitter
+        let mut rng = self.rng.lock().unwrap();
+        match self.jitter {
+            JitterStrategy::Full => rng.gen_range(0..=schedule.capped_backoff(attempt)),
+            JitterStrategy::Equal => {
+                let capped_delay = schedule.capped_backoff(attempt);
+                let half = capped_delay / 2;
+                half + rng.gen_range(0..=capped_delay - half)
+            }
+            JitterStrategy::Decorrelated => {
+                let upper = previous_delay_ms.saturating_mul(3).max(schedule.base_delay_ms);
+                rng.gen_range(schedule.base_delay_ms..=upper).min(schedule.max_delay_ms)
+            }
         }
     }
 
@@ -54,13 +172,55 @@ impl RetryLogic {
     /// Calculate total maximum time for all retries
     pub fn calculate_max_total_time(&self) -> u64 {
         let mut total_time = 0;
+        let mut previous_delay_ms = self.base_delay_ms;
         for attempt in 1..=self.max_retries {
-            total_time += self.calculate_delay(attempt);
+            previous_delay_ms = self.next_delay(attempt, previous_delay_ms);
+            total_time += previous_delay_ms;
         }
         total_time
     }
 }
 
+/// Gateway-wide token bucket capping how many retries may be attempted per second,
+/// so a burst of simultaneous failures can't turn into a retry storm
+pub struct RetryBudget {
+    retries_per_sec: f64,
+    burst: f64,
+    bucket: Mutex<(f64, Instant)>, // (tokens, last refill)
+}
+
+impl RetryBudget {
+    /// Allow `retries_per_sec` sustained retries, with bursts of up to `burst`
+    pub fn new(retries_per_sec: f64, burst: u32) -> Self {
+        let burst = f64::from(burst);
+        Self {
+            retries_per_sec: retries_per_sec.max(0.0),
+            burst,
+            bucket: Mutex::new((burst, Instant::now())),
+        }
+    }
+
+    /// Take a token for one retry; `false` means the retry should be skipped
+    pub fn try_acquire(&self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&self, now: Instant) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        let (tokens, last_refill) = *bucket;
+        let elapsed = now.saturating_duration_since(last_refill).as_secs_f64();
+        let tokens = (tokens + elapsed * self.retries_per_sec).min(self.burst);
+
+        if tokens >= 1.0 {
+            *bucket = (tokens - 1.0, now);
+            true
+        } else {
+            *bucket = (tokens, now);
+            false
+        }
+    }
+}
+
 /// Retry policy for different types of errors
 #[derive(Debug, Clone, Copy)]
 pub enum RetryPolicy {
@@ -72,28 +232,40 @@ pub enum RetryPolicy {
     NoRetry,
 }
 
+/// Why an order was refused; `None` when the error isn't a refusal, e.g. a timeout
+pub fn reject_reason(error: &rust_common::TradingError) -> Option<RejectReason> {
+    match error {
+        rust_common::TradingError::OrderRejected { reason, .. } => Some(*reason),
+        rust_common::TradingError::InsufficientFunds { .. } => Some(RejectReason::InsufficientFunds),
+        rust_common::TradingError::RateLimited { .. } => Some(RejectReason::RateLimited),
+        // Risk gates only ever refuse
+        rust_common::TradingError::RiskLimitError { .. } => Some(RejectReason::Unknown),
+        rust_common::TradingError::ExecutionError { .. }
+        | rust_common::TradingError::CircuitBreakerOpen { .. }
+        | rust_common::TradingError::DataError { .. }
+        | rust_common::TradingError::NetworkError(_)
+        | rust_common::TradingError::SerializationError(_)
+        | rust_common::TradingError::Timeout { .. }
+        | rust_common::TradingError::ValidationError { .. }
+        | rust_common::TradingError::Other(_) => None,
+    }
+}
+
 /// Determine retry policy based on error type
 pub fn determine_retry_policy(error: &rust_common::TradingError) -> RetryPolicy {
     match error {
-        rust_common::TradingError::NetworkError(_) => RetryPolicy::ExponentialBackoff,
-        rust_common::TradingError::ExecutionError { message } => {
-            // Check if it's a temporary error
-            if message.contains("timeout") || 
-               message.contains("rate limit") || 
-               message.contains("temporary") ||
-               message.contains("service unavailable") {
-                RetryPolicy::ExponentialBackoff
-            } else if message.contains("insufficient funds") ||
-                     message.contains("invalid order") ||
-                     message.contains("market closed") {
-                RetryPolicy::NoRetry
-            } else {
-                RetryPolicy::ExponentialBackoff
-            }
-        }
-        rust_common::TradingError::RiskLimitError { .. } => RetryPolicy::NoRetry,
-        rust_common::TradingError::DataError { .. } => RetryPolicy::ExponentialBackoff,
-        rust_common::TradingError::SerializationError(_) => RetryPolicy::NoRetry,
+        rust_common::TradingError::NetworkError(_)
+        | rust_common::TradingError::Timeout { .. }
+        | rust_common::TradingError::RateLimited { .. }
+        | rust_common::TradingError::DataError { .. }
+        | rust_common::TradingError::ExecutionError { .. }
+        | rust_common::TradingError::Other(_) => RetryPolicy::ExponentialBackoff,
+        rust_common::TradingError::InsufficientFunds { .. }
+        | rust_common::TradingError::OrderRejected { .. }
+        | rust_common::TradingError::RiskLimitError { .. }
+        | rust_common::TradingError::CircuitBreakerOpen { .. }
+        | rust_common::TradingError::ValidationError { .. }
+        | rust_common::TradingError::SerializationError(_) => RetryPolicy::NoRetry,
     }
 }
 
@@ -103,13 +275,13 @@ mod tests {
 
     #[test]
     fn test_retry_logic_creation() {
-        let retry_logic = RetryLogic::new(3, 100, 5000);
+        let retry_logic = RetryLogic::new(3, 100, 5000, JitterStrategy::Full);
         assert_eq!(retry_logic.max_retries(), 3);
     }
 
     #[test]
     fn test_should_retry() {
-        let retry_logic = RetryLogic::new(3, 100, 5000);
+        let retry_logic = RetryLogic::new(3, 100, 5000, JitterStrategy::Full);
         
         assert!(retry_logic.should_retry(0));
         assert!(retry_logic.should_retry(1));
@@ -120,7 +292,7 @@ mod tests {
 
     #[test]
     fn test_calculate_delay_exponential_backoff() {
-        let retry_logic = RetryLogic::new(5, 100, 5000);
+        let retry_logic = RetryLogic::new(5, 100, 5000, JitterStrategy::Equal);
         
         // First attempt should have no delay
         assert_eq!(retry_logic.calculate_delay(0), 0);
@@ -130,24 +302,97 @@ mod tests {
         let delay2 = retry_logic.calculate_delay(2);
         let delay3 = retry_logic.calculate_delay(3);
         
-        // Should be roughly exponential (allowing for jitter)
-        assert!(delay1 >= 75 && delay1 <= 125); // ~100ms ±25%
-        assert!(delay2 >= 150 && delay2 <= 250); // ~200ms ±25%
-        assert!(delay3 >= 300 && delay3 <= 500); // ~400ms ±25%
+        // Equal jitter keeps each delay within the upper half of its backoff
+        assert!((50..=100).contains(&delay1));
+        assert!((100..=200).contains(&delay2));
+        assert!((200..=400).contains(&delay3));
     }
 
     #[test]
     fn test_calculate_delay_max_cap() {
-        let retry_logic = RetryLogic::new(10, 100, 1000);
+        for jitter in [JitterStrategy::Full, JitterStrategy::Equal, JitterStrategy::Decorrelated] {
+            let retry_logic = RetryLogic::new(10, 100, 1000, jitter);
+            
+            // Large attempt should be capped at max_delay
+            let delay = retry_logic.calculate_delay(10);
+            assert!(delay <= 1000);
+            assert!(retry_logic.calculate_delay(64) <= 1000);
+        }
+    }
+
+    const SAMPLES: usize = 10_000;
+
+    /// Mean of `SAMPLES` delays
+    fn sample_mean(mut delay: impl FnMut() -> u64) -> f64 {
+        (0..SAMPLES).map(|_| delay() as f64).sum::<f64>() / SAMPLES as f64
+    }
+
+    #[test]
+    fn test_full_jitter_is_uniform_up_to_backoff() {
+        let retry_logic = RetryLogic::new(5, 100, 5000, JitterStrategy::Full).with_seed(42);
+        let delays: Vec<u64> = (0..SAMPLES).map(|_| retry_logic.calculate_delay(4)).collect();
         
-        // Large attempt should be capped at max_delay
-        let delay = retry_logic.calculate_delay(10);
-        assert!(delay <= 1250); // max_delay + 25% jitter
+        // Backoff for attempt 4 is 800ms
+        assert!(delays.iter().all(|&delay| delay <= 800));
+        assert!(delays.iter().any(|&delay| delay < 80));
+        assert!(delays.iter().any(|&delay| delay > 720));
+        let mean = sample_mean(|| retry_logic.calculate_delay(4));
+        assert!((mean - 400.0).abs() < 20.0, "mean {}", mean);
+    }
+
+    #[test]
+    fn test_equal_jitter_keeps_half_the_backoff() {
+        let retry_logic = RetryLogic::new(5, 100, 5000, JitterStrategy::Equal).with_seed(42);
+        let delays: Vec<u64> = (0..SAMPLES).map(|_| retry_logic.calculate_delay(4)).collect();
+        
+        assert!(delays.iter().all(|&delay| (400..=800).contains(&delay)));
+        assert!(delays.iter().any(|&delay| delay < 440));
+        assert!(delays.iter().any(|&delay| delay > 760));
+        let mean = sample_mean(|| retry_logic.calculate_delay(4));
+        assert!((mean - 600.0).abs() < 10.0, "mean {}", mean);
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_grows_from_previous_delay() {
+        let retry_logic = RetryLogic::new(5, 100, 5000, JitterStrategy::Decorrelated).with_seed(42);
+        let delays: Vec<u64> = (0..SAMPLES).map(|_| retry_logic.next_delay(3, 1000)).collect();
+        
+        // Uniform in [base, 3 * previous]
+        assert!(delays.iter().all(|&delay| (100..=3000).contains(&delay)));
+        let mean = sample_mean(|| retry_logic.next_delay(3, 1000));
+        assert!((mean - 1550.0).abs() < 50.0, "mean {}", mean);
+        
+        // Capped at the maximum delay, which a long previous delay hits often
+        let delays: Vec<u64> = (0..SAMPLES).map(|_| retry_logic.next_delay(3, 4000)).collect();
+        assert!(delays.iter().all(|&delay| (100..=5000).contains(&delay)));
+        assert!(delays.iter().filter(|&&delay| delay == 5000).count() > SAMPLES / 4);
+        
+        // Never below the base delay, even after a short previous delay
+        assert!((0..SAMPLES).all(|_| retry_logic.next_delay(1, 10) >= 100));
+    }
+
+    #[test]
+    fn test_seeded_jitter_is_reproducible() {
+        let delay_sequence = |jitter: JitterStrategy, seed: u64| {
+            let retry_logic = RetryLogic::new(5, 100, 5000, jitter).with_seed(seed);
+            let mut previous_delay_ms = 100;
+            (1..=20)
+                .map(|attempt| {
+                    previous_delay_ms = retry_logic.next_delay(attempt % 5 + 1, previous_delay_ms);
+                    previous_delay_ms
+                })
+                .collect::<Vec<u64>>()
+        };
+        
+        for jitter in [JitterStrategy::Full, JitterStrategy::Equal, JitterStrategy::Decorrelated] {
+            assert_eq!(delay_sequence(jitter, 7), delay_sequence(jitter, 7));
+            assert_ne!(delay_sequence(jitter, 7), delay_sequence(jitter, 8));
+        }
     }
 
     #[test]
     fn test_calculate_max_total_time() {
-        let retry_logic = RetryLogic::new(3, 100, 5000);
+        let retry_logic = RetryLogic::new(3, 100, 5000, JitterStrategy::Full);
         let total_time = retry_logic.calculate_max_total_time();
         
         // Should be sum of all retry delays
@@ -155,6 +400,70 @@ mod tests {
         assert!(total_time < 10000); // Reasonable upper bound
     }
 
+    #[test]
+    fn test_rate_limit_backs_off_longer_than_timeouts() {
+        let retry_logic = RetryLogic::new(5, 100, 5000, JitterStrategy::Equal).with_class_schedule(
+            RetryErrorClass::RateLimited,
+            BackoffSchedule { base_delay_ms: 1000, max_delay_ms: 30000 },
+        );
+        let rate_limited = rust_common::TradingError::RateLimited { message: "429".to_string(), retry_after_ms: None, rejection: None };
+        let timeout = rust_common::TradingError::Timeout { operation: "place_order".to_string(), timeout_ms: 10 };
+        assert_eq!(RetryErrorClass::of(&rate_limited), RetryErrorClass::RateLimited);
+        assert_eq!(RetryErrorClass::of(&timeout), RetryErrorClass::Timeout);
+        
+        for attempt in 1..=5 {
+            let rate_limited_delay = retry_logic.next_delay_for(RetryErrorClass::of(&rate_limited), attempt, 1000);
+            let timeout_delay = retry_logic.next_delay_for(RetryErrorClass::of(&timeout), attempt, 100);
+            // Equal jitter keeps at least half the backoff, so the ranges never overlap
+            assert!(rate_limited_delay >= 500 * 2_u64.pow(attempt - 1));
+            assert!(timeout_delay <= 100 * 2_u64.pow(attempt - 1));
+            assert!(rate_limited_delay > timeout_delay);
+        }
+        
+        // Classes without an override keep the default schedule
+        assert_eq!(
+            retry_logic.schedule_for(RetryErrorClass::Network),
+            BackoffSchedule { base_delay_ms: 100, max_delay_ms: 5000 }
+        );
+    }
+
+    #[test]
+    fn test_retry_after_overrides_shorter_backoff() {
+        let retry_logic = RetryLogic::new(5, 100, 5000, JitterStrategy::Full);
+        let rate_limited = |retry_after_ms| rust_common::TradingError::RateLimited { message: "429".to_string(), retry_after_ms, rejection: None };
+        
+        // Full jitter never exceeds the 100ms backoff of the first retry
+        assert!((0..100).all(|_| retry_logic.next_delay_after(&rate_limited(Some(2500)), 1, 0) == 2500));
+        assert!((0..100).all(|_| retry_logic.next_delay_after(&rate_limited(None), 1, 0) <= 100));
+        // A Retry-After shorter than the backoff doesn't shorten it
+        assert!((0..100).all(|_| retry_logic.next_delay_after(&rate_limited(Some(0)), 3, 0) <= 400));
+    }
+
+    #[test]
+    fn test_retry_after_capped_at_max_delay() {
+        let retry_logic = RetryLogic::new(5, 100, 5000, JitterStrategy::Full)
+            .with_class_schedule(RetryErrorClass::RateLimited, BackoffSchedule { base_delay_ms: 500, max_delay_ms: 10000 });
+        let rate_limited = rust_common::TradingError::RateLimited { message: "429".to_string(), retry_after_ms: Some(u64::MAX), rejection: None };
+        
+        // A hostile or broken Retry-After can't park the retry loop past the schedule's ceiling
+        assert!((0..100).all(|_| retry_logic.next_delay_after(&rate_limited, 1, 0) == 10000));
+    }
+
+    #[test]
+    fn test_retry_budget_refills_at_configured_rate() {
+        let budget = RetryBudget::new(2.0, 3);
+        let start = Instant::now();
+        
+        for _ in 0..3 {
+            assert!(budget.try_acquire_at(start));
+        }
+        assert!(!budget.try_acquire_at(start));
+        
+        // Half a second at 2 retries/s refills one token
+        assert!(budget.try_acquire_at(start + std::time::Duration::from_millis(500)));
+        assert!(!budget.try_acquire_at(start + std::time::Duration::from_millis(500)));
+    }
+
     #[test]
     fn test_retry_policy_network_error() {
         let error = rust_common::TradingError::NetworkError(
@@ -164,16 +473,17 @@ mod tests {
     }
 
     #[test]
-    fn test_retry_policy_execution_error_timeout() {
-        let error = rust_common::TradingError::ExecutionError {
-            message: "Request timeout".to_string(),
+    fn test_retry_policy_timeout() {
+        let error = rust_common::TradingError::Timeout {
+            operation: "place_order".to_string(),
+            timeout_ms: 30000,
         };
         assert!(matches!(determine_retry_policy(&error), RetryPolicy::ExponentialBackoff));
     }
 
     #[test]
-    fn test_retry_policy_execution_error_insufficient_funds() {
-        let error = rust_common::TradingError::ExecutionError {
+    fn test_retry_policy_insufficient_funds() {
+        let error = rust_common::TradingError::InsufficientFunds {
             message: "Insufficient funds".to_string(),
         };
         assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
@@ -183,7 +493,57 @@ mod tests {
     fn test_retry_policy_risk_limit_error() {
         let error = rust_common::TradingError::RiskLimitError {
             limit: "Position size too large".to_string(),
+            rejection: None,
         };
         assert!(matches!(determine_retry_policy(&error), RetryPolicy::NoRetry));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_retry_policy_ignores_message_wording() {
+        // Wording that used to be matched on no longer changes the outcome
+        let misleading = "insufficient funds; rate limit; market closed; timeout";
+        let rate_limited = rust_common::TradingError::RateLimited { message: misleading.to_string(), retry_after_ms: None, rejection: None };
+        assert!(matches!(determine_retry_policy(&rate_limited), RetryPolicy::ExponentialBackoff));
+        let execution = rust_common::TradingError::ExecutionError { message: misleading.to_string() };
+        assert!(matches!(determine_retry_policy(&execution), RetryPolicy::ExponentialBackoff));
+        let other = rust_common::TradingError::Other(misleading.to_string());
+        assert!(matches!(determine_retry_policy(&other), RetryPolicy::ExponentialBackoff));
+        let funds = rust_common::TradingError::InsufficientFunds { message: "balance 0".to_string() };
+        assert!(matches!(determine_retry_policy(&funds), RetryPolicy::NoRetry));
+        let rejected = rust_common::TradingError::OrderRejected {
+            reason: RejectReason::MarketClosed,
+            message: "try again later".to_string(),
+        };
+        assert!(matches!(determine_retry_policy(&rejected), RetryPolicy::NoRetry));
+    }
+
+    #[test]
+    fn test_reject_reason_from_typed_errors() {
+        let rejected = |reason| rust_common::TradingError::OrderRejected { reason, message: String::new() };
+        for reason in [
+            RejectReason::PriceOutOfBounds,
+            RejectReason::SizeOutOfBounds,
+            RejectReason::MarketClosed,
+            RejectReason::DuplicateOrder,
+            RejectReason::WouldCrossBook,
+            RejectReason::Unsupported,
+            RejectReason::Unknown,
+        ] {
+            assert_eq!(reject_reason(&rejected(reason)), Some(reason));
+        }
+
+        let funds = rust_common::TradingError::InsufficientFunds { message: String::new() };
+        assert_eq!(reject_reason(&funds), Some(RejectReason::InsufficientFunds));
+        let rate_limited = rust_common::TradingError::RateLimited { message: String::new(), retry_after_ms: None, rejection: None };
+        assert_eq!(reject_reason(&rate_limited), Some(RejectReason::RateLimited));
+        let risk = rust_common::TradingError::RiskLimitError { limit: "Daily loss limit".to_string(), rejection: None };
+        assert_eq!(reject_reason(&risk), Some(RejectReason::Unknown));
+
+        let timeout = rust_common::TradingError::Timeout { operation: "place_order".to_string(), timeout_ms: 10 };
+        assert_eq!(reject_reason(&timeout), None);
+        let execution = rust_common::TradingError::ExecutionError { message: "Insufficient funds".to_string() };
+        assert_eq!(reject_reason(&execution), None);
+        let breaker = rust_common::TradingError::CircuitBreakerOpen { exchange: "mock".to_string(), retry_after_ms: 100 };
+        assert_eq!(reject_reason(&breaker), None);
+    }
+}
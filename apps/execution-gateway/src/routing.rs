@@ -0,0 +1,93 @@
+use rust_common::{Direction, Quote};
+use serde::{Deserialize, Serialize};
+
+/// How the gateway picks a venue for decisions that don't name an exchange
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingStrategy {
+    /// The default exchange, then the others in name order
+    #[default]
+    FirstAvailable,
+    /// The venue quoting the best price on the order's side of the book
+    BestPrice,
+    /// The venue charging the least commission for the order
+    LowestFee,
+}
+
+/// A registered venue being considered for an order
+#[derive(Debug, Clone)]
+pub struct VenueCandidate {
+    pub exchange: String,
+    pub quote: Option<Quote>,
+    /// Estimated commission on the whole order, in the quote asset
+    pub commission: f64,
+}
+
+/// Order venues from most to least preferred.
+///
+/// `candidates` arrive in first-available order, which also breaks ties.
+pub fn rank_venues(strategy: RoutingStrategy, direction: Direction, mut candidates: Vec<VenueCandidate>) -> Vec<String> {
+    match strategy {
+        RoutingStrategy::FirstAvailable => {}
+        RoutingStrategy::BestPrice => {
+            // Buys lift the lowest ask, sells hit the highest bid; unquoted venues go last
+            let cost = |candidate: &VenueCandidate| match (&candidate.quote, direction) {
+                (Some(quote), Direction::Long) => quote.ask,
+                (Some(quote), Direction::Short) => -quote.bid,
+                (None, _) => f64::INFINITY,
+            };
+            candidates.sort_by(|a, b| cost(a).total_cmp(&cost(b)));
+        }
+        RoutingStrategy::LowestFee => {
+            candidates.sort_by(|a, b| a.commission.total_cmp(&b.commission));
+        }
+    }
+    candidates.into_iter().map(|candidate| candidate.exchange).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(exchange: &str, quote: Option<(f64, f64)>, commission: f64) -> VenueCandidate {
+        VenueCandidate {
+            exchange: exchange.to_string(),
+            quote: quote.map(|(bid, ask)| Quote {
+                symbol: "BTCUSD".to_string(),
+                bid,
+                ask,
+                bid_size: 1.0,
+                ask_size: 1.0,
+                timestamp: chrono::Utc::now(),
+            }),
+            commission,
+        }
+    }
+
+    #[test]
+    fn test_rank_venues_by_strategy() {
+        let candidates = vec![
+            candidate("default", Some((100.0, 101.0)), 5.0),
+            candidate("unquoted", None, 1.0),
+            candidate("tight", Some((100.5, 100.6)), 5.0),
+        ];
+
+        assert_eq!(
+            rank_venues(RoutingStrategy::FirstAvailable, Direction::Long, candidates.clone()),
+            vec!["default", "unquoted", "tight"]
+        );
+        assert_eq!(
+            rank_venues(RoutingStrategy::BestPrice, Direction::Long, candidates.clone()),
+            vec!["tight", "default", "unquoted"]
+        );
+        assert_eq!(
+            rank_venues(RoutingStrategy::BestPrice, Direction::Short, candidates.clone()),
+            vec!["tight", "default", "unquoted"]
+        );
+        // Equal fees keep first-available order
+        assert_eq!(
+            rank_venues(RoutingStrategy::LowestFee, Direction::Long, candidates),
+            vec!["unquoted", "default", "tight"]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use rust_common::TradingError;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Source of the current time, injectable for tests
+pub type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+
+/// Maps timestamps to trading sessions delimited by a local boundary time.
+///
+/// A session is labelled by the local date on which it ends, so with a 17:00
+/// America/New_York boundary, Monday 17:00 through Tuesday 16:59 is "Tuesday".
+pub struct SessionClock {
+    boundary: NaiveTime,
+    timezone: Tz,
+    clock: Clock,
+}
+
+impl SessionClock {
+    /// Create a clock from a boundary time ("17:00:00") and IANA timezone ("America/New_York")
+    pub fn new(boundary_time: &str, timezone: &str) -> Result<Self, TradingError> {
+        let boundary = NaiveTime::parse_from_str(boundary_time, "%H:%M:%S")
+            .map_err(|e| TradingError::ExecutionError {
+                message: format!("Invalid session boundary time '{}': {}", boundary_time, e),
+            })?;
+        let timezone = timezone.parse::<Tz>()
+            .map_err(|e| TradingError::ExecutionError {
+                message: format!("Invalid session timezone '{}': {}", timezone, e),
+            })?;
+
+        Ok(Self {
+            boundary,
+            timezone,
+            clock: Arc::new(Utc::now),
+        })
+    }
+
+    /// Sessions that roll over at UTC midnight
+    pub fn utc_midnight() -> Self {
+        Self {
+            boundary: NaiveTime::MIN,
+            timezone: Tz::UTC,
+            clock: Arc::new(Utc::now),
+        }
+    }
+
+    /// Replace the time source
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Current time according to this clock
+    pub fn now(&self) -> DateTime<Utc> {
+        (self.clock)()
+    }
+
+    /// Trading date of the session containing `at`
+    pub fn session_date_at(&self, at: DateTime<Utc>) -> NaiveDate {
+        let local = at.with_timezone(&self.timezone);
+        let date = local.date_naive();
+        if self.boundary > NaiveTime::MIN && local.time() >= self.boundary {
+            date + Duration::days(1)
+        } else {
+            date
+        }
+    }
+
+    /// Identifier of the session containing `at`
+    pub fn session_id_at(&self, at: DateTime<Utc>) -> String {
+        self.session_date_at(at).format("%Y-%m-%d").to_string()
+    }
+
+    /// Identifier of the current session
+    pub fn current_session_id(&self) -> String {
+        self.session_id_at(self.now())
+    }
+}
+
+/// Per-session activity counters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionStats {
+    pub session_id: String,
+    pub orders_placed: u64,
+    pub filled_notional: f64,
+}
+
+/// Counters that reset whenever the session clock rolls into a new session
+pub struct SessionCounters {
+    clock: Arc<SessionClock>,
+    stats: Mutex<SessionStats>,
+}
+
+impl SessionCounters {
+    pub fn new(clock: Arc<SessionClock>) -> Self {
+        let stats = SessionStats {
+            session_id: clock.current_session_id(),
+            ..Default::default()
+        };
+
+        Self {
+            clock,
+            stats: Mutex::new(stats),
+        }
+    }
+
+    /// Record a placed order and its filled notional
+    pub fn record_order(&self, filled_notional: f64) {
+        let mut stats = self.stats.lock().unwrap();
+        self.roll_session(&mut stats);
+        stats.orders_placed += 1;
+        stats.filled_notional += filled_notional;
+    }
+
+    /// Get the counters for the current session
+    pub fn snapshot(&self) -> SessionStats {
+        let mut stats = self.stats.lock().unwrap();
+        self.roll_session(&mut stats);
+        stats.clone()
+    }
+
+    fn roll_session(&self, stats: &mut SessionStats) {
+        let session_id = self.clock.current_session_id();
+        if stats.session_id != session_id {
+            *stats = SessionStats {
+                session_id,
+                ..Default::default()
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn mock_clock(start: DateTime<Utc>) -> (Clock, Arc<Mutex<DateTime<Utc>>>) {
+        let now = Arc::new(Mutex::new(start));
+        let clock_now = now.clone();
+        (Arc::new(move || *clock_now.lock().unwrap()), now)
+    }
+
+    #[test]
+    fn test_session_id_uses_local_boundary() {
+        let clock = SessionClock::new("17:00:00", "America/New_York").unwrap();
+
+        // 16:59 ET on 2024-03-04 (EST, UTC-5) is still the Monday session
+        let before = Utc.with_ymd_and_hms(2024, 3, 4, 21, 59, 0).unwrap();
+        assert_eq!(clock.session_id_at(before), "2024-03-04");
+
+        // 17:00 ET starts the Tuesday session
+        let after = Utc.with_ymd_and_hms(2024, 3, 4, 22, 0, 0).unwrap();
+        assert_eq!(clock.session_id_at(after), "2024-03-05");
+    }
+
+    #[test]
+    fn test_invalid_session_config_is_rejected() {
+        assert!(SessionClock::new("25:00:00", "UTC").is_err());
+        assert!(SessionClock::new("17:00:00", "Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn test_counters_reset_at_session_boundary_not_utc_midnight() {
+        // 18:00 ET on Monday, just after the 17:00 boundary
+        let (clock, now) = mock_clock(Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap() - Duration::hours(1));
+        let session_clock = Arc::new(
+            SessionClock::new("17:00:00", "America/New_York").unwrap().with_clock(clock),
+        );
+        let counters = SessionCounters::new(session_clock);
+
+        counters.record_order(1000.0);
+
+        // Crossing 00:00 UTC (20:00 ET) keeps the same session
+        *now.lock().unwrap() = Utc.with_ymd_and_hms(2024, 3, 5, 1, 0, 0).unwrap();
+        counters.record_order(500.0);
+        let stats = counters.snapshot();
+        assert_eq!(stats.session_id, "2024-03-05");
+        assert_eq!(stats.orders_placed, 2);
+        assert_eq!(stats.filled_notional, 1500.0);
+
+        // Crossing 17:00 ET (22:00 UTC) resets the counters
+        *now.lock().unwrap() = Utc.with_ymd_and_hms(2024, 3, 5, 22, 0, 0).unwrap();
+        let stats = counters.snapshot();
+        assert_eq!(stats.session_id, "2024-03-06");
+        assert_eq!(stats.orders_placed, 0);
+        assert_eq!(stats.filled_notional, 0.0);
+    }
+}
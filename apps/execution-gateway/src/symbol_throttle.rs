@@ -0,0 +1,159 @@
+use rust_common::Symbol;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Caps on what one symbol may send within the throttle window; `None` leaves that side unlimited
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThrottleLimits {
+    pub max_orders: Option<u32>,
+    /// Summed order notional, in the quote asset
+    pub max_notional: Option<f64>,
+}
+
+/// Which limit a throttled order ran into, and when it would fit again
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThrottleExceeded {
+    Orders { limit: u32, retry_after: Duration },
+    Notional { limit: f64, retry_after: Duration },
+}
+
+impl ThrottleExceeded {
+    pub fn retry_after(&self) -> Duration {
+        match self {
+            ThrottleExceeded::Orders { retry_after, .. } | ThrottleExceeded::Notional { retry_after, .. } => *retry_after,
+        }
+    }
+}
+
+/// Per-symbol order count and notional limits over a sliding window.
+///
+/// Symbols are normalized, so `btc-usd`, `BTC/USD` and `BTCUSD` share one window and one limit.
+pub struct SymbolThrottle {
+    window: Duration,
+    default_limits: ThrottleLimits,
+    symbol_limits: HashMap<Symbol, ThrottleLimits>,
+    sent: Mutex<HashMap<Symbol, VecDeque<(Instant, f64)>>>, // symbol -> (sent at, notional), oldest first
+}
+
+impl SymbolThrottle {
+    /// Apply `default_limits` to every symbol without its own entry in `symbol_limits`
+    pub fn new(window: Duration, default_limits: ThrottleLimits, symbol_limits: HashMap<String, ThrottleLimits>) -> Self {
+        Self {
+            window,
+            default_limits,
+            symbol_limits: symbol_limits.into_iter()
+                .map(|(symbol, limits)| (Symbol::from(symbol), limits))
+                .collect(),
+            sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn limits_for(&self, symbol: &str) -> ThrottleLimits {
+        self.symbol_limits.get(&Symbol::from(symbol)).copied().unwrap_or(self.default_limits)
+    }
+
+    /// Count an order of `notional` against `symbol`, or refuse it if it would exceed a limit
+    pub fn check(&self, symbol: &str, notional: f64) -> Result<(), ThrottleExceeded> {
+        self.check_at(symbol, notional, Instant::now())
+    }
+
+    fn check_at(&self, symbol: &str, notional: f64, now: Instant) -> Result<(), ThrottleExceeded> {
+        let limits = self.limits_for(symbol);
+        if limits == ThrottleLimits::default() {
+            return Ok(());
+        }
+
+        let mut sent = self.sent.lock().unwrap();
+        let window = sent.entry(Symbol::from(symbol)).or_default();
+        while window.front().is_some_and(|(sent_at, _)| now.saturating_duration_since(*sent_at) >= self.window) {
+            window.pop_front();
+        }
+        let expires_in = |sent_at: Instant| self.window.saturating_sub(now.saturating_duration_since(sent_at));
+
+        if let Some(limit) = limits.max_orders {
+            if window.len() >= limit as usize {
+                // The order fits once enough of the oldest ones leave the window
+                let retry_after = window.len().checked_sub(limit as usize)
+                    .and_then(|index| window.get(index))
+                    .map_or(Duration::ZERO, |(sent_at, _)| expires_in(*sent_at));
+                return Err(ThrottleExceeded::Orders { limit, retry_after });
+            }
+        }
+
+        if let Some(limit) = limits.max_notional {
+            let mut total: f64 = window.iter().map(|(_, sent_notional)| sent_notional).sum::<f64>() + notional;
+            if total > limit {
+                let mut retry_after = self.window;
+                for (sent_at, sent_notional) in window.iter() {
+                    total -= sent_notional;
+                    if total <= limit {
+                        retry_after = expires_in(*sent_at);
+                        break;
+                    }
+                }
+                return Err(ThrottleExceeded::Notional { limit, retry_after });
+            }
+        }
+
+        window.push_back((now, notional));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_and_notional_limits_slide_per_symbol() {
+        let throttle = SymbolThrottle::new(
+            Duration::from_secs(60),
+            ThrottleLimits { max_orders: Some(10), max_notional: None },
+            HashMap::from([(
+                "ETHUSD".to_string(),
+                ThrottleLimits { max_orders: None, max_notional: Some(100_000.0) },
+            )]),
+        );
+        let start = Instant::now();
+
+        for i in 0..10 {
+            assert!(throttle.check_at("BTCUSD", 5_000.0, start + Duration::from_secs(i)).is_ok());
+        }
+        let exceeded = throttle.check_at("BTCUSD", 5_000.0, start + Duration::from_secs(10)).unwrap_err();
+        assert_eq!(exceeded, ThrottleExceeded::Orders { limit: 10, retry_after: Duration::from_secs(50) });
+
+        // Once the first order leaves the window there is room for one more
+        assert!(throttle.check_at("BTCUSD", 5_000.0, start + Duration::from_secs(60)).is_ok());
+        assert!(throttle.check_at("BTCUSD", 5_000.0, start + Duration::from_secs(60)).is_err());
+
+        // The override replaces the default rather than adding to it
+        for _ in 0..20 {
+            assert!(throttle.check_at("ETHUSD", 5_000.0, start).is_ok());
+        }
+        let exceeded = throttle.check_at("ETHUSD", 1.0, start + Duration::from_secs(30)).unwrap_err();
+        assert_eq!(exceeded, ThrottleExceeded::Notional { limit: 100_000.0, retry_after: Duration::from_secs(30) });
+        assert!(throttle.check_at("ETHUSD", 1.0, start + Duration::from_secs(60)).is_ok());
+    }
+
+    #[test]
+    fn test_symbol_spellings_share_one_limit() {
+        let throttle = SymbolThrottle::new(
+            Duration::from_secs(60),
+            ThrottleLimits::default(),
+            HashMap::from([(
+                "btc/usd".to_string(),
+                ThrottleLimits { max_orders: Some(3), max_notional: None },
+            )]),
+        );
+        let start = Instant::now();
+
+        assert_eq!(throttle.limits_for("BTCUSD").max_orders, Some(3));
+        for symbol in ["btc-usd", "BTC/USD", "BTCUSD"] {
+            assert!(throttle.check_at(symbol, 5_000.0, start).is_ok());
+        }
+        assert!(throttle.check_at("btc-usd", 5_000.0, start).is_err());
+        assert!(throttle.check_at("BTC/USD", 5_000.0, start).is_err());
+    }
+}
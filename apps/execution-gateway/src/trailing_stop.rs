@@ -0,0 +1,113 @@
+use rust_common::Direction;
+use serde::{Deserialize, Serialize};
+
+/// Stop that follows the best price seen by a fixed percentage.
+///
+/// Held in the gateway until triggered. `direction` is the position being protected:
+/// a long trail ratchets up with new highs and fires a sell, a short trail ratchets
+/// down and fires a buy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrailingStop {
+    pub order_id: String,
+    pub symbol: String,
+    pub exchange: String,
+    pub direction: Direction,
+    pub quantity: f64,
+    pub trail_pct: f64,
+    pub best_price: f64,
+    pub stop_price: f64,
+}
+
+impl TrailingStop {
+    pub fn new(
+        order_id: String,
+        symbol: String,
+        exchange: String,
+        direction: Direction,
+        quantity: f64,
+        trail_pct: f64,
+        reference_price: f64,
+    ) -> Self {
+        Self {
+            order_id,
+            symbol,
+            exchange,
+            direction,
+            quantity,
+            trail_pct,
+            best_price: reference_price,
+            stop_price: Self::stop_for(direction, reference_price, trail_pct),
+        }
+    }
+
+    /// Feed a market price, ratcheting the stop toward it. Returns true once the stop is hit.
+    pub fn update(&mut self, price: f64) -> bool {
+        let improved = match self.direction {
+            Direction::Long => price > self.best_price,
+            Direction::Short => price < self.best_price,
+        };
+        if improved {
+            self.best_price = price;
+            self.stop_price = Self::stop_for(self.direction, price, self.trail_pct);
+        }
+
+        match self.direction {
+            Direction::Long => price <= self.stop_price,
+            Direction::Short => price >= self.stop_price,
+        }
+    }
+
+    fn stop_for(direction: Direction, best_price: f64, trail_pct: f64) -> f64 {
+        match direction {
+            Direction::Long => best_price * (1.0 - trail_pct / 100.0),
+            Direction::Short => best_price * (1.0 + trail_pct / 100.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_stop(direction: Direction) -> TrailingStop {
+        TrailingStop::new(
+            "order-1".to_string(),
+            "BTCUSD".to_string(),
+            "default".to_string(),
+            direction,
+            0.1,
+            2.0,
+            100.0,
+        )
+    }
+
+    #[test]
+    fn test_long_stop_moves_up_never_down() {
+        let mut stop = create_stop(Direction::Long);
+        assert!((stop.stop_price - 98.0).abs() < 1e-9);
+
+        assert!(!stop.update(110.0));
+        assert!((stop.stop_price - 107.8).abs() < 1e-9);
+
+        // A pullback that stays above the stop leaves it where it is
+        assert!(!stop.update(108.0));
+        assert!((stop.stop_price - 107.8).abs() < 1e-9);
+        assert_eq!(stop.best_price, 110.0);
+
+        assert!(stop.update(107.5));
+    }
+
+    #[test]
+    fn test_short_stop_moves_down_never_up() {
+        let mut stop = create_stop(Direction::Short);
+        assert!((stop.stop_price - 102.0).abs() < 1e-9);
+
+        assert!(!stop.update(90.0));
+        assert!((stop.stop_price - 91.8).abs() < 1e-9);
+
+        assert!(!stop.update(91.0));
+        assert!((stop.stop_price - 91.8).abs() < 1e-9);
+
+        assert!(stop.update(92.0));
+    }
+}
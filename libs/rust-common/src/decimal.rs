@@ -0,0 +1,144 @@
+//! Decimal-backed rounding for prices and quantities, avoiding f64 drift.
+
+use rust_decimal::RoundingStrategy;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::{OrderRequest, TradingError};
+
+pub use rust_decimal::Decimal;
+
+/// How a value is rounded to a tick or lot increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round half to even (banker's rounding)
+    #[default]
+    HalfEven,
+    /// Round half away from zero
+    HalfUp,
+    /// Round toward zero
+    Down,
+    /// Round away from zero
+    Up,
+}
+
+impl RoundingMode {
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::Down => RoundingStrategy::ToZero,
+            RoundingMode::Up => RoundingStrategy::AwayFromZero,
+        }
+    }
+}
+
+/// Convert an f64 to the Decimal with the same shortest decimal representation
+pub fn to_decimal(value: f64) -> Result<Decimal, TradingError> {
+    if !value.is_finite() {
+        return Err(TradingError::ExecutionError {
+            message: format!("Cannot represent {} as a decimal", value),
+        });
+    }
+    Decimal::from_str(&value.to_string()).map_err(|e| TradingError::ExecutionError {
+        message: format!("Cannot represent {} as a decimal: {}", value, e),
+    })
+}
+
+/// Round `value` to a multiple of `increment`; non-positive increments leave it unchanged
+pub fn round_to_increment(value: Decimal, increment: Decimal, mode: RoundingMode) -> Decimal {
+    if increment <= Decimal::ZERO {
+        return value;
+    }
+    match value.checked_div(increment) {
+        Some(steps) => (steps.round_dp_with_strategy(0, mode.strategy()) * increment).normalize(),
+        None => value,
+    }
+}
+
+/// f64 convenience wrapper around [`round_to_increment`], returning `value` if it can't be represented
+pub fn round_f64_to_increment(value: f64, increment: f64, mode: RoundingMode) -> f64 {
+    use rust_decimal::prelude::ToPrimitive;
+
+    match (to_decimal(value), to_decimal(increment)) {
+        (Ok(value_dec), Ok(increment_dec)) => round_to_increment(value_dec, increment_dec, mode)
+            .to_f64()
+            .unwrap_or(value),
+        _ => value,
+    }
+}
+
+/// Decimal-backed order size and price after tick/lot rounding
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecimalOrderAmounts {
+    pub size: Decimal,
+    pub price: Option<Decimal>,
+}
+
+impl OrderRequest {
+    /// Round the size down to `lot_size` and the price half-even to `tick_size`
+    pub fn rounded_to(&self, tick_size: f64, lot_size: f64) -> Result<DecimalOrderAmounts, TradingError> {
+        let size = round_to_increment(to_decimal(self.size)?, to_decimal(lot_size)?, RoundingMode::Down);
+        let price = match self.price {
+            Some(price) => Some(round_to_increment(
+                to_decimal(price)?,
+                to_decimal(tick_size)?,
+                RoundingMode::HalfEven,
+            )),
+            None => None,
+        };
+
+        Ok(DecimalOrderAmounts { size, price })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OrderSide, OrderType, TimeInForce};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_rounding_modes_at_midpoint() {
+        let value = dec("50000.125");
+        let tick = dec("0.01");
+        assert_eq!(round_to_increment(value, tick, RoundingMode::HalfEven), dec("50000.12"));
+        assert_eq!(round_to_increment(value, tick, RoundingMode::HalfUp), dec("50000.13"));
+        assert_eq!(round_to_increment(value, tick, RoundingMode::Down), dec("50000.12"));
+        assert_eq!(round_to_increment(value, tick, RoundingMode::Up), dec("50000.13"));
+    }
+
+    #[test]
+    fn test_rounded_to_returns_exact_decimals() {
+        let order = OrderRequest {
+            id: Uuid::new_v4(),
+            symbol: "BTCUSD".to_string(),
+            side: OrderSide::Buy,
+            size: 0.3,
+            price: Some(50000.126),
+            order_type: OrderType::Limit,
+            timestamp: Utc::now(),
+            reduce_only: false,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+        };
+
+        // 0.3 / 0.1 is 2.9999999999999996 in f64, which would floor to 0.2
+        let amounts = order.rounded_to(0.01, 0.1).unwrap();
+        assert_eq!(amounts.size, dec("0.3"));
+        assert_eq!(amounts.price, Some(dec("50000.13")));
+    }
+
+    #[test]
+    fn test_non_finite_values_are_rejected() {
+        assert!(to_decimal(f64::NAN).is_err());
+        assert!(to_decimal(f64::INFINITY).is_err());
+        assert!(round_f64_to_increment(f64::NAN, 0.01, RoundingMode::HalfEven).is_nan());
+    }
+}
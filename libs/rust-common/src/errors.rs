@@ -1,12 +1,56 @@
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::RejectReason;
+
+/// Why a decision was rejected by a risk gate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectionReason {
+    ExcessiveLeverage,
+    ExcessiveRiskPerTrade,
+    PortfolioRiskExceeded,
+    RiskTooHighForLeverage,
+    PriceDeviatesFromMark,
+    OrderNotionalExceeded,
+    PositionLimitExceeded,
+    TotalNotionalExceeded,
+    DailyLossLimitReached,
+    SymbolThrottled,
+    TradingHalted,
+}
+
+/// Structured explanation of a risk rejection, returned to the signal layer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RejectionFeedback {
+    pub reason: RejectionReason,
+    pub rule: String,
+    pub message: String,
+    pub suggested_correction: Option<String>,
+}
+
+impl RejectionFeedback {
+    pub fn new(reason: RejectionReason, rule: &str, message: String, suggested_correction: String) -> Self {
+        Self {
+            reason,
+            rule: rule.to_string(),
+            message,
+            suggested_correction: Some(suggested_correction),
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum TradingError {
     #[error("Order execution failed: {message}")]
     ExecutionError { message: String },
     
+    /// `rejection` explains the gateway risk gate that refused the order; `None` when the exchange did
     #[error("Risk limit violated: {limit}")]
-    RiskLimitError { limit: String },
+    RiskLimitError { limit: String, rejection: Option<RejectionFeedback> },
+    
+    #[error("Circuit breaker open for exchange {exchange}; retry in {retry_after_ms} ms")]
+    CircuitBreakerOpen { exchange: String, retry_after_ms: u64 },
     
     #[error("Data error: {source}")]
     DataError { source: String },
@@ -16,4 +60,44 @@ pub enum TradingError {
     
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
-}
\ No newline at end of file
+    
+    #[error("{operation} timeout after {timeout_ms}ms")]
+    Timeout { operation: String, timeout_ms: u64 },
+    
+    /// `retry_after_ms` is the wait the exchange asked for, e.g. from a `Retry-After` header;
+    /// `rejection` is set when the gateway's own throttle refused the order
+    #[error("Rate limited by exchange: {message}")]
+    RateLimited { message: String, retry_after_ms: Option<u64>, rejection: Option<RejectionFeedback> },
+    
+    #[error("Insufficient funds: {message}")]
+    InsufficientFunds { message: String },
+    
+    #[error("Order rejected: {message}")]
+    OrderRejected { reason: RejectReason, message: String },
+    
+    /// A request that failed validation before reaching an exchange
+    #[error("Invalid {field}: {message}")]
+    ValidationError { field: String, message: String },
+    
+    /// Exchange failures no other variant describes
+    #[error("{0}")]
+    Other(String),
+}
+
+impl TradingError {
+    /// Risk rejection explained by the gate that raised it
+    pub fn risk_rejection(feedback: RejectionFeedback) -> Self {
+        TradingError::RiskLimitError {
+            limit: feedback.message.clone(),
+            rejection: Some(feedback),
+        }
+    }
+
+    /// Structured feedback for a rejection raised by a gateway risk gate
+    pub fn rejection(&self) -> Option<&RejectionFeedback> {
+        match self {
+            TradingError::RiskLimitError { rejection, .. } | TradingError::RateLimited { rejection, .. } => rejection.as_ref(),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,11 @@
 pub mod types;
 pub mod errors;
 pub mod trading_models;
+#[cfg(feature = "decimal")]
+pub mod decimal;
 
 pub use types::*;
 pub use errors::*;
-pub use trading_models::*;
\ No newline at end of file
+pub use trading_models::*;
+#[cfg(feature = "decimal")]
+pub use decimal::*;
\ No newline at end of file
@@ -1,10 +1,16 @@
 //! Trading enums compatible with Python models.
 
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Timeframe {
+    #[serde(rename = "1m")]
+    M1,
+    #[serde(rename = "5m")]
+    M5,
     #[serde(rename = "15m")]
     M15,
     #[serde(rename = "1h")]
@@ -18,6 +24,8 @@ pub enum Timeframe {
 impl Timeframe {
     pub fn as_str(&self) -> &'static str {
         match self {
+            Self::M1 => "1m",
+            Self::M5 => "5m",
             Self::M15 => "15m",
             Self::H1 => "1h",
             Self::H4 => "4h",
@@ -27,6 +35,8 @@ impl Timeframe {
     
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
+            "1m" => Some(Self::M1),
+            "5m" => Some(Self::M5),
             "15m" => Some(Self::M15),
             "1h" => Some(Self::H1),
             "4h" => Some(Self::H4),
@@ -34,6 +44,65 @@ impl Timeframe {
             _ => None,
         }
     }
+
+    /// Length of one bar in minutes
+    pub fn minutes(&self) -> i64 {
+        match self {
+            Self::M1 => 1,
+            Self::M5 => 5,
+            Self::M15 => 15,
+            Self::H1 => 60,
+            Self::H4 => 240,
+            Self::D1 => 1440,
+        }
+    }
+
+    /// Length of one bar
+    pub fn duration(&self) -> Duration {
+        Duration::minutes(self.minutes())
+    }
+
+    /// Bars in a year of round-the-clock trading, used to annualize per-bar statistics
+    pub fn periods_per_year(&self) -> f64 {
+        365.0 * 24.0 * 60.0 / self.minutes() as f64
+    }
+
+    /// Next longer timeframe, if any
+    pub fn higher(&self) -> Option<Self> {
+        let all = Self::all();
+        let index = all.iter().position(|timeframe| timeframe == self)?;
+        all.get(index + 1).copied()
+    }
+
+    /// Next shorter timeframe, if any
+    pub fn lower(&self) -> Option<Self> {
+        let all = Self::all();
+        let index = all.iter().position(|timeframe| timeframe == self)?;
+        index.checked_sub(1).map(|lower| all[lower])
+    }
+
+    /// Every timeframe, shortest first
+    pub fn all() -> [Self; 6] {
+        [Self::M1, Self::M5, Self::M15, Self::H1, Self::H4, Self::D1]
+    }
+
+    /// Position among `all()` counting from 1, so longer timeframes rank higher
+    pub fn rank(&self) -> usize {
+        Self::all().iter().position(|timeframe| timeframe == self).map_or(0, |index| index + 1)
+    }
+}
+
+/// Timeframes order by bar length
+impl Ord for Timeframe {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.minutes().cmp(&other.minutes())
+    }
+}
+
+impl PartialOrd for Timeframe {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -53,13 +122,49 @@ pub enum Direction {
     Short,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum OrderType {
     Market,
     Limit,
     Stop,
     StopLimit,
+    StopLoss,
+    TakeProfit,
+    TrailingStop,
+}
+
+/// Why an exchange or the gateway refused an order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectReason {
+    InsufficientFunds,
+    PriceOutOfBounds,
+    SizeOutOfBounds,
+    MarketClosed,
+    RateLimited,
+    DuplicateOrder,
+    /// A post-only order whose limit price would have crossed the book
+    WouldCrossBook,
+    /// The exchange doesn't offer the order type or feature requested
+    Unsupported,
+    /// Refused for a reason that isn't recognised
+    Unknown,
+}
+
+/// How long an order stays working before the unfilled remainder is pulled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TimeInForce {
+    /// Good till cancelled
+    #[default]
+    Gtc,
+    /// Good till the given time, then expired
+    Gtd(DateTime<Utc>),
+    /// Immediate or cancel: fill what is available now, cancel the rest
+    Ioc,
+    /// Fill or kill: fill completely now or not at all
+    Fok,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -84,6 +189,10 @@ pub enum PatternType {
     Engulfing,
     Doji,
     Divergence,
+    DoubleTop,
+    DoubleBottom,
+    HeadAndShoulders,
+    InverseHeadAndShoulders,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -0,0 +1,75 @@
+//! Exchange trading rules shared by adapters and clients.
+
+use chrono::{DateTime, Datelike, NaiveTime, Timelike, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::TradingError;
+
+/// Exchange-specific trading rules and constraints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeInfo {
+    pub name: String,
+    pub tick_size: f64,
+    pub lot_size: f64,
+    pub min_order_size: f64,
+    pub max_order_size: f64,
+    pub min_price: f64,
+    pub max_price: f64,
+    pub trading_hours: Vec<TradingHours>,
+    pub supported_order_types: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradingHours {
+    pub day_of_week: u8, // 0 = Sunday, 6 = Saturday
+    pub open_time: String, // "09:30:00"
+    pub close_time: String, // "16:00:00"
+    pub timezone: String, // "America/New_York"
+}
+
+impl TradingHours {
+    /// Whether `at` falls inside this window; a close before the open runs overnight into the next day
+    pub fn contains(&self, at: DateTime<Utc>) -> Result<bool, TradingError> {
+        let timezone = self.timezone.parse::<Tz>()
+            .map_err(|e| TradingError::ExecutionError {
+                message: format!("Invalid trading hours timezone '{}': {}", self.timezone, e),
+            })?;
+        let open = Self::parse_time(&self.open_time)?;
+        let close = Self::parse_time(&self.close_time)?;
+
+        // Compare at whole seconds so a "23:59:59" close covers the final second
+        let local = at.with_timezone(&timezone);
+        let time = local.time().with_nanosecond(0).unwrap_or_else(|| local.time());
+        let weekday = local.weekday().num_days_from_sunday() as u8;
+
+        if open <= close {
+            Ok(weekday == self.day_of_week && time >= open && time <= close)
+        } else {
+            let next_day = (self.day_of_week + 1) % 7;
+            Ok((weekday == self.day_of_week && time >= open) || (weekday == next_day && time <= close))
+        }
+    }
+
+    fn parse_time(time: &str) -> Result<NaiveTime, TradingError> {
+        NaiveTime::parse_from_str(time, "%H:%M:%S")
+            .map_err(|e| TradingError::ExecutionError {
+                message: format!("Invalid trading hours time '{}': {}", time, e),
+            })
+    }
+}
+
+impl ExchangeInfo {
+    /// Whether any trading window contains `at`; an exchange without windows never closes
+    pub fn is_open_at(&self, at: DateTime<Utc>) -> Result<bool, TradingError> {
+        if self.trading_hours.is_empty() {
+            return Ok(true);
+        }
+        for window in &self.trading_hours {
+            if window.contains(at)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
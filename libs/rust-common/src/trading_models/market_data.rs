@@ -1,30 +1,46 @@
 //! Market data structures.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, DurationRound, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
 
 use super::enums::Timeframe;
+use super::schema::decimal_str;
+use super::symbol::{self, Symbol};
+
+#[cfg(feature = "arrow")]
+mod columnar;
+#[cfg(feature = "arrow")]
+pub use columnar::*;
 
 /// OHLCV market data bar.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketBar {
+    #[serde(deserialize_with = "symbol::normalized")]
     pub symbol: String,
     pub timeframe: Timeframe,
     pub timestamp: DateTime<Utc>,
     
     // Price data - using f64 for Rust, will be converted to/from Decimal in Python
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub open: f64,
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub high: f64,
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub low: f64,
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub close: f64,
     
     // Volume data
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub volume: f64,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub quote_volume: Option<f64>,
     
     // Additional metadata
     pub trades_count: Option<u64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub taker_buy_volume: Option<f64>,
 }
 
@@ -53,19 +69,418 @@ impl MarketBar {
         
         Ok(())
     }
+    
+    /// Validate that the bar starts on a boundary of its timeframe, in UTC.
+    ///
+    /// Separate from `validate` so callers working with partial bars can skip it.
+    pub fn validate_alignment(&self) -> Result<(), String> {
+        let boundary = self.timestamp
+            .duration_trunc(self.timeframe.duration())
+            .map_err(|e| format!("Cannot align timestamp {}: {}", self.timestamp, e))?;
+        
+        if boundary != self.timestamp {
+            return Err(format!(
+                "Timestamp {} is not aligned to the {} timeframe (expected {})",
+                self.timestamp,
+                self.timeframe.as_str(),
+                boundary
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Find missing bars in a series.
+///
+/// Returns the first and last missing bar start time of each gap, i.e. the range to
+/// re-fetch. Bars need not be sorted; they are ordered by timestamp first.
+pub fn find_gaps(bars: &[MarketBar], timeframe: Timeframe) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let interval = timeframe.duration();
+    let mut timestamps: Vec<DateTime<Utc>> = bars.iter().map(|bar| bar.timestamp).collect();
+    timestamps.sort();
+    timestamps.dedup();
+    
+    timestamps
+        .windows(2)
+        .filter(|pair| pair[1] - pair[0] > interval)
+        .map(|pair| (pair[0] + interval, pair[1] - interval))
+        .collect()
+}
+
+/// Columns of a bar CSV, in the order `write_bars_csv` emits them.
+const BAR_CSV_COLUMNS: [&str; 6] = ["timestamp", "open", "high", "low", "close", "volume"];
+
+/// A CSV row that was skipped on import.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRowError {
+    /// 1-based line number, counting the header
+    pub line: usize,
+    pub message: String,
+}
+
+/// Bars read from a CSV, plus the malformed rows that were skipped.
+#[derive(Debug, Clone)]
+pub struct BarCsvImport {
+    pub bars: Vec<MarketBar>,
+    pub errors: Vec<CsvRowError>,
+}
+
+impl BarCsvImport {
+    pub fn error_count(&self) -> usize {
+        self.errors.len()
+    }
+}
+
+/// Read `timestamp,open,high,low,close,volume` rows into bars of `symbol`, normalized, and `timeframe`.
+///
+/// Header columns are matched by name, so their order may differ and extra columns are
+/// ignored. Timestamps are RFC 3339 or epoch milliseconds. Rows that fail to parse or
+/// fail `MarketBar::validate` are skipped and reported; only I/O errors and a missing
+/// header abort the import.
+pub fn read_bars_csv<R: Read>(reader: R, symbol: impl Into<Symbol>, timeframe: Timeframe) -> io::Result<BarCsvImport> {
+    let symbol = symbol.into();
+    let mut lines = BufReader::new(reader).lines().enumerate();
+    let header = loop {
+        match lines.next() {
+            Some((_, line)) => {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    break line;
+                }
+            }
+            None => return Ok(BarCsvImport { bars: Vec::new(), errors: Vec::new() }),
+        }
+    };
+
+    let header: Vec<String> = header.split(',').map(|column| column.trim().to_ascii_lowercase()).collect();
+    let mut indices = [0usize; BAR_CSV_COLUMNS.len()];
+    for (index, name) in indices.iter_mut().zip(BAR_CSV_COLUMNS) {
+        *index = header.iter().position(|column| column == name).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Bar CSV header is missing the '{}' column", name))
+        })?;
+    }
+
+    let mut import = BarCsvImport { bars: Vec::new(), errors: Vec::new() };
+    for (line_index, line) in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row: Vec<&str> = line.split(',').map(str::trim).collect();
+        match parse_bar_row(&row, &indices, symbol.as_str(), timeframe) {
+            Ok(bar) => import.bars.push(bar),
+            Err(message) => import.errors.push(CsvRowError { line: line_index + 1, message }),
+        }
+    }
+    Ok(import)
+}
+
+/// Write bars as `timestamp,open,high,low,close,volume` with RFC 3339 timestamps.
+pub fn write_bars_csv<W: Write>(mut writer: W, bars: &[MarketBar]) -> io::Result<()> {
+    writeln!(writer, "{}", BAR_CSV_COLUMNS.join(","))?;
+    for bar in bars {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{}",
+            bar.timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            bar.open,
+            bar.high,
+            bar.low,
+            bar.close,
+            bar.volume
+        )?;
+    }
+    writer.flush()
+}
+
+fn parse_bar_row(row: &[&str], indices: &[usize; 6], symbol: &str, timeframe: Timeframe) -> Result<MarketBar, String> {
+    let field = |column: usize| -> Result<&str, String> {
+        row.get(indices[column])
+            .copied()
+            .ok_or_else(|| format!("Missing '{}' column", BAR_CSV_COLUMNS[column]))
+    };
+    let number = |column: usize| -> Result<f64, String> {
+        let value = field(column)?;
+        value
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+            .ok_or_else(|| format!("Invalid {} '{}'", BAR_CSV_COLUMNS[column], value))
+    };
+
+    let bar = MarketBar {
+        symbol: symbol.to_string(),
+        timeframe,
+        timestamp: parse_csv_timestamp(field(0)?)?,
+        open: number(1)?,
+        high: number(2)?,
+        low: number(3)?,
+        close: number(4)?,
+        volume: number(5)?,
+        quote_volume: None,
+        trades_count: None,
+        taker_buy_volume: None,
+    };
+    bar.validate()?;
+    Ok(bar)
+}
+
+fn parse_csv_timestamp(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(millis) = value.parse::<i64>() {
+        return DateTime::from_timestamp_millis(millis).ok_or_else(|| format!("Timestamp out of range '{}'", value));
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid timestamp '{}': {}", value, e))
+}
+
+/// One price level of an order book.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OrderBookLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// Order book depth for a symbol, best levels first on each side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    #[serde(deserialize_with = "symbol::normalized")]
+    pub symbol: String,
+    pub bids: Vec<OrderBookLevel>,
+    pub asks: Vec<OrderBookLevel>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl OrderBook {
+    /// Whether the best bid meets or exceeds the best ask.
+    pub fn is_crossed(&self) -> bool {
+        match (self.bids.first(), self.asks.first()) {
+            (Some(bid), Some(ask)) => bid.price >= ask.price,
+            _ => false,
+        }
+    }
+    
+    /// Top of book as a quote; `None` unless both sides have a level.
+    pub fn best_quote(&self) -> Option<Quote> {
+        let (bid, ask) = (self.bids.first()?, self.asks.first()?);
+        Some(Quote {
+            symbol: self.symbol.clone(),
+            bid: bid.price,
+            ask: ask.price,
+            bid_size: bid.size,
+            ask_size: ask.size,
+            timestamp: self.timestamp,
+        })
+    }
+    
+    /// Validate level ordering: bids strictly descending, asks strictly ascending, not crossed.
+    pub fn validate(&self) -> Result<(), String> {
+        for level in self.bids.iter().chain(&self.asks) {
+            if level.price <= 0.0 || level.size < 0.0 {
+                return Err(format!("Invalid level {} @ {}", level.size, level.price));
+            }
+        }
+        
+        if self.bids.windows(2).any(|pair| pair[1].price >= pair[0].price) {
+            return Err("Bids must be in descending price order".to_string());
+        }
+        
+        if self.asks.windows(2).any(|pair| pair[1].price <= pair[0].price) {
+            return Err("Asks must be in ascending price order".to_string());
+        }
+        
+        if self.is_crossed() {
+            return Err(format!(
+                "Book is crossed: best bid {} >= best ask {}",
+                self.bids[0].price, self.asks[0].price
+            ));
+        }
+        
+        Ok(())
+    }
+}
+
+/// Volume traded at one price bucket of a volume profile.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfileLevel {
+    pub price: f64,
+    pub volume: f64,
+}
+
+/// Volume traded per price bucket, in ascending price order.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VolumeProfile {
+    pub levels: Vec<VolumeProfileLevel>,
+}
+
+impl VolumeProfile {
+    /// Build a profile from levels in any order.
+    pub fn new(mut levels: Vec<VolumeProfileLevel>) -> Self {
+        levels.sort_by(|a, b| a.price.total_cmp(&b.price));
+        Self { levels }
+    }
+    
+    pub fn total_volume(&self) -> f64 {
+        self.levels.iter().map(|level| level.volume).sum()
+    }
+    
+    /// Price of the highest-volume bucket, the lowest such price on ties.
+    pub fn point_of_control(&self) -> Option<f64> {
+        self.poc_index().map(|index| self.levels[index].price)
+    }
+    
+    /// Lowest and highest price of the range around the point of control holding at least
+    /// `pct` (a fraction in (0, 1]) of the total volume.
+    ///
+    /// Grows from the point of control one bucket at a time, taking whichever neighbour
+    /// traded more, the upper one on ties. `None` for an empty profile or `pct` out of range.
+    pub fn value_area(&self, pct: f64) -> Option<(f64, f64)> {
+        if !(pct > 0.0 && pct <= 1.0) {
+            return None;
+        }
+        let poc = self.poc_index()?;
+        let target = self.total_volume() * pct;
+        
+        let (mut low, mut high) = (poc, poc);
+        let mut volume = self.levels[poc].volume;
+        while volume < target {
+            let below = low.checked_sub(1).map(|index| self.levels[index].volume);
+            let above = self.levels.get(high + 1).map(|level| level.volume);
+            match (below, above) {
+                (Some(below), Some(above)) if below > above => {
+                    low -= 1;
+                    volume += below;
+                }
+                (_, Some(above)) => {
+                    high += 1;
+                    volume += above;
+                }
+                (Some(below), None) => {
+                    low -= 1;
+                    volume += below;
+                }
+                (None, None) => break,
+            }
+        }
+        Some((self.levels[low].price, self.levels[high].price))
+    }
+    
+    /// Validate prices are positive and strictly ascending and volumes non-negative.
+    pub fn validate(&self) -> Result<(), String> {
+        for level in &self.levels {
+            if !(level.price.is_finite() && level.price > 0.0) {
+                return Err(format!("Invalid volume profile price {}", level.price));
+            }
+            if !(level.volume.is_finite() && level.volume >= 0.0) {
+                return Err(format!("Invalid volume {} at {}", level.volume, level.price));
+            }
+        }
+        
+        if self.levels.windows(2).any(|pair| pair[1].price <= pair[0].price) {
+            return Err("Volume profile prices must be strictly ascending".to_string());
+        }
+        
+        Ok(())
+    }
+    
+    fn poc_index(&self) -> Option<usize> {
+        self.levels
+            .iter()
+            .enumerate()
+            .fold(None, |best: Option<(usize, f64)>, (index, level)| match best {
+                Some((_, volume)) if volume >= level.volume => best,
+                _ => Some((index, level.volume)),
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+impl TryFrom<&HashMap<String, f64>> for VolumeProfile {
+    type Error = String;
+    
+    /// Convert `IndicatorSnapshot::volume_profile`, whose keys are bucket prices.
+    fn try_from(buckets: &HashMap<String, f64>) -> Result<Self, Self::Error> {
+        let levels = buckets
+            .iter()
+            .map(|(price, &volume)| {
+                price
+                    .trim()
+                    .parse::<f64>()
+                    .map(|price| VolumeProfileLevel { price, volume })
+                    .map_err(|e| format!("Invalid volume profile price '{}': {}", price, e))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let profile = Self::new(levels);
+        profile.validate()?;
+        Ok(profile)
+    }
+}
+
+/// Best bid and ask for a symbol.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Quote {
+    #[serde(deserialize_with = "symbol::normalized")]
+    pub symbol: String,
+    pub bid: f64,
+    pub ask: f64,
+    pub bid_size: f64,
+    pub ask_size: f64,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Quote {
+    /// Midpoint between bid and ask.
+    pub fn mid(&self) -> f64 {
+        (self.bid + self.ask) / 2.0
+    }
+    
+    /// Bid-ask spread in basis points of the mid; negative when crossed.
+    pub fn spread_bps(&self) -> f64 {
+        (self.ask - self.bid) / self.mid() * 10_000.0
+    }
+    
+    /// Whether the bid meets or exceeds the ask.
+    pub fn is_crossed(&self) -> bool {
+        self.bid >= self.ask
+    }
+}
+
+/// Funding rate of a perpetual contract.
+///
+/// `rate` is paid per funding interval at `next_funding_time`: longs pay shorts
+/// when it is positive, shorts pay longs when it is negative.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundingRate {
+    #[serde(deserialize_with = "symbol::normalized")]
+    pub symbol: String,
+    pub rate: f64,
+    pub next_funding_time: DateTime<Utc>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl FundingRate {
+    /// Funding received by a position of signed `net_size` valued at `price`; negative when paid.
+    pub fn payment(&self, net_size: f64, price: f64) -> f64 {
+        -net_size * price * self.rate
+    }
 }
 
 /// Snapshot of technical indicators at a point in time.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndicatorSnapshot {
+    #[serde(deserialize_with = "symbol::normalized")]
     pub symbol: String,
     pub timeframe: Timeframe,
     pub timestamp: DateTime<Utc>,
     
     // Trend indicators
     pub rsi: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub ema_20: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub ema_50: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub ema_200: Option<f64>,
     
     // MACD
@@ -74,15 +489,20 @@ pub struct IndicatorSnapshot {
     pub macd_histogram: Option<f64>,
     
     // Bollinger Bands
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub bb_upper: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub bb_middle: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub bb_lower: Option<f64>,
     pub bb_width: Option<f64>,
     
     // Volatility
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub atr: Option<f64>,
     
     // Volume indicators
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub volume_sma: Option<f64>,
     pub volume_profile: Option<HashMap<String, f64>>,
     
@@ -145,4 +565,38 @@ impl IndicatorSnapshot {
         
         Ok(())
     }
+    
+    /// Bollinger Band width as a percentage of the middle band.
+    ///
+    /// `bb_width` is a ratio, `(upper - lower) / middle`; the bands are used when it is absent.
+    pub fn bb_width_pct(&self) -> Option<f64> {
+        if let Some(width) = self.bb_width {
+            return Some(width * 100.0);
+        }
+        let (upper, middle, lower) = (self.bb_upper?, self.bb_middle?, self.bb_lower?);
+        (middle > 0.0).then(|| (upper - lower) / middle * 100.0)
+    }
+}
+
+/// Percentile of the lookback window the current band width must fall in to count as a squeeze.
+const BB_SQUEEZE_PERCENTILE: f64 = 10.0;
+
+/// Whether the latest snapshot's Bollinger Band width is in the lowest 10% of the last
+/// `lookback` snapshots, itself included.
+///
+/// Snapshots without band values are skipped, in the window and as the latest one, so the
+/// squeeze is judged on the most recent snapshot that has them. Needs at least two widths.
+pub fn detect_bb_squeeze(history: &[IndicatorSnapshot], lookback: usize) -> bool {
+    let widths: Vec<f64> = history
+        .iter()
+        .rev()
+        .take(lookback)
+        .filter_map(IndicatorSnapshot::bb_width_pct)
+        .collect();
+    if widths.len() < 2 {
+        return false;
+    }
+    let current = widths[0];
+    let narrower = widths.iter().filter(|&&width| width < current).count();
+    narrower as f64 / widths.len() as f64 * 100.0 <= BB_SQUEEZE_PERCENTILE
 }
\ No newline at end of file
@@ -0,0 +1,175 @@
+//! Arrow record batches and Parquet files of bar series.
+
+use arrow::array::{Array, ArrayRef, Float64Array, StringArray, TimestampMillisecondArray, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use chrono::DateTime;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+use parquet::file::reader::ChunkReader;
+use std::io::Write;
+use std::sync::Arc;
+
+use super::MarketBar;
+use crate::trading_models::enums::Timeframe;
+
+/// Arrow schema of a bar series; the `Option` fields of `MarketBar` are the nullable columns.
+pub fn bar_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("timeframe", DataType::Utf8, false),
+        Field::new("timestamp", DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())), false),
+        Field::new("open", DataType::Float64, false),
+        Field::new("high", DataType::Float64, false),
+        Field::new("low", DataType::Float64, false),
+        Field::new("close", DataType::Float64, false),
+        Field::new("volume", DataType::Float64, false),
+        Field::new("quote_volume", DataType::Float64, true),
+        Field::new("trades_count", DataType::UInt64, true),
+        Field::new("taker_buy_volume", DataType::Float64, true),
+    ]))
+}
+
+/// One typed column per `MarketBar` field. Timestamps are truncated to milliseconds.
+pub fn bars_to_record_batch(bars: &[MarketBar]) -> RecordBatch {
+    let float_column = |value: fn(&MarketBar) -> f64| -> ArrayRef { Arc::new(Float64Array::from_iter_values(bars.iter().map(value))) };
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(bars.iter().map(|bar| Some(bar.symbol.as_str())).collect::<StringArray>()),
+        Arc::new(bars.iter().map(|bar| Some(bar.timeframe.as_str())).collect::<StringArray>()),
+        Arc::new(
+            TimestampMillisecondArray::from_iter_values(bars.iter().map(|bar| bar.timestamp.timestamp_millis()))
+                .with_timezone("UTC"),
+        ),
+        float_column(|bar| bar.open),
+        float_column(|bar| bar.high),
+        float_column(|bar| bar.low),
+        float_column(|bar| bar.close),
+        float_column(|bar| bar.volume),
+        Arc::new(bars.iter().map(|bar| bar.quote_volume).collect::<Float64Array>()),
+        Arc::new(bars.iter().map(|bar| bar.trades_count).collect::<UInt64Array>()),
+        Arc::new(bars.iter().map(|bar| bar.taker_buy_volume).collect::<Float64Array>()),
+    ];
+    RecordBatch::try_new(bar_schema(), columns).expect("bar columns match the bar schema")
+}
+
+/// Rebuild bars from a batch with the columns of `bar_schema`, matched by name.
+pub fn record_batch_to_bars(batch: &RecordBatch) -> Result<Vec<MarketBar>, ArrowError> {
+    let symbol = column::<StringArray>(batch, "symbol")?;
+    let timeframe = column::<StringArray>(batch, "timeframe")?;
+    let timestamp = column::<TimestampMillisecondArray>(batch, "timestamp")?;
+    let open = column::<Float64Array>(batch, "open")?;
+    let high = column::<Float64Array>(batch, "high")?;
+    let low = column::<Float64Array>(batch, "low")?;
+    let close = column::<Float64Array>(batch, "close")?;
+    let volume = column::<Float64Array>(batch, "volume")?;
+    let quote_volume = column::<Float64Array>(batch, "quote_volume")?;
+    let trades_count = column::<UInt64Array>(batch, "trades_count")?;
+    let taker_buy_volume = column::<Float64Array>(batch, "taker_buy_volume")?;
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let timeframe = Timeframe::from_str(timeframe.value(row))
+                .ok_or_else(|| ArrowError::ParseError(format!("Unknown timeframe '{}'", timeframe.value(row))))?;
+            let timestamp = DateTime::from_timestamp_millis(timestamp.value(row))
+                .ok_or_else(|| ArrowError::ParseError(format!("Timestamp out of range: {}", timestamp.value(row))))?;
+            Ok(MarketBar {
+                symbol: symbol.value(row).to_string(),
+                timeframe,
+                timestamp,
+                open: open.value(row),
+                high: high.value(row),
+                low: low.value(row),
+                close: close.value(row),
+                volume: volume.value(row),
+                quote_volume: quote_volume.is_valid(row).then(|| quote_volume.value(row)),
+                trades_count: trades_count.is_valid(row).then(|| trades_count.value(row)),
+                taker_buy_volume: taker_buy_volume.is_valid(row).then(|| taker_buy_volume.value(row)),
+            })
+        })
+        .collect()
+}
+
+/// Write bars as a single-row-group Parquet file.
+pub fn write_bars_parquet<W: Write + Send>(writer: W, bars: &[MarketBar]) -> Result<(), ParquetError> {
+    let mut writer = ArrowWriter::try_new(writer, bar_schema(), None)?;
+    writer.write(&bars_to_record_batch(bars))?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Read every bar from a Parquet file written by `write_bars_parquet`.
+pub fn read_bars_parquet<R: ChunkReader + 'static>(reader: R) -> Result<Vec<MarketBar>, ParquetError> {
+    let mut bars = Vec::new();
+    for batch in ParquetRecordBatchReaderBuilder::try_new(reader)?.build()? {
+        bars.extend(record_batch_to_bars(&batch?)?);
+    }
+    Ok(bars)
+}
+
+fn column<'a, T: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a T, ArrowError> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| ArrowError::SchemaError(format!("Missing column '{}'", name)))?
+        .as_any()
+        .downcast_ref::<T>()
+        .ok_or_else(|| ArrowError::SchemaError(format!("Column '{}' has an unexpected type", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn bar(hour: i64, optional: bool) -> MarketBar {
+        MarketBar {
+            symbol: "BTCUSDT".to_string(),
+            timeframe: Timeframe::H1,
+            timestamp: "2024-03-04T10:00:00Z".parse::<DateTime<Utc>>().unwrap() + chrono::Duration::hours(hour),
+            open: 50000.0,
+            high: 51000.25,
+            low: 49500.5,
+            close: 50500.125,
+            volume: 100.5,
+            quote_volume: optional.then_some(5_050_000.0),
+            trades_count: optional.then_some(1234),
+            taker_buy_volume: optional.then_some(60.25),
+        }
+    }
+
+    #[test]
+    fn test_bars_parquet_round_trip() {
+        let bars = vec![bar(0, true), bar(1, false), bar(2, true)];
+
+        let batch = bars_to_record_batch(&bars);
+        assert_eq!(batch.num_rows(), 3);
+        let schema = batch.schema();
+        for name in ["quote_volume", "trades_count", "taker_buy_volume"] {
+            assert!(schema.field_with_name(name).unwrap().is_nullable());
+        }
+        assert!(!schema.field_with_name("close").unwrap().is_nullable());
+        assert_eq!(
+            schema.field_with_name("timestamp").unwrap().data_type(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+        );
+        assert_eq!(batch.column_by_name("trades_count").unwrap().null_count(), 1);
+
+        let path = std::env::temp_dir().join(format!("bars-{}.parquet", uuid::Uuid::new_v4()));
+        write_bars_parquet(std::fs::File::create(&path).unwrap(), &bars).unwrap();
+        let read = read_bars_parquet(std::fs::File::open(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+        let read = read.unwrap();
+
+        assert_eq!(read.len(), bars.len());
+        for (read, written) in read.iter().zip(&bars) {
+            assert_eq!(read.symbol, written.symbol);
+            assert_eq!(read.timeframe, written.timeframe);
+            assert_eq!(read.timestamp, written.timestamp);
+            assert_eq!((read.open, read.high, read.low, read.close, read.volume), (written.open, written.high, written.low, written.close, written.volume));
+            assert_eq!(read.quote_volume, written.quote_volume);
+            assert_eq!(read.trades_count, written.trades_count);
+            assert_eq!(read.taker_buy_volume, written.taker_buy_volume);
+        }
+    }
+}
@@ -1,16 +1,22 @@
 //! Trading data models compatible with Python Pydantic models.
 
 pub mod enums;
+pub mod exchange;
 pub mod market_data;
 pub mod patterns;
 pub mod signals;
 pub mod orders;
+pub mod schema;
+pub mod symbol;
 
 #[cfg(test)]
 mod tests;
 
 pub use enums::*;
+pub use exchange::*;
 pub use market_data::*;
 pub use patterns::*;
 pub use signals::*;
-pub use orders::*;
\ No newline at end of file
+pub use orders::*;
+pub use schema::*;
+pub use symbol::*;
\ No newline at end of file
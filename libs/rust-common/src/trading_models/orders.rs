@@ -5,37 +5,69 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use super::enums::{Direction, OrderStatus, OrderType, Timeframe};
+use super::enums::{Direction, OrderStatus, OrderType, RejectReason, TimeInForce, Timeframe};
+use super::exchange::ExchangeInfo;
+use super::schema::decimal_str;
+use super::symbol::{self, Symbol};
+
+/// Maximum leverage an order decision may use
+pub const MAX_LEVERAGE: f64 = 10.0;
+/// Maximum risk per trade, in percent of portfolio
+pub const MAX_RISK_PERCENTAGE: f64 = 10.0;
+/// Maximum combined trade risk and current exposure, in percent of portfolio
+pub const MAX_PORTFOLIO_RISK_PCT: f64 = 20.0;
+/// Risk budget divided by leverage to get the maximum risk per trade at that leverage
+pub const LEVERAGE_RISK_BUDGET: f64 = 5.0;
+
+/// Policy for resubmitting the unfilled remainder of an all-or-nothing order.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PartialRetryPolicy {
+    pub max_attempts: u32,
+    pub delay_ms: u64,
+}
 
 /// Trading order decision with risk management.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderDecision {
     pub decision_id: String,
     pub signal_id: String,
+    #[serde(deserialize_with = "symbol::normalized")]
     pub symbol: String,
     pub timestamp: DateTime<Utc>,
     
     // Order details
     pub direction: Direction,
     pub order_type: OrderType,
+    /// Distance the stop trails the best price, in percent; trailing stop orders only
+    #[serde(default)]
+    pub trail_pct: Option<f64>,
     
     // Position sizing
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub base_quantity: f64,
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub risk_adjusted_quantity: f64,
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub max_position_value: f64,
     
     // Price levels
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub entry_price: f64,
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub stop_loss: f64,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub take_profit: Option<f64>,
     
     // Risk management
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub risk_amount: f64,
     pub risk_percentage: f64,
     pub leverage: f64,
     
     // Portfolio context
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub portfolio_value: f64,
+    #[serde(deserialize_with = "decimal_str::f64")]
     pub available_margin: f64,
     pub current_exposure: f64,
     
@@ -48,6 +80,19 @@ pub struct OrderDecision {
     pub slippage_tolerance: f64,
     pub max_execution_time: u32,
     pub partial_fill_acceptable: bool,
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    /// Maker-only: rejected by the exchange rather than filled against the book
+    #[serde(default)]
+    pub post_only: bool,
+    /// Only ever shrinks an open position; the exchange refuses any part that would grow it
+    #[serde(default)]
+    pub reduce_only: bool,
+    #[serde(default)]
+    pub partial_retry_policy: Option<PartialRetryPolicy>,
+    /// Exchange to route the order to; `None` uses the gateway default
+    #[serde(default)]
+    pub exchange: Option<String>,
     
     // Decision reasoning
     pub decision_reason: String,
@@ -60,15 +105,16 @@ pub struct OrderDecision {
 }
 
 impl OrderDecision {
-    /// Create a new order decision with generated ID.
-    pub fn new(signal_id: String, symbol: String) -> Self {
+    /// Create a new order decision with generated ID and normalized symbol.
+    pub fn new(signal_id: String, symbol: impl Into<Symbol>) -> Self {
         Self {
             decision_id: Uuid::new_v4().to_string(),
             signal_id,
-            symbol,
+            symbol: symbol.into().into(),
             timestamp: Utc::now(),
             direction: Direction::Long,
             order_type: OrderType::Market,
+            trail_pct: None,
             base_quantity: 0.0,
             risk_adjusted_quantity: 0.0,
             max_position_value: 0.0,
@@ -87,6 +133,11 @@ impl OrderDecision {
             slippage_tolerance: 0.001,
             max_execution_time: 300,
             partial_fill_acceptable: true,
+            time_in_force: TimeInForce::Gtc,
+            post_only: false,
+            reduce_only: false,
+            partial_retry_policy: None,
+            exchange: None,
             decision_reason: String::new(),
             risk_factors: Vec::new(),
             supporting_factors: Vec::new(),
@@ -97,105 +148,126 @@ impl OrderDecision {
     
     /// Validate order decision data.
     pub fn validate(&self) -> Result<(), String> {
+        self.validate_fields().map_err(|(_, message)| message)
+    }
+    
+    /// Validate order decision data, naming the field that failed alongside the message.
+    pub fn validate_fields(&self) -> Result<(), (&'static str, String)> {
+        Symbol::parse(&self.symbol).map_err(|message| ("symbol", message))?;
+        
         // Validate positive values
         if self.base_quantity <= 0.0 {
-            return Err("Base quantity must be positive".to_string());
+            return Err(("base_quantity", "Base quantity must be positive".to_string()));
         }
         
         if self.risk_adjusted_quantity <= 0.0 {
-            return Err("Risk adjusted quantity must be positive".to_string());
+            return Err(("risk_adjusted_quantity", "Risk adjusted quantity must be positive".to_string()));
         }
         
         if self.max_position_value <= 0.0 {
-            return Err("Max position value must be positive".to_string());
+            return Err(("max_position_value", "Max position value must be positive".to_string()));
         }
         
         if self.entry_price <= 0.0 {
-            return Err("Entry price must be positive".to_string());
+            return Err(("entry_price", "Entry price must be positive".to_string()));
         }
         
         if self.stop_loss <= 0.0 {
-            return Err("Stop loss must be positive".to_string());
+            return Err(("stop_loss", "Stop loss must be positive".to_string()));
         }
         
         if let Some(tp) = self.take_profit {
             if tp <= 0.0 {
-                return Err("Take profit must be positive".to_string());
+                return Err(("take_profit", "Take profit must be positive".to_string()));
             }
         }
         
         if self.risk_amount <= 0.0 {
-            return Err("Risk amount must be positive".to_string());
+            return Err(("risk_amount", "Risk amount must be positive".to_string()));
         }
         
         if self.portfolio_value <= 0.0 {
-            return Err("Portfolio value must be positive".to_string());
+            return Err(("portfolio_value", "Portfolio value must be positive".to_string()));
         }
         
         // Validate ranges
-        if !(0.0..=10.0).contains(&self.risk_percentage) {
-            return Err("Risk percentage must be between 0 and 10".to_string());
+        if !(0.0..=MAX_RISK_PERCENTAGE).contains(&self.risk_percentage) {
+            return Err(("risk_percentage", format!("Risk percentage must be between 0 and {}", MAX_RISK_PERCENTAGE)));
         }
         
         if !(0.0..=50.0).contains(&self.leverage) || self.leverage == 0.0 {
-            return Err("Leverage must be between 0 (exclusive) and 50".to_string());
+            return Err(("leverage", "Leverage must be between 0 (exclusive) and 50".to_string()));
         }
         
         if !(0.0..=1.0).contains(&self.current_exposure) {
-            return Err("Current exposure must be between 0 and 1".to_string());
+            return Err(("current_exposure", "Current exposure must be between 0 and 1".to_string()));
         }
         
         if !(0.0..=1.0).contains(&self.confidence_score) {
-            return Err("Confidence score must be between 0 and 1".to_string());
+            return Err(("confidence_score", "Confidence score must be between 0 and 1".to_string()));
         }
         
         if !(0.0..=100.0).contains(&self.confluence_score) {
-            return Err("Confluence score must be between 0 and 100".to_string());
+            return Err(("confluence_score", "Confluence score must be between 0 and 100".to_string()));
         }
         
         if self.risk_reward_ratio <= 0.0 {
-            return Err("Risk reward ratio must be positive".to_string());
+            return Err(("risk_reward_ratio", "Risk reward ratio must be positive".to_string()));
         }
         
         if !(0.0..=0.1).contains(&self.slippage_tolerance) {
-            return Err("Slippage tolerance must be between 0 and 0.1".to_string());
+            return Err(("slippage_tolerance", "Slippage tolerance must be between 0 and 0.1".to_string()));
+        }
+        
+        if self.order_type == OrderType::TrailingStop {
+            let trail_pct = self.trail_pct
+                .ok_or_else(|| ("trail_pct", "Trailing stop orders require a trail percentage".to_string()))?;
+            if !(trail_pct > 0.0 && trail_pct <= 20.0) {
+                return Err(("trail_pct", "Trail percentage must be between 0 (exclusive) and 20".to_string()));
+            }
+        } else if self.trail_pct.is_some() {
+            return Err(("trail_pct", "Trail percentage is only valid for trailing stop orders".to_string()));
+        }
+        
+        if self.post_only && self.order_type != OrderType::Limit {
+            return Err(("post_only", "Post-only is only valid for limit orders".to_string()));
         }
         
         // Validate risk adjustment
         if self.risk_adjusted_quantity > self.base_quantity * 2.0 {
-            return Err("Risk adjusted quantity cannot exceed 2x base quantity".to_string());
+            return Err(("risk_adjusted_quantity", "Risk adjusted quantity cannot exceed 2x base quantity".to_string()));
         }
         
         if self.risk_adjusted_quantity < self.base_quantity * 0.1 {
-            return Err("Risk adjusted quantity cannot be less than 10% of base".to_string());
+            return Err(("risk_adjusted_quantity", "Risk adjusted quantity cannot be less than 10% of base".to_string()));
         }
         
         // Validate portfolio risk
         let total_risk = self.risk_percentage + (self.current_exposure * 100.0);
-        if total_risk > 20.0 {
-            return Err("Total portfolio risk would exceed 20%".to_string());
+        if total_risk > MAX_PORTFOLIO_RISK_PCT {
+            return Err(("current_exposure", format!("Total portfolio risk would exceed {}%", MAX_PORTFOLIO_RISK_PCT)));
         }
         
         // Validate leverage vs risk
-        if self.leverage > 10.0 {
-            return Err("Leverage cannot exceed 10x".to_string());
+        if self.leverage > MAX_LEVERAGE {
+            return Err(("leverage", format!("Leverage cannot exceed {}x", MAX_LEVERAGE)));
         }
         
-        let max_risk_for_leverage = 5.0 / self.leverage;
+        let max_risk_for_leverage = LEVERAGE_RISK_BUDGET / self.leverage;
         if self.risk_percentage > max_risk_for_leverage {
-            return Err("Risk percentage too high for leverage level".to_string());
+            return Err(("risk_percentage", "Risk percentage too high for leverage level".to_string()));
         }
         
         // Validate stop loss placement
         match self.direction {
             Direction::Long => {
                 if self.stop_loss >= self.entry_price {
-                    return Err("Stop loss must be below entry price for long positions".to_string());
+                    return Err(("stop_loss", "Stop loss must be below entry price for long positions".to_string()));
                 }
             }
             Direction::Short => {
                 if self.stop_loss <= self.entry_price {
-                    return Err("Stop loss must be above entry price for short positions".to_string());
+                    return Err(("stop_loss", "Stop loss must be above entry price for short positions".to_string()));
                 }
             }
         }
@@ -203,7 +275,7 @@ impl OrderDecision {
         // Validate stop loss distance (max 20% from entry)
         let stop_diff_pct = (self.stop_loss - self.entry_price).abs() / self.entry_price;
         if stop_diff_pct > 0.2 {
-            return Err("Stop loss too far from entry (>20%)".to_string());
+            return Err(("stop_loss", "Stop loss too far from entry (>20%)".to_string()));
         }
         
         // Validate take profit placement
@@ -211,12 +283,12 @@ impl OrderDecision {
             match self.direction {
                 Direction::Long => {
                     if tp <= self.entry_price {
-                        return Err("Take profit must be above entry price for long positions".to_string());
+                        return Err(("take_profit", "Take profit must be above entry price for long positions".to_string()));
                     }
                 }
                 Direction::Short => {
                     if tp >= self.entry_price {
-                        return Err("Take profit must be below entry price for short positions".to_string());
+                        return Err(("take_profit", "Take profit must be below entry price for short positions".to_string()));
                     }
                 }
             }
@@ -225,6 +297,60 @@ impl OrderDecision {
         Ok(())
     }
     
+    /// Check the entry price and risk adjusted quantity against an exchange's tick and lot
+    /// grid and its price and size bounds, so off-grid orders fail before submission.
+    /// A zero tick or lot size disables that grid check.
+    pub fn validate_against_exchange(&self, exchange_info: &ExchangeInfo) -> Result<(), String> {
+        let price = self.entry_price;
+        if !is_multiple_of(price, exchange_info.tick_size) {
+            return Err(format!("Entry price {} is not a multiple of tick size {}", price, exchange_info.tick_size));
+        }
+        if price < exchange_info.min_price || price > exchange_info.max_price {
+            return Err(format!(
+                "Entry price {} is outside the exchange range {} to {}",
+                price, exchange_info.min_price, exchange_info.max_price
+            ));
+        }
+        
+        let quantity = self.risk_adjusted_quantity;
+        if !is_multiple_of(quantity, exchange_info.lot_size) {
+            return Err(format!("Quantity {} is not a multiple of lot size {}", quantity, exchange_info.lot_size));
+        }
+        if quantity < exchange_info.min_order_size || quantity > exchange_info.max_order_size {
+            return Err(format!(
+                "Quantity {} is outside the exchange range {} to {}",
+                quantity, exchange_info.min_order_size, exchange_info.max_order_size
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Size the position so that a stop `atr * atr_multiplier` away from entry loses `risk_amount`.
+    ///
+    /// Sets the entry, stop loss, risk amount and risk adjusted quantity; leaves the
+    /// decision unchanged if the inputs are invalid or the stop would be more than 20%
+    /// from entry.
+    pub fn size_from_atr(&mut self, risk_amount: f64, atr: f64, atr_multiplier: f64, entry_price: f64) -> Result<(), String> {
+        if risk_amount <= 0.0 || atr <= 0.0 || atr_multiplier <= 0.0 || entry_price <= 0.0 {
+            return Err("Risk amount, ATR, ATR multiplier and entry price must be positive".to_string());
+        }
+        
+        let stop_distance = atr * atr_multiplier;
+        if stop_distance / entry_price > 0.2 {
+            return Err("Stop loss too far from entry (>20%)".to_string());
+        }
+        
+        self.entry_price = entry_price;
+        self.stop_loss = match self.direction {
+            Direction::Long => entry_price - stop_distance,
+            Direction::Short => entry_price + stop_distance,
+        };
+        self.risk_amount = risk_amount;
+        self.risk_adjusted_quantity = risk_amount / stop_distance;
+        Ok(())
+    }
+    
     /// Calculate total position value including leverage.
     pub fn calculate_position_value(&self) -> f64 {
         self.risk_adjusted_quantity * self.entry_price * self.leverage
@@ -262,8 +388,17 @@ impl OrderDecision {
     }
 }
 
+/// Whether `value` lies on the grid of `increment`, within floating-point error.
+fn is_multiple_of(value: f64, increment: f64) -> bool {
+    if increment <= 0.0 {
+        return true;
+    }
+    let steps = value / increment;
+    (steps - steps.round()).abs() <= 1e-9 * steps.abs().max(1.0)
+}
+
 /// Result of order execution.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExecutionResult {
     pub execution_id: String,
     pub decision_id: String,
@@ -284,10 +419,14 @@ pub struct ExecutionResult {
     
     // Execution quality
     pub execution_time_ms: Option<u32>,
+    #[serde(default)]
+    pub decision_to_submit_ms: Option<u64>,
     pub partial_fills: Vec<HashMap<String, serde_json::Value>>,
     
     // Error handling
     pub error_message: Option<String>,
+    #[serde(default)]
+    pub reject_reason: Option<RejectReason>,
     pub retry_count: u32,
 }
 
@@ -306,8 +445,10 @@ impl ExecutionResult {
             commission: 0.0,
             slippage: None,
             execution_time_ms: None,
+            decision_to_submit_ms: None,
             partial_fills: Vec::new(),
             error_message: None,
+            reject_reason: None,
             retry_count: 0,
         }
     }
@@ -329,4 +470,38 @@ impl ExecutionResult {
         }
         (self.filled_quantity / original_quantity) * 100.0
     }
+
+    /// Filled notional plus commission plus the estimated cost of slippage.
+    /// Slippage cost is approximated as notional × `slippage`, so favourable slippage lowers it.
+    pub fn total_cost(&self) -> f64 {
+        let notional = self.filled_quantity * self.average_price.unwrap_or(0.0);
+        notional + self.commission + notional * self.slippage.unwrap_or(0.0)
+    }
+
+    /// Cash flow of the fills net of commission, in the quote asset: a `Long` (buy) fill pays
+    /// out its notional so is negative, a `Short` (sell) fill receives it. Commission always
+    /// reduces the value.
+    pub fn net_value(&self, direction: Direction) -> f64 {
+        let notional = self.filled_quantity * self.average_price.unwrap_or(0.0);
+        let signed_notional = match direction {
+            Direction::Long => -notional,
+            Direction::Short => notional,
+        };
+        signed_notional - self.commission
+    }
+
+    /// Profit of closing a `position` opened at `entry_price` with these fills, net of this
+    /// result's commission; positive is a gain. `position` is the side being closed, so a
+    /// `Long` position is closed by selling. The opening fills' commission is not included.
+    pub fn realized_pnl(&self, entry_price: f64, position: Direction) -> f64 {
+        let exit_price = match self.average_price {
+            Some(price) if self.filled_quantity > 0.0 => price,
+            _ => return -self.commission,
+        };
+        let per_unit = match position {
+            Direction::Long => exit_price - entry_price,
+            Direction::Short => entry_price - exit_price,
+        };
+        per_unit * self.filled_quantity - self.commission
+    }
 }
\ No newline at end of file
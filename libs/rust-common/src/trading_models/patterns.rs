@@ -4,13 +4,17 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::enums::{PatternType, Timeframe};
+use super::enums::{Direction, PatternType, Timeframe};
+use super::market_data::MarketBar;
+use super::schema::decimal_str;
+use super::symbol::{self, Symbol};
 
 /// Detected pattern with confidence and metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatternHit {
     pub pattern_id: String,
     pub pattern_type: PatternType,
+    #[serde(deserialize_with = "symbol::normalized")]
     pub symbol: String,
     pub timeframe: Timeframe,
     pub timestamp: DateTime<Utc>,
@@ -20,14 +24,25 @@ pub struct PatternHit {
     pub strength: f64,
     
     // Price levels
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub entry_price: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub stop_loss: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub take_profit: Option<f64>,
     
     // Support/Resistance levels
+    #[serde(deserialize_with = "decimal_str::vec")]
     pub support_levels: Vec<f64>,
+    #[serde(deserialize_with = "decimal_str::vec")]
     pub resistance_levels: Vec<f64>,
     
+    // Reversal pattern geometry
+    #[serde(default, deserialize_with = "decimal_str::option")]
+    pub neckline: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
+    pub measured_move_target: Option<f64>,
+    
     // Pattern-specific data
     pub pattern_data: HashMap<String, serde_json::Value>,
     
@@ -98,6 +113,30 @@ impl PatternHit {
             return Err("Resistance levels must be sorted".to_string());
         }
         
+        // Validate reversal geometry
+        if let Some(neckline) = self.neckline {
+            if neckline <= 0.0 {
+                return Err("Neckline must be positive".to_string());
+            }
+        }
+        
+        if let Some(target) = self.measured_move_target {
+            if target <= 0.0 {
+                return Err("Measured move target must be positive".to_string());
+            }
+            if let (Some(entry), Some(direction)) = (self.entry_price, self.implied_direction()) {
+                match direction {
+                    Direction::Long if target <= entry => {
+                        return Err("Measured move target must be above entry for bullish patterns".to_string());
+                    }
+                    Direction::Short if target >= entry => {
+                        return Err("Measured move target must be below entry for bearish patterns".to_string());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        
         // Validate historical win rate
         if let Some(win_rate) = self.historical_win_rate {
             if !(0.0..=1.0).contains(&win_rate) {
@@ -116,11 +155,85 @@ impl PatternHit {
         
         Ok(())
     }
+    
+    /// Closest support level at or below `price`; assumes sorted levels, as `validate` requires.
+    pub fn nearest_support(&self, price: f64) -> Option<f64> {
+        let index = self.support_levels.partition_point(|level| *level <= price);
+        index.checked_sub(1).map(|index| self.support_levels[index])
+    }
+    
+    /// Closest resistance level at or above `price`; assumes sorted levels, as `validate` requires.
+    pub fn nearest_resistance(&self, price: f64) -> Option<f64> {
+        let index = self.resistance_levels.partition_point(|level| *level < price);
+        self.resistance_levels.get(index).copied()
+    }
+    
+    /// Distance from entry to stop loss as a percentage of entry, if both are set.
+    pub fn distance_to_stop_pct(&self) -> Option<f64> {
+        match (self.entry_price, self.stop_loss) {
+            (Some(entry), Some(stop)) if entry > 0.0 => Some((entry - stop).abs() / entry * 100.0),
+            _ => None,
+        }
+    }
+    
+    /// Direction the pattern points to: fixed for reversal chart patterns, otherwise from `bias`.
+    pub fn implied_direction(&self) -> Option<Direction> {
+        match self.pattern_type {
+            PatternType::DoubleBottom | PatternType::InverseHeadAndShoulders => Some(Direction::Long),
+            PatternType::DoubleTop | PatternType::HeadAndShoulders => Some(Direction::Short),
+            _ if self.bias() > 0.0 => Some(Direction::Long),
+            _ if self.bias() < 0.0 => Some(Direction::Short),
+            _ => None,
+        }
+    }
+    
+    /// Price target of the pattern.
+    ///
+    /// Uses `measured_move_target` when set; otherwise projects the pattern height (neckline to
+    /// the highest resistance for bearish patterns, or to the lowest support for bullish ones)
+    /// from entry in the implied direction.
+    pub fn projected_target(&self) -> Option<f64> {
+        if self.measured_move_target.is_some() {
+            return self.measured_move_target;
+        }
+        
+        let entry = self.entry_price?;
+        let neckline = self.neckline?;
+        match self.implied_direction()? {
+            Direction::Long => {
+                let trough = self.support_levels.first()?;
+                Some(entry + (neckline - trough).abs())
+            }
+            Direction::Short => {
+                let peak = self.resistance_levels.last()?;
+                Some(entry - (peak - neckline).abs())
+            }
+        }
+    }
+    
+    /// Directional bias from the pattern data: 1.0 bullish, -1.0 bearish, 0.0 neutral.
+    ///
+    /// Detectors record the bias as a string value such as `"breakout_direction": "bullish"`
+    /// or `"pin_type": "bearish_shooting_star"`.
+    pub fn bias(&self) -> f64 {
+        for value in self.pattern_data.values() {
+            if let Some(text) = value.as_str() {
+                if text.starts_with("bullish") {
+                    return 1.0;
+                }
+                if text.starts_with("bearish") {
+                    return -1.0;
+                }
+            }
+        }
+        0.0
+    }
 }
 
 /// Collection of patterns for a symbol/timeframe.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatternCollection {
+    #[serde(deserialize_with = "symbol::normalized")]
     pub symbol: String,
     pub timeframe: Timeframe,
     pub timestamp: DateTime<Utc>,
@@ -135,9 +248,9 @@ pub struct PatternCollection {
 
 impl PatternCollection {
     /// Create a new pattern collection.
-    pub fn new(symbol: String, timeframe: Timeframe) -> Self {
+    pub fn new(symbol: impl Into<Symbol>, timeframe: Timeframe) -> Self {
         Self {
-            symbol,
+            symbol: symbol.into().into(),
             timeframe,
             timestamp: Utc::now(),
             patterns: Vec::new(),
@@ -185,4 +298,112 @@ impl PatternCollection {
             .filter(|p| p.confidence >= min_confidence)
             .collect()
     }
-}
\ No newline at end of file
+}
+
+/// Two swing points of a divergence: earlier and later (bar index, price, RSI).
+struct DivergenceSwing {
+    earlier: (usize, f64, f64),
+    later: (usize, f64, f64),
+}
+
+impl DivergenceSwing {
+    fn price_change(&self) -> f64 {
+        (self.later.1 - self.earlier.1) / self.earlier.1
+    }
+    
+    fn rsi_change(&self) -> f64 {
+        (self.later.2 - self.earlier.2) / self.earlier.2
+    }
+    
+    /// Per-bar difference between the price and RSI slopes
+    fn slope_mismatch(&self) -> f64 {
+        let bars = (self.later.0 - self.earlier.0).max(1) as f64;
+        (self.price_change() - self.rsi_change()).abs() / bars
+    }
+}
+
+/// Detect RSI divergence over the last `lookback` bars.
+///
+/// The window is split in half and the extreme of each half compared: a higher high in
+/// price with a lower RSI high is bearish, a lower low in price with a higher RSI low is
+/// bullish. `rsi` must hold one value per bar. Returns the stronger divergence, if any.
+pub fn detect_rsi_divergence(bars: &[MarketBar], rsi: &[f64], lookback: usize) -> Result<Option<PatternHit>, String> {
+    if bars.len() != rsi.len() {
+        return Err(format!("Bar and RSI series lengths differ ({} vs {})", bars.len(), rsi.len()));
+    }
+    if lookback < 4 || bars.len() < lookback {
+        return Ok(None);
+    }
+    
+    let start = bars.len() - lookback;
+    let middle = start + lookback / 2;
+    let extreme = |range: std::ops::Range<usize>, price: fn(&MarketBar) -> f64, higher: bool| {
+        range
+            .map(|index| (index, price(&bars[index]), rsi[index]))
+            .reduce(|best, candidate| {
+                let better = if higher { candidate.1 > best.1 } else { candidate.1 < best.1 };
+                if better { candidate } else { best }
+            })
+            .expect("divergence halves are never empty")
+    };
+    
+    let highs = DivergenceSwing {
+        earlier: extreme(start..middle, |bar| bar.high, true),
+        later: extreme(middle..bars.len(), |bar| bar.high, true),
+    };
+    let lows = DivergenceSwing {
+        earlier: extreme(start..middle, |bar| bar.low, false),
+        later: extreme(middle..bars.len(), |bar| bar.low, false),
+    };
+    
+    let bearish = highs.later.1 > highs.earlier.1 && highs.later.2 < highs.earlier.2;
+    let bullish = lows.later.1 < lows.earlier.1 && lows.later.2 > lows.earlier.2;
+    let (divergence_type, swing) = match (bearish, bullish) {
+        (true, true) if lows.slope_mismatch() > highs.slope_mismatch() => ("bullish", lows),
+        (true, _) => ("bearish", highs),
+        (false, true) => ("bullish", lows),
+        (false, false) => return Ok(None),
+    };
+    
+    let price_change = swing.price_change().abs();
+    let rsi_change = swing.rsi_change().abs();
+    let last = &bars[bars.len() - 1];
+    
+    let mut pattern_data = HashMap::new();
+    pattern_data.insert("divergence_type".to_string(), serde_json::json!(divergence_type));
+    pattern_data.insert("indicator".to_string(), serde_json::json!("RSI"));
+    pattern_data.insert("price_change_pct".to_string(), serde_json::json!(price_change));
+    pattern_data.insert("indicator_change_pct".to_string(), serde_json::json!(rsi_change));
+    pattern_data.insert("prev_price".to_string(), serde_json::json!(swing.earlier.1));
+    pattern_data.insert("curr_price".to_string(), serde_json::json!(swing.later.1));
+    pattern_data.insert("prev_indicator".to_string(), serde_json::json!(swing.earlier.2));
+    pattern_data.insert("curr_indicator".to_string(), serde_json::json!(swing.later.2));
+    
+    let (support_levels, resistance_levels) = if divergence_type == "bullish" {
+        (vec![swing.later.1], Vec::new())
+    } else {
+        (Vec::new(), vec![swing.later.1])
+    };
+    
+    Ok(Some(PatternHit {
+        pattern_id: format!("divergence_{}_{}", last.symbol, last.timestamp.to_rfc3339()),
+        pattern_type: PatternType::Divergence,
+        symbol: last.symbol.clone(),
+        timeframe: last.timeframe,
+        timestamp: last.timestamp,
+        confidence: (0.4 + swing.slope_mismatch() * 20.0).min(0.8),
+        strength: ((price_change + rsi_change) * 10.0).min(10.0),
+        entry_price: Some(last.close),
+        stop_loss: Some(swing.later.1),
+        take_profit: None,
+        support_levels,
+        resistance_levels,
+        neckline: None,
+        measured_move_target: None,
+        pattern_data,
+        bars_analyzed: lookback as u32,
+        lookback_period: lookback as u32,
+        historical_win_rate: None,
+        avg_return: None,
+    }))
+}
@@ -0,0 +1,202 @@
+//! Versioned envelope for models exchanged with the Python side.
+
+use serde::de::{self, DeserializeOwned, Deserializer, Unexpected, Visitor};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+#[cfg(test)]
+use serde_json::Value;
+#[cfg(test)]
+use std::fs;
+#[cfg(test)]
+use std::path::{Path, PathBuf};
+
+use super::market_data::MarketBar;
+use super::orders::OrderDecision;
+use super::signals::Signal;
+
+/// Version of the wire schema shared with the Pydantic models; bump on any incompatible field change.
+pub const SCHEMA_VERSION: &str = "1.0.0";
+
+/// Fixtures exported by `scripts/export_python_fixtures.py`.
+#[cfg(test)]
+const PYTHON_FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/python");
+
+/// Top-level model that can travel inside a [`SchemaEnvelope`].
+pub trait Versioned: Serialize + DeserializeOwned {
+    /// Tag written to the envelope's `schema` field.
+    const SCHEMA: &'static str;
+}
+
+impl Versioned for MarketBar {
+    const SCHEMA: &'static str = "market_bar";
+}
+
+impl Versioned for Signal {
+    const SCHEMA: &'static str = "signal";
+}
+
+impl Versioned for OrderDecision {
+    const SCHEMA: &'static str = "order_decision";
+}
+
+/// Payload tagged with its type and the schema version it was written against.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaEnvelope<T> {
+    pub schema: String,
+    pub schema_version: String,
+    pub data: T,
+}
+
+impl<T: Versioned> SchemaEnvelope<T> {
+    /// Wrap `data` with its schema tag and the current [`SCHEMA_VERSION`].
+    pub fn new(data: T) -> Self {
+        Self {
+            schema: T::SCHEMA.to_string(),
+            schema_version: SCHEMA_VERSION.to_string(),
+            data,
+        }
+    }
+
+    /// Unwrap the payload, refusing envelopes tagged for another type or schema version.
+    pub fn into_data(self) -> Result<T, String> {
+        if self.schema != T::SCHEMA {
+            return Err(format!("Expected schema '{}', got '{}'", T::SCHEMA, self.schema));
+        }
+        if self.schema_version != SCHEMA_VERSION {
+            return Err(format!(
+                "Schema version {} does not match {}",
+                self.schema_version, SCHEMA_VERSION
+            ));
+        }
+        Ok(self.data)
+    }
+}
+
+/// Deserialize every Python fixture into the Rust type its `schema` tag names.
+///
+/// Panics on the first fixture that does not deserialize, carries another schema version,
+/// has fields the Rust type would drop, writes fields the Pydantic model forbids, or does
+/// not survive a Rust round trip.
+#[cfg(test)]
+pub fn assert_python_compat() {
+    let dir = Path::new(PYTHON_FIXTURE_DIR);
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Cannot read fixture directory {}: {}", dir.display(), e))
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "No JSON fixtures in {}", dir.display());
+
+    for path in &paths {
+        let raw = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Cannot read {}: {}", path.display(), e));
+        let envelope: SchemaEnvelope<Value> = serde_json::from_str(&raw)
+            .unwrap_or_else(|e| panic!("{} is not a schema envelope: {}", path.display(), e));
+
+        let result = match envelope.schema.as_str() {
+            MarketBar::SCHEMA => check_fixture::<MarketBar>(&raw, &envelope.data),
+            Signal::SCHEMA => check_fixture::<Signal>(&raw, &envelope.data),
+            OrderDecision::SCHEMA => check_fixture::<OrderDecision>(&raw, &envelope.data),
+            other => Err(format!("Unknown schema '{}'", other)),
+        };
+        if let Err(e) = result {
+            panic!("{} is not compatible: {}", path.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn check_fixture<T: Versioned>(raw: &str, python: &Value) -> Result<(), String> {
+    let envelope: SchemaEnvelope<T> = serde_json::from_str(raw)
+        .map_err(|e| format!("Cannot deserialize into {}: {}", T::SCHEMA, e))?;
+    let rust = serde_json::to_value(envelope.into_data()?).map_err(|e| e.to_string())?;
+
+    let mut dropped = Vec::new();
+    collect_missing_fields(python, &rust, "data", &mut dropped);
+    if !dropped.is_empty() {
+        return Err(format!("Fields ignored by the Rust type: {}", dropped.join(", ")));
+    }
+
+    // Pydantic models forbid extra fields, so anything Rust adds would be rejected on the way back
+    let mut added = Vec::new();
+    collect_missing_fields(&rust, python, "data", &mut added);
+    if !added.is_empty() {
+        return Err(format!("Fields rejected by the Python model: {}", added.join(", ")));
+    }
+
+    let reparsed: T = serde_json::from_value(rust.clone())
+        .map_err(|e| format!("Rust output does not deserialize again: {}", e))?;
+    if serde_json::to_value(reparsed).map_err(|e| e.to_string())? != rust {
+        return Err("Rust round trip changed the payload".to_string());
+    }
+    Ok(())
+}
+
+/// Object keys present in `source` but missing from `target`, recursing through nested objects and arrays.
+#[cfg(test)]
+fn collect_missing_fields(source: &Value, target: &Value, path: &str, missing: &mut Vec<String>) {
+    match (source, target) {
+        (Value::Object(source), Value::Object(target)) => {
+            for (key, value) in source {
+                let field = format!("{}.{}", path, key);
+                match target.get(key) {
+                    Some(target_value) => collect_missing_fields(value, target_value, &field, missing),
+                    None => missing.push(field),
+                }
+            }
+        }
+        (Value::Array(source), Value::Array(target)) => {
+            for (index, (value, target_value)) in source.iter().zip(target).enumerate() {
+                collect_missing_fields(value, target_value, &format!("{}[{}]", path, index), missing);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Deserializers for Python `Decimal` fields, which Pydantic writes as JSON strings.
+pub(crate) mod decimal_str {
+    use super::*;
+
+    struct DecimalVisitor;
+
+    impl Visitor<'_> for DecimalVisitor {
+        type Value = f64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a number or a decimal string")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<f64, E> {
+            Ok(value)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<f64, E> {
+            value.parse().map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Decimal(#[serde(deserialize_with = "f64")] f64);
+
+    pub fn f64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        deserializer.deserialize_any(DecimalVisitor)
+    }
+
+    pub fn option<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+        Ok(Option::<Decimal>::deserialize(deserializer)?.map(|decimal| decimal.0))
+    }
+
+    pub fn vec<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+        Ok(Vec::<Decimal>::deserialize(deserializer)?.into_iter().map(|decimal| decimal.0).collect())
+    }
+}
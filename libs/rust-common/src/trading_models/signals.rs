@@ -2,12 +2,14 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use super::{
     enums::{Direction, MarketRegime, Timeframe},
     market_data::IndicatorSnapshot,
     patterns::PatternHit,
+    schema::decimal_str,
+    symbol,
 };
 
 /// Analysis results for a specific timeframe.
@@ -124,10 +126,35 @@ impl LlmAnalysis {
     }
 }
 
+/// Relative weight of each component in `Signal::compute_confluence`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConfluenceWeights {
+    /// Trend and momentum across timeframes, scaled by volume
+    pub timeframes: f64,
+    /// Confidence-weighted strength of directional patterns
+    pub patterns: f64,
+    /// Net bullish over bearish indicator counts
+    pub indicators: f64,
+    /// Net LLM bullish over bearish score
+    pub llm: f64,
+}
+
+impl Default for ConfluenceWeights {
+    fn default() -> Self {
+        Self {
+            timeframes: 0.4,
+            patterns: 0.25,
+            indicators: 0.2,
+            llm: 0.15,
+        }
+    }
+}
+
 /// Trading signal with confluence analysis.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Signal {
     pub signal_id: String,
+    #[serde(deserialize_with = "symbol::normalized")]
     pub symbol: String,
     pub timestamp: DateTime<Utc>,
     
@@ -147,8 +174,11 @@ pub struct Signal {
     pub llm_analysis: Option<LlmAnalysis>,
     
     // Price targets
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub entry_price: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub stop_loss: Option<f64>,
+    #[serde(default, deserialize_with = "decimal_str::option")]
     pub take_profit: Option<f64>,
     
     // Risk metrics
@@ -272,7 +302,11 @@ impl Signal {
             return self.confluence_score;
         }
         
-        let weighted_sum: f64 = self.timeframe_analysis.values()
+        // Sum in timeframe order so the result doesn't depend on hash order
+        let mut timeframes: Vec<&Timeframe> = self.timeframe_analysis.keys().collect();
+        timeframes.sort();
+        let weighted_sum: f64 = timeframes.into_iter()
+            .map(|timeframe| &self.timeframe_analysis[timeframe])
             .map(|ta| (ta.trend_score + ta.momentum_score) * ta.timeframe_weight)
             .sum();
         
@@ -280,4 +314,178 @@ impl Signal {
         let normalized_score = (weighted_sum / total_weight + 10.0) * 5.0;
         normalized_score.max(0.0).min(100.0)
     }
-}
\ No newline at end of file
+    
+    /// Blend timeframe, pattern, indicator and LLM analysis into a 0-100 confluence score.
+    ///
+    /// Each component is a bullish-positive value in [-1, 1]; components with no data are
+    /// left out of the blend. The blend is signed by `direction`, so 100 is full agreement
+    /// with the signal, 50 is neutral and 0 is full disagreement. Falls back to
+    /// `confluence_score` when there is nothing to blend.
+    pub fn compute_confluence(&self, weights: ConfluenceWeights) -> f64 {
+        let mut timeframes: Vec<&TimeframeAnalysis> = self.timeframe_analysis.values().collect();
+        timeframes.sort_by_key(|ta| ta.timeframe);
+        
+        let mut components = Vec::new();
+        
+        let timeframe_weight: f64 = timeframes.iter().map(|ta| ta.timeframe_weight).sum();
+        if timeframe_weight > 0.0 {
+            let trend: f64 = timeframes.iter()
+                .map(|ta| {
+                    let direction = (ta.trend_score + ta.momentum_score) / 20.0;
+                    let volume_conviction = 0.5 + ta.volume_score / 20.0;
+                    direction * volume_conviction * ta.timeframe_weight
+                })
+                .sum();
+            components.push((weights.timeframes, trend / timeframe_weight));
+        }
+        
+        let pattern_confidence: f64 = self.patterns.iter().map(|p| p.confidence).sum();
+        if pattern_confidence > 0.0 {
+            let strength: f64 = self.patterns.iter()
+                .map(|p| p.bias() * p.confidence * p.strength / 10.0)
+                .sum();
+            components.push((weights.patterns, strength / pattern_confidence));
+        }
+        
+        let bullish: u32 = timeframes.iter().map(|ta| ta.bullish_indicators).sum();
+        let bearish: u32 = timeframes.iter().map(|ta| ta.bearish_indicators).sum();
+        let neutral: u32 = timeframes.iter().map(|ta| ta.neutral_indicators).sum();
+        let indicator_count = bullish + bearish + neutral;
+        if indicator_count > 0 {
+            let net = (f64::from(bullish) - f64::from(bearish)) / f64::from(indicator_count);
+            components.push((weights.indicators, net));
+        }
+        
+        if let Some(llm) = &self.llm_analysis {
+            let net = (llm.bullish_score - llm.bearish_score) / 10.0 * llm.confidence;
+            components.push((weights.llm, net));
+        }
+        
+        let total_weight: f64 = components.iter().map(|(weight, _)| weight).sum();
+        if total_weight <= 0.0 {
+            return self.confluence_score;
+        }
+        
+        let bullish_score = components.iter().map(|(weight, value)| weight * value).sum::<f64>() / total_weight;
+        let directional_score = match self.direction {
+            Direction::Long => bullish_score,
+            Direction::Short => -bullish_score,
+        };
+        ((directional_score + 1.0) * 50.0).clamp(0.0, 100.0)
+    }
+}
+
+/// Merge signals on the same symbol from several timeframes, requiring every timeframe to agree.
+///
+/// See `aggregate_with_quorum`.
+pub fn aggregate(signals: &[Signal]) -> Option<Signal> {
+    let timeframes = signals.iter()
+        .map(|signal| signal.primary_timeframe)
+        .collect::<HashSet<_>>()
+        .len();
+    aggregate_with_quorum(signals, timeframes)
+}
+
+/// Merge signals on the same symbol from several timeframes once at least `quorum` timeframes
+/// agree on direction and fewer than `quorum` oppose it.
+///
+/// Only the agreeing signals are merged. Confluence and confidence are averaged weighted by
+/// `Timeframe::rank`, the stop is the one closest to entry and the target the one furthest
+/// from it. Entry, regime and reasoning come from the longest agreeing timeframe. Returns
+/// `None` for an empty or mixed-symbol slice, or when no direction reaches the quorum.
+pub fn aggregate_with_quorum(signals: &[Signal], quorum: usize) -> Option<Signal> {
+    let first = signals.first()?;
+    if quorum == 0 || signals.iter().any(|signal| signal.symbol != first.symbol) {
+        return None;
+    }
+
+    let timeframes_for = |direction: Direction| {
+        signals.iter()
+            .filter(|signal| signal.direction == direction)
+            .map(|signal| signal.primary_timeframe)
+            .collect::<HashSet<_>>()
+            .len()
+    };
+    let (long, short) = (timeframes_for(Direction::Long), timeframes_for(Direction::Short));
+    let direction = match (long >= quorum, short >= quorum) {
+        (true, false) => Direction::Long,
+        (false, true) => Direction::Short,
+        _ => return None,
+    };
+
+    let mut agreeing: Vec<&Signal> = signals.iter().filter(|signal| signal.direction == direction).collect();
+    // Longest timeframe first; it anchors the merged signal
+    agreeing.sort_by(|a, b| b.primary_timeframe.cmp(&a.primary_timeframe).then(b.timestamp.cmp(&a.timestamp)));
+    let mut merged = agreeing[0].clone();
+
+    let total_rank: f64 = agreeing.iter().map(|signal| signal.primary_timeframe.rank() as f64).sum();
+    let weighted = |value: fn(&Signal) -> f64| {
+        agreeing.iter()
+            .map(|signal| value(signal) * signal.primary_timeframe.rank() as f64)
+            .sum::<f64>() / total_rank
+    };
+    merged.confluence_score = weighted(|signal| signal.confluence_score);
+    merged.confidence = weighted(|signal| signal.confidence);
+
+    let stops = agreeing.iter().filter_map(|signal| signal.stop_loss);
+    let targets = agreeing.iter().filter_map(|signal| signal.take_profit);
+    (merged.stop_loss, merged.take_profit) = match direction {
+        Direction::Long => (stops.reduce(f64::max), targets.reduce(f64::max)),
+        Direction::Short => (stops.reduce(f64::min), targets.reduce(f64::min)),
+    };
+    merged.risk_reward_ratio = match (merged.entry_price, merged.stop_loss, merged.take_profit) {
+        (Some(entry), Some(stop), Some(target)) if entry != stop => Some((target - entry).abs() / (entry - stop).abs()),
+        _ => merged.risk_reward_ratio,
+    };
+
+    merged.signal_id = uuid::Uuid::new_v4().to_string();
+    merged.timestamp = agreeing.iter().map(|signal| signal.timestamp).max()?;
+    merged.expires_at = agreeing.iter().filter_map(|signal| signal.expires_at).min();
+    merged.priority = agreeing.iter().map(|signal| signal.priority).max()?;
+    for signal in &agreeing[1..] {
+        for (timeframe, analysis) in &signal.timeframe_analysis {
+            merged.timeframe_analysis.entry(*timeframe).or_insert_with(|| analysis.clone());
+        }
+        for (timeframe, indicators) in &signal.indicators {
+            merged.indicators.entry(*timeframe).or_insert_with(|| indicators.clone());
+        }
+        merged.patterns.extend(signal.patterns.iter().cloned());
+        merged.key_factors.extend(signal.key_factors.iter().cloned());
+    }
+    Some(merged)
+}
+
+/// Direction of a fresh MACD/signal-line or EMA 20/50 crossover between `prev` and `snapshot`.
+///
+/// A crossover is the fast series moving from at-or-below the slow one to above it (long)
+/// or from at-or-above to below it (short). Pairs with a missing value are skipped. Returns
+/// `None` without `prev`, when nothing crossed, or when the two pairs cross in opposite
+/// directions.
+pub fn from_indicators(snapshot: &IndicatorSnapshot, prev: Option<&IndicatorSnapshot>) -> Option<Direction> {
+    let prev = prev?;
+    let crossovers = [
+        crossover(prev.macd_line, prev.macd_signal, snapshot.macd_line, snapshot.macd_signal),
+        crossover(prev.ema_20, prev.ema_50, snapshot.ema_20, snapshot.ema_50),
+    ];
+
+    let mut direction = None;
+    for crossed in crossovers.into_iter().flatten() {
+        match direction {
+            None => direction = Some(crossed),
+            Some(existing) if existing != crossed => return None,
+            Some(_) => {}
+        }
+    }
+    direction
+}
+
+fn crossover(prev_fast: Option<f64>, prev_slow: Option<f64>, fast: Option<f64>, slow: Option<f64>) -> Option<Direction> {
+    let (prev_fast, prev_slow, fast, slow) = (prev_fast?, prev_slow?, fast?, slow?);
+    if prev_fast <= prev_slow && fast > slow {
+        Some(Direction::Long)
+    } else if prev_fast >= prev_slow && fast < slow {
+        Some(Direction::Short)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,150 @@
+//! Normalized instrument symbols.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Characters exchanges put between base and quote, e.g. `BTC-USD`, `BTC/USD`, `BTC_USD`.
+const SEPARATORS: &[char] = &['-', '/', '_', ':', '.', ' '];
+
+/// Quote assets recognised when splitting a symbol, longest match first.
+const QUOTE_ASSETS: &[&str] = &[
+    "FDUSD", "USDT", "USDC", "BUSD", "TUSD", "USD", "EUR", "GBP", "JPY", "BTC", "ETH", "BNB",
+];
+
+const MAX_LEN: usize = 32;
+
+/// Instrument symbol in canonical form: uppercase with separators removed, so
+/// `"BTC-USD"`, `"btc/usd"` and `"BTCUSD"` are the same symbol.
+///
+/// Serializes as a plain string. Deserializing normalizes and validates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(String);
+
+impl Symbol {
+    /// Normalize and validate a symbol.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let symbol = Self::from(raw);
+        symbol.validate().map(|_| symbol)
+    }
+
+    /// Canonical form of `raw`: uppercase, separators and surrounding whitespace removed.
+    pub fn normalize(raw: &str) -> String {
+        raw.trim()
+            .chars()
+            .filter(|c| !SEPARATORS.contains(c))
+            .map(|c| c.to_ascii_uppercase())
+            .collect()
+    }
+
+    /// Check the symbol is non-empty ASCII alphanumeric of at most 32 characters.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.0.is_empty() {
+            return Err("Symbol must not be empty".to_string());
+        }
+        if self.0.len() > MAX_LEN {
+            return Err(format!("Symbol '{}' is longer than {} characters", self.0, MAX_LEN));
+        }
+        if !self.0.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("Symbol '{}' must be alphanumeric", self.0));
+        }
+        Ok(())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Split into base and quote assets by the known quote suffixes, e.g. `ETHBTC` into
+    /// `("ETH", "BTC")`; `None` for symbols like `BTCPERP` with no recognised quote.
+    pub fn base_quote(&self) -> Option<(&str, &str)> {
+        QUOTE_ASSETS.iter().find_map(|quote| {
+            let base = self.0.strip_suffix(quote)?;
+            (!base.is_empty()).then(|| (base, &self.0[base.len()..]))
+        })
+    }
+
+    pub fn base(&self) -> Option<&str> {
+        self.base_quote().map(|(base, _)| base)
+    }
+
+    pub fn quote(&self) -> Option<&str> {
+        self.base_quote().map(|(_, quote)| quote)
+    }
+}
+
+impl From<&str> for Symbol {
+    /// Normalize without validating; use `Symbol::parse` to reject malformed symbols.
+    fn from(raw: &str) -> Self {
+        Self(Self::normalize(raw))
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(raw: String) -> Self {
+        Self::from(raw.as_str())
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(raw: &String) -> Self {
+        Self::from(raw.as_str())
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(symbol: Symbol) -> Self {
+        symbol.0
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Self::parse(raw)
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Self::parse(&raw).map_err(serde::de::Error::custom)
+    }
+}
+
+/// `deserialize_with` for models that keep their symbol as a `String`, so a symbol read
+/// off the wire is normalized and validated just like a [`Symbol`].
+pub(crate) fn normalized<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Symbol::deserialize(deserializer).map(String::from)
+}
@@ -41,6 +41,214 @@ mod tests {
         assert!(bar.validate().is_err());
     }
 
+    #[test]
+    fn test_market_bar_alignment() {
+        let at = |time: &str| time.parse::<chrono::DateTime<Utc>>().unwrap();
+        let mut bar = MarketBar {
+            symbol: "BTCUSDT".to_string(),
+            timeframe: Timeframe::H1,
+            timestamp: at("2024-03-04T10:00:00Z"),
+            open: 50000.0,
+            high: 51000.0,
+            low: 49500.0,
+            close: 50500.0,
+            volume: 100.5,
+            quote_volume: None,
+            trades_count: None,
+            taker_buy_volume: None,
+        };
+        assert!(bar.validate_alignment().is_ok());
+
+        // An H1 bar at 10:37 names the hour it should start on
+        bar.timestamp = at("2024-03-04T10:37:00Z");
+        let error = bar.validate_alignment().unwrap_err();
+        assert!(error.contains("2024-03-04 10:00:00 UTC"), "{}", error);
+        assert!(bar.validate().is_ok()); // plain validation still accepts partial bars
+
+        for (timeframe, aligned, misaligned) in [
+            (Timeframe::M1, "2024-03-04T10:37:00Z", "2024-03-04T10:37:30Z"),
+            (Timeframe::M5, "2024-03-04T10:35:00Z", "2024-03-04T10:37:00Z"),
+            (Timeframe::M15, "2024-03-04T10:45:00Z", "2024-03-04T10:50:00Z"),
+            (Timeframe::H4, "2024-03-04T08:00:00Z", "2024-03-04T10:00:00Z"),
+            (Timeframe::D1, "2024-03-04T00:00:00Z", "2024-03-04T01:00:00Z"),
+        ] {
+            bar.timeframe = timeframe;
+            bar.timestamp = at(aligned);
+            assert!(bar.validate_alignment().is_ok(), "{:?} at {}", timeframe, aligned);
+            bar.timestamp = at(misaligned);
+            assert!(bar.validate_alignment().is_err(), "{:?} at {}", timeframe, misaligned);
+        }
+    }
+
+    #[test]
+    fn test_find_gaps() {
+        let start = "2024-03-04T10:00:00Z".parse::<chrono::DateTime<Utc>>().unwrap();
+        let bars_at = |hours: &[i64]| -> Vec<MarketBar> {
+            hours.iter()
+                .map(|&hour| MarketBar {
+                    symbol: "BTCUSDT".to_string(),
+                    timeframe: Timeframe::H1,
+                    timestamp: start + chrono::Duration::hours(hour),
+                    open: 50000.0,
+                    high: 51000.0,
+                    low: 49500.0,
+                    close: 50500.0,
+                    volume: 100.5,
+                    quote_volume: None,
+                    trades_count: None,
+                    taker_buy_volume: None,
+                })
+                .collect()
+        };
+
+        // Contiguous series
+        assert!(find_gaps(&bars_at(&[0, 1, 2, 3]), Timeframe::H1).is_empty());
+        assert!(find_gaps(&[], Timeframe::H1).is_empty());
+
+        // A single missing bar
+        let gaps = find_gaps(&bars_at(&[0, 1, 3, 4]), Timeframe::H1);
+        assert_eq!(gaps, vec![(start + chrono::Duration::hours(2), start + chrono::Duration::hours(2))]);
+
+        // Unsorted input is ordered before scanning
+        let gaps = find_gaps(&bars_at(&[6, 0, 1, 2]), Timeframe::H1);
+        assert_eq!(gaps, vec![(start + chrono::Duration::hours(3), start + chrono::Duration::hours(5))]);
+    }
+
+    #[test]
+    fn test_bars_csv_round_trip() {
+        let start = "2024-03-04T10:00:00Z".parse::<chrono::DateTime<Utc>>().unwrap();
+        let bars: Vec<MarketBar> = (0..3)
+            .map(|hour| MarketBar {
+                symbol: "BTCUSDT".to_string(),
+                timeframe: Timeframe::H1,
+                timestamp: start + chrono::Duration::hours(hour),
+                open: 50000.0 + hour as f64,
+                high: 51000.25,
+                low: 49500.5,
+                close: 50500.125,
+                volume: 100.5 * (hour + 1) as f64,
+                quote_volume: None,
+                trades_count: None,
+                taker_buy_volume: None,
+            })
+            .collect();
+
+        let mut csv = Vec::new();
+        write_bars_csv(&mut csv, &bars).unwrap();
+        let text = String::from_utf8(csv.clone()).unwrap();
+        assert!(text.starts_with("timestamp,open,high,low,close,volume\n2024-03-04T10:00:00Z,50000,"));
+
+        let import = read_bars_csv(csv.as_slice(), "BTCUSDT", Timeframe::H1).unwrap();
+        assert_eq!(import.error_count(), 0);
+        assert_eq!(import.bars.len(), 3);
+        for (read, written) in import.bars.iter().zip(&bars) {
+            assert_eq!(read.timestamp, written.timestamp);
+            assert_eq!((read.open, read.high, read.low, read.close, read.volume), (written.open, written.high, written.low, written.close, written.volume));
+            assert_eq!(read.timeframe, Timeframe::H1);
+        }
+
+        // Epoch milliseconds and reordered columns read the same
+        let millis = format!("close,volume,timestamp,open,high,low\n50500,1,{},50000,51000,49500\n", start.timestamp_millis());
+        let import = read_bars_csv(millis.as_bytes(), "BTCUSDT", Timeframe::H1).unwrap();
+        assert_eq!(import.bars[0].timestamp, start);
+        assert_eq!(import.bars[0].close, 50500.0);
+    }
+
+    #[test]
+    fn test_bars_csv_skips_malformed_rows() {
+        let csv = "timestamp,open,high,low,close,volume\n\
+            2024-03-04T10:00:00Z,100,110,90,105,5\n\
+            2024-03-04T11:00:00Z,100,abc,90,105,5\n\
+            \n\
+            2024-03-04T12:00:00Z,100,95,90,105,5\n\
+            not-a-time,100,110,90,105,5\n\
+            2024-03-04T14:00:00Z,100,110\n\
+            2024-03-04T15:00:00+01:00,105,112,101,110,7\n";
+        let import = read_bars_csv(csv.as_bytes(), "BTCUSDT", Timeframe::H1).unwrap();
+
+        assert_eq!(import.bars.len(), 2);
+        assert_eq!(import.bars[1].timestamp, "2024-03-04T14:00:00Z".parse::<chrono::DateTime<Utc>>().unwrap());
+        assert_eq!(import.error_count(), 4);
+        let lines: Vec<usize> = import.errors.iter().map(|error| error.line).collect();
+        assert_eq!(lines, vec![3, 5, 6, 7]);
+        assert!(import.errors[0].message.contains("high"));
+        assert_eq!(import.errors[1].message, "High must be the highest price");
+
+        // A missing header column aborts the import
+        assert!(read_bars_csv("timestamp,open,high,low,close\n".as_bytes(), "BTCUSDT", Timeframe::H1).is_err());
+        assert_eq!(read_bars_csv("".as_bytes(), "BTCUSDT", Timeframe::H1).unwrap().bars.len(), 0);
+    }
+
+    #[test]
+    fn test_quote_spread() {
+        let quote = Quote {
+            symbol: "BTCUSDT".to_string(),
+            bid: 49995.0,
+            ask: 50005.0,
+            bid_size: 1.5,
+            ask_size: 2.0,
+            timestamp: Utc::now(),
+        };
+        assert_eq!(quote.mid(), 50000.0);
+        assert!((quote.spread_bps() - 2.0).abs() < 1e-9);
+        assert!(!quote.is_crossed());
+
+        let crossed = Quote { bid: 50010.0, ..quote };
+        assert!(crossed.is_crossed());
+        assert!(crossed.spread_bps() < 0.0);
+    }
+
+    #[test]
+    fn test_funding_payment_sign() {
+        let mut funding = FundingRate {
+            symbol: "BTCUSDT".to_string(),
+            rate: 0.0001,
+            next_funding_time: Utc::now(),
+            timestamp: Utc::now(),
+        };
+        // Positive rate: longs pay, shorts receive
+        assert!((funding.payment(2.0, 50000.0) - -10.0).abs() < 1e-9);
+        assert!((funding.payment(-2.0, 50000.0) - 10.0).abs() < 1e-9);
+
+        // Negative rate: shorts pay, longs receive
+        funding.rate = -0.0001;
+        assert!((funding.payment(2.0, 50000.0) - 10.0).abs() < 1e-9);
+        assert!((funding.payment(-2.0, 50000.0) - -10.0).abs() < 1e-9);
+        assert_eq!(funding.payment(0.0, 50000.0), 0.0);
+    }
+
+    #[test]
+    fn test_order_book_validation() {
+        let level = |price, size| OrderBookLevel { price, size };
+        let mut book = OrderBook {
+            symbol: "BTCUSDT".to_string(),
+            bids: vec![level(49995.0, 1.0), level(49990.0, 3.0)],
+            asks: vec![level(50005.0, 2.0), level(50010.0, 4.0)],
+            timestamp: Utc::now(),
+        };
+        assert!(book.validate().is_ok());
+        let quote = book.best_quote().unwrap();
+        assert_eq!((quote.bid, quote.ask, quote.bid_size, quote.ask_size), (49995.0, 50005.0, 1.0, 2.0));
+
+        // Out-of-order sides
+        book.bids.reverse();
+        assert!(book.validate().unwrap_err().contains("descending"));
+        book.bids.reverse();
+        book.asks.reverse();
+        assert!(book.validate().unwrap_err().contains("ascending"));
+        book.asks.reverse();
+
+        // Crossed book
+        book.bids.insert(0, level(50006.0, 0.5));
+        assert!(book.is_crossed());
+        assert!(book.validate().unwrap_err().contains("crossed"));
+
+        // A one-sided book has no quote and can't be crossed
+        book.asks.clear();
+        assert!(!book.is_crossed());
+        assert!(book.best_quote().is_none());
+    }
+
     #[test]
     fn test_indicator_snapshot_validation() {
         let mut snapshot = IndicatorSnapshot {
@@ -80,6 +288,128 @@ mod tests {
         assert!(snapshot.validate().is_err());
     }
 
+    fn trend_snapshot(macd: Option<(f64, f64)>, ema: Option<(f64, f64)>) -> IndicatorSnapshot {
+        IndicatorSnapshot {
+            symbol: "BTCUSDT".to_string(),
+            timeframe: Timeframe::H1,
+            timestamp: Utc::now(),
+            rsi: None,
+            ema_20: ema.map(|(fast, _)| fast),
+            ema_50: ema.map(|(_, slow)| slow),
+            ema_200: None,
+            macd_line: macd.map(|(line, _)| line),
+            macd_signal: macd.map(|(_, signal)| signal),
+            macd_histogram: macd.map(|(line, signal)| line - signal),
+            bb_upper: None,
+            bb_middle: None,
+            bb_lower: None,
+            bb_width: None,
+            atr: None,
+            volume_sma: None,
+            volume_profile: None,
+            stoch_k: None,
+            stoch_d: None,
+            cci: None,
+            mfi: None,
+        }
+    }
+
+    #[test]
+    fn test_volume_profile_value_area() {
+        let buckets: HashMap<String, f64> = [
+            ("100", 5.0), ("101", 10.0), ("102", 20.0), ("103", 40.0),
+            ("104", 15.0), ("105", 8.0), ("106", 2.0),
+        ]
+        .into_iter()
+        .map(|(price, volume)| (price.to_string(), volume))
+        .collect();
+        let profile = VolumeProfile::try_from(&buckets).unwrap();
+        assert!(profile.validate().is_ok());
+        assert_eq!(profile.levels.first().unwrap().price, 100.0);
+        assert_eq!(profile.total_volume(), 100.0);
+        assert_eq!(profile.point_of_control(), Some(103.0));
+
+        // 40 at the POC, then 102 (20 beats 15) reaches 60, then 104 (15 beats 10) reaches 75
+        assert_eq!(profile.value_area(0.7), Some((102.0, 104.0)));
+        assert_eq!(profile.value_area(0.4), Some((103.0, 103.0)));
+        assert_eq!(profile.value_area(1.0), Some((100.0, 106.0)));
+        assert_eq!(profile.value_area(1.5), None);
+        assert_eq!(VolumeProfile::default().value_area(0.7), None);
+        assert_eq!(VolumeProfile::default().point_of_control(), None);
+
+        let mut invalid = buckets.clone();
+        invalid.insert("abc".to_string(), 1.0);
+        assert!(VolumeProfile::try_from(&invalid).is_err());
+        let negative = VolumeProfile::new(vec![VolumeProfileLevel { price: 100.0, volume: -1.0 }]);
+        assert!(negative.validate().is_err());
+    }
+
+    #[test]
+    fn test_bb_squeeze_on_narrowing_bands() {
+        let banded = |half_width: f64| {
+            let mut snapshot = trend_snapshot(None, None);
+            snapshot.bb_upper = Some(100.0 + half_width);
+            snapshot.bb_middle = Some(100.0);
+            snapshot.bb_lower = Some(100.0 - half_width);
+            snapshot
+        };
+        let mut with_width = trend_snapshot(None, None);
+        with_width.bb_width = Some(0.08);
+        assert!((with_width.bb_width_pct().unwrap() - 8.0).abs() < 1e-9);
+        assert!((banded(5.0).bb_width_pct().unwrap() - 10.0).abs() < 1e-9);
+        assert_eq!(trend_snapshot(None, None).bb_width_pct(), None);
+
+        // Wide, gently varying bands, then a steady contraction
+        let mut history: Vec<IndicatorSnapshot> = (0..20)
+            .map(|i| banded(5.0 + (i % 3) as f64 * 0.5))
+            .collect();
+        assert!(!detect_bb_squeeze(&history, 20));
+        for half_width in [4.0, 3.0, 2.0, 1.5] {
+            history.push(banded(half_width));
+        }
+        assert!(detect_bb_squeeze(&history, 20));
+
+        // Snapshots missing band values are skipped
+        history.push(trend_snapshot(None, None));
+        assert!(detect_bb_squeeze(&history, 20));
+
+        // A widening after the squeeze ends it
+        history.push(banded(3.5));
+        assert!(!detect_bb_squeeze(&history, 20));
+
+        // Too little data to judge
+        assert!(!detect_bb_squeeze(&history[..1], 20));
+        assert!(!detect_bb_squeeze(&history, 0));
+    }
+
+    #[test]
+    fn test_from_indicators_detects_fresh_crossovers() {
+        // MACD line crossing up through its signal line
+        let prev = trend_snapshot(Some((-0.5, 0.2)), None);
+        let current = trend_snapshot(Some((0.4, 0.1)), None);
+        assert_eq!(from_indicators(&current, Some(&prev)), Some(Direction::Long));
+        // The sign alone isn't a crossover
+        assert_eq!(from_indicators(&current, None), None);
+        assert_eq!(from_indicators(&current, Some(&current)), None);
+
+        // EMA 20 falling through EMA 50, with MACD data missing
+        let prev = trend_snapshot(None, Some((101.0, 100.0)));
+        let current = trend_snapshot(None, Some((99.0, 100.0)));
+        assert_eq!(from_indicators(&current, Some(&prev)), Some(Direction::Short));
+
+        // Agreeing crossovers reinforce, conflicting ones cancel out
+        let prev = trend_snapshot(Some((-0.5, 0.2)), Some((99.0, 100.0)));
+        let agreeing = trend_snapshot(Some((0.4, 0.1)), Some((101.0, 100.0)));
+        assert_eq!(from_indicators(&agreeing, Some(&prev)), Some(Direction::Long));
+        let prev = trend_snapshot(Some((-0.5, 0.2)), Some((101.0, 100.0)));
+        let conflicting = trend_snapshot(Some((0.4, 0.1)), Some((99.0, 100.0)));
+        assert_eq!(from_indicators(&conflicting, Some(&prev)), None);
+
+        // No indicator data at all
+        let empty = trend_snapshot(None, None);
+        assert_eq!(from_indicators(&empty, Some(&empty)), None);
+    }
+
     #[test]
     fn test_pattern_hit_validation() {
         let mut pattern = PatternHit {
@@ -95,6 +425,8 @@ mod tests {
             take_profit: Some(52000.0),
             support_levels: vec![48000.0, 49000.0],
             resistance_levels: vec![51000.0, 52000.0],
+            neckline: None,
+            measured_move_target: None,
             pattern_data: HashMap::new(),
             bars_analyzed: 100,
             lookback_period: 50,
@@ -120,6 +452,49 @@ mod tests {
         assert!(pattern.validate().is_err());
     }
 
+    #[test]
+    fn test_pattern_hit_nearest_levels() {
+        let mut pattern = PatternHit {
+            pattern_id: "pattern_123".to_string(),
+            pattern_type: PatternType::Breakout,
+            symbol: "BTCUSDT".to_string(),
+            timeframe: Timeframe::H1,
+            timestamp: Utc::now(),
+            confidence: 0.85,
+            strength: 7.5,
+            entry_price: Some(50000.0),
+            stop_loss: Some(49000.0),
+            take_profit: Some(52000.0),
+            support_levels: vec![48000.0, 49000.0],
+            resistance_levels: vec![51000.0, 52000.0],
+            neckline: None,
+            measured_move_target: None,
+            pattern_data: HashMap::new(),
+            bars_analyzed: 100,
+            lookback_period: 50,
+            historical_win_rate: None,
+            avg_return: None,
+        };
+
+        // Between supports and resistances
+        assert_eq!(pattern.nearest_support(50000.0), Some(49000.0));
+        assert_eq!(pattern.nearest_resistance(50000.0), Some(51000.0));
+        assert_eq!(pattern.nearest_support(48500.0), Some(48000.0));
+        assert_eq!(pattern.nearest_resistance(51500.0), Some(52000.0));
+
+        // Below all supports
+        assert_eq!(pattern.nearest_support(47000.0), None);
+        assert_eq!(pattern.nearest_resistance(47000.0), Some(51000.0));
+
+        // Above all resistances
+        assert_eq!(pattern.nearest_support(53000.0), Some(49000.0));
+        assert_eq!(pattern.nearest_resistance(53000.0), None);
+
+        assert_eq!(pattern.distance_to_stop_pct(), Some(2.0));
+        pattern.stop_loss = None;
+        assert_eq!(pattern.distance_to_stop_pct(), None);
+    }
+
     #[test]
     fn test_signal_validation() {
         let mut signal = Signal {
@@ -160,6 +535,148 @@ mod tests {
         assert!(signal.validate().is_err());
     }
 
+    fn timeframe_signal(timeframe: Timeframe, direction: Direction, confluence: f64, stop: f64, target: f64) -> Signal {
+        Signal {
+            signal_id: format!("signal_{}", timeframe.as_str()),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc::now(),
+            direction,
+            confluence_score: confluence,
+            confidence: 0.8,
+            market_regime: MarketRegime::Bull,
+            primary_timeframe: timeframe,
+            timeframe_analysis: HashMap::new(),
+            patterns: Vec::new(),
+            indicators: HashMap::new(),
+            llm_analysis: None,
+            entry_price: Some(50000.0),
+            stop_loss: Some(stop),
+            take_profit: Some(target),
+            risk_reward_ratio: None,
+            max_risk_pct: Some(2.0),
+            reasoning: format!("{} setup", timeframe.as_str()),
+            key_factors: vec![timeframe.as_str().to_string()],
+            expires_at: None,
+            priority: 3,
+        }
+    }
+
+    #[test]
+    fn test_aggregate_merges_agreeing_timeframes() {
+        let signals = [
+            timeframe_signal(Timeframe::M15, Direction::Long, 60.0, 49500.0, 51000.0),
+            timeframe_signal(Timeframe::H1, Direction::Long, 70.0, 49000.0, 52000.0),
+            timeframe_signal(Timeframe::H4, Direction::Long, 80.0, 48000.0, 54000.0),
+        ];
+
+        // Ranks 3, 4 and 5 weight the longer timeframes more
+        let merged = aggregate(&signals).unwrap();
+        assert_eq!(merged.direction, Direction::Long);
+        assert_eq!(merged.primary_timeframe, Timeframe::H4);
+        assert!((merged.confluence_score - (60.0 * 3.0 + 70.0 * 4.0 + 80.0 * 5.0) / 12.0).abs() < 1e-9);
+        assert_eq!(merged.stop_loss, Some(49500.0));
+        assert_eq!(merged.take_profit, Some(54000.0));
+        assert_eq!(merged.risk_reward_ratio, Some(8.0));
+        assert_eq!(merged.key_factors.len(), 3);
+
+        // A single dissenting timeframe blocks a unanimous merge
+        let mut split = signals.clone();
+        split[2] = timeframe_signal(Timeframe::H4, Direction::Short, 80.0, 51000.0, 47000.0);
+        assert!(aggregate(&split).is_none());
+
+        // ...but two of three is enough for a 2-timeframe quorum
+        let merged = aggregate_with_quorum(&split, 2).unwrap();
+        assert_eq!(merged.direction, Direction::Long);
+        assert_eq!(merged.primary_timeframe, Timeframe::H1);
+        assert!((merged.confluence_score - (60.0 * 3.0 + 70.0 * 4.0) / 7.0).abs() < 1e-9);
+        assert_eq!(merged.stop_loss, Some(49500.0));
+        assert_eq!(merged.take_profit, Some(52000.0));
+
+        // Signals on different symbols never merge
+        split[1].symbol = "ETHUSDT".to_string();
+        assert!(aggregate_with_quorum(&split, 1).is_none());
+        assert!(aggregate(&[]).is_none());
+    }
+
+    #[test]
+    fn test_compute_confluence_blends_components() {
+        let mut signal = Signal {
+            signal_id: "signal_123".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            timestamp: Utc::now(),
+            direction: Direction::Long,
+            confluence_score: 42.0,
+            confidence: 0.8,
+            market_regime: MarketRegime::Bull,
+            primary_timeframe: Timeframe::H1,
+            timeframe_analysis: HashMap::new(),
+            patterns: Vec::new(),
+            indicators: HashMap::new(),
+            llm_analysis: None,
+            entry_price: None,
+            stop_loss: None,
+            take_profit: None,
+            risk_reward_ratio: None,
+            max_risk_pct: None,
+            reasoning: String::new(),
+            key_factors: Vec::new(),
+            expires_at: None,
+            priority: 3,
+        };
+        let weights = ConfluenceWeights::default();
+
+        // Nothing to blend falls back to the stored score
+        assert_eq!(signal.compute_confluence(weights), 42.0);
+
+        signal.timeframe_analysis.insert(Timeframe::H1, TimeframeAnalysis {
+            timeframe: Timeframe::H1,
+            timestamp: Utc::now(),
+            trend_score: 2.0,
+            momentum_score: 2.0,
+            volatility_score: 5.0,
+            volume_score: 5.0,
+            pattern_count: 0,
+            strongest_pattern_confidence: 0.0,
+            bullish_indicators: 3,
+            bearish_indicators: 1,
+            neutral_indicators: 1,
+            timeframe_weight: 0.5,
+        });
+        let mildly_bullish = signal.compute_confluence(weights);
+        assert!(mildly_bullish > 50.0);
+
+        // A strong bullish pattern raises the score for a long signal
+        let mut pattern_data = HashMap::new();
+        pattern_data.insert("engulfing_type".to_string(), serde_json::json!("bullish"));
+        signal.patterns.push(PatternHit {
+            pattern_id: "pattern_123".to_string(),
+            pattern_type: PatternType::Engulfing,
+            symbol: "BTCUSDT".to_string(),
+            timeframe: Timeframe::H1,
+            timestamp: Utc::now(),
+            confidence: 0.9,
+            strength: 9.0,
+            entry_price: None,
+            stop_loss: None,
+            take_profit: None,
+            support_levels: Vec::new(),
+            resistance_levels: Vec::new(),
+            neckline: None,
+            measured_move_target: None,
+            pattern_data,
+            bars_analyzed: 2,
+            lookback_period: 2,
+            historical_win_rate: None,
+            avg_return: None,
+        });
+        let with_pattern = signal.compute_confluence(weights);
+        assert!(with_pattern > mildly_bullish);
+
+        // The same bullish evidence counts against a short signal
+        signal.direction = Direction::Short;
+        assert!((signal.compute_confluence(weights) - (100.0 - with_pattern)).abs() < 1e-9);
+    }
+
     #[test]
     fn test_order_decision_validation() {
         let mut decision = OrderDecision::new(
@@ -194,6 +711,169 @@ mod tests {
         decision.stop_loss = 49000.0;
         decision.leverage = 15.0; // > 10x
         assert!(decision.validate().is_err());
+        assert_eq!(decision.validate_fields().unwrap_err().0, "leverage");
+
+        // The failing field is named alongside the message
+        decision.leverage = 1.0;
+        decision.stop_loss = 51000.0;
+        let (field, message) = decision.validate_fields().unwrap_err();
+        assert_eq!(field, "stop_loss");
+        assert_eq!(decision.validate().unwrap_err(), message);
+    }
+
+    #[test]
+    fn test_order_decision_validate_against_exchange() {
+        let exchange_info = ExchangeInfo {
+            name: "test".to_string(),
+            tick_size: 0.5,
+            lot_size: 0.001,
+            min_order_size: 0.001,
+            max_order_size: 100.0,
+            min_price: 1.0,
+            max_price: 1_000_000.0,
+            trading_hours: Vec::new(),
+            supported_order_types: vec!["market".to_string(), "limit".to_string()],
+        };
+        let mut decision = OrderDecision::new("signal_123".to_string(), "BTCUSDT");
+        decision.entry_price = 50000.5;
+        decision.risk_adjusted_quantity = 0.123;
+        assert!(decision.validate_against_exchange(&exchange_info).is_ok());
+
+        decision.entry_price = 50000.25;
+        let err = decision.validate_against_exchange(&exchange_info).unwrap_err();
+        assert!(err.contains("not a multiple of tick size 0.5"), "{}", err);
+
+        decision.entry_price = 50000.0;
+        decision.risk_adjusted_quantity = 0.1234;
+        let err = decision.validate_against_exchange(&exchange_info).unwrap_err();
+        assert!(err.contains("not a multiple of lot size 0.001"), "{}", err);
+
+        decision.risk_adjusted_quantity = 150.0;
+        let err = decision.validate_against_exchange(&exchange_info).unwrap_err();
+        assert!(err.contains("Quantity 150 is outside the exchange range"), "{}", err);
+
+        decision.risk_adjusted_quantity = 1.0;
+        decision.entry_price = 2_000_000.0;
+        let err = decision.validate_against_exchange(&exchange_info).unwrap_err();
+        assert!(err.contains("Entry price 2000000 is outside the exchange range"), "{}", err);
+    }
+
+    #[test]
+    fn test_size_from_atr() {
+        let mut decision = OrderDecision::new(
+            "signal_123".to_string(),
+            "BTCUSDT".to_string(),
+        );
+        
+        decision.direction = Direction::Long;
+        decision.base_quantity = 1.0;
+        decision.max_position_value = 40000.0;
+        decision.risk_percentage = 2.0;
+        decision.portfolio_value = 100000.0;
+        decision.available_margin = 50000.0;
+        decision.risk_reward_ratio = 1.25;
+
+        // Long: stop 2 ATR below entry
+        decision.size_from_atr(800.0, 500.0, 2.0, 50000.0).unwrap();
+        assert_eq!(decision.stop_loss, 49000.0);
+        assert!((decision.risk_adjusted_quantity - 0.8).abs() < 1e-9);
+        assert_eq!(decision.risk_amount, 800.0);
+        assert!(decision.validate().is_ok());
+
+        // Short: stop 2 ATR above entry
+        decision.direction = Direction::Short;
+        decision.size_from_atr(800.0, 500.0, 2.0, 50000.0).unwrap();
+        assert_eq!(decision.stop_loss, 51000.0);
+        assert!((decision.risk_adjusted_quantity - 0.8).abs() < 1e-9);
+        assert!(decision.validate().is_ok());
+
+        // A stop more than 20% away is rejected and leaves the decision untouched
+        assert!(decision.size_from_atr(800.0, 6000.0, 2.0, 50000.0).is_err());
+        assert_eq!(decision.stop_loss, 51000.0);
+        assert!((decision.risk_adjusted_quantity - 0.8).abs() < 1e-9);
+
+        assert!(decision.size_from_atr(800.0, 0.0, 2.0, 50000.0).is_err());
+    }
+
+    #[test]
+    fn test_trailing_stop_trail_pct_validation() {
+        let mut decision = OrderDecision::new(
+            "signal_123".to_string(),
+            "BTCUSDT".to_string(),
+        );
+        
+        decision.direction = Direction::Long;
+        decision.base_quantity = 1.0;
+        decision.risk_adjusted_quantity = 0.8;
+        decision.max_position_value = 40000.0;
+        decision.entry_price = 50000.0;
+        decision.stop_loss = 49000.0;
+        decision.risk_amount = 800.0;
+        decision.risk_percentage = 2.0;
+        decision.portfolio_value = 100000.0;
+        decision.available_margin = 50000.0;
+        decision.risk_reward_ratio = 1.25;
+        decision.order_type = OrderType::TrailingStop;
+
+        // Trailing stops need a trail percentage
+        assert!(decision.validate().is_err());
+
+        decision.trail_pct = Some(2.5);
+        assert!(decision.validate().is_ok());
+
+        // Out of range trail percentages fail
+        decision.trail_pct = Some(0.0);
+        assert!(decision.validate().is_err());
+        decision.trail_pct = Some(25.0);
+        assert!(decision.validate().is_err());
+
+        // Other order types can't carry a trail percentage
+        decision.order_type = OrderType::Limit;
+        decision.trail_pct = Some(2.5);
+        assert!(decision.validate().is_err());
+    }
+
+    #[test]
+    fn test_post_only_requires_limit_order() {
+        let mut decision = OrderDecision::new("signal_123".to_string(), "BTCUSDT".to_string());
+        decision.base_quantity = 1.0;
+        decision.risk_adjusted_quantity = 0.8;
+        decision.max_position_value = 40000.0;
+        decision.entry_price = 50000.0;
+        decision.stop_loss = 49000.0;
+        decision.risk_amount = 800.0;
+        decision.risk_percentage = 2.0;
+        decision.portfolio_value = 100000.0;
+        decision.risk_reward_ratio = 1.25;
+        decision.post_only = true;
+
+        // Market orders always take liquidity
+        assert!(decision.validate().is_err());
+        decision.order_type = OrderType::Limit;
+        assert!(decision.validate().is_ok());
+
+        // Decisions serialized before the flag existed aren't post-only
+        let mut json = serde_json::to_value(&decision).unwrap();
+        json.as_object_mut().unwrap().remove("post_only");
+        let decision: OrderDecision = serde_json::from_value(json).unwrap();
+        assert!(!decision.post_only);
+    }
+
+    #[test]
+    fn test_time_in_force_serialization() {
+        let expires_at = "2024-03-04T16:00:00Z".parse::<chrono::DateTime<Utc>>().unwrap();
+        assert_eq!(serde_json::to_string(&TimeInForce::Ioc).unwrap(), "\"IOC\"");
+        assert_eq!(
+            serde_json::to_value(TimeInForce::Gtd(expires_at)).unwrap(),
+            serde_json::json!({"GTD": "2024-03-04T16:00:00Z"})
+        );
+
+        // Decisions without a time in force stay good till cancelled
+        let decision = OrderDecision::new("signal_123".to_string(), "BTCUSDT".to_string());
+        let mut json = serde_json::to_value(&decision).unwrap();
+        json.as_object_mut().unwrap().remove("time_in_force");
+        let decision: OrderDecision = serde_json::from_value(json).unwrap();
+        assert_eq!(decision.time_in_force, TimeInForce::Gtc);
     }
 
     #[test]
@@ -216,6 +896,8 @@ mod tests {
             take_profit: None,
             support_levels: Vec::new(),
             resistance_levels: Vec::new(),
+            neckline: None,
+            measured_move_target: None,
             pattern_data: HashMap::new(),
             bars_analyzed: 100,
             lookback_period: 50,
@@ -237,6 +919,164 @@ mod tests {
         assert_eq!(high_conf_patterns.len(), 1);
     }
 
+    #[test]
+    fn test_reversal_pattern_projected_target() {
+        let mut pattern = PatternHit {
+            pattern_id: "hs_1".to_string(),
+            pattern_type: PatternType::HeadAndShoulders,
+            symbol: "BTCUSDT".to_string(),
+            timeframe: Timeframe::H4,
+            timestamp: Utc::now(),
+            confidence: 0.7,
+            strength: 6.0,
+            entry_price: Some(49500.0),
+            stop_loss: Some(52500.0),
+            take_profit: None,
+            support_levels: Vec::new(),
+            resistance_levels: vec![51000.0, 53000.0],
+            neckline: Some(50000.0),
+            measured_move_target: None,
+            pattern_data: HashMap::new(),
+            bars_analyzed: 80,
+            lookback_period: 80,
+            historical_win_rate: None,
+            avg_return: None,
+        };
+
+        // Head at 53000 over a 50000 neckline projects 3000 below entry
+        assert_eq!(pattern.projected_target(), Some(46500.0));
+        assert!(pattern.validate().is_ok());
+
+        // An explicit target wins, but must sit below entry for a bearish pattern
+        pattern.measured_move_target = Some(47000.0);
+        assert_eq!(pattern.projected_target(), Some(47000.0));
+        assert!(pattern.validate().is_ok());
+        pattern.measured_move_target = Some(51000.0);
+        assert!(pattern.validate().is_err());
+
+        // Bullish reversal projects upward from the lowest support
+        pattern.pattern_type = PatternType::DoubleBottom;
+        pattern.measured_move_target = None;
+        pattern.entry_price = Some(50500.0);
+        pattern.stop_loss = Some(47500.0);
+        pattern.support_levels = vec![48000.0];
+        assert_eq!(pattern.projected_target(), Some(52500.0));
+        pattern.measured_move_target = Some(50000.0);
+        assert!(pattern.validate().is_err());
+
+        // Older payloads without the geometry fields still deserialize
+        let mut json = serde_json::to_value(&pattern).unwrap();
+        json.as_object_mut().unwrap().remove("neckline");
+        json.as_object_mut().unwrap().remove("measured_move_target");
+        let restored: PatternHit = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.neckline, None);
+        assert_eq!(restored.measured_move_target, None);
+    }
+
+    fn divergence_bars(highs: &[f64], lows: &[f64]) -> Vec<MarketBar> {
+        highs.iter().zip(lows).enumerate()
+            .map(|(index, (&high, &low))| MarketBar {
+                symbol: "BTCUSDT".to_string(),
+                timeframe: Timeframe::H1,
+                timestamp: Utc::now() + chrono::Duration::hours(index as i64),
+                open: (high + low) / 2.0,
+                high,
+                low,
+                close: (high + low) / 2.0,
+                volume: 10.0,
+                quote_volume: None,
+                trades_count: None,
+                taker_buy_volume: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_rsi_divergence() {
+        // Price makes a higher high while RSI makes a lower high
+        let bars = divergence_bars(
+            &[100.0, 105.0, 102.0, 101.0, 103.0, 107.0, 104.0, 103.0],
+            &[98.0, 100.0, 99.0, 98.5, 99.0, 101.0, 100.0, 99.5],
+        );
+        let rsi = [60.0, 72.0, 65.0, 62.0, 64.0, 66.0, 60.0, 58.0];
+        let pattern = detect_rsi_divergence(&bars, &rsi, 8).unwrap().unwrap();
+        assert_eq!(pattern.pattern_type, PatternType::Divergence);
+        assert_eq!(pattern.pattern_data["divergence_type"], "bearish");
+        assert_eq!(pattern.resistance_levels, vec![107.0]);
+        assert!((0.4..=0.8).contains(&pattern.confidence));
+        assert!(pattern.validate().is_ok());
+
+        // Price makes a lower low while RSI makes a higher low
+        let bars = divergence_bars(
+            &[102.0, 101.0, 103.0, 104.0, 101.0, 100.0, 102.0, 103.0],
+            &[99.0, 95.0, 98.0, 99.0, 96.0, 93.0, 97.0, 98.0],
+        );
+        let rsi = [45.0, 30.0, 40.0, 44.0, 38.0, 35.0, 42.0, 46.0];
+        let pattern = detect_rsi_divergence(&bars, &rsi, 8).unwrap().unwrap();
+        assert_eq!(pattern.pattern_data["divergence_type"], "bullish");
+        assert_eq!(pattern.support_levels, vec![93.0]);
+        assert_eq!(pattern.implied_direction(), Some(Direction::Long));
+
+        // RSI confirming the new high is not a divergence
+        let bars = divergence_bars(
+            &[100.0, 105.0, 102.0, 101.0, 103.0, 107.0, 104.0, 103.0],
+            &[98.0, 100.0, 99.0, 98.5, 99.0, 101.0, 100.0, 99.5],
+        );
+        let rsi = [60.0, 65.0, 62.0, 61.0, 66.0, 72.0, 68.0, 66.0];
+        assert!(detect_rsi_divergence(&bars, &rsi, 8).unwrap().is_none());
+
+        // Mismatched series are an error
+        assert!(detect_rsi_divergence(&bars, &rsi[..7], 8).is_err());
+    }
+
+    #[test]
+    fn test_reversal_chart_pattern_types() {
+        let mut collection = PatternCollection::new(
+            "BTCUSDT".to_string(),
+            Timeframe::H4,
+        );
+
+        for (pattern_id, pattern_type) in [("double_bottom_1", PatternType::DoubleBottom), ("breakout_1", PatternType::Breakout)] {
+            collection.add_pattern(PatternHit {
+                pattern_id: pattern_id.to_string(),
+                pattern_type,
+                symbol: "BTCUSDT".to_string(),
+                timeframe: Timeframe::H4,
+                timestamp: Utc::now(),
+                confidence: 0.75,
+                strength: 6.0,
+                entry_price: None,
+                stop_loss: None,
+                take_profit: None,
+                support_levels: vec![48000.0],
+                resistance_levels: Vec::new(),
+                neckline: None,
+                measured_move_target: None,
+                pattern_data: HashMap::new(),
+                bars_analyzed: 60,
+                lookback_period: 60,
+                historical_win_rate: None,
+                avg_return: None,
+            });
+        }
+
+        let double_bottoms = collection.get_patterns_by_type(PatternType::DoubleBottom);
+        assert_eq!(double_bottoms.len(), 1);
+        assert_eq!(double_bottoms[0].pattern_id, "double_bottom_1");
+        assert!(collection.get_patterns_by_type(PatternType::DoubleTop).is_empty());
+
+        // Serialized names match the Python enum values
+        for (pattern_type, name) in [
+            (PatternType::DoubleTop, "\"double_top\""),
+            (PatternType::DoubleBottom, "\"double_bottom\""),
+            (PatternType::HeadAndShoulders, "\"head_and_shoulders\""),
+            (PatternType::InverseHeadAndShoulders, "\"inverse_head_and_shoulders\""),
+        ] {
+            assert_eq!(serde_json::to_string(&pattern_type).unwrap(), name);
+            assert_eq!(serde_json::from_str::<PatternType>(name).unwrap(), pattern_type);
+        }
+    }
+
     #[test]
     fn test_json_serialization_compatibility() {
         // Test MarketBar serialization
@@ -294,20 +1134,111 @@ mod tests {
         assert_eq!(signal.market_regime, signal_restored.market_regime);
     }
 
+    #[test]
+    fn test_python_fixtures_deserialize() {
+        assert_python_compat();
+    }
+
+    #[test]
+    fn test_python_compat_flags_fields_python_would_reject() {
+        let raw = include_str!("../../fixtures/python/order_decision.json");
+        let mut envelope: serde_json::Value = serde_json::from_str(raw).unwrap();
+        envelope["data"].as_object_mut().unwrap().remove("exchange");
+        let python = envelope["data"].clone();
+
+        let err = crate::trading_models::schema::check_fixture::<OrderDecision>(&envelope.to_string(), &python)
+            .unwrap_err();
+        assert!(err.contains("rejected by the Python model"), "{}", err);
+        assert!(err.contains("data.exchange"), "{}", err);
+    }
+
+    #[test]
+    fn test_schema_envelope_round_trip() {
+        let mut decision = OrderDecision::new("signal_123".to_string(), "BTCUSDT");
+        decision.entry_price = 50000.0;
+
+        let json_str = serde_json::to_string(&SchemaEnvelope::new(decision)).unwrap();
+        assert!(json_str.contains(r#""schema":"order_decision""#));
+        assert!(json_str.contains(&format!(r#""schema_version":"{}""#, SCHEMA_VERSION)));
+
+        let envelope: SchemaEnvelope<OrderDecision> = serde_json::from_str(&json_str).unwrap();
+        assert_eq!(envelope.clone().into_data().unwrap().entry_price, 50000.0);
+
+        let stale = SchemaEnvelope { schema_version: "0.9.0".to_string(), ..envelope.clone() };
+        assert!(stale.into_data().unwrap_err().contains("0.9.0"));
+
+        let mislabelled = SchemaEnvelope { schema: "market_bar".to_string(), ..envelope };
+        assert!(mislabelled.into_data().is_err());
+    }
+
+    #[test]
+    fn test_decimal_fields_accept_python_strings() {
+        let bar: MarketBar = serde_json::from_str(r#"{
+            "symbol": "BTCUSDT", "timeframe": "1h", "timestamp": "2024-03-05T12:00:00Z",
+            "open": "50000", "high": 50500, "low": "49800.5", "close": "50250.25",
+            "volume": "0", "quote_volume": null, "trades_count": null, "taker_buy_volume": "1.5"
+        }"#).unwrap();
+        assert_eq!(bar.high, 50500.0);
+        assert_eq!(bar.low, 49800.5);
+        assert_eq!(bar.quote_volume, None);
+        assert_eq!(bar.taker_buy_volume, Some(1.5));
+
+        // Numbers still go out as JSON numbers
+        assert!(serde_json::to_string(&bar).unwrap().contains(r#""open":50000.0"#));
+
+        let malformed = r#"{"symbol": "BTCUSDT", "timeframe": "1h", "timestamp": "2024-03-05T12:00:00Z",
+            "open": "n/a", "high": 1, "low": 1, "close": 1, "volume": 0}"#;
+        assert!(serde_json::from_str::<MarketBar>(malformed).is_err());
+    }
+
     #[test]
     fn test_timeframe_enum_conversion() {
         // Test string conversion
+        assert_eq!(Timeframe::M1.as_str(), "1m");
+        assert_eq!(Timeframe::M5.as_str(), "5m");
         assert_eq!(Timeframe::M15.as_str(), "15m");
         assert_eq!(Timeframe::H1.as_str(), "1h");
         assert_eq!(Timeframe::H4.as_str(), "4h");
         assert_eq!(Timeframe::D1.as_str(), "1d");
 
         // Test from string
+        assert_eq!(Timeframe::from_str("1m"), Some(Timeframe::M1));
+        assert_eq!(Timeframe::from_str("5m"), Some(Timeframe::M5));
         assert_eq!(Timeframe::from_str("15m"), Some(Timeframe::M15));
         assert_eq!(Timeframe::from_str("1h"), Some(Timeframe::H1));
         assert_eq!(Timeframe::from_str("4h"), Some(Timeframe::H4));
         assert_eq!(Timeframe::from_str("1d"), Some(Timeframe::D1));
         assert_eq!(Timeframe::from_str("invalid"), None);
+
+        // Test JSON round trip matches the Python values
+        for timeframe in Timeframe::all() {
+            let json = serde_json::to_string(&timeframe).unwrap();
+            assert_eq!(json, format!("\"{}\"", timeframe.as_str()));
+            assert_eq!(serde_json::from_str::<Timeframe>(&json).unwrap(), timeframe);
+        }
+
+        // Test durations are ordered shortest first
+        assert_eq!(Timeframe::M5.duration(), chrono::Duration::minutes(5));
+        assert_eq!(Timeframe::D1.duration(), chrono::Duration::days(1));
+        assert!(Timeframe::all().windows(2).all(|pair| pair[0].duration() < pair[1].duration()));
+    }
+
+    #[test]
+    fn test_timeframe_ordering_and_navigation() {
+        let mut timeframes = vec![Timeframe::D1, Timeframe::M5, Timeframe::H4, Timeframe::M1, Timeframe::H1, Timeframe::M15];
+        timeframes.sort();
+        assert_eq!(timeframes, Timeframe::all().to_vec());
+        assert!(Timeframe::M15 < Timeframe::H1);
+        assert_eq!(Timeframe::H4.minutes(), 240);
+        assert_eq!(Timeframe::D1.periods_per_year(), 365.0);
+        assert_eq!(Timeframe::H1.periods_per_year(), 8760.0);
+
+        assert_eq!(Timeframe::M1.lower(), None);
+        assert_eq!(Timeframe::M1.higher(), Some(Timeframe::M5));
+        assert_eq!(Timeframe::H1.lower(), Some(Timeframe::M15));
+        assert_eq!(Timeframe::H1.higher(), Some(Timeframe::H4));
+        assert_eq!(Timeframe::D1.higher(), None);
+        assert_eq!(Timeframe::D1.lower(), Some(Timeframe::H4));
     }
 
     #[test]
@@ -334,6 +1265,93 @@ mod tests {
         assert_eq!(partial_fill_pct, 50.0);
     }
 
+    #[test]
+    fn test_symbol_normalization() {
+        let canonical = Symbol::from("BTCUSD");
+        for raw in ["BTC-USD", "btcusd", "BTC/USD", "btc_usd", " BTC:USD "] {
+            assert_eq!(Symbol::from(raw), canonical);
+            assert_eq!(raw.parse::<Symbol>().unwrap(), canonical);
+        }
+        assert_eq!(canonical.as_str(), "BTCUSD");
+        assert_eq!(canonical.base_quote(), Some(("BTC", "USD")));
+        assert_eq!(Symbol::from("eth-usdt").base_quote(), Some(("ETH", "USDT")));
+        assert_eq!(Symbol::from("ETHBTC").quote(), Some("BTC"));
+        assert_eq!(Symbol::from("BTC-PERP").base(), None);
+
+        assert!(Symbol::parse("").is_err());
+        assert!(Symbol::parse("--").is_err());
+        assert!(Symbol::parse("BTC$USD").is_err());
+        assert!(Symbol::parse(&"X".repeat(33)).is_err());
+
+        // Plain strings on the wire, normalized and validated on the way in
+        assert_eq!(serde_json::to_string(&canonical).unwrap(), "\"BTCUSD\"");
+        assert_eq!(serde_json::from_str::<Symbol>("\"btc-usd\"").unwrap(), canonical);
+        assert!(serde_json::from_str::<Symbol>("\"\"").is_err());
+
+        // Model constructors accept any equivalent spelling
+        assert_eq!(OrderDecision::new("signal".to_string(), "btc-usd").symbol, "BTCUSD");
+        assert_eq!(PatternCollection::new(String::from("BTC/USD"), Timeframe::H1).symbol, "BTCUSD");
+
+        // And so do models read off the wire
+        let mut decision = serde_json::to_value(OrderDecision::new("signal".to_string(), "BTCUSD")).unwrap();
+        decision["symbol"] = serde_json::json!("btc/usd");
+        assert_eq!(serde_json::from_value::<OrderDecision>(decision.clone()).unwrap().symbol, "BTCUSD");
+        decision["symbol"] = serde_json::json!("");
+        assert!(serde_json::from_value::<OrderDecision>(decision).is_err());
+        let quote: Quote = serde_json::from_value(serde_json::json!({
+            "symbol": "eth-usdt",
+            "bid": 3000.0,
+            "ask": 3001.0,
+            "bid_size": 2.0,
+            "ask_size": 1.5,
+            "timestamp": "2024-03-05T12:00:00Z",
+        }))
+        .unwrap();
+        assert_eq!(quote.symbol, "ETHUSDT");
+    }
+
+    #[test]
+    fn test_execution_result_total_cost() {
+        let mut result = ExecutionResult::new("decision_123".to_string(), "order_456".to_string());
+        assert_eq!(result.total_cost(), 0.0);
+
+        result.filled_quantity = 2.0;
+        result.average_price = Some(100.0);
+        result.commission = 0.5;
+        assert!((result.total_cost() - 200.5).abs() < 1e-9);
+
+        result.slippage = Some(0.01);
+        assert!((result.total_cost() - 202.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_execution_result_net_value_and_realized_pnl() {
+        let closing_fill = |price: f64| {
+            let mut result = ExecutionResult::new("decision_123".to_string(), "order_456".to_string());
+            result.status = OrderStatus::Filled;
+            result.filled_quantity = 2.0;
+            result.average_price = Some(price);
+            result.commission = 0.5;
+            result
+        };
+
+        // Buying pays out, selling receives; commission comes off both
+        let result = closing_fill(110.0);
+        assert!((result.net_value(Direction::Long) + 220.5).abs() < 1e-9);
+        assert!((result.net_value(Direction::Short) - 219.5).abs() < 1e-9);
+
+        // Long closed above entry: (110 - 100) * 2 - 0.5
+        assert!((closing_fill(110.0).realized_pnl(100.0, Direction::Long) - 19.5).abs() < 1e-9);
+        // Long closed below entry: (95 - 100) * 2 - 0.5
+        assert!((closing_fill(95.0).realized_pnl(100.0, Direction::Long) + 10.5).abs() < 1e-9);
+        // Short bought back below entry: (100 - 95) * 2 - 0.5
+        assert!((closing_fill(95.0).realized_pnl(100.0, Direction::Short) - 9.5).abs() < 1e-9);
+
+        let unfilled = ExecutionResult::new("decision_123".to_string(), "order_456".to_string());
+        assert_eq!(unfilled.net_value(Direction::Long), 0.0);
+        assert_eq!(unfilled.realized_pnl(100.0, Direction::Long), 0.0);
+    }
+
     #[test]
     fn test_order_decision_calculations() {
         let mut decision = OrderDecision::new(
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use crate::trading_models::{OrderType, TimeInForce};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderRequest {
@@ -11,6 +12,13 @@ pub struct OrderRequest {
     pub price: Option<f64>,
     pub order_type: OrderType,
     pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub reduce_only: bool,
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    /// Reject instead of taking liquidity if the limit price would cross the book
+    #[serde(default)]
+    pub post_only: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,11 +26,3 @@ pub enum OrderSide {
     Buy,
     Sell,
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum OrderType {
-    Market,
-    Limit,
-    StopLoss,
-    TakeProfit,
-}
\ No newline at end of file
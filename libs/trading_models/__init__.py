@@ -13,6 +13,7 @@ from .enums import (
     OrderType,
     PatternType,
     Timeframe,
+    TimeInForce,
     TradingAction,
 )
 from .llm_integration import (
@@ -91,6 +92,7 @@ __all__ = [
     "Direction",
     "OrderType",
     "OrderStatus",
+    "TimeInForce",
     "PatternType",
     "MarketRegime",
     "BaseModel",
@@ -6,6 +6,8 @@ from typing import Literal
 
 class Timeframe(str, Enum):
     """Trading timeframes."""
+    M1 = "1m"
+    M5 = "5m"
     M15 = "15m"
     H1 = "1h"
     H4 = "4h"
@@ -33,6 +35,16 @@ class OrderType(str, Enum):
     LIMIT = "limit"
     STOP = "stop"
     STOP_LIMIT = "stop_limit"
+    STOP_LOSS = "stop_loss"
+    TAKE_PROFIT = "take_profit"
+    TRAILING_STOP = "trailing_stop"
+
+
+class TimeInForce(str, Enum):
+    """How long an order stays working; GTD is written as {"GTD": <expiry>}."""
+    GTC = "GTC"
+    IOC = "IOC"
+    FOK = "FOK"
 
 
 class OrderStatus(str, Enum):
@@ -55,6 +67,10 @@ class PatternType(str, Enum):
     ENGULFING = "engulfing"
     DOJI = "doji"
     DIVERGENCE = "divergence"
+    DOUBLE_TOP = "double_top"
+    DOUBLE_BOTTOM = "double_bottom"
+    HEAD_AND_SHOULDERS = "head_and_shoulders"
+    INVERSE_HEAD_AND_SHOULDERS = "inverse_head_and_shoulders"
 
 
 class MarketRegime(str, Enum):
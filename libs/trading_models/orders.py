@@ -2,13 +2,20 @@
 
 from datetime import datetime, timedelta
 from decimal import Decimal
-from typing import Any, Optional
+from typing import Any, Literal, Optional, Union
 from uuid import uuid4
 
 from pydantic import Field, field_validator, model_validator
 
 from .base import BaseModel
-from .enums import Direction, OrderStatus, OrderType, Timeframe
+from .enums import Direction, OrderStatus, OrderType, Timeframe, TimeInForce
+
+
+class PartialRetryPolicy(BaseModel):
+    """Policy for resubmitting the unfilled remainder of an all-or-nothing order."""
+
+    max_attempts: int = Field(..., ge=0, description="Resubmissions allowed for the remainder")
+    delay_ms: int = Field(..., ge=0, description="Wait before each resubmission in milliseconds")
 
 
 class OrderDecision(BaseModel):
@@ -22,6 +29,7 @@ class OrderDecision(BaseModel):
     # Order details
     direction: Direction = Field(..., description="Trade direction")
     order_type: OrderType = Field(..., description="Order type")
+    trail_pct: Optional[float] = Field(None, gt=0, le=20, description="Trailing stop distance in percent")
 
     # Position sizing
     base_quantity: Decimal = Field(..., gt=0, description="Base position size")
@@ -52,6 +60,13 @@ class OrderDecision(BaseModel):
     slippage_tolerance: float = Field(0.001, ge=0, le=0.1, description="Acceptable slippage")
     max_execution_time: int = Field(300, gt=0, description="Max execution time in seconds")
     partial_fill_acceptable: bool = Field(True, description="Accept partial fills")
+    time_in_force: Union[TimeInForce, dict[Literal["GTD"], datetime]] = Field(
+        TimeInForce.GTC, description="GTC, IOC, FOK, or {\"GTD\": expiry}"
+    )
+    post_only: bool = Field(False, description="Maker-only; limit orders only")
+    reduce_only: bool = Field(False, description="Only ever shrinks an open position")
+    partial_retry_policy: Optional[PartialRetryPolicy] = Field(None, description="Remainder resubmission policy")
+    exchange: Optional[str] = Field(None, description="Exchange to route to; None uses the gateway default")
 
     # Decision reasoning
     decision_reason: str = Field(..., description="Human-readable decision reasoning")
@@ -82,6 +97,22 @@ class OrderDecision(BaseModel):
             raise ValueError("Total portfolio risk would exceed 20%")
         return self
 
+    @model_validator(mode='after')
+    def validate_trail_pct(self):
+        """Validate trail percentage is set exactly for trailing stop orders."""
+        if self.order_type == OrderType.TRAILING_STOP and self.trail_pct is None:
+            raise ValueError("Trailing stop orders require a trail percentage")
+        if self.order_type != OrderType.TRAILING_STOP and self.trail_pct is not None:
+            raise ValueError("Trail percentage is only valid for trailing stop orders")
+        return self
+
+    @model_validator(mode='after')
+    def validate_post_only(self):
+        """Validate post-only is only requested on limit orders."""
+        if self.post_only and self.order_type != OrderType.LIMIT:
+            raise ValueError("Post-only is only valid for limit orders")
+        return self
+
     @field_validator("leverage")
     @classmethod
     def validate_leverage(cls, v, info):
@@ -32,6 +32,10 @@ class PatternHit(BaseModel):
     support_levels: list[Decimal] = Field(default_factory=list, description="Support price levels")
     resistance_levels: list[Decimal] = Field(default_factory=list, description="Resistance price levels")
 
+    # Reversal pattern geometry
+    neckline: Optional[Decimal] = Field(None, gt=0, description="Neckline of a reversal pattern")
+    measured_move_target: Optional[Decimal] = Field(None, gt=0, description="Target projected from the pattern height")
+
     # Pattern-specific data
     pattern_data: dict[str, Any] = Field(default_factory=dict, description="Pattern-specific metadata")
 
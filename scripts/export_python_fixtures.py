@@ -0,0 +1,250 @@
+#!/usr/bin/env python3
+"""
+Export Pydantic model fixtures for the Rust compatibility tests.
+
+Writes one JSON file per sample into libs/rust-common/fixtures/python, each
+wrapped in the same schema envelope the Rust side uses. Bump SCHEMA_VERSION
+together with rust_common::trading_models::SCHEMA_VERSION.
+"""
+
+import json
+import sys
+from datetime import datetime, timezone
+from decimal import Decimal
+from pathlib import Path
+
+sys.path.append(str(Path(__file__).parent.parent))
+
+from libs.trading_models.market_data import IndicatorSnapshot, MarketBar
+from libs.trading_models.orders import OrderDecision
+from libs.trading_models.patterns import PatternHit
+from libs.trading_models.signals import LLMAnalysis, Signal, TimeframeAnalysis
+
+SCHEMA_VERSION = "1.0.0"
+FIXTURE_DIR = Path(__file__).parent.parent / "libs" / "rust-common" / "fixtures" / "python"
+
+TIMESTAMP = datetime(2024, 3, 5, 12, 0, tzinfo=timezone.utc)
+
+
+def market_bars():
+    yield "market_bar", MarketBar(
+        symbol="BTCUSDT",
+        timeframe="1h",
+        timestamp=TIMESTAMP,
+        open=Decimal("50000"),
+        high=Decimal("50500"),
+        low=Decimal("49800"),
+        close=Decimal("50250.5"),
+        volume=Decimal("123.45"),
+        quote_volume=Decimal("6200000"),
+        trades_count=1520,
+        taker_buy_volume=Decimal("61.2"),
+    )
+    yield "market_bar_minimal", MarketBar(
+        symbol="ETHUSDT",
+        timeframe="1d",
+        timestamp=TIMESTAMP.replace(hour=0),
+        open=Decimal("3000"),
+        high=Decimal("3100"),
+        low=Decimal("2950"),
+        close=Decimal("3050"),
+        volume=Decimal("0"),
+    )
+
+
+def signals():
+    analysis = TimeframeAnalysis(
+        timeframe="1h",
+        timestamp=TIMESTAMP,
+        trend_score=6.5,
+        momentum_score=4.0,
+        volatility_score=3.2,
+        volume_score=5.5,
+        pattern_count=1,
+        strongest_pattern_confidence=0.8,
+        bullish_indicators=5,
+        bearish_indicators=1,
+        neutral_indicators=2,
+        timeframe_weight=0.4,
+    )
+    pattern = PatternHit(
+        pattern_id="pat-001",
+        pattern_type="breakout",
+        symbol="BTCUSDT",
+        timeframe="1h",
+        timestamp=TIMESTAMP,
+        confidence=0.8,
+        strength=7.5,
+        entry_price=Decimal("50300"),
+        stop_loss=Decimal("49500"),
+        take_profit=Decimal("52000"),
+        support_levels=[Decimal("48800"), Decimal("49500")],
+        resistance_levels=[Decimal("52000")],
+        pattern_data={"range_high": 50200, "retest": True},
+        bars_analyzed=120,
+        lookback_period=50,
+        historical_win_rate=0.62,
+    )
+    indicators = IndicatorSnapshot(
+        symbol="BTCUSDT",
+        timeframe="1h",
+        timestamp=TIMESTAMP,
+        rsi=61.5,
+        ema_20=Decimal("49900.25"),
+        ema_50=Decimal("49200"),
+        macd_line=120.5,
+        macd_signal=98.0,
+        macd_histogram=22.5,
+        atr=Decimal("640.8"),
+    )
+    yield "signal", Signal(
+        signal_id="sig-001",
+        symbol="BTCUSDT",
+        timestamp=TIMESTAMP,
+        direction="long",
+        confluence_score=78.5,
+        confidence=0.82,
+        market_regime="bull",
+        primary_timeframe="1h",
+        timeframe_analysis={"1h": analysis},
+        patterns=[pattern],
+        indicators={"1h": indicators},
+        llm_analysis=LLMAnalysis(
+            model_id="gpt-4o-mini",
+            timestamp=TIMESTAMP,
+            market_sentiment="Constructive above the range high",
+            key_insights=["Breakout retested"],
+            risk_factors=["Funding elevated"],
+            bullish_score=7.0,
+            bearish_score=2.5,
+            confidence=0.7,
+            tokens_used=850,
+            latency_ms=420,
+            cost_usd=0.0012,
+        ),
+        entry_price=Decimal("50300"),
+        stop_loss=Decimal("49500"),
+        take_profit=Decimal("52000"),
+        risk_reward_ratio=2.125,
+        max_risk_pct=1.5,
+        reasoning="Breakout above the 1h range with momentum confirmation",
+        key_factors=["breakout", "rsi_trend"],
+        expires_at=TIMESTAMP.replace(hour=16),
+        priority=2,
+    )
+    yield "signal_minimal", Signal(
+        signal_id="sig-002",
+        symbol="ETHUSDT",
+        timestamp=TIMESTAMP,
+        direction="short",
+        confluence_score=55.0,
+        confidence=0.6,
+        market_regime="sideways",
+        primary_timeframe="4h",
+        reasoning="Rejection at range high",
+    )
+
+
+def order_decisions():
+    yield "order_decision", OrderDecision(
+        decision_id="dec-001",
+        signal_id="sig-001",
+        symbol="BTCUSDT",
+        timestamp=TIMESTAMP,
+        direction="long",
+        order_type="limit",
+        base_quantity=Decimal("0.1"),
+        risk_adjusted_quantity=Decimal("0.08"),
+        max_position_value=Decimal("5000"),
+        entry_price=Decimal("50300"),
+        stop_loss=Decimal("49500"),
+        take_profit=Decimal("52000"),
+        risk_amount=Decimal("64"),
+        risk_percentage=0.64,
+        leverage=2.0,
+        portfolio_value=Decimal("10000"),
+        available_margin=Decimal("7500.5"),
+        current_exposure=0.15,
+        confidence_score=0.82,
+        confluence_score=78.5,
+        risk_reward_ratio=2.125,
+        decision_reason="Breakout entry sized to 0.64% portfolio risk",
+        risk_factors=["Funding elevated"],
+        supporting_factors=["breakout", "rsi_trend"],
+        timeframe_context="1h",
+        market_conditions={"volatility": "normal", "spread_bps": 1.5},
+    )
+    yield "order_decision_trailing_stop", OrderDecision(
+        decision_id="dec-002",
+        signal_id="sig-002",
+        symbol="ETHUSDT",
+        timestamp=TIMESTAMP,
+        direction="short",
+        order_type="trailing_stop",
+        trail_pct=1.5,
+        base_quantity=Decimal("1"),
+        risk_adjusted_quantity=Decimal("1"),
+        max_position_value=Decimal("3100"),
+        entry_price=Decimal("3050"),
+        stop_loss=Decimal("3100"),
+        risk_amount=Decimal("50"),
+        risk_percentage=0.5,
+        portfolio_value=Decimal("10000"),
+        available_margin=Decimal("9000"),
+        confidence_score=0.6,
+        confluence_score=55.0,
+        risk_reward_ratio=1.5,
+        decision_reason="Trail the short from the range high",
+        timeframe_context="4h",
+    )
+    yield "order_decision_execution_options", OrderDecision(
+        decision_id="dec-003",
+        signal_id="sig-003",
+        symbol="SOLUSDT",
+        timestamp=TIMESTAMP,
+        direction="long",
+        order_type="limit",
+        base_quantity=Decimal("10"),
+        risk_adjusted_quantity=Decimal("10"),
+        max_position_value=Decimal("1500"),
+        entry_price=Decimal("145"),
+        stop_loss=Decimal("140"),
+        risk_amount=Decimal("50"),
+        risk_percentage=0.5,
+        portfolio_value=Decimal("10000"),
+        available_margin=Decimal("9000"),
+        confidence_score=0.7,
+        confluence_score=64.0,
+        risk_reward_ratio=2.0,
+        time_in_force={"GTD": TIMESTAMP.replace(hour=18)},
+        post_only=True,
+        partial_retry_policy={"max_attempts": 2, "delay_ms": 500},
+        exchange="binance",
+        decision_reason="Rest a maker bid at support until the session close",
+        timeframe_context="1h",
+    )
+
+
+SCHEMAS = {
+    "market_bar": market_bars,
+    "signal": signals,
+    "order_decision": order_decisions,
+}
+
+
+def main() -> None:
+    FIXTURE_DIR.mkdir(parents=True, exist_ok=True)
+    for schema, samples in SCHEMAS.items():
+        for name, model in samples():
+            envelope = {
+                "schema": schema,
+                "schema_version": SCHEMA_VERSION,
+                "data": json.loads(model.model_dump_json()),
+            }
+            path = FIXTURE_DIR / f"{name}.json"
+            path.write_text(json.dumps(envelope, indent=2) + "\n")
+            print(f"Wrote {path}")
+
+
+if __name__ == "__main__":
+    main()